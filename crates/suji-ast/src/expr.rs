@@ -28,10 +28,11 @@ pub enum Expr {
     /// Postfix decrement: x--
     PostfixDecrement { target: Box<Expr>, span: Span },
 
-    /// Function call: f(a, b, c)
+    /// Function call: f(a, b, c) or f(a, c: 3); named arguments follow positional ones
     Call {
         callee: Box<Expr>,
         args: Vec<Expr>,
+        named_args: Vec<(String, Expr)>,
         span: Span,
     },
 
@@ -77,6 +78,13 @@ pub enum Expr {
         span: Span,
     },
 
+    /// Declaration: let target = value (always binds in the current scope)
+    Let {
+        target: Box<Expr>,
+        value: Box<Expr>,
+        span: Span,
+    },
+
     /// Compound assignment: target += value, target -= value, etc.
     CompoundAssign {
         target: Box<Expr>,
@@ -85,7 +93,7 @@ pub enum Expr {
         span: Span,
     },
 
-    /// Method call: receiver::method(args)
+    /// Method call: receiver::method(args); `span` covers `::method(args)`
     MethodCall {
         target: Box<Expr>,
         method: String,
@@ -131,6 +139,7 @@ impl Expr {
             Expr::Slice { span, .. } => span,
             Expr::MapAccessByName { span, .. } => span,
             Expr::Assign { span, .. } => span,
+            Expr::Let { span, .. } => span,
             Expr::CompoundAssign { span, .. } => span,
             Expr::MethodCall { span, .. } => span,
             Expr::Match { span, .. } => span,
@@ -161,8 +170,15 @@ impl Expr {
             Expr::PostfixIncrement { target, .. } | Expr::PostfixDecrement { target, .. } => {
                 target.has_control_flow()
             }
-            Expr::Call { callee, args, .. } => {
-                callee.has_control_flow() || args.iter().any(|arg| arg.has_control_flow())
+            Expr::Call {
+                callee,
+                args,
+                named_args,
+                ..
+            } => {
+                callee.has_control_flow()
+                    || args.iter().any(|arg| arg.has_control_flow())
+                    || named_args.iter().any(|(_, arg)| arg.has_control_flow())
             }
             Expr::Grouping { expr, .. } => expr.has_control_flow(),
             // FunctionLiteral: return false - control flow inside functions doesn't escape
@@ -178,7 +194,7 @@ impl Expr {
                     || end.as_deref().is_some_and(|e| e.has_control_flow())
             }
             Expr::MapAccessByName { target, .. } => target.has_control_flow(),
-            Expr::Assign { target, value, .. } => {
+            Expr::Assign { target, value, .. } | Expr::Let { target, value, .. } => {
                 target.has_control_flow() || value.has_control_flow()
             }
             Expr::CompoundAssign { target, value, .. } => {
@@ -191,9 +207,9 @@ impl Expr {
             Expr::Destructure { elements, .. } => {
                 elements.iter().any(|elem| elem.has_control_flow())
             }
-            Expr::ShellCommandTemplate { parts, .. } => parts
-                .iter()
-                .any(|part| matches!(part, StringPart::Expr(e) if e.has_control_flow())),
+            Expr::ShellCommandTemplate { parts, .. } => {
+                parts.iter().any(|part| part.has_control_flow())
+            }
             Expr::Literal(lit) => lit.has_control_flow(),
         }
     }
@@ -223,10 +239,14 @@ impl Expr {
 
             // Call: from callee to last arg (or closing paren)
             Expr::Call {
-                callee, args, span, ..
+                callee,
+                args,
+                named_args,
+                span,
             } => {
                 let callee_span = callee.covering_span();
-                if let Some(last_arg) = args.last() {
+                let last_arg = named_args.last().map(|(_, arg)| arg).or(args.last());
+                if let Some(last_arg) = last_arg {
                     let last_span = last_arg.covering_span();
                     combine_three_spans(&callee_span, span, &last_span)
                 } else {
@@ -284,12 +304,18 @@ impl Expr {
                 combine_spans(&target_span, span)
             }
 
-            // Assign: from target through value
+            // Assign/Let: from target through value
             Expr::Assign {
                 target,
                 value,
                 span,
                 ..
+            }
+            | Expr::Let {
+                target,
+                value,
+                span,
+                ..
             } => {
                 let target_span = target.covering_span();
                 let value_span = value.covering_span();
@@ -457,6 +483,7 @@ mod tests {
         let expr = Expr::Call {
             callee: Box::new(callee),
             args: vec![],
+            named_args: vec![],
             span: call_span,
         };
         let cov = expr.covering_span();
@@ -471,6 +498,7 @@ mod tests {
         let expr = Expr::Call {
             callee: Box::new(callee),
             args: vec![arg],
+            named_args: vec![],
             span: call_span,
         };
         let cov = expr.covering_span();
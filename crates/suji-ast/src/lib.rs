@@ -5,6 +5,7 @@ mod loop_bindings;
 mod module;
 mod ops;
 mod pattern;
+mod printer;
 mod stmt;
 mod string_part;
 mod value_like;
@@ -15,7 +16,8 @@ pub use literal::Literal;
 pub use loop_bindings::LoopBindings;
 pub use module::{ExportBody, ExportSpec, ImportSpec};
 pub use ops::{BinaryOp, CompoundOp, UnaryOp};
-pub use pattern::Pattern;
+pub use pattern::{ListRest, Pattern};
+pub use printer::function_to_source;
 pub use stmt::{MatchArm, Stmt};
 pub use string_part::StringPart;
 pub use value_like::ValueLike;
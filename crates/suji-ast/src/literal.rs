@@ -56,9 +56,7 @@ impl Literal {
                 .iter()
                 .any(|(k, v)| k.has_control_flow() || v.has_control_flow()),
             Literal::Tuple(exprs, _) => exprs.iter().any(|e| e.has_control_flow()),
-            Literal::StringTemplate(parts, _) => parts
-                .iter()
-                .any(|part| matches!(part, StringPart::Expr(e) if e.has_control_flow())),
+            Literal::StringTemplate(parts, _) => parts.iter().any(|part| part.has_control_flow()),
             _ => false,
         }
     }
@@ -16,6 +16,14 @@ pub enum ImportSpec {
         name: String,
         alias: String,
     }, // import module:item as alias
+    Items {
+        module: String,
+        names: Vec<String>,
+    }, // import module:{item, other}
+    File {
+        path: String,
+        alias: String,
+    }, // import "path/to/file.json" as alias
 }
 
 /// Export specification - maps names to expressions
@@ -1,4 +1,5 @@
 use super::{Expr, ValueLike};
+use rust_decimal::Decimal;
 use suji_lexer::Span;
 
 /// Pattern nodes for match expressions
@@ -16,11 +17,72 @@ pub enum Pattern {
     /// Wildcard pattern: _
     Wildcard { span: Span },
 
+    /// Range pattern: 1..10, 0..=9 (bounds are numeric literals, parsed once by the parser)
+    Range {
+        start: Decimal,
+        end: Decimal,
+        inclusive: bool,
+        span: Span,
+    },
+
+    /// Type pattern: :number, :string, ... (matches on the value's type name)
+    Type { name: String, span: Span },
+
+    /// Alternation nested inside a tuple pattern: (1 | 2, _) matches if any alternative matches.
+    /// Top-level `a | b =>` alternation is desugared into separate arms by the parser.
+    Or { patterns: Vec<Pattern>, span: Span },
+
+    /// Binding pattern: name @ pattern matches like `pattern` and binds the whole
+    /// matched value to `name` in the arm body
+    Binding {
+        name: String,
+        pattern: Box<Pattern>,
+        span: Span,
+    },
+
+    /// String prefix/suffix pattern: "go " + rest, name + ".txt", "<" + inner + ">".
+    /// Matches strings that start with `prefix` and end with `suffix` without the two
+    /// overlapping, and binds the text between them to `rest` (None for `_`)
+    StringAffix {
+        prefix: String,
+        rest: Option<String>,
+        suffix: String,
+        span: Span,
+    },
+
+    /// List pattern: [first, _, last]. A rest element splits it, `[first, ...middle, last]`:
+    /// then `before` and `after` match the two ends of a list at least that long, and the
+    /// elements between them are bound as a list (`before` holds every element without a rest)
+    List {
+        before: Vec<Pattern>,
+        rest: Option<ListRest>,
+        after: Vec<Pattern>,
+        span: Span,
+    },
+
     /// Expression pattern for conditional match: condition: body
     Expression(Expr),
 }
 
+/// The `...name` (or `..._`) element of a list pattern
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListRest {
+    /// Name bound to the middle elements; None for `..._`
+    pub name: Option<String>,
+    pub span: Span,
+}
+
 impl Pattern {
+    /// Parse the numeric literal of a range bound, e.g. `-5` or `1e3`; None if it does not
+    /// fit a decimal
+    pub fn parse_range_bound(text: &str) -> Option<Decimal> {
+        if text.contains(['e', 'E']) {
+            Decimal::from_scientific(text).ok().map(|d| d.normalize())
+        } else {
+            text.parse().ok()
+        }
+    }
+
     /// Get the span of this pattern
     pub fn span(&self) -> &Span {
         match self {
@@ -28,15 +90,56 @@ impl Pattern {
             Pattern::Tuple { span, .. } => span,
             Pattern::Regex { span, .. } => span,
             Pattern::Wildcard { span, .. } => span,
+            Pattern::Range { span, .. } => span,
+            Pattern::Type { span, .. } => span,
+            Pattern::Or { span, .. } => span,
+            Pattern::Binding { span, .. } => span,
+            Pattern::StringAffix { span, .. } => span,
+            Pattern::List { span, .. } => span,
             Pattern::Expression(expr) => expr.span(),
         }
     }
 
+    /// Names bound by `name @ pattern`, string affix and list rest sub-patterns, in source
+    /// order (duplicates kept)
+    pub fn bound_names(&self) -> Vec<&str> {
+        match self {
+            Pattern::Binding { name, pattern, .. } => {
+                let mut names = vec![name.as_str()];
+                names.extend(pattern.bound_names());
+                names
+            }
+            Pattern::Tuple { patterns, .. } => {
+                patterns.iter().flat_map(|p| p.bound_names()).collect()
+            }
+            Pattern::StringAffix { rest, .. } => rest.iter().map(String::as_str).collect(),
+            Pattern::List {
+                before,
+                rest,
+                after,
+                ..
+            } => {
+                let mut names: Vec<&str> = before.iter().flat_map(|p| p.bound_names()).collect();
+                names.extend(rest.iter().filter_map(|rest| rest.name.as_deref()));
+                names.extend(after.iter().flat_map(|p| p.bound_names()));
+                names
+            }
+            // Alternatives must bind the same names, so the first one speaks for all
+            Pattern::Or { patterns, .. } => patterns
+                .first()
+                .map(|p| p.bound_names())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Check if this pattern is exhaustive (matches everything)
     pub fn is_exhaustive(&self) -> bool {
         match self {
             Pattern::Wildcard { .. } => true,
             Pattern::Tuple { patterns, .. } => patterns.iter().all(|p| p.is_exhaustive()),
+            Pattern::Or { patterns, .. } => patterns.iter().any(|p| p.is_exhaustive()),
+            Pattern::Binding { pattern, .. } => pattern.is_exhaustive(),
             Pattern::Expression(_) => false, // Expression patterns are never exhaustive
             _ => false,
         }
@@ -59,7 +162,18 @@ impl Pattern {
                         .zip(values.iter())
                         .all(|(p, v)| p.can_match_value(v))
             }
-            (Pattern::Regex { .. }, ValueLike::String(_)) => true,
+            (Pattern::Regex { .. } | Pattern::StringAffix { .. }, ValueLike::String(_)) => true,
+            (Pattern::Range { .. }, ValueLike::Number(_)) => true,
+            (Pattern::Or { patterns, .. }, val) => patterns.iter().any(|p| p.can_match_value(val)),
+            (Pattern::Binding { pattern, .. }, val) => pattern.can_match_value(val),
+            (Pattern::Type { name, .. }, val) => matches!(
+                (name.as_str(), val),
+                ("number", ValueLike::Number(_))
+                    | ("boolean", ValueLike::Boolean(_))
+                    | ("string", ValueLike::String(_))
+                    | ("tuple", ValueLike::Tuple(_))
+                    | ("nil", ValueLike::Nil)
+            ),
             (Pattern::Expression(_), _) => true, // Expression patterns can match any value (they evaluate to boolean)
             _ => false,
         }
@@ -0,0 +1,749 @@
+//! Source printer: turns AST nodes back into readable SUJI code.
+//!
+//! The output is not byte-identical to the original text (comments, quoting style and
+//! layout are not kept), but it parses back to an equivalent tree. Parentheses are
+//! added from operator precedence, so synthesized trees without `Grouping` nodes print
+//! correctly too.
+
+use super::{
+    BinaryOp, CompoundOp, ExportBody, Expr, ImportSpec, Literal, LoopBindings, MatchArm, Pattern,
+    Stmt, StringPart, UnaryOp, ValueLike,
+};
+
+const INDENT: &str = "    ";
+
+/// Words the lexer never reads as identifiers
+const KEYWORDS: &[&str] = &[
+    "return", "loop", "as", "through", "with", "continue", "break", "match", "import", "export",
+    "let", "true", "false", "nil",
+];
+
+/// Binding strength of each expression form, loosest first. An operand whose
+/// precedence is below what its position requires is wrapped in parentheses.
+mod prec {
+    pub const LOWEST: u8 = 0;
+    /// Assignments, closures and `return`: they extend as far right as possible
+    pub const ASSIGN: u8 = 1;
+    pub const PIPE_BACKWARD: u8 = 2;
+    pub const PIPE_FORWARD: u8 = 3;
+    pub const PIPE: u8 = 4;
+    pub const COMPOSE: u8 = 5;
+    pub const OR: u8 = 6;
+    pub const AND: u8 = 7;
+    pub const REGEX: u8 = 8;
+    pub const EQUALITY: u8 = 9;
+    pub const COMPARISON: u8 = 10;
+    pub const RANGE: u8 = 11;
+    pub const TERM: u8 = 12;
+    pub const FACTOR: u8 = 13;
+    pub const UNARY: u8 = 14;
+    pub const POWER: u8 = 15;
+    pub const POSTFIX: u8 = 16;
+    pub const ATOM: u8 = 17;
+}
+
+impl Expr {
+    /// Render this expression as SUJI source
+    pub fn to_source(&self) -> String {
+        let mut printer = Printer::default();
+        printer.expr(self, prec::LOWEST);
+        printer.out
+    }
+}
+
+impl Stmt {
+    /// Render this statement as SUJI source; blocks span several lines
+    pub fn to_source(&self) -> String {
+        let mut printer = Printer::default();
+        printer.stmt(self);
+        printer.out
+    }
+}
+
+impl Pattern {
+    /// Render this match pattern as SUJI source
+    pub fn to_source(&self) -> String {
+        let mut printer = Printer::default();
+        printer.pattern(self);
+        printer.out
+    }
+}
+
+/// Render a closure from its parameters (name and optional default) and body:
+/// `|a, b = 1| body`
+pub fn function_to_source<'a>(
+    params: impl IntoIterator<Item = (&'a str, Option<&'a Expr>)>,
+    body: &Stmt,
+) -> String {
+    let mut printer = Printer::default();
+    printer.function(params.into_iter(), body);
+    printer.out
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    indent: usize,
+}
+
+impl Printer {
+    fn push(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Expr(expr) => self.statement_expr(expr),
+            Stmt::Block { statements, .. } => self.block(statements),
+            Stmt::Loop { label, body, .. } => {
+                self.push("loop ");
+                if let Some(label) = label {
+                    self.push(&format!("as {} ", label));
+                }
+                self.stmt(body);
+            }
+            Stmt::LoopThrough {
+                label,
+                iterable,
+                bindings,
+                body,
+                ..
+            } => {
+                self.push("loop through ");
+                self.expr(iterable, prec::LOWEST);
+                match bindings {
+                    LoopBindings::None => {}
+                    LoopBindings::One(name) => self.push(&format!(" with {}", name)),
+                    LoopBindings::Two(key, value) => {
+                        self.push(&format!(" with {}, {}", key, value))
+                    }
+                }
+                if let Some(label) = label {
+                    self.push(&format!(" as {}", label));
+                }
+                self.push(" ");
+                self.stmt(body);
+            }
+            Stmt::Import { spec, .. } => self.import(spec),
+            Stmt::Export { body, .. } => {
+                self.push("export ");
+                match body {
+                    ExportBody::Map(spec) => {
+                        let pairs: Vec<_> = spec
+                            .items
+                            .iter()
+                            .map(|(name, value)| (name.as_str(), value))
+                            .collect();
+                        self.braced_pairs(&pairs);
+                    }
+                    ExportBody::Expr(expr) => self.expr(expr, prec::LOWEST),
+                }
+            }
+        }
+    }
+
+    /// An expression in statement (or closure/arm body) position. A leading `{` would
+    /// be read as a block there, so such expressions are parenthesized.
+    fn statement_expr(&mut self, expr: &Expr) {
+        let mut inner = Printer {
+            out: String::new(),
+            indent: self.indent,
+        };
+        inner.expr(expr, prec::LOWEST);
+        match inner.out.starts_with('{') {
+            true => self.push(&format!("({})", inner.out)),
+            false => self.push(&inner.out),
+        }
+    }
+
+    fn block(&mut self, statements: &[Stmt]) {
+        if statements.is_empty() {
+            self.push("{ }");
+            return;
+        }
+        self.push("{");
+        self.indent += 1;
+        for stmt in statements {
+            let mut inner = Printer {
+                out: String::new(),
+                indent: self.indent,
+            };
+            inner.stmt(stmt);
+            // A line starting with `(` would continue the previous one as a call
+            if inner.out.starts_with('(') && !self.out.ends_with('{') {
+                self.push(";");
+            }
+            self.newline();
+            self.push(&inner.out);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+    }
+
+    fn import(&mut self, spec: &ImportSpec) {
+        let text = match spec {
+            ImportSpec::Module { name } => format!("import {}", name),
+            ImportSpec::Item { module, name } => format!("import {}:{}", module, name),
+            ImportSpec::ItemAs {
+                module,
+                name,
+                alias,
+            } => format!("import {}:{} as {}", module, name, alias),
+            ImportSpec::Items { module, names } => {
+                format!("import {}:{{{}}}", module, names.join(", "))
+            }
+            ImportSpec::File { path, alias } => {
+                format!("import {} as {}", quote(path, '"'), alias)
+            }
+        };
+        self.push(&text);
+    }
+
+    /// `{ name: value, ... }` with one entry per line
+    fn braced_pairs(&mut self, pairs: &[(&str, &Expr)]) {
+        if pairs.is_empty() {
+            self.push("{}");
+            return;
+        }
+        self.push("{");
+        self.indent += 1;
+        for (name, value) in pairs {
+            self.newline();
+            self.push(&format!("{}: ", name));
+            self.expr(value, prec::LOWEST);
+            self.push(",");
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+    }
+
+    /// Print `expr`, parenthesized if it binds more loosely than `min`
+    fn expr(&mut self, expr: &Expr, min: u8) {
+        let wrap = precedence(expr) < min;
+        if wrap {
+            self.push("(");
+        }
+        self.expr_unwrapped(expr);
+        if wrap {
+            self.push(")");
+        }
+    }
+
+    fn expr_unwrapped(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Literal(literal) => self.literal(literal),
+            Expr::Unary { op, expr, .. } => {
+                self.push(match op {
+                    UnaryOp::Negate => "-",
+                    UnaryOp::Not => "!",
+                });
+                self.expr(expr, prec::UNARY);
+            }
+            Expr::Binary {
+                left, op, right, ..
+            } => {
+                let level = binary_precedence(*op);
+                let (left_min, right_min) = match op {
+                    // Right-associative layers
+                    BinaryOp::Power => (prec::POSTFIX, level),
+                    BinaryOp::PipeApplyBwd => (level + 1, level),
+                    _ => (level, level + 1),
+                };
+                self.expr(left, left_min);
+                let op = binary_symbol(*op);
+                match op {
+                    ".." | "..=" | "^" => self.push(op),
+                    _ => self.push(&format!(" {} ", op)),
+                }
+                self.expr(right, right_min);
+            }
+            Expr::PostfixIncrement { target, .. } => {
+                self.expr(target, prec::POSTFIX);
+                self.push("++");
+            }
+            Expr::PostfixDecrement { target, .. } => {
+                self.expr(target, prec::POSTFIX);
+                self.push("--");
+            }
+            Expr::Call {
+                callee,
+                args,
+                named_args,
+                ..
+            } => {
+                self.expr(callee, prec::POSTFIX);
+                self.push("(");
+                self.comma_separated(args);
+                for (i, (name, arg)) in named_args.iter().enumerate() {
+                    if i > 0 || !args.is_empty() {
+                        self.push(", ");
+                    }
+                    self.push(name);
+                    self.push(": ");
+                    self.expr(arg, prec::LOWEST);
+                }
+                self.push(")");
+            }
+            Expr::Grouping { expr, .. } => {
+                self.push("(");
+                self.expr(expr, prec::LOWEST);
+                self.push(")");
+            }
+            Expr::FunctionLiteral { params, body, .. } => self.function(
+                params.iter().map(|p| (p.name.as_str(), p.default.as_ref())),
+                body,
+            ),
+            Expr::ShellCommandTemplate { parts, .. } => self.template(parts, '`'),
+            Expr::Index { target, index, .. } => {
+                self.expr(target, prec::POSTFIX);
+                self.push("[");
+                self.expr(index, prec::LOWEST);
+                self.push("]");
+            }
+            Expr::Slice {
+                target, start, end, ..
+            } => {
+                self.expr(target, prec::POSTFIX);
+                self.push("[");
+                if let Some(start) = start {
+                    self.expr(start, prec::LOWEST);
+                }
+                self.push(":");
+                if let Some(end) = end {
+                    self.expr(end, prec::LOWEST);
+                }
+                self.push("]");
+            }
+            Expr::MapAccessByName { target, key, .. } => {
+                self.expr(target, prec::POSTFIX);
+                self.push(&format!(":{}", key));
+            }
+            Expr::Assign { target, value, .. } => {
+                self.expr(target, prec::PIPE_BACKWARD);
+                self.push(" = ");
+                self.expr(value, prec::ASSIGN);
+            }
+            Expr::Let { target, value, .. } => {
+                self.push("let ");
+                self.expr(target, prec::PIPE_BACKWARD);
+                self.push(" = ");
+                self.expr(value, prec::ASSIGN);
+            }
+            Expr::CompoundAssign {
+                target, op, value, ..
+            } => {
+                self.expr(target, prec::PIPE_BACKWARD);
+                self.push(match op {
+                    CompoundOp::PlusAssign => " += ",
+                    CompoundOp::MinusAssign => " -= ",
+                    CompoundOp::MultiplyAssign => " *= ",
+                    CompoundOp::DivideAssign => " /= ",
+                    CompoundOp::ModuloAssign => " %= ",
+                });
+                self.expr(value, prec::ASSIGN);
+            }
+            Expr::MethodCall {
+                target,
+                method,
+                args,
+                ..
+            } => {
+                self.expr(target, prec::POSTFIX);
+                self.push(&format!("::{}", method));
+                self.args(args);
+            }
+            Expr::Match {
+                scrutinee, arms, ..
+            } => self.match_expr(scrutinee.as_deref(), arms),
+            Expr::Destructure { elements, .. } => {
+                self.push("(");
+                self.comma_separated(elements);
+                self.push(")");
+            }
+            Expr::Return { values, .. } => {
+                self.push("return");
+                if !values.is_empty() {
+                    self.push(" ");
+                    self.comma_separated(values);
+                }
+            }
+            Expr::Break { label, .. } => self.jump("break", label.as_deref()),
+            Expr::Continue { label, .. } => self.jump("continue", label.as_deref()),
+        }
+    }
+
+    fn jump(&mut self, keyword: &str, label: Option<&str>) {
+        self.push(keyword);
+        if let Some(label) = label {
+            self.push(&format!(" {}", label));
+        }
+    }
+
+    fn args(&mut self, args: &[Expr]) {
+        self.push("(");
+        self.comma_separated(args);
+        self.push(")");
+    }
+
+    fn comma_separated(&mut self, exprs: &[Expr]) {
+        for (i, expr) in exprs.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.expr(expr, prec::LOWEST);
+        }
+    }
+
+    fn function<'a>(
+        &mut self,
+        params: impl Iterator<Item = (&'a str, Option<&'a Expr>)>,
+        body: &Stmt,
+    ) {
+        self.push("|");
+        for (i, (name, default)) in params.enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.push(name);
+            if let Some(default) = default {
+                self.push(" = ");
+                // Defaults stop before pipe operators, which would close the parameter list
+                self.expr(default, prec::OR);
+            }
+        }
+        self.push("| ");
+        self.stmt(body);
+    }
+
+    fn match_expr(&mut self, scrutinee: Option<&Expr>, arms: &[MatchArm]) {
+        self.push("match ");
+        if let Some(scrutinee) = scrutinee {
+            self.expr(scrutinee, prec::LOWEST);
+            self.push(" ");
+        }
+        self.push("{");
+        self.indent += 1;
+        for arm in arms {
+            self.newline();
+            match &arm.pattern {
+                Pattern::Expression(condition) => self.expr(condition, prec::LOWEST),
+                pattern => self.pattern(pattern),
+            }
+            self.push(" => ");
+            self.stmt(&arm.body);
+            self.push(",");
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Number(n, _) => self.push(n),
+            Literal::Boolean(b, _) => self.push(&b.to_string()),
+            Literal::Identifier(name, _) => self.push(name),
+            Literal::StringTemplate(parts, _) => self.template(parts, '"'),
+            Literal::List(items, _) => {
+                self.push("[");
+                self.comma_separated(items);
+                self.push("]");
+            }
+            Literal::Map(pairs, _) => {
+                if pairs.is_empty() {
+                    self.push("{}");
+                    return;
+                }
+                self.push("{ ");
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        self.push(", ");
+                    }
+                    self.map_key(key);
+                    self.push(": ");
+                    self.expr(value, prec::LOWEST);
+                }
+                self.push(" }");
+            }
+            Literal::Tuple(items, _) => {
+                self.push("(");
+                self.comma_separated(items);
+                if items.len() == 1 {
+                    self.push(",");
+                }
+                self.push(")");
+            }
+            Literal::RegexLiteral(pattern, _) => self.push(&format!("/{}/", pattern)),
+            Literal::Nil(_) => self.push("nil"),
+        }
+    }
+
+    /// Map literal keys: plain names stay bare, and anything that could be confused
+    /// with postfix syntax is parenthesized
+    fn map_key(&mut self, key: &Expr) {
+        match key {
+            Expr::Literal(Literal::StringTemplate(parts, _)) => match parts.as_slice() {
+                [StringPart::Text(name)] if is_identifier(name) => self.push(name),
+                _ => self.template(parts, '"'),
+            },
+            Expr::Literal(
+                Literal::Number(..) | Literal::Boolean(..) | Literal::Nil(_) | Literal::Tuple(..),
+            )
+            | Expr::Grouping { .. } => self.expr(key, prec::LOWEST),
+            _ => {
+                self.push("(");
+                self.expr(key, prec::LOWEST);
+                self.push(")");
+            }
+        }
+    }
+
+    fn template(&mut self, parts: &[StringPart], delimiter: char) {
+        self.out.push(delimiter);
+        for part in parts {
+            match part {
+                StringPart::Text(text) => {
+                    let quoted = quote(text, delimiter);
+                    self.push(&quoted[1..quoted.len() - 1]);
+                }
+                StringPart::Expr(expr) => {
+                    self.push("${");
+                    self.expr(expr, prec::LOWEST);
+                    self.push("}");
+                }
+                StringPart::Formatted(expr, spec) => {
+                    self.push("${");
+                    self.expr(expr, prec::LOWEST);
+                    self.push(&format!(":{}}}", spec));
+                }
+            }
+        }
+        self.out.push(delimiter);
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Literal { value, .. } => self.value_like(value),
+            Pattern::Tuple { patterns, .. } => {
+                self.push("(");
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        self.push(", ");
+                    }
+                    self.pattern(pattern);
+                }
+                if patterns.len() == 1 {
+                    self.push(",");
+                }
+                self.push(")");
+            }
+            Pattern::Regex { pattern, .. } => self.push(&format!("/{}/", pattern)),
+            Pattern::Wildcard { .. } => self.push("_"),
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+                ..
+            } => {
+                let op = if *inclusive { "..=" } else { ".." };
+                self.push(&format!("{}{}{}", start, op, end));
+            }
+            Pattern::Type { name, .. } => self.push(&format!(":{}", name)),
+            Pattern::Or { patterns, .. } => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        self.push(" | ");
+                    }
+                    self.pattern(pattern);
+                }
+            }
+            Pattern::Binding { name, pattern, .. } => {
+                self.push(&format!("{} @ ", name));
+                self.pattern(pattern);
+            }
+            Pattern::StringAffix {
+                prefix,
+                rest,
+                suffix,
+                ..
+            } => {
+                let mut pieces = Vec::new();
+                if !prefix.is_empty() {
+                    pieces.push(quote(prefix, '"'));
+                }
+                pieces.push(rest.clone().unwrap_or_else(|| "_".to_string()));
+                if !suffix.is_empty() || prefix.is_empty() {
+                    pieces.push(quote(suffix, '"'));
+                }
+                self.push(&pieces.join(" + "));
+            }
+            Pattern::List {
+                before,
+                rest,
+                after,
+                ..
+            } => {
+                self.push("[");
+                let mut first = true;
+                let mut separate = |printer: &mut Self| {
+                    if !std::mem::take(&mut first) {
+                        printer.push(", ");
+                    }
+                };
+                for pattern in before {
+                    separate(self);
+                    self.pattern(pattern);
+                }
+                if let Some(rest) = rest {
+                    separate(self);
+                    self.push(&format!("...{}", rest.name.as_deref().unwrap_or("_")));
+                }
+                for pattern in after {
+                    separate(self);
+                    self.pattern(pattern);
+                }
+                self.push("]");
+            }
+            Pattern::Expression(expr) => self.expr(expr, prec::LOWEST),
+        }
+    }
+
+    fn value_like(&mut self, value: &ValueLike) {
+        match value {
+            ValueLike::Number(n) => self.push(n),
+            ValueLike::Boolean(b) => self.push(&b.to_string()),
+            ValueLike::String(s) => self.push(&quote(s, '"')),
+            ValueLike::Tuple(items) => {
+                self.push("(");
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.push(", ");
+                    }
+                    self.value_like(item);
+                }
+                if items.len() == 1 {
+                    self.push(",");
+                }
+                self.push(")");
+            }
+            ValueLike::Nil => self.push("nil"),
+        }
+    }
+}
+
+fn precedence(expr: &Expr) -> u8 {
+    match expr {
+        Expr::Assign { .. }
+        | Expr::Let { .. }
+        | Expr::CompoundAssign { .. }
+        | Expr::FunctionLiteral { .. }
+        | Expr::Return { .. } => prec::ASSIGN,
+        Expr::Binary { op, .. } => binary_precedence(*op),
+        Expr::Unary { .. } => prec::UNARY,
+        Expr::PostfixIncrement { .. }
+        | Expr::PostfixDecrement { .. }
+        | Expr::Call { .. }
+        | Expr::Index { .. }
+        | Expr::Slice { .. }
+        | Expr::MapAccessByName { .. }
+        | Expr::MethodCall { .. } => prec::POSTFIX,
+        Expr::Literal(_)
+        | Expr::Grouping { .. }
+        | Expr::ShellCommandTemplate { .. }
+        | Expr::Match { .. }
+        | Expr::Destructure { .. }
+        | Expr::Break { .. }
+        | Expr::Continue { .. } => prec::ATOM,
+    }
+}
+
+fn binary_precedence(op: BinaryOp) -> u8 {
+    match op {
+        BinaryOp::PipeApplyBwd => prec::PIPE_BACKWARD,
+        BinaryOp::PipeApplyFwd => prec::PIPE_FORWARD,
+        BinaryOp::Pipe => prec::PIPE,
+        BinaryOp::ComposeRight | BinaryOp::ComposeLeft => prec::COMPOSE,
+        BinaryOp::Or => prec::OR,
+        BinaryOp::And => prec::AND,
+        BinaryOp::RegexMatch | BinaryOp::RegexNotMatch => prec::REGEX,
+        BinaryOp::Equal | BinaryOp::NotEqual => prec::EQUALITY,
+        BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual => {
+            prec::COMPARISON
+        }
+        BinaryOp::Range | BinaryOp::RangeInclusive => prec::RANGE,
+        BinaryOp::Add | BinaryOp::Subtract => prec::TERM,
+        BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Modulo => prec::FACTOR,
+        BinaryOp::Power => prec::POWER,
+    }
+}
+
+fn binary_symbol(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "+",
+        BinaryOp::Subtract => "-",
+        BinaryOp::Multiply => "*",
+        BinaryOp::Divide => "/",
+        BinaryOp::Modulo => "%",
+        BinaryOp::Power => "^",
+        BinaryOp::Equal => "==",
+        BinaryOp::NotEqual => "!=",
+        BinaryOp::Less => "<",
+        BinaryOp::LessEqual => "<=",
+        BinaryOp::Greater => ">",
+        BinaryOp::GreaterEqual => ">=",
+        BinaryOp::And => "&&",
+        BinaryOp::Or => "||",
+        BinaryOp::Pipe => "|",
+        BinaryOp::PipeApplyFwd => "|>",
+        BinaryOp::PipeApplyBwd => "<|",
+        BinaryOp::ComposeRight => ">>",
+        BinaryOp::ComposeLeft => "<<",
+        BinaryOp::Range => "..",
+        BinaryOp::RangeInclusive => "..=",
+        BinaryOp::RegexMatch => "~",
+        BinaryOp::RegexNotMatch => "!~",
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    text != "_"
+        && !KEYWORDS.contains(&text)
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Quote text as a string (or shell command) literal, escaping what the lexer would
+/// otherwise interpret
+fn quote(text: &str, delimiter: char) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push(delimiter);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '$' if chars.peek() == Some(&'{') => out.push_str("\\$"),
+            c if c == delimiter => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push(delimiter);
+    out
+}
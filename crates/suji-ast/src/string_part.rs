@@ -1,10 +1,13 @@
 use super::Expr;
+use suji_lexer::FormatSpec;
 
 /// Parts of a string template (for interpolation)
 #[derive(Debug, Clone, PartialEq)]
 pub enum StringPart {
     Text(String),
     Expr(Expr),
+    /// Interpolation with a format spec: ${expr:spec}
+    Formatted(Expr, FormatSpec),
 }
 
 impl StringPart {
@@ -12,7 +15,7 @@ impl StringPart {
     pub fn has_control_flow(&self) -> bool {
         match self {
             StringPart::Text(_) => false,
-            StringPart::Expr(expr) => expr.has_control_flow(),
+            StringPart::Expr(expr) | StringPart::Formatted(expr, _) => expr.has_control_flow(),
         }
     }
 }
@@ -2,40 +2,44 @@ use std::env;
 use std::fs;
 use std::process;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use suji_diagnostics::{DiagnosticContext, DiagnosticKind, print_diagnostic};
 use suji_interpreter::{AstInterpreter, eval_module_source_callback};
 use suji_repl::Repl;
-use suji_runtime::{Executor, ModuleRegistry};
+use suji_runtime::ModuleRegistry;
 use suji_stdlib::{setup_global_env, setup_module_registry};
-use suji_values::Env;
+use suji_values::{Env, IoContext};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    match args.len() {
-        1 => {
+    // Optional leading --time flag reports parse/eval durations on stderr
+    let time = args.get(1).is_some_and(|arg| arg == "--time");
+    let rest = if time { &args[2..] } else { &args[1..] };
+
+    match rest {
+        [] if !time => {
             // No arguments - start REPL
             if let Err(e) = run_repl() {
                 eprintln!("REPL error: {}", e);
                 process::exit(1);
             }
         }
-        2 => {
+        [filename] => {
             // One argument - execute file
-            let filename = &args[1];
-            if let Err(e) = run_file(filename) {
+            if let Err(e) = run_file(filename, time) {
                 eprintln!("Error: {}", e);
                 process::exit(1);
             }
         }
         _ => {
-            eprintln!("Usage: {} <file.si>", args[0]);
+            eprintln!("Usage: {} [--time] <file.si>", args[0]);
             process::exit(1);
         }
     }
 }
 
-fn run_file(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn run_file(filename: &str, time: bool) -> Result<(), Box<dyn std::error::Error>> {
     // Read the file
     let source = fs::read_to_string(filename)?;
 
@@ -54,8 +58,27 @@ fn run_file(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     // Create interpreter instance
     let interpreter = AstInterpreter;
 
-    // Evaluate the source using the interpreter
-    if let Err(e) = interpreter.eval_source(&source, env.clone(), &module_registry, false) {
+    // Lex/parse and evaluate as separate phases so each can be timed
+    let parse_start = Instant::now();
+    let parsed = AstInterpreter::parse_source(&source);
+    let parse_time = parse_start.elapsed();
+
+    let mut eval_time = Duration::ZERO;
+    let result = parsed.and_then(|statements| {
+        let eval_start = Instant::now();
+        let result = interpreter.eval_statements(&statements, env.clone(), &module_registry, false);
+        eval_time = eval_start.elapsed();
+        result
+    });
+
+    if time {
+        print_timings(parse_time, eval_time);
+    }
+
+    // process::exit skips destructors, so write out unclosed buffered streams first
+    IoContext::flush_buffered_streams();
+
+    if let Err(e) = result {
         let context = DiagnosticContext::from_file(filename)?;
         if print_diagnostic(DiagnosticKind::Runtime(e.clone()), &context).is_err() {
             eprintln!("Error: {}", e);
@@ -66,6 +89,18 @@ fn run_file(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     process::exit(0)
 }
 
+/// Print phase durations to stderr as a single `key=value` line in milliseconds.
+/// `eval_ms` is 0.000 when parsing failed and evaluation never ran.
+fn print_timings(parse_time: Duration, eval_time: Duration) {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    eprintln!(
+        "suji-time parse_ms={:.3} eval_ms={:.3} total_ms={:.3}",
+        ms(parse_time),
+        ms(eval_time),
+        ms(parse_time + eval_time)
+    );
+}
+
 fn run_repl() -> Result<(), Box<dyn std::error::Error>> {
     let mut repl = Repl::new()?;
     repl.run()?;
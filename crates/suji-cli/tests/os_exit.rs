@@ -0,0 +1,33 @@
+use std::process::Command;
+
+#[test]
+fn test_os_exit_flushes_buffered_streams() {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("exit.si");
+    std::fs::write(
+        &script,
+        r#"
+import std:io
+import std:os
+
+let block = io:open("block.txt", { mode: "w", buffering: "block" })
+block::write("first\n")
+block::write("second\n")
+let line = io:open("line.txt", { mode: "w", buffering: "line" })
+line::write("no newline yet")
+os:exit(3)
+"#,
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_suji"))
+        .arg(&script)
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+
+    assert_eq!(status.code(), Some(3));
+    let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).unwrap();
+    assert_eq!(read("block.txt"), "first\nsecond\n");
+    assert_eq!(read("line.txt"), "no newline yet");
+}
@@ -0,0 +1,66 @@
+#![cfg(unix)]
+
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Append everything the REPL has printed so far to `output`
+fn drain(output: &mut String, chunks: &mpsc::Receiver<String>) {
+    while let Ok(chunk) = chunks.try_recv() {
+        output.push_str(&chunk);
+    }
+}
+
+#[test]
+fn test_repl_keeps_running_after_interrupt() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_suji"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    let (sender, chunks) = mpsc::channel();
+    let reader = std::thread::spawn(move || {
+        let mut buffer = [0; 1024];
+        while let Ok(n @ 1..) = stdout.read(&mut buffer) {
+            sender
+                .send(String::from_utf8_lossy(&buffer[..n]).into_owned())
+                .ok();
+        }
+    });
+
+    // Wait for the banner, so the Ctrl+C handler is installed before the first signal
+    let mut output = String::new();
+    while output.is_empty() {
+        output.push_str(&chunks.recv_timeout(Duration::from_secs(10)).unwrap());
+    }
+    stdin.write_all(b"let n = 41\nloop {}\n").unwrap();
+    stdin.flush().unwrap();
+
+    // A signal that lands before the loop starts is dropped, so keep sending until it stops
+    for _ in 0..50 {
+        std::thread::sleep(Duration::from_millis(100));
+        Command::new("kill")
+            .args(["-INT", &child.id().to_string()])
+            .status()
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(100));
+        drain(&mut output, &chunks);
+        if output.contains("Interrupted") {
+            break;
+        }
+    }
+    assert!(output.contains("Interrupted"), "{}", output);
+
+    stdin.write_all(b"n + 1\n:quit\n").unwrap();
+    drop(stdin);
+    assert!(child.wait().unwrap().success());
+    reader.join().unwrap();
+    drain(&mut output, &chunks);
+
+    let after = &output[output.find("Interrupted").unwrap()..];
+    assert!(after.contains("42"), "{}", output);
+}
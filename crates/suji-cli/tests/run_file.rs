@@ -0,0 +1,47 @@
+use std::process::Command;
+
+fn run_script(source: &str) -> (tempfile::TempDir, Option<i32>) {
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("main.si");
+    std::fs::write(&script, source).unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_suji"))
+        .arg(&script)
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    (dir, status.code())
+}
+
+#[test]
+fn test_normal_end_flushes_unclosed_streams() {
+    let (dir, code) = run_script(
+        r#"
+import std:io
+
+let out = io:open("out.txt", { mode: "w", buffering: "block" })
+out::write("kept\n")
+"#,
+    );
+
+    assert_eq!(code, Some(0));
+    let contents = std::fs::read_to_string(dir.path().join("out.txt")).unwrap();
+    assert_eq!(contents, "kept\n");
+}
+
+#[test]
+fn test_runtime_error_flushes_unclosed_streams() {
+    let (dir, code) = run_script(
+        r#"
+import std:io
+
+let out = io:open("out.txt", { mode: "w", buffering: "block" })
+out::write("before the error\n")
+undefined_name
+"#,
+    );
+
+    assert_eq!(code, Some(1));
+    let contents = std::fs::read_to_string(dir.path().join("out.txt")).unwrap();
+    assert_eq!(contents, "before the error\n");
+}
@@ -33,6 +33,10 @@ pub fn print_lex_error(
             let template = lexer_errors::unexpected_character(ch);
             ErrorBuilder::new(template, context.clone()).print_with_span(span)?;
         }
+        LexError::InvalidFormatSpec { spec, span } => {
+            let template = lexer_errors::invalid_format_spec(&spec);
+            ErrorBuilder::new(template, context.clone()).print_with_span(span)?;
+        }
     }
     Ok(())
 }
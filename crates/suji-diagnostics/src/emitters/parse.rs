@@ -49,6 +49,28 @@ pub fn print_parse_error(
             ErrorBuilder::new(parser_errors::invalid_alias(), context.clone())
                 .print_with_span(span)?;
         }
+        ParseError::DuplicateCatchAll { message, span } => {
+            ErrorBuilder::new(
+                parser_errors::duplicate_catch_all(&message),
+                context.clone(),
+            )
+            .print_with_span(span)?;
+        }
+        ParseError::PositionalAfterNamed { name, span } => {
+            ErrorBuilder::new(
+                parser_errors::positional_after_named(&name),
+                context.clone(),
+            )
+            .print_with_span(span)?;
+        }
+        ParseError::NamedArgumentInMethodCall { name, span } => {
+            ErrorBuilder::new(parser_errors::named_method_argument(&name), context.clone())
+                .print_with_span(span)?;
+        }
+        ParseError::SpacedMapAccess { span } => {
+            ErrorBuilder::new(parser_errors::spaced_map_access(), context.clone())
+                .print_with_span(span)?;
+        }
     }
     Ok(())
 }
@@ -12,6 +12,7 @@ pub const LEX_UNTERMINATED_REGEX: u32 = 103;
 pub const LEX_INVALID_ESCAPE: u32 = 104;
 pub const LEX_INVALID_NUMBER: u32 = 105;
 pub const LEX_UNEXPECTED_CHARACTER: u32 = 106;
+pub const LEX_INVALID_FORMAT_SPEC: u32 = 107;
 
 // Parser (PARSE_2XX)
 pub const PARSE_UNEXPECTED_TOKEN: u32 = 201;
@@ -21,6 +22,10 @@ pub const PARSE_MULTIPLE_EXPORTS: u32 = 204;
 pub const PARSE_EXPECTED_TOKEN: u32 = 205;
 pub const PARSE_INVALID_IMPORT_PATH: u32 = 206;
 pub const PARSE_INVALID_ALIAS: u32 = 207;
+pub const PARSE_DUPLICATE_CATCH_ALL: u32 = 208;
+pub const PARSE_POSITIONAL_AFTER_NAMED: u32 = 209;
+pub const PARSE_NAMED_METHOD_ARGUMENT: u32 = 210;
+pub const PARSE_SPACED_MAP_ACCESS: u32 = 211;
 
 // Runtime (RUNTIME_3XX)
 pub const RUNTIME_TYPE_ERROR: u32 = 300;
@@ -60,6 +65,7 @@ pub const RUNTIME_PIPE_APPLY_LEFT_TYPE_ERROR: u32 = 333;
 pub const RUNTIME_DESTRUCTURE_TYPE_ERROR: u32 = 334;
 pub const RUNTIME_DESTRUCTURE_ARITY_MISMATCH: u32 = 335;
 pub const RUNTIME_DESTRUCTURE_INVALID_TARGET: u32 = 336;
+pub const RUNTIME_INTERRUPTED: u32 = 337;
 
 #[cfg(test)]
 mod tests {
@@ -75,6 +81,7 @@ mod tests {
             LEX_INVALID_ESCAPE,
             LEX_INVALID_NUMBER,
             LEX_UNEXPECTED_CHARACTER,
+            LEX_INVALID_FORMAT_SPEC,
         ];
 
         let mut set = HashSet::new();
@@ -95,6 +102,13 @@ mod tests {
             PARSE_UNEXPECTED_EOF,
             PARSE_GENERIC_ERROR,
             PARSE_MULTIPLE_EXPORTS,
+            PARSE_EXPECTED_TOKEN,
+            PARSE_INVALID_IMPORT_PATH,
+            PARSE_INVALID_ALIAS,
+            PARSE_DUPLICATE_CATCH_ALL,
+            PARSE_POSITIONAL_AFTER_NAMED,
+            PARSE_NAMED_METHOD_ARGUMENT,
+            PARSE_SPACED_MAP_ACCESS,
         ];
 
         let mut set = HashSet::new();
@@ -148,6 +162,7 @@ mod tests {
             RUNTIME_DESTRUCTURE_TYPE_ERROR,
             RUNTIME_DESTRUCTURE_ARITY_MISMATCH,
             RUNTIME_DESTRUCTURE_INVALID_TARGET,
+            RUNTIME_INTERRUPTED,
         ];
 
         let mut set = HashSet::new();
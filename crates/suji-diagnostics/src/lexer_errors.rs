@@ -51,7 +51,7 @@ pub fn invalid_number(literal: &str) -> ErrorTemplate {
         "Invalid number literal",
         &format!("Invalid number literal '{}'", literal),
     )
-    .with_suggestion("Numbers can be integers (42) or decimals (3.14)")
+    .with_suggestion("Numbers can be integers (42), decimals (3.14) or use an exponent (1.5e-3)")
 }
 
 pub fn unexpected_character(ch: char) -> ErrorTemplate {
@@ -76,3 +76,12 @@ pub fn unexpected_character(ch: char) -> ErrorTemplate {
     )
     .with_suggestion(suggestion)
 }
+
+pub fn invalid_format_spec(spec: &str) -> ErrorTemplate {
+    ErrorTemplate::new(
+        LEX_INVALID_FORMAT_SPEC,
+        "Invalid format spec",
+        &format!("Invalid format spec '{}'", spec),
+    )
+    .with_suggestion("Format specs look like ${value:.2}, ${value:>8} or ${value:*^+010.3}")
+}
@@ -105,3 +105,50 @@ pub fn invalid_alias() -> ErrorTemplate {
         "Expected alias name after 'as'",
     )
 }
+
+pub fn duplicate_catch_all(message: &str) -> ErrorTemplate {
+    let message = format!("Duplicate catch-all arms in match: {}", message);
+    ErrorTemplate::new(
+        PARSE_DUPLICATE_CATCH_ALL,
+        "Duplicate catch-all arm",
+        &message,
+    )
+    .with_suggestion("Keep a single catch-all arm, `_ =>` or `else =>`, as the last arm")
+}
+
+pub fn positional_after_named(name: &str) -> ErrorTemplate {
+    let message = format!(
+        "Positional arguments must come before named ones (found one after '{}: ...')",
+        name
+    );
+    ErrorTemplate::new(
+        PARSE_POSITIONAL_AFTER_NAMED,
+        "Positional argument after named argument",
+        &message,
+    )
+    .with_suggestion("Move the positional arguments ahead of the `name: value` ones")
+}
+
+pub fn named_method_argument(name: &str) -> ErrorTemplate {
+    let message = format!(
+        "Methods only take positional arguments (found '{}: ...')",
+        name
+    );
+    ErrorTemplate::new(
+        PARSE_NAMED_METHOD_ARGUMENT,
+        "Named argument in method call",
+        &message,
+    )
+    .with_suggestion(
+        "Pass the value by position, or write `name:key` without a space for map access",
+    )
+}
+
+pub fn spaced_map_access() -> ErrorTemplate {
+    ErrorTemplate::new(
+        PARSE_SPACED_MAP_ACCESS,
+        "Space in map access",
+        "Map access is written without a space after ':'",
+    )
+    .with_suggestion("Write `map:key`; `name: value` is a named argument inside a call")
+}
@@ -116,6 +116,7 @@ fn error_code_for_variant(error: &RuntimeError) -> u32 {
         RuntimeError::DestructureTypeError => RUNTIME_DESTRUCTURE_TYPE_ERROR,
         RuntimeError::DestructureArityMismatch { .. } => RUNTIME_DESTRUCTURE_ARITY_MISMATCH,
         RuntimeError::DestructureInvalidTarget { .. } => RUNTIME_DESTRUCTURE_INVALID_TARGET,
+        RuntimeError::Interrupted => RUNTIME_INTERRUPTED,
         RuntimeError::Parse(_) => PARSE_GENERIC_ERROR,
         // WithSpan wraps another error, unwrap and recurse
         RuntimeError::WithSpan { error, .. } => error_code_for_variant(error),
@@ -271,6 +272,13 @@ impl RuntimeErrorExt for RuntimeError {
                     format!("Expected {} values but got {}", expected, actual),
                 ).with_suggestions(generate_category_suggestions(ErrorCategory::Execution, self))
             }
+            RuntimeError::Interrupted => {
+                ErrorContext::new(
+                    error_code,
+                    "Interrupted",
+                    "Evaluation was interrupted".to_string(),
+                )
+            }
             RuntimeError::DestructureInvalidTarget { message } => {
                 ErrorContext::new(
                     error_code,
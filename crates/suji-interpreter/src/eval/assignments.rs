@@ -16,6 +16,54 @@ pub fn eval_assignment(
     eval_complex_assignment(target, rhs_value, env, registry)
 }
 
+/// Evaluate a `let` declaration, binding in the current scope (shadowing any outer binding)
+pub fn eval_let(
+    target: &Expr,
+    value: &Expr,
+    env: Rc<Env>,
+    registry: Option<&ModuleRegistry>,
+) -> EvalResult<Value> {
+    let rhs_value = eval_expr(value, env.clone(), registry)?;
+    match target {
+        Expr::Literal(Literal::Identifier(name, _)) => {
+            env.define(name, rhs_value.clone())?;
+            Ok(rhs_value)
+        }
+        Expr::Destructure { elements, .. } => match &rhs_value {
+            Value::Tuple(items) | Value::List(items) => {
+                if items.len() != elements.len() {
+                    return Err(RuntimeError::DestructureArityMismatch {
+                        expected: elements.len(),
+                        actual: items.len(),
+                    });
+                }
+
+                for (element_expr, element_value) in elements.iter().zip(items.iter()) {
+                    match element_expr {
+                        Expr::Literal(Literal::Identifier(name, _)) if name == "_" => {}
+                        Expr::Literal(Literal::Identifier(name, _)) => {
+                            env.define(name, element_value.clone())?;
+                        }
+                        _ => {
+                            return Err(RuntimeError::DestructureInvalidTarget {
+                                message: "Only identifiers or '_' are allowed".to_string(),
+                            });
+                        }
+                    }
+                }
+
+                Ok(rhs_value)
+            }
+            _ => Err(RuntimeError::DestructureInvalidTarget {
+                message: "Can only destructure tuples and lists".to_string(),
+            }),
+        },
+        _ => Err(RuntimeError::InvalidOperation {
+            message: "Invalid 'let' target".to_string(),
+        }),
+    }
+}
+
 /// Evaluate complex assignment with support for nested structures
 pub fn eval_complex_assignment(
     target: &Expr,
@@ -1,5 +1,6 @@
 use super::handler::{ControlFlowAction, handle_control_flow};
 use crate::eval::{EvalResult, eval_expr, eval_stmt};
+use crate::interrupt::check_interrupt;
 use std::rc::Rc;
 use suji_ast::{Expr, LoopBindings, Stmt};
 use suji_runtime::ModuleRegistry;
@@ -19,6 +20,9 @@ pub fn eval_infinite_loop(
     }
 
     let result = loop {
+        if let Err(e) = check_interrupt() {
+            break Err(e);
+        }
         match eval_stmt(body, env.clone(), loop_stack, registry) {
             Ok(_) => continue,
             Err(e) => {
@@ -52,6 +56,7 @@ fn execute_loop_body(
     loop_stack: &mut Vec<String>,
     registry: Option<&ModuleRegistry>,
 ) -> EvalResult<Option<Value>> {
+    check_interrupt()?;
     match eval_stmt(body, env, loop_stack, registry) {
         Ok(_) => Ok(None), // Continue iteration
         Err(e) => match handle_control_flow(&e, label) {
@@ -1,3 +1,4 @@
+use crate::eval::patterns::{expression_pattern_matches, pattern_bindings, pattern_matches};
 use crate::eval::{EvalResult, eval_expr, eval_stmt, implicit_return::eval_implicit_return};
 use std::rc::Rc;
 use suji_ast::Expr;
@@ -12,32 +13,53 @@ pub fn eval_match_expression(
     env: Rc<Env>,
     registry: Option<&ModuleRegistry>,
 ) -> EvalResult<Value> {
+    // The scrutinee is evaluated exactly once, before any arm is tried, so side effects
+    // and expensive calls are not repeated per arm
+    let scrutinee_value = match scrutinee {
+        Some(scrutinee_expr) => Some(eval_expr(scrutinee_expr, env.clone(), registry)?),
+        None => None,
+    };
+
     for arm in arms {
-        let matches = if let Some(scrutinee_expr) = scrutinee {
-            // Traditional match: evaluate scrutinee and use pattern matching
-            let scrutinee_value = eval_expr(scrutinee_expr, env.clone(), registry)?;
-            super::super::patterns::pattern_matches(&arm.pattern, &scrutinee_value)?
+        let bindings = if let Some(scrutinee_value) = &scrutinee_value {
+            // Traditional match: use pattern matching against the scrutinee
+            if !pattern_matches(&arm.pattern, scrutinee_value)? {
+                continue;
+            }
+            pattern_bindings(&arm.pattern, scrutinee_value)?
         } else {
             // Conditional match: evaluate expression pattern directly
-            super::super::patterns::expression_pattern_matches(&arm.pattern, env.clone(), registry)?
+            if !expression_pattern_matches(&arm.pattern, env.clone(), registry)? {
+                continue;
+            }
+            Vec::new()
+        };
+
+        // `name @ pattern` bindings live in a scope of their own for the arm body
+        let env = if bindings.is_empty() {
+            env
+        } else {
+            let arm_env = Rc::new(Env::new_child(env));
+            for (name, value) in bindings {
+                arm_env.define_or_set(&name, value);
+            }
+            arm_env
         };
 
-        if matches {
-            // Evaluate the arm body and handle implicit returns
-            let mut loop_stack = Vec::new();
-            match eval_stmt(&arm.body, env.clone(), &mut loop_stack, registry) {
-                Ok(result) => {
-                    // Handle implicit returns
-                    match result {
-                        Some(value) => return Ok(value), // Statement returned a value
-                        None => {
-                            // No explicit return, use shared implicit return logic
-                            return eval_implicit_return(&arm.body, env, registry);
-                        }
+        // Evaluate the arm body and handle implicit returns
+        let mut loop_stack = Vec::new();
+        match eval_stmt(&arm.body, env.clone(), &mut loop_stack, registry) {
+            Ok(result) => {
+                // Handle implicit returns
+                match result {
+                    Some(value) => return Ok(value), // Statement returned a value
+                    None => {
+                        // No explicit return, use shared implicit return logic
+                        return eval_implicit_return(&arm.body, env, registry);
                     }
                 }
-                Err(e) => return Err(e),
             }
+            Err(e) => return Err(e),
         }
     }
 
@@ -21,36 +21,26 @@ pub fn eval_binary_expr(
     match op {
         BinaryOp::And => {
             let left_val = eval_expr(left, env.clone(), registry)?;
-            if !left_val.is_truthy() {
+            if !left_val.as_condition("Logical AND operand")? {
                 Ok(Value::Boolean(false))
             } else {
-                // Evaluate right side
-                match eval_expr(right, env, registry) {
-                    Ok(Value::Boolean(b)) => Ok(Value::Boolean(b)),
-                    Ok(_) => Err(RuntimeError::TypeError {
-                        message: "Logical AND requires boolean operands".to_string(),
-                    }),
-                    // Propagate control flow from right side (e.g., continue, break, return)
-                    Err(e @ RuntimeError::ControlFlow { .. }) => Err(e),
-                    Err(e) => Err(e),
-                }
+                // Control flow from the right side (e.g., continue, break, return) propagates
+                let right_val = eval_expr(right, env, registry)?;
+                Ok(Value::Boolean(
+                    right_val.as_condition("Logical AND operand")?,
+                ))
             }
         }
         BinaryOp::Or => {
             let left_val = eval_expr(left, env.clone(), registry)?;
-            if left_val.is_truthy() {
+            if left_val.as_condition("Logical OR operand")? {
                 Ok(Value::Boolean(true))
             } else {
-                // Evaluate right side
-                match eval_expr(right, env, registry) {
-                    Ok(Value::Boolean(b)) => Ok(Value::Boolean(b)),
-                    Ok(_) => Err(RuntimeError::TypeError {
-                        message: "Logical OR requires boolean operands".to_string(),
-                    }),
-                    // Propagate control flow from right side (e.g., continue, break, return)
-                    Err(e @ RuntimeError::ControlFlow { .. }) => Err(e),
-                    Err(e) => Err(e),
-                }
+                // Control flow from the right side (e.g., continue, break, return) propagates
+                let right_val = eval_expr(right, env, registry)?;
+                Ok(Value::Boolean(
+                    right_val.as_condition("Logical OR operand")?,
+                ))
             }
         }
         BinaryOp::Pipe => super::pipe::eval_pipe_expression(left, right, env.clone(), registry),
@@ -88,7 +78,12 @@ pub fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> EvalResult<Va
         }
         // Arithmetic operations
         BinaryOp::Add => match (&left, &right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a.add(b))),
+            (Value::Number(a), Value::Number(b)) => match a.add(b) {
+                Ok(result) => Ok(Value::Number(result)),
+                Err(err) => Err(RuntimeError::InvalidOperation {
+                    message: err.to_string(),
+                }),
+            },
             (Value::String(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
             (Value::List(a), Value::List(b)) => {
                 // List concatenation: pass-by-value semantics
@@ -190,6 +185,7 @@ pub fn eval_composition_expression(
             inner_callee_span.clone(),
         )),
         args: vec![x_expr],
+        named_args: Vec::new(),
         span: inner_callee_span,
     };
 
@@ -204,6 +200,7 @@ pub fn eval_composition_expression(
             outer_callee_span.clone(),
         )),
         args: vec![inner_call],
+        named_args: Vec::new(),
         span: outer_callee_span,
     };
 
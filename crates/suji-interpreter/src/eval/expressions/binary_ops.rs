@@ -64,7 +64,12 @@ pub fn eval_comparison_op(op: BinaryOp, left: Value, right: Value) -> EvalResult
 pub fn eval_arithmetic_op(op: BinaryOp, left: Value, right: Value) -> EvalResult<Value> {
     match op {
         BinaryOp::Subtract => match (&left, &right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a.sub(b))),
+            (Value::Number(a), Value::Number(b)) => match a.sub(b) {
+                Ok(result) => Ok(Value::Number(result)),
+                Err(err) => Err(RuntimeError::InvalidOperation {
+                    message: err.to_string(),
+                }),
+            },
             _ => Err(RuntimeError::TypeError {
                 message: format!(
                     "Cannot subtract {} and {}",
@@ -74,7 +79,12 @@ pub fn eval_arithmetic_op(op: BinaryOp, left: Value, right: Value) -> EvalResult
             }),
         },
         BinaryOp::Multiply => match (&left, &right) {
-            (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a.mul(b))),
+            (Value::Number(a), Value::Number(b)) => match a.mul(b) {
+                Ok(result) => Ok(Value::Number(result)),
+                Err(err) => Err(RuntimeError::InvalidOperation {
+                    message: err.to_string(),
+                }),
+            },
             _ => Err(RuntimeError::TypeError {
                 message: format!(
                     "Cannot multiply {} and {}",
@@ -1,5 +1,5 @@
 use crate::eval::utils::evaluate_exprs;
-use crate::eval::{EvalResult, call_function, eval_expr};
+use crate::eval::{EvalResult, call_function, call_function_with_named, eval_expr};
 use std::rc::Rc;
 use suji_ast::{Expr, Stmt, StringPart};
 use suji_runtime::ModuleRegistry;
@@ -27,6 +27,7 @@ pub fn eval_function_literal(
 pub fn eval_function_call(
     callee: &Expr,
     args: &[Expr],
+    named_args: &[(String, Expr)],
     env: Rc<Env>,
     registry: Option<&ModuleRegistry>,
 ) -> EvalResult<Value> {
@@ -36,9 +37,13 @@ pub fn eval_function_call(
         Value::Function(func) => {
             // Evaluate arguments
             let arg_values = evaluate_exprs(args, env.clone(), registry)?;
+            if named_args.is_empty() {
+                // Delegate to call_function
+                return call_function(&func, arg_values, Some(env), registry, None);
+            }
 
-            // Delegate to call_function
-            call_function(&func, arg_values, Some(env), registry, None)
+            let named_values = evaluate_named_args(named_args, env, registry)?;
+            call_function_with_named(&func, arg_values, named_values, registry)
         }
         _ => Err(RuntimeError::TypeError {
             message: format!("Cannot call {}", function_value.type_name()),
@@ -46,6 +51,18 @@ pub fn eval_function_call(
     }
 }
 
+/// Evaluate `name: value` call arguments left to right
+pub fn evaluate_named_args(
+    named_args: &[(String, Expr)],
+    env: Rc<Env>,
+    registry: Option<&ModuleRegistry>,
+) -> EvalResult<Vec<(String, Value)>> {
+    named_args
+        .iter()
+        .map(|(name, arg)| Ok((name.clone(), eval_expr(arg, env.clone(), registry)?)))
+        .collect()
+}
+
 /// Evaluate a shell command template
 pub fn eval_shell_command_template(
     parts: &[StringPart],
@@ -129,7 +146,7 @@ mod tests {
             "5".to_string(),
             Span::default(),
         ))];
-        let result = eval_function_call(&callee, &args, env.clone(), None).unwrap();
+        let result = eval_function_call(&callee, &args, &[], env.clone(), None).unwrap();
         assert_eq!(result, Value::Number(DecimalNumber::from_i64(6)));
     }
 }
@@ -19,7 +19,7 @@ pub fn eval_method_call(
 
     // For method calls, we need to determine if we have a mutable or immutable receiver
     match target {
-        Expr::Literal(Literal::Identifier(name, _)) => {
+        Expr::Literal(Literal::Identifier(name, _)) if !env.is_constant(name) => {
             // Variable - can be mutable
             let mut target_value = env.get(name)?;
             let receiver = ValueRef::Mutable(&mut target_value);
@@ -40,7 +40,7 @@ pub fn eval_method_call(
             Ok(result)
         }
         _ => {
-            // Expression result - immutable
+            // Expression result or constant - immutable
             let target_value = eval_expr(target, env, registry)?;
             let receiver = ValueRef::Immutable(&target_value);
 
@@ -75,7 +75,12 @@ pub fn eval_expr(
 
         Expr::PostfixDecrement { target, .. } => eval_postfix_decrement(target, env, registry),
 
-        Expr::Call { callee, args, .. } => eval_function_call(callee, args, env, registry),
+        Expr::Call {
+            callee,
+            args,
+            named_args,
+            ..
+        } => eval_function_call(callee, args, named_args, env, registry),
 
         Expr::Grouping { expr: inner, .. } => eval_expr(inner, env, registry),
 
@@ -101,6 +106,11 @@ pub fn eval_expr(
             eval_assignment(target, value, env, registry)
         }
 
+        Expr::Let { target, value, .. } => {
+            use crate::eval::assignments::eval_let;
+            eval_let(target, value, env, registry)
+        }
+
         Expr::CompoundAssign {
             target, op, value, ..
         } => {
@@ -112,8 +122,14 @@ pub fn eval_expr(
             target,
             method,
             args,
-            ..
-        } => method_calls::eval_method_call(target, method, args, env, registry),
+            span,
+        } => method_calls::eval_method_call(target, method, args, env, registry).map_err(|e| {
+            // Point unknown-method errors at `::method(...)` rather than the whole expression
+            match e {
+                RuntimeError::MethodError { .. } => e.with_span(span.clone()),
+                _ => e,
+            }
+        }),
 
         Expr::Match {
             scrutinee, arms, ..
@@ -1,4 +1,5 @@
-use crate::eval::{EvalResult, call_function, eval_expr};
+use super::evaluate_named_args;
+use crate::eval::{EvalResult, call_function, call_function_with_named, eval_expr};
 use std::rc::Rc;
 use suji_ast::{BinaryOp, Expr};
 use suji_runtime::ModuleRegistry;
@@ -14,6 +15,7 @@ enum PipeStage {
     Invocation {
         function: Box<FunctionValue>,
         args: Vec<Value>,
+        named_args: Vec<(String, Value)>,
     },
     ShellTemplate(Vec<suji_ast::StringPart>),
 }
@@ -51,7 +53,12 @@ fn collect_pipe_stages(
             // Unwrap grouping and continue collection
             collect_pipe_stages(inner, env, registry, out)
         }
-        Expr::Call { callee, args, .. } => {
+        Expr::Call {
+            callee,
+            args,
+            named_args,
+            ..
+        } => {
             // Defer invocation to stage execution to run under redirected std/io
             let callee_val = eval_expr(callee, env.clone(), Some(registry))?;
             match callee_val {
@@ -61,9 +68,11 @@ fn collect_pipe_stages(
                     for arg in args {
                         arg_values.push(eval_expr(arg, env.clone(), Some(registry))?);
                     }
+                    let named_values = evaluate_named_args(named_args, env.clone(), Some(registry))?;
                     out.push(PipeStage::Invocation {
                         function: Box::new(f),
                         args: arg_values,
+                        named_args: named_values,
                     });
                     Ok(())
                 }
@@ -189,7 +198,11 @@ pub fn eval_pipe_expression(
                     }
                 }
             }
-            PipeStage::Invocation { function, args } => {
+            PipeStage::Invocation {
+                function,
+                args,
+                named_args,
+            } => {
                 // Prepare stdin override if there is input
                 let stdin_override = current_input
                     .take()
@@ -209,13 +222,8 @@ pub fn eval_pipe_expression(
                     None, // stderr unchanged
                     || {
                         // Call function with args
-                        let result = call_function(
-                            &function,
-                            args,
-                            Some(env.clone()),
-                            Some(registry),
-                            None, // no env overrides needed
-                        )?;
+                        let result =
+                            call_function_with_named(&function, args, named_args, Some(registry))?;
 
                         // If the result is a function, call it too (handles nested closures)
                         // The inner function inherits the same redirected IO context
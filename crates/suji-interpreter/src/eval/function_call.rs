@@ -1,6 +1,7 @@
 use crate::eval::{eval_expr, eval_stmt, implicit_return::eval_implicit_return};
+use crate::interrupt::check_interrupt;
 use std::rc::Rc;
-use suji_runtime::{ModuleRegistry, call_builtin};
+use suji_runtime::{ModuleRegistry, call_builtin_with_callbacks};
 use suji_values::{ControlFlow, Env, FunctionBody, FunctionValue, RuntimeError, Value};
 
 /// Context for function call execution
@@ -9,105 +10,173 @@ pub struct CallContext {
     pub func: FunctionValue,
     /// Evaluated arguments
     pub args: Vec<Value>,
-    /// Optional caller environment for default parameter evaluation
-    pub caller_env: Option<Rc<Env>>,
+    /// Evaluated named arguments, bound by parameter name after the positional ones
+    pub named_args: Vec<(String, Value)>,
     /// Function call environment (created during call)
     pub call_env: Rc<Env>,
 }
 
 impl CallContext {
     /// Create a new call context
-    pub fn new(func: FunctionValue, args: Vec<Value>, caller_env: Option<Rc<Env>>) -> Self {
+    pub fn new(func: FunctionValue, args: Vec<Value>) -> Self {
         // Create new environment for function execution
         let call_env = Rc::new(Env::new_child(func.env.clone()));
 
         Self {
             func,
             args,
-            caller_env,
+            named_args: Vec::new(),
             call_env,
         }
     }
 }
 
-/// Unified function call implementation with optional module registry
+/// Unified function call implementation with optional module registry.
+/// The caller environment is accepted for executor compatibility but not used:
+/// defaults are evaluated in the function's own call environment.
 pub fn call_function(
     func: &FunctionValue,
     args: Vec<Value>,
-    caller_env: Option<Rc<Env>>,
+    _caller_env: Option<Rc<Env>>,
     registry: Option<&ModuleRegistry>,
     env_overrides: Option<Vec<(String, Value)>>,
 ) -> Result<Value, RuntimeError> {
-    let mut context = CallContext::new(func.clone(), args, caller_env);
+    check_interrupt()?;
+    let mut context = CallContext::new(func.clone(), args);
     // Delegate to internal executor with optional module registry and env overrides
     execute_function(&mut context, registry, env_overrides)
 }
 
-/// Phase 1: Resolve arguments with default parameter handling and arity checking
-fn resolve_arguments(context: &mut CallContext) -> Result<(), RuntimeError> {
-    let provided_args = context.args.len();
+/// Call a function with positional arguments followed by `name: value` arguments.
+/// Each name must match a parameter not already given positionally.
+pub fn call_function_with_named(
+    func: &FunctionValue,
+    args: Vec<Value>,
+    named_args: Vec<(String, Value)>,
+    registry: Option<&ModuleRegistry>,
+) -> Result<Value, RuntimeError> {
+    check_interrupt()?;
+    let mut context = CallContext::new(func.clone(), args);
+    context.named_args = named_args;
+    execute_function(&mut context, registry, None)
+}
+
+/// Phase 1: Check arity, then bind parameters in order, evaluating defaults for omitted ones.
+///
+/// Named arguments fill the parameters after the positional ones, in any order; an unknown
+/// name, or a parameter given twice, is an error.
+///
+/// Defaults are evaluated at call time in the call environment, a child of the function's
+/// definition environment, so they see the closure's captured variables and every parameter
+/// bound before them (`|a, b = a + 1|`), never the caller's locals.
+fn bind_arguments(
+    context: &mut CallContext,
+    module_registry: Option<&ModuleRegistry>,
+) -> Result<(), RuntimeError> {
+    let positional_args = context.args.len();
+    let provided_args = positional_args + context.named_args.len();
     let expected_params = context.func.params.len();
 
-    // If we have fewer arguments than parameters, try to fill with defaults
-    if provided_args < expected_params {
-        for i in provided_args..expected_params {
-            match &context.func.params[i].default {
-                Some(default_expr) => {
-                    // Evaluate default in the appropriate environment:
-                    // - If caller_env is provided, use it (for eval_function_call compatibility)
-                    // - Otherwise, use function's closure environment (for eval_closure compatibility)
-                    let eval_env = context
-                        .caller_env
-                        .as_ref()
-                        .unwrap_or(&context.func.env)
-                        .clone();
-
-                    let default_value = eval_expr(default_expr, eval_env, None)?;
-                    context.args.push(default_value);
-                }
-                None => {
-                    return Err(RuntimeError::ArityMismatch {
-                        message: format!(
-                            "Function expects {} arguments, got {}",
-                            expected_params, provided_args
-                        ),
-                    });
-                }
-            }
+    let arity_error = || RuntimeError::ArityMismatch {
+        message: format!(
+            "Function expects {} arguments, got {}",
+            expected_params, provided_args
+        ),
+    };
+    if positional_args > expected_params {
+        return Err(arity_error());
+    }
+
+    let mut named = vec![None; expected_params];
+    for (name, value) in context.named_args.drain(..) {
+        let index = context
+            .func
+            .params
+            .iter()
+            .position(|param| param.name == name)
+            .ok_or_else(|| RuntimeError::ArityMismatch {
+                message: format!("Function has no parameter named '{}'", name),
+            })?;
+        if index < positional_args || named[index].is_some() {
+            return Err(RuntimeError::ArityMismatch {
+                message: format!("Argument '{}' is given more than once", name),
+            });
         }
-    } else if provided_args > expected_params {
-        return Err(RuntimeError::ArityMismatch {
-            message: format!(
-                "Function expects {} arguments, got {}",
-                expected_params, provided_args
-            ),
-        });
+        named[index] = Some(value);
     }
 
-    Ok(())
-}
+    if context.func.params[positional_args..]
+        .iter()
+        .zip(&named[positional_args..])
+        .any(|(param, value)| value.is_none() && param.default.is_none())
+    {
+        return Err(arity_error());
+    }
 
-/// Phase 2: Bind parameters to arguments in the call environment
-fn bind_parameters(context: &CallContext) -> Result<(), RuntimeError> {
-    // Bind parameters to arguments
-    for (param, arg_value) in context.func.params.iter().zip(&context.args) {
-        context
-            .call_env
-            .define_or_set(&param.name, arg_value.clone());
+    for (i, param) in context.func.params.iter().enumerate() {
+        let value = match context.args.get(i) {
+            Some(value) => value.clone(),
+            None => {
+                let value = match named[i].take() {
+                    Some(value) => value,
+                    None => {
+                        let default_expr = param.default.as_ref().ok_or_else(arity_error)?;
+                        eval_expr(default_expr, context.call_env.clone(), module_registry)?
+                    }
+                };
+                context.args.push(value.clone());
+                value
+            }
+        };
+        context.call_env.define_or_set(&param.name, value);
     }
 
     Ok(())
 }
 
+/// Builtin and native functions only take positional arguments
+fn reject_named_args(
+    builtin_name: &str,
+    named_args: &[(String, Value)],
+) -> Result<(), RuntimeError> {
+    match named_args.first() {
+        Some((name, _)) => Err(RuntimeError::ArityMismatch {
+            message: format!(
+                "Builtin function '{}' does not accept named arguments (got '{}')",
+                builtin_name, name
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Internal unified executor used by both public call paths
 fn execute_function(
     context: &mut CallContext,
     module_registry: Option<&ModuleRegistry>,
     env_overrides: Option<Vec<(String, Value)>>,
 ) -> Result<Value, RuntimeError> {
-    // Check for builtins
-    if let FunctionBody::Builtin(builtin_name) = &context.func.body {
-        return call_builtin(builtin_name, &context.args);
+    // Check for builtins and embedder-registered native functions
+    match &context.func.body {
+        FunctionBody::Builtin(builtin_name) => {
+            reject_named_args(builtin_name, &context.named_args)?;
+            let call_fn = |func: &FunctionValue, args: Vec<Value>, caller_env: Option<Rc<Env>>| {
+                call_function(func, args, caller_env, module_registry, None)
+            };
+            return call_builtin_with_callbacks(builtin_name, &context.args, &call_fn);
+        }
+        FunctionBody::Native(native) => {
+            reject_named_args(&native.name, &context.named_args)?;
+            return (native.func)(&context.args);
+        }
+        FunctionBody::Partial(partial) => {
+            // Call the target with the bound arguments ahead of this call's ones
+            let args = partial.args.iter().cloned().chain(context.args.drain(..));
+            let mut target_context = CallContext::new((*partial.target).clone(), args.collect());
+            target_context.named_args = std::mem::take(&mut context.named_args);
+            return execute_function(&mut target_context, module_registry, env_overrides);
+        }
+        _ => {}
     }
 
     // Apply environment overrides (e.g., std/io for pipe) before binding params
@@ -117,16 +186,13 @@ fn execute_function(
         }
     }
 
-    // Phase 1: Argument validation and default resolution
-    resolve_arguments(context)?;
-
-    // Phase 2: Parameter binding
-    bind_parameters(context)?;
+    // Phase 1: Argument validation, default resolution and parameter binding
+    bind_arguments(context, module_registry)?;
 
     // Get AST body (will fail if bytecode has been passed)
     let body_stmt = context.func.as_ast_body()?;
 
-    // Phase 3: Body execution
+    // Phase 2: Body execution
     let mut loop_stack = Vec::new();
     match eval_stmt(
         body_stmt,
@@ -16,12 +16,65 @@ fn force_load_if_module(
     }
 }
 
-/// Evaluate an import statement
+/// Resolve and load the module an item import reads from
+fn resolve_import_base(
+    executor: &dyn Executor,
+    env: &Rc<Env>,
+    module: &str,
+    module_registry: &ModuleRegistry,
+) -> Result<Value, RuntimeError> {
+    let base = module_registry.resolve_module_path(executor, env, module, true)?;
+    force_load_if_module(executor, base, module_registry)
+}
+
+/// Look up one exported item of a loaded module, force-loading it if it is a module too
+fn import_item(
+    executor: &dyn Executor,
+    base: &Value,
+    module: &str,
+    name: &str,
+    module_registry: &ModuleRegistry,
+) -> Result<Value, RuntimeError> {
+    let item = match base {
+        Value::Map(map) => {
+            let key = suji_values::MapKey::String(name.to_string());
+            map.get(&key)
+                .cloned()
+                .ok_or_else(|| RuntimeError::InvalidOperation {
+                    message: format!("Item '{}' not found in module '{}'", name, module),
+                })?
+        }
+        _ => {
+            return Err(RuntimeError::InvalidOperation {
+                message: format!("Module '{}' is not a valid module (not a map)", module),
+            });
+        }
+    };
+    force_load_if_module(executor, item, module_registry)
+}
+
+/// Evaluate an import statement. Inside a module file it resolves relative to that
+/// file's directory, even when it runs later in a function called from elsewhere.
 pub fn eval_import(
     executor: &dyn Executor,
     spec: &ImportSpec,
     env: Rc<Env>,
     module_registry: &ModuleRegistry,
+) -> Result<(), RuntimeError> {
+    match env.module_dir().map(|dir| dir.to_path_buf()) {
+        Some(dir) => {
+            module_registry.with_dir(&dir, || bind_import(executor, spec, env, module_registry))
+        }
+        None => bind_import(executor, spec, env, module_registry),
+    }
+}
+
+/// Resolve an import against the registry's current directory and bind its names
+fn bind_import(
+    executor: &dyn Executor,
+    spec: &ImportSpec,
+    env: Rc<Env>,
+    module_registry: &ModuleRegistry,
 ) -> Result<(), RuntimeError> {
     match spec {
         ImportSpec::Module { name } => {
@@ -29,33 +82,16 @@ pub fn eval_import(
             // Env → FS → builtins
             let module = module_registry.resolve_module_root(executor, &env, name)?;
             let module = force_load_if_module(executor, module, module_registry)?;
-            env.define_or_set(name, module);
+            env.define(name, module)?;
             Ok(())
         }
 
         ImportSpec::Item { module, name } => {
             // import module:item - bind the specific item to its name
             // Env → FS → builtins for module path
-            let base = module_registry.resolve_module_path(executor, &env, module, true)?;
-            let base = force_load_if_module(executor, base, module_registry)?;
-            let mut item = match base {
-                Value::Map(map) => {
-                    let key = suji_values::MapKey::String(name.to_string());
-                    map.get(&key)
-                        .cloned()
-                        .ok_or_else(|| RuntimeError::InvalidOperation {
-                            message: format!("Item '{}' not found in module '{}'", name, module),
-                        })?
-                }
-                _ => {
-                    return Err(RuntimeError::InvalidOperation {
-                        message: format!("Module '{}' is not a valid module (not a map)", module),
-                    });
-                }
-            };
-            // Force-load the item if it's also a module
-            item = force_load_if_module(executor, item, module_registry)?;
-            env.define_or_set(name, item);
+            let base = resolve_import_base(executor, &env, module, module_registry)?;
+            let item = import_item(executor, &base, module, name, module_registry)?;
+            env.define(name, item)?;
             Ok(())
         }
 
@@ -65,26 +101,30 @@ pub fn eval_import(
             alias,
         } => {
             // import module:item as alias - bind the specific item to the alias
-            let base = module_registry.resolve_module_path(executor, &env, module, true)?;
-            let base = force_load_if_module(executor, base, module_registry)?;
-            let mut item = match base {
-                Value::Map(map) => {
-                    let key = suji_values::MapKey::String(name.to_string());
-                    map.get(&key)
-                        .cloned()
-                        .ok_or_else(|| RuntimeError::InvalidOperation {
-                            message: format!("Item '{}' not found in module '{}'", name, module),
-                        })?
-                }
-                _ => {
-                    return Err(RuntimeError::InvalidOperation {
-                        message: format!("Module '{}' is not a valid module (not a map)", module),
-                    });
-                }
-            };
-            // Force-load the item if it's also a module
-            item = force_load_if_module(executor, item, module_registry)?;
-            env.define_or_set(alias, item);
+            let base = resolve_import_base(executor, &env, module, module_registry)?;
+            let item = import_item(executor, &base, module, name, module_registry)?;
+            env.define(alias, item)?;
+            Ok(())
+        }
+
+        ImportSpec::Items { module, names } => {
+            // import module:{item, other} - bind each item to its own name,
+            // only once every item has been found
+            let base = resolve_import_base(executor, &env, module, module_registry)?;
+            let items = names
+                .iter()
+                .map(|name| import_item(executor, &base, module, name, module_registry))
+                .collect::<Result<Vec<_>, _>>()?;
+            for (name, item) in names.iter().zip(items) {
+                env.define(name, item)?;
+            }
+            Ok(())
+        }
+
+        ImportSpec::File { path, alias } => {
+            // import "file.json" as alias - bind the parsed data (or module exports) to the alias
+            let value = module_registry.resolve_file_import(executor, path)?;
+            env.define(alias, value)?;
             Ok(())
         }
     }
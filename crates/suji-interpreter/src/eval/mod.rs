@@ -16,7 +16,7 @@ mod data_structures;
 mod expressions;
 mod utils;
 
-pub use function_call::call_function;
+pub use function_call::{call_function, call_function_with_named};
 pub use imports::*;
 
 pub use control_flow::{eval_infinite_loop, eval_loop_through, eval_match_expression};
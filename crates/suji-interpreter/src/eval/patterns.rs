@@ -4,12 +4,41 @@ use std::rc::Rc;
 use suji_ast::Pattern;
 use suji_runtime::ModuleRegistry;
 use suji_values::Env;
-use suji_values::{DecimalNumber, RuntimeError, Value};
+use suji_values::{DecimalNumber, RuntimeError, Value, range_contains};
 
 /// Check if a pattern matches a value
 pub fn pattern_matches(pattern: &Pattern, value: &Value) -> EvalResult<bool> {
     match pattern {
         Pattern::Wildcard { .. } => Ok(true),
+        Pattern::Type { name, .. } => Ok(value.type_name() == name),
+        Pattern::Range {
+            start,
+            end,
+            inclusive,
+            ..
+        } => match value {
+            Value::Number(n) => Ok(range_contains(
+                n,
+                &DecimalNumber::from_decimal(*start),
+                &DecimalNumber::from_decimal(*end),
+                *inclusive,
+            )),
+            // Non-numeric values never fall inside a numeric range
+            _ => Ok(false),
+        },
+        Pattern::Or { patterns, .. } => {
+            for pattern in patterns {
+                if pattern_matches(pattern, value)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        Pattern::Binding { pattern, .. } => pattern_matches(pattern, value),
+        Pattern::StringAffix { prefix, suffix, .. } => Ok(match value {
+            Value::String(s) => affix_rest(s, prefix, suffix).is_some(),
+            _ => false,
+        }),
         Pattern::Literal {
             value: pattern_value,
             ..
@@ -29,6 +58,28 @@ pub fn pattern_matches(pattern: &Pattern, value: &Value) -> EvalResult<bool> {
             }
             _ => Ok(false),
         },
+        Pattern::List {
+            before,
+            rest,
+            after,
+            ..
+        } => match value {
+            Value::List(items) => {
+                match split_list(items, before.len(), rest.is_some(), after.len()) {
+                    Some((head, _, tail)) => {
+                        for (pattern, item) in before.iter().zip(head).chain(after.iter().zip(tail))
+                        {
+                            if !pattern_matches(pattern, item)? {
+                                return Ok(false);
+                            }
+                        }
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            _ => Ok(false),
+        },
         Pattern::Regex {
             pattern: regex_pattern,
             ..
@@ -49,6 +100,102 @@ pub fn pattern_matches(pattern: &Pattern, value: &Value) -> EvalResult<bool> {
     }
 }
 
+/// Collect the `name @ pattern` bindings of a pattern that is known to match `value`
+pub fn pattern_bindings(pattern: &Pattern, value: &Value) -> EvalResult<Vec<(String, Value)>> {
+    let mut bindings = Vec::new();
+    collect_bindings(pattern, value, &mut bindings)?;
+    Ok(bindings)
+}
+
+fn collect_bindings(
+    pattern: &Pattern,
+    value: &Value,
+    bindings: &mut Vec<(String, Value)>,
+) -> EvalResult<()> {
+    match (pattern, value) {
+        (Pattern::Binding { name, pattern, .. }, _) => {
+            bindings.push((name.clone(), value.clone()));
+            collect_bindings(pattern, value, bindings)
+        }
+        (
+            Pattern::StringAffix {
+                prefix,
+                rest: Some(name),
+                suffix,
+                ..
+            },
+            Value::String(s),
+        ) => {
+            if let Some(rest) = affix_rest(s, prefix, suffix) {
+                bindings.push((name.clone(), Value::String(rest.to_string())));
+            }
+            Ok(())
+        }
+        (Pattern::Tuple { patterns, .. }, Value::Tuple(items)) => {
+            for (pattern, item) in patterns.iter().zip(items.iter()) {
+                collect_bindings(pattern, item, bindings)?;
+            }
+            Ok(())
+        }
+        (
+            Pattern::List {
+                before,
+                rest,
+                after,
+                ..
+            },
+            Value::List(items),
+        ) => {
+            if let Some((head, middle, tail)) =
+                split_list(items, before.len(), rest.is_some(), after.len())
+            {
+                for (pattern, item) in before.iter().zip(head) {
+                    collect_bindings(pattern, item, bindings)?;
+                }
+                if let Some(name) = rest.as_ref().and_then(|rest| rest.name.as_ref()) {
+                    bindings.push((name.clone(), Value::List(middle.to_vec())));
+                }
+                for (pattern, item) in after.iter().zip(tail) {
+                    collect_bindings(pattern, item, bindings)?;
+                }
+            }
+            Ok(())
+        }
+        (Pattern::Or { patterns, .. }, _) => {
+            for pattern in patterns {
+                if pattern_matches(pattern, value)? {
+                    return collect_bindings(pattern, value, bindings);
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Split a list into the parts a list pattern matches: `before` leading items, the middle,
+/// and `after` trailing items. Without a rest the middle must be empty.
+fn split_list(
+    items: &[Value],
+    before: usize,
+    has_rest: bool,
+    after: usize,
+) -> Option<(&[Value], &[Value], &[Value])> {
+    let fixed = before + after;
+    if items.len() < fixed || (!has_rest && items.len() != fixed) {
+        return None;
+    }
+    let (head, rest) = items.split_at(before);
+    let (middle, tail) = rest.split_at(rest.len() - after);
+    Some((head, middle, tail))
+}
+
+/// The text between `prefix` and `suffix`, if `s` starts and ends with them without overlap
+fn affix_rest<'a>(s: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    s.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+/// Parse a range pattern bound (a numeric literal from the source)
 /// Check if an expression pattern matches (for conditional match)
 pub fn expression_pattern_matches(
     pattern: &Pattern,
@@ -59,7 +206,7 @@ pub fn expression_pattern_matches(
         Pattern::Expression(expr) => {
             use super::eval_expr;
             let result = eval_expr(expr, env, registry)?;
-            Ok(result.is_truthy())
+            result.as_condition("Match condition")
         }
         _ => Err(RuntimeError::ConditionalMatchError {
             message: "Only boolean expressions are allowed in conditional match conditions"
@@ -196,6 +343,73 @@ mod tests {
         assert!(!pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(42))).unwrap());
     }
 
+    #[test]
+    fn test_type_pattern() {
+        let pattern = Pattern::Type {
+            name: "number".to_string(),
+            span: Span::default(),
+        };
+
+        assert!(pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(42))).unwrap());
+        assert!(!pattern_matches(&pattern, &Value::String("42".to_string())).unwrap());
+
+        let nil_pattern = Pattern::Type {
+            name: "nil".to_string(),
+            span: Span::default(),
+        };
+        assert!(pattern_matches(&nil_pattern, &Value::Nil).unwrap());
+        assert!(!pattern_matches(&nil_pattern, &Value::Boolean(false)).unwrap());
+    }
+
+    #[test]
+    fn test_range_pattern() {
+        let bound = |text: &str| Pattern::parse_range_bound(text).unwrap();
+        let pattern = Pattern::Range {
+            start: bound("1"),
+            end: bound("10"),
+            inclusive: false,
+            span: Span::default(),
+        };
+
+        assert!(pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(1))).unwrap());
+        assert!(pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(9))).unwrap());
+        assert!(!pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(10))).unwrap());
+        assert!(!pattern_matches(&pattern, &Value::String("5".to_string())).unwrap());
+
+        let inclusive = Pattern::Range {
+            start: bound("-5"),
+            end: bound("0"),
+            inclusive: true,
+            span: Span::default(),
+        };
+        assert!(pattern_matches(&inclusive, &Value::Number(DecimalNumber::from_i64(0))).unwrap());
+        assert!(pattern_matches(&inclusive, &Value::Number(DecimalNumber::from_i64(-5))).unwrap());
+        assert!(!pattern_matches(&inclusive, &Value::Nil).unwrap());
+    }
+
+    #[test]
+    fn test_or_pattern() {
+        let number = |n: &str| Pattern::Literal {
+            value: suji_ast::ValueLike::Number(n.to_string()),
+            span: Span::default(),
+        };
+        let pattern = Pattern::Or {
+            patterns: vec![
+                number("1"),
+                Pattern::Type {
+                    name: "string".to_string(),
+                    span: Span::default(),
+                },
+            ],
+            span: Span::default(),
+        };
+
+        assert!(pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(1))).unwrap());
+        assert!(pattern_matches(&pattern, &Value::String("a".to_string())).unwrap());
+        assert!(!pattern_matches(&pattern, &Value::Number(DecimalNumber::from_i64(2))).unwrap());
+        assert!(!pattern_matches(&pattern, &Value::Nil).unwrap());
+    }
+
     #[test]
     fn test_invalid_regex_pattern() {
         let pattern = Pattern::Regex {
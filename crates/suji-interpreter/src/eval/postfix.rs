@@ -16,7 +16,11 @@ pub fn eval_postfix_increment(
             let current_value = env.get(name)?;
             match current_value {
                 Value::Number(n) => {
-                    let incremented = n.add(&DecimalNumber::from_i64(1));
+                    let incremented = n.add(&DecimalNumber::from_i64(1)).map_err(|err| {
+                        RuntimeError::InvalidOperation {
+                            message: err.to_string(),
+                        }
+                    })?;
                     let new_value = Value::Number(incremented.clone());
                     env.set_existing(name, new_value)?;
                     Ok(Value::Number(incremented)) // Return the incremented value
@@ -43,7 +47,11 @@ pub fn eval_postfix_decrement(
             let current_value = env.get(name)?;
             match current_value {
                 Value::Number(n) => {
-                    let decremented = n.sub(&DecimalNumber::from_i64(1));
+                    let decremented = n.sub(&DecimalNumber::from_i64(1)).map_err(|err| {
+                        RuntimeError::InvalidOperation {
+                            message: err.to_string(),
+                        }
+                    })?;
                     let new_value = Value::Number(decremented.clone());
                     env.set_existing(name, new_value)?;
                     Ok(Value::Number(decremented)) // Return the decremented value
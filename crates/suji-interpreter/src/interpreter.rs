@@ -10,6 +10,65 @@ use suji_values::{Env, FunctionValue, RuntimeError, Value};
 /// AST-walking interpreter implementation
 pub struct AstInterpreter;
 
+impl AstInterpreter {
+    /// Stop the running evaluation at its next loop iteration or function call with
+    /// `RuntimeError::Interrupted`. Safe to call from a signal handler.
+    pub fn interrupt() {
+        crate::interrupt::request_interrupt();
+    }
+
+    /// Consume a pending interrupt request as `RuntimeError::Interrupted`, for native
+    /// code that waits in a loop without calling back into scripts
+    pub fn check_interrupt() -> Result<(), RuntimeError> {
+        crate::interrupt::check_interrupt()
+    }
+
+    /// Forget an interrupt that arrived while nothing was running
+    pub fn clear_interrupt() {
+        crate::interrupt::clear_interrupt();
+    }
+
+    /// Lex and parse source into statements without evaluating them
+    pub fn parse_source(source: &str) -> Result<Vec<Stmt>, RuntimeError> {
+        let tokens = Lexer::lex(source).map_err(|e| RuntimeError::from(ParseError::from(e)))?;
+        let mut parser = Parser::new(tokens);
+        parser.parse().map_err(RuntimeError::from)
+    }
+
+    /// Evaluate parsed statements in order, returning the last value (or the export)
+    pub fn eval_statements(
+        &self,
+        statements: &[Stmt],
+        env: Rc<Env>,
+        module_registry: &ModuleRegistry,
+        expect_export: bool,
+    ) -> Result<Value, RuntimeError> {
+        let mut last_value = None;
+        let mut export_value = None;
+
+        for stmt in statements {
+            match self.execute_stmt(stmt, env.clone(), module_registry) {
+                Ok(Some(v)) => {
+                    if matches!(stmt, Stmt::Export { .. }) {
+                        export_value = Some(v.clone());
+                    }
+                    last_value = Some(v);
+                }
+                Ok(None) => {}
+                Err(e) => return Err(e.with_span(stmt.span().clone())),
+            }
+        }
+
+        if expect_export {
+            export_value.ok_or_else(|| RuntimeError::InvalidOperation {
+                message: "Module has no export".to_string(),
+            })
+        } else {
+            Ok(last_value.unwrap_or(Value::Nil))
+        }
+    }
+}
+
 impl Executor for AstInterpreter {
     fn execute_stmt(
         &self,
@@ -50,34 +109,7 @@ impl Executor for AstInterpreter {
         module_registry: &ModuleRegistry,
         expect_export: bool,
     ) -> Result<Value, RuntimeError> {
-        // Parse the source
-        let tokens = Lexer::lex(source).map_err(|e| RuntimeError::from(ParseError::from(e)))?;
-        let mut parser = Parser::new(tokens);
-        let statements = parser.parse().map_err(RuntimeError::from)?;
-
-        // Evaluate statements using execute_stmt
-        let mut last_value = None;
-        let mut export_value = None;
-
-        for stmt in &statements {
-            match self.execute_stmt(stmt, env.clone(), module_registry) {
-                Ok(Some(v)) => {
-                    if matches!(stmt, Stmt::Export { .. }) {
-                        export_value = Some(v.clone());
-                    }
-                    last_value = Some(v);
-                }
-                Ok(None) => {}
-                Err(e) => return Err(e.with_span(stmt.span().clone())),
-            }
-        }
-
-        if expect_export {
-            export_value.ok_or_else(|| RuntimeError::InvalidOperation {
-                message: "Module has no export".to_string(),
-            })
-        } else {
-            Ok(last_value.unwrap_or(Value::Nil))
-        }
+        let statements = Self::parse_source(source)?;
+        self.eval_statements(&statements, env, module_registry, expect_export)
     }
 }
@@ -0,0 +1,25 @@
+//! User interrupt flag, checked at loop iterations and function calls.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use suji_values::RuntimeError;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Request that the running evaluation stop. Only touches an atomic, so it is safe to
+/// call from a signal handler.
+pub fn request_interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Drop any pending interrupt request
+pub fn clear_interrupt() {
+    INTERRUPTED.store(false, Ordering::SeqCst);
+}
+
+/// Consume a pending interrupt request, turning it into `RuntimeError::Interrupted`
+pub(crate) fn check_interrupt() -> Result<(), RuntimeError> {
+    match INTERRUPTED.swap(false, Ordering::SeqCst) {
+        true => Err(RuntimeError::Interrupted),
+        false => Ok(()),
+    }
+}
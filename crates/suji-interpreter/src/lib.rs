@@ -4,4 +4,5 @@ mod interpreter;
 pub use interpreter::AstInterpreter;
 
 mod eval;
+mod interrupt;
 pub use eval::eval_module_source_callback;
@@ -0,0 +1,43 @@
+//! The interrupt flag is process-wide, so these checks live in their own test binary
+//! and run as a single test.
+
+use std::rc::Rc;
+use suji_interpreter::AstInterpreter;
+use suji_runtime::ModuleRegistry;
+use suji_values::{DecimalNumber, Env, RuntimeError, Value};
+
+fn eval(source: &str) -> Result<Value, RuntimeError> {
+    let statements = AstInterpreter::parse_source(source)?;
+    AstInterpreter.eval_statements(
+        &statements,
+        Rc::new(Env::new()),
+        &ModuleRegistry::new(),
+        false,
+    )
+}
+
+#[test]
+fn test_interrupt_stops_evaluation_once() {
+    for source in ["loop {}", "let f = |n| f(n + 1)\nf(0)"] {
+        AstInterpreter::interrupt();
+        let error = eval(source).unwrap_err();
+        assert!(
+            matches!(error.without_span(), RuntimeError::Interrupted),
+            "{}: {:?}",
+            source,
+            error
+        );
+
+        // The request was consumed, so the next evaluation runs to the end
+        let result = eval("let n = 0\nloop { n += 1\nmatch n { 1000 => break, _ => {}, } }\nn");
+        assert_eq!(
+            result.unwrap(),
+            Value::Number(DecimalNumber::from_i64(1000))
+        );
+    }
+
+    // A request made while nothing runs can be dropped before the next evaluation
+    AstInterpreter::interrupt();
+    AstInterpreter::clear_interrupt();
+    assert!(AstInterpreter::check_interrupt().is_ok());
+}
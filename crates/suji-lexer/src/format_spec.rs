@@ -0,0 +1,196 @@
+//! Format specs for string interpolation: `"${value:spec}"`.
+//!
+//! Grammar (a subset of Rust's): `[[fill]align][+][0][width][.precision]`
+//! where `align` is one of `<`, `>`, `^`.
+
+/// Alignment of a padded interpolation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatAlign {
+    Left,
+    Right,
+    Center,
+}
+
+/// Parsed interpolation format spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Option<FormatAlign>,
+    pub sign_plus: bool,
+    pub zero_pad: bool,
+    pub width: Option<usize>,
+    pub precision: Option<usize>,
+}
+
+fn align_from_char(ch: char) -> Option<FormatAlign> {
+    match ch {
+        '<' => Some(FormatAlign::Left),
+        '>' => Some(FormatAlign::Right),
+        '^' => Some(FormatAlign::Center),
+        _ => None,
+    }
+}
+
+impl FormatSpec {
+    /// Parse a spec (the text after the `:`), returning None if it is malformed
+    pub fn parse(spec: &str) -> Option<FormatSpec> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut i = 0;
+        let mut result = FormatSpec {
+            fill: ' ',
+            align: None,
+            sign_plus: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+        };
+
+        if chars.len() >= 2
+            && let Some(align) = align_from_char(chars[1])
+        {
+            result.fill = chars[0];
+            result.align = Some(align);
+            i = 2;
+        } else if let Some(align) = chars.first().and_then(|&c| align_from_char(c)) {
+            result.align = Some(align);
+            i = 1;
+        }
+
+        if chars.get(i) == Some(&'+') {
+            result.sign_plus = true;
+            i += 1;
+        }
+
+        if chars.get(i) == Some(&'0') {
+            result.zero_pad = true;
+            i += 1;
+        }
+
+        let width_start = i;
+        while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+            i += 1;
+        }
+        if i > width_start {
+            result.width = Some(
+                chars[width_start..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse()
+                    .ok()?,
+            );
+        }
+
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            let precision_start = i;
+            while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            if i == precision_start {
+                return None;
+            }
+            result.precision = Some(
+                chars[precision_start..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse()
+                    .ok()?,
+            );
+        }
+
+        if i != chars.len() || chars.is_empty() {
+            return None;
+        }
+        Some(result)
+    }
+
+    /// Whether the text after a top-level `:` in an interpolation is meant as a format spec
+    /// rather than map access (`${map:key}`) — specs never start like an identifier.
+    pub(crate) fn is_spec_candidate(text: &str) -> bool {
+        let mut chars = text.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        if first.is_whitespace() || text.contains(['[', ']', '(', ')', '{', '"', '\'', '`']) {
+            return false;
+        }
+        let starts_like_identifier = first.is_alphabetic() || first == '_';
+        !starts_like_identifier || chars.next().and_then(align_from_char).is_some()
+    }
+}
+
+/// Writes the spec back in the form `parse` accepts, so it round-trips
+impl std::fmt::Display for FormatSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(align) = self.align {
+            if self.fill != ' ' {
+                write!(f, "{}", self.fill)?;
+            }
+            let align = match align {
+                FormatAlign::Left => '<',
+                FormatAlign::Right => '>',
+                FormatAlign::Center => '^',
+            };
+            write!(f, "{}", align)?;
+        }
+        if self.sign_plus {
+            write!(f, "+")?;
+        }
+        if self.zero_pad {
+            write!(f, "0")?;
+        }
+        if let Some(width) = self.width {
+            write!(f, "{}", width)?;
+        }
+        if let Some(precision) = self.precision {
+            write!(f, ".{}", precision)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_specs_in_parseable_form() {
+        for spec in ["*^+08.2", ">10", "<", ".3", "+", "05"] {
+            assert_eq!(FormatSpec::parse(spec).unwrap().to_string(), spec);
+        }
+    }
+
+    #[test]
+    fn parses_full_spec() {
+        let spec = FormatSpec::parse("*^+08.3").unwrap();
+        assert_eq!(spec.fill, '*');
+        assert_eq!(spec.align, Some(FormatAlign::Center));
+        assert!(spec.sign_plus);
+        assert!(spec.zero_pad);
+        assert_eq!(spec.width, Some(8));
+        assert_eq!(spec.precision, Some(3));
+    }
+
+    #[test]
+    fn parses_short_specs() {
+        assert_eq!(FormatSpec::parse(".2").unwrap().precision, Some(2));
+        assert_eq!(FormatSpec::parse(">5").unwrap().width, Some(5));
+        assert_eq!(FormatSpec::parse("5").unwrap().align, None);
+    }
+
+    #[test]
+    fn rejects_malformed_specs() {
+        for bad in ["", ".", ".2x", "5.", ">>>", "+-"] {
+            assert!(FormatSpec::parse(bad).is_none(), "accepted {:?}", bad);
+        }
+    }
+
+    #[test]
+    fn distinguishes_specs_from_map_keys() {
+        assert!(FormatSpec::is_spec_candidate(".2"));
+        assert!(FormatSpec::is_spec_candidate("_<5"));
+        assert!(!FormatSpec::is_spec_candidate("key"));
+        assert!(!FormatSpec::is_spec_candidate("2]"));
+        assert!(!FormatSpec::is_spec_candidate(" key"));
+    }
+}
@@ -23,6 +23,8 @@ pub enum LexError {
     InvalidNumber { literal: String, span: Span },
     #[error("Unexpected character '{ch}'")]
     UnexpectedCharacter { ch: char, span: Span },
+    #[error("Invalid format spec '{spec}'")]
+    InvalidFormatSpec { spec: String, span: Span },
 }
 
 pub struct Lexer<'a> {
@@ -1,9 +1,11 @@
+mod format_spec;
 mod lexer;
 mod span;
 mod states;
 mod token;
 mod utils;
 
+pub use format_spec::{FormatAlign, FormatSpec};
 pub use lexer::{LexError, Lexer};
 pub use span::Span;
 pub use token::{SpannedToken, Token};
@@ -1,6 +1,7 @@
 use super::context::ParentInterpolation;
 use super::normal::NormalScanner;
 use super::{LexState, ScannerContext, ScannerResult};
+use crate::format_spec::FormatSpec;
 use crate::lexer::LexError;
 use crate::span::Span;
 use crate::token::{SpannedToken, Token};
@@ -78,6 +79,19 @@ impl InterpolationScanner {
                             };
                         }
                     }
+                    Token::Colon if brace_depth == 1 => {
+                        *state = LexState::InStringInterp {
+                            start_pos,
+                            quote_type,
+                            multiline,
+                            brace_depth,
+                        };
+                        if let Some(spec_token) =
+                            Self::scan_format_spec(context, &token_with_span.span)?
+                        {
+                            return Ok(spec_token);
+                        }
+                    }
                     _ => {
                         if is_nested_context {
                             // Nested context started - push current interpolation state to stack
@@ -109,6 +123,41 @@ impl InterpolationScanner {
         token_result
     }
 
+    /// After a top-level `:` in a string interpolation, consume a trailing format spec
+    /// (`${pi:.2}`) if the text up to the closing `}` is meant as one.
+    fn scan_format_spec(
+        context: &mut ScannerContext,
+        colon_span: &Span,
+    ) -> Result<Option<SpannedToken>, LexError> {
+        let rest = &context.input[context.position..];
+        let Some(end) = rest.find(['}', '\n']) else {
+            return Ok(None);
+        };
+        if !rest[end..].starts_with('}') {
+            return Ok(None);
+        }
+        let text = &rest[..end];
+        if !FormatSpec::is_spec_candidate(text) {
+            return Ok(None);
+        }
+
+        let text = text.to_string();
+        for ch in text.chars() {
+            context.advance_position(ch);
+        }
+        let span = Span::new(
+            colon_span.start,
+            context.position,
+            colon_span.line,
+            colon_span.column,
+        );
+
+        match FormatSpec::parse(&text) {
+            Some(spec) => Ok(Some(SpannedToken::new(Token::FormatSpec(spec), span))),
+            None => Err(LexError::InvalidFormatSpec { spec: text, span }),
+        }
+    }
+
     /// Scan shell interpolation content
     pub fn scan_shell_interpolation(
         context: &mut ScannerContext,
@@ -152,16 +152,7 @@ impl NormalScanner {
                     Token::Greater
                 }
             }
-            '&' => {
-                if context.match_char('&') {
-                    Token::And
-                } else {
-                    return Err(LexError::UnexpectedCharacter {
-                        ch,
-                        span: Span::new(start_pos, context.position, start_line, start_column),
-                    });
-                }
-            }
+            '&' if context.match_char('&') => Token::And,
             '|' => {
                 if context.match_char('|') {
                     Token::Or
@@ -178,22 +169,18 @@ impl NormalScanner {
                     Token::Colon
                 }
             }
-            '.' => {
-                if context.match_char('.') {
-                    if context.match_char('=') {
-                        Token::RangeInclusive
-                    } else {
-                        Token::Range
-                    }
+            '.' if context.match_char('.') => {
+                if context.match_char('=') {
+                    Token::RangeInclusive
+                } else if context.match_char('.') {
+                    Token::Ellipsis
                 } else {
-                    return Err(LexError::UnexpectedCharacter {
-                        ch,
-                        span: Span::new(start_pos, context.position, start_line, start_column),
-                    });
+                    Token::Range
                 }
             }
             '~' => Token::RegexMatch,
             ';' => Token::Semicolon,
+            '@' => Token::At,
             '#' => LexerUtils::scan_comment(context),
             '\n' => {
                 context.line += 1;
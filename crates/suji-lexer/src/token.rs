@@ -1,3 +1,4 @@
+use crate::format_spec::FormatSpec;
 use crate::span::Span;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,6 +26,7 @@ pub enum Token {
     Match,
     Import,
     Export,
+    Let,
     True,
     False,
     Nil,
@@ -40,8 +42,9 @@ pub enum Token {
     // String template tokens
     StringStart,
     StringText(String),
-    InterpStart, // ${
-    InterpEnd,   // } (implicit when closing interpolation)
+    InterpStart,            // ${
+    InterpEnd,              // } (implicit when closing interpolation)
+    FormatSpec(FormatSpec), // :spec before the closing } of an interpolation
     StringEnd,
 
     // Shell command template tokens (reuse string interpolation tokens)
@@ -93,6 +96,9 @@ pub enum Token {
     Range,          // ..
     RangeInclusive, // ..=
 
+    // Rest element in list patterns
+    Ellipsis, // ...
+
     // Regex match operators
     RegexMatch,
     RegexNotMatch,
@@ -111,6 +117,7 @@ pub enum Token {
     PipeForward,
     PipeBackward,
     Semicolon,
+    At,
 
     // Special tokens
     Comment(String),
@@ -157,6 +164,7 @@ impl Token {
                 | Token::Match
                 | Token::Import
                 | Token::Export
+                | Token::Let
                 | Token::True
                 | Token::False
                 | Token::Nil
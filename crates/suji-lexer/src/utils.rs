@@ -67,6 +67,27 @@ impl LexerUtils {
             }
         }
 
+        // Look for an exponent: e10, E+5, e-3
+        if !context.is_at_end() && matches!(context.peek(), 'e' | 'E') {
+            context.advance(); // consume the 'e'
+            if !context.is_at_end() && matches!(context.peek(), '+' | '-') {
+                context.advance();
+            }
+
+            let mut has_exponent_digits = false;
+            while !context.is_at_end() && context.peek().is_ascii_digit() {
+                context.advance();
+                has_exponent_digits = true;
+            }
+
+            if !has_exponent_digits {
+                return Err(LexError::InvalidNumber {
+                    literal: context.input[start_pos..context.position].to_string(),
+                    span: Span::new(start_pos, context.position, start_line, start_column),
+                });
+            }
+        }
+
         let literal = &context.input[start_pos..context.position];
         // Validate that it's a valid number format, but return as string
         match literal.parse::<f64>() {
@@ -108,6 +129,7 @@ impl LexerUtils {
             "match" => Token::Match,
             "import" => Token::Import,
             "export" => Token::Export,
+            "let" => Token::Let,
             "true" => Token::True,
             "false" => Token::False,
             "nil" => Token::Nil,
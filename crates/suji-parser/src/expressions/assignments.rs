@@ -3,6 +3,37 @@ use suji_ast::Expr;
 use suji_lexer::Token;
 
 impl Parser {
+    /// Parse `let target = value` after the `let` keyword has been consumed.
+    pub(crate) fn parse_let_declaration(&mut self) -> ParseResult<Expr> {
+        let span = self.previous().span.clone();
+
+        let mut target = self.parse_pipe_apply_backward()?;
+        if self.check(Token::Comma) && self.looks_like_destructure_pattern() {
+            target = self.parse_destructure_pattern(target)?;
+        }
+        let target = match target {
+            Expr::Literal(suji_ast::Literal::Tuple(elements, tuple_span)) => {
+                self.convert_tuple_to_destructure(elements, tuple_span)?
+            }
+            Expr::Literal(suji_ast::Literal::Identifier(_, _)) | Expr::Destructure { .. } => target,
+            _ => {
+                return Err(ParseError::Generic {
+                    message: "'let' can only declare identifiers or destructuring patterns"
+                        .to_string(),
+                });
+            }
+        };
+
+        self.consume(Token::Assign, "Expected '=' after 'let' binding")?;
+        let value = self.parse_assignment()?;
+
+        Ok(Expr::Let {
+            target: Box::new(target),
+            value: Box::new(value),
+            span,
+        })
+    }
+
     pub(crate) fn looks_like_destructure_pattern(&self) -> bool {
         let mut index = self.current;
         let mut expect_binding = false;
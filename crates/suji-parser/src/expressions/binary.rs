@@ -150,6 +150,10 @@ impl Parser {
 
     /// Parse assignment expressions (right-associative)
     pub(super) fn parse_assignment(&mut self) -> ParseResult<Expr> {
+        if self.match_token(Token::Let) {
+            return self.parse_let_declaration();
+        }
+
         let mut expr = self.parse_pipe_apply_backward()?;
 
         if self.check(Token::Comma) && self.looks_like_destructure_pattern() {
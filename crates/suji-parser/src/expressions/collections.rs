@@ -159,7 +159,13 @@ impl Parser {
             } else if self.match_token(Token::InterpStart) {
                 // Parse interpolated expression
                 let expr = self.expression()?;
-                parts.push(StringPart::Expr(expr));
+                if let Token::FormatSpec(spec) = &self.peek().token {
+                    let spec = spec.clone();
+                    self.advance();
+                    parts.push(StringPart::Formatted(expr, spec));
+                } else {
+                    parts.push(StringPart::Expr(expr));
+                }
                 self.consume(
                     Token::InterpEnd,
                     "Expected '}' after interpolated expression",
@@ -24,6 +24,10 @@ impl Parser {
         if self.match_token(Token::LeftBrace) {
             // Traditional block syntax: { statements }
             let statements = self.parse_block()?;
+            // The last statement is the function's implicit return value
+            if let Some(Stmt::Expr(tail)) = statements.last() {
+                self.check_boolean_match_coverage(tail)?;
+            }
             Ok(Stmt::Block {
                 statements,
                 span: start_span,
@@ -1,5 +1,5 @@
 use crate::{ParseError, ParseResult, Parser};
-use suji_ast::{Expr, MatchArm, Pattern, Stmt};
+use suji_ast::{Expr, MatchArm, Pattern, Stmt, ValueLike};
 use suji_lexer::{Span, Token};
 
 /// Type of content inside braces in match arms
@@ -12,8 +12,14 @@ enum BracedContentType {
 impl Parser {
     /// Parse match expression: match expr? { pattern => expr, ... } or match { condition => expr, ... }
     pub(super) fn parse_match_expression(&mut self) -> ParseResult<Expr> {
+        let starts_statement = self.statement_start == Some(self.current);
         if self.match_token(Token::Match) {
-            return self.parse_match_expression_impl();
+            let expr = self.parse_match_expression_impl()?;
+            // A match that makes up a whole statement has its value discarded
+            if !(starts_statement && self.at_statement_end()) {
+                self.check_boolean_match_coverage(&expr)?;
+            }
+            return Ok(expr);
         }
 
         let current = self.peek();
@@ -50,10 +56,16 @@ impl Parser {
         )?;
 
         let mut arms = Vec::new();
+        let mut catch_alls = CatchAlls::default();
         while !self.check(Token::RightBrace) && !self.is_at_end() {
             if scrutinee.is_none() {
                 // Conditional match: parse boolean expression as pattern
-                let condition_expr = if self.check(Token::Underscore) {
+                let condition_expr = if let Some(else_span) = self.match_else_arm() {
+                    // `else` reads like `_`: always true
+                    catch_alls.record_else(else_span.clone())?;
+                    Expr::Literal(suji_ast::Literal::Boolean(true, else_span))
+                } else if self.check(Token::Underscore) {
+                    catch_alls.record_wildcard(self.peek().span)?;
                     // Special case: wildcard in conditional match - treat as "true"
                     self.advance(); // consume the underscore
                     Expr::Literal(suji_ast::Literal::Boolean(
@@ -78,7 +90,21 @@ impl Parser {
                     span: span.clone(),
                 });
             } else {
-                // Traditional match: parse pattern(s) with alternation using '|'
+                // Traditional match: `else` is a non-binding catch-all, otherwise parse
+                // pattern(s) with alternation using '|'
+                if let Some(else_span) = self.match_else_arm() {
+                    catch_alls.record_else(else_span.clone())?;
+                    self.consume(Token::FatArrow, "Expected '=>' after match pattern")?;
+                    let body = self.parse_match_arm_body(span.clone())?;
+                    arms.push(MatchArm {
+                        pattern: Pattern::Wildcard { span: else_span },
+                        body,
+                        span: span.clone(),
+                    });
+                    self.finish_match_arm(&arms)?;
+                    continue;
+                }
+
                 let first_pattern = self.parse_pattern()?;
                 let mut patterns = vec![first_pattern];
                 while self.match_token(Token::Pipe) {
@@ -97,6 +123,13 @@ impl Parser {
                     patterns.push(next_pattern);
                 }
 
+                self.validate_pattern_bindings(&patterns)?;
+                if let Some(Pattern::Wildcard { span }) = patterns
+                    .iter()
+                    .find(|pattern| matches!(pattern, Pattern::Wildcard { .. }))
+                {
+                    catch_alls.record_wildcard(span.clone())?;
+                }
                 self.consume(Token::FatArrow, "Expected '=>' after match pattern")?;
 
                 // Parse body (either block or single expression)
@@ -112,23 +145,7 @@ impl Parser {
                 }
             }
 
-            // Comma is optional for braced block arm bodies. Required otherwise.
-            let allow_optional_comma =
-                matches!(arms.last().map(|a| &a.body), Some(Stmt::Block { .. }));
-
-            if allow_optional_comma {
-                if self.check(Token::Comma) {
-                    // Optional comma present; consume and proceed
-                    self.advance();
-                }
-                // Whether or not a comma was present, allow the next token to be
-                // either another arm or the closing '}'. Just normalize newlines.
-                self.skip_newlines();
-            } else {
-                // Require comma for non-braced bodies
-                self.consume(Token::Comma, "Expected ',' after match arm")?;
-                self.skip_newlines();
-            }
+            self.finish_match_arm(&arms)?;
         }
 
         self.consume(Token::RightBrace, "Expected '}' after match arms")?;
@@ -140,6 +157,41 @@ impl Parser {
         })
     }
 
+    /// Consume the separator after the last parsed arm
+    fn finish_match_arm(&mut self, arms: &[MatchArm]) -> ParseResult<()> {
+        // Comma is optional for braced block arm bodies. Required otherwise.
+        let allow_optional_comma = matches!(arms.last().map(|a| &a.body), Some(Stmt::Block { .. }));
+
+        if allow_optional_comma {
+            if self.check(Token::Comma) {
+                // Optional comma present; consume and proceed
+                self.advance();
+            }
+            // Whether or not a comma was present, allow the next token to be
+            // either another arm or the closing '}'. Just normalize newlines.
+            self.skip_newlines();
+        } else {
+            // Require comma for non-braced bodies
+            self.consume(Token::Comma, "Expected ',' after match arm")?;
+            self.skip_newlines();
+        }
+        Ok(())
+    }
+
+    /// Consume the `else` of an `else =>` arm, returning its span.
+    /// `else` is only special here; elsewhere it stays an ordinary name.
+    fn match_else_arm(&mut self) -> Option<Span> {
+        let is_else = matches!(&self.peek().token, Token::Identifier(name) if name == "else")
+            && matches!(
+                self.tokens.get(self.current + 1).map(|t| &t.token),
+                Some(Token::FatArrow)
+            );
+        if !is_else {
+            return None;
+        }
+        Some(self.advance().span.clone())
+    }
+
     /// Parse braced arm body - either { statements } or { map_literal }
     fn parse_braced_arm_body(&mut self, span: Span) -> ParseResult<Stmt> {
         self.advance(); // consume LeftBrace
@@ -282,4 +334,111 @@ impl Parser {
         self.current + 1 < self.tokens.len()
             && matches!(self.tokens[self.current + 1].token, Token::Colon)
     }
+
+    /// Whether the last token consumed ends a statement
+    fn at_statement_end(&self) -> bool {
+        matches!(self.previous().token, Token::Newline | Token::Comment(_))
+            || matches!(
+                self.peek().token,
+                Token::Semicolon | Token::RightBrace | Token::Eof
+            )
+    }
+
+    /// Reject a match whose value is used (`x = match flag { true => ... }`) when its arms
+    /// dispatch on `true`/`false` but leave one of them unhandled, since that case would
+    /// silently produce nil. Statement-level matches, the usual `if` idiom, are not checked,
+    /// except as the last statement of a function body, which is its return value.
+    pub(super) fn check_boolean_match_coverage(&self, value: &Expr) -> ParseResult<()> {
+        let Expr::Match {
+            scrutinee: Some(_),
+            arms,
+            ..
+        } = value
+        else {
+            return Ok(());
+        };
+
+        let is_boolean_dispatch = arms.iter().all(|arm| is_boolean_pattern(&arm.pattern))
+            && arms.iter().any(|arm| !arm.pattern.is_exhaustive());
+        if !is_boolean_dispatch {
+            return Ok(());
+        }
+
+        for case in [true, false] {
+            if !arms.iter().any(|arm| covers_boolean(&arm.pattern, case)) {
+                return Err(ParseError::Generic {
+                    message: format!(
+                        "Non-exhaustive match on a boolean: no arm handles `{}` (add a `{} =>` arm or a `_ =>` arm)",
+                        case, case
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The catch-all arms seen so far in one match: `_` and `else` may not both appear
+/// and `else` may appear only once. Errors point at the arm that breaks the rule.
+#[derive(Default)]
+struct CatchAlls {
+    wildcard: bool,
+    else_arm: bool,
+}
+
+impl CatchAlls {
+    fn record_wildcard(&mut self, span: Span) -> ParseResult<()> {
+        self.wildcard = true;
+        self.check(span)
+    }
+
+    fn record_else(&mut self, span: Span) -> ParseResult<()> {
+        if self.else_arm {
+            return Err(ParseError::DuplicateCatchAll {
+                message: "`else` appears more than once".to_string(),
+                span,
+            });
+        }
+        self.else_arm = true;
+        self.check(span)
+    }
+
+    fn check(&self, span: Span) -> ParseResult<()> {
+        if self.wildcard && self.else_arm {
+            return Err(ParseError::DuplicateCatchAll {
+                message: "use either `_ =>` or `else =>`, not both".to_string(),
+                span,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Whether a pattern only ever tests a boolean: `true`, `false`, `:boolean` or `_`
+fn is_boolean_pattern(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Literal {
+            value: ValueLike::Boolean(_),
+            ..
+        }
+        | Pattern::Wildcard { .. } => true,
+        Pattern::Type { name, .. } => name == "boolean",
+        Pattern::Binding { pattern, .. } => is_boolean_pattern(pattern),
+        Pattern::Or { patterns, .. } => patterns.iter().all(is_boolean_pattern),
+        _ => false,
+    }
+}
+
+/// Whether a boolean pattern matches `value`
+fn covers_boolean(pattern: &Pattern, value: bool) -> bool {
+    match pattern {
+        Pattern::Literal {
+            value: ValueLike::Boolean(b),
+            ..
+        } => *b == value,
+        Pattern::Wildcard { .. } | Pattern::Type { .. } => true,
+        Pattern::Binding { pattern, .. } => covers_boolean(pattern, value),
+        Pattern::Or { patterns, .. } => patterns.iter().any(|p| covers_boolean(p, value)),
+        _ => false,
+    }
 }
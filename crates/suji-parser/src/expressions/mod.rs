@@ -41,14 +41,14 @@ impl Parser {
             return Ok(expr);
         }
 
-        // Try parsing functions
-        if let Ok(expr) = self.parse_functions() {
-            return Ok(expr);
+        // Function literals report their own errors once `|` or `||` is seen
+        if self.check(Token::Pipe) || self.check(Token::Or) {
+            return self.parse_functions();
         }
 
-        // Try parsing match expressions
-        if let Ok(expr) = self.parse_match_expression() {
-            return Ok(expr);
+        // Match expressions report their own errors once `match` is seen
+        if self.check(Token::Match) {
+            return self.parse_match_expression();
         }
 
         // Try parsing shell commands
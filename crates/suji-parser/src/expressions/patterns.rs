@@ -1,14 +1,59 @@
 use crate::{ParseError, ParseResult, Parser};
 use suji_lexer::Token;
 
+/// Type names accepted by `:type` patterns (mirrors `Value::type_name()`)
+const TYPE_PATTERN_NAMES: &[&str] = &[
+    "number", "boolean", "string", "list", "map", "tuple", "regex", "function", "stream", "env",
+    "module", "nil",
+];
+
 impl Parser {
     /// Parse pattern for match statements
     pub(super) fn parse_pattern(&mut self) -> ParseResult<suji_ast::Pattern> {
         if self.match_token(Token::Underscore) {
             let span = self.previous().span.clone();
+            if self.match_token(Token::Plus) {
+                return self.parse_suffix_pattern(None, span);
+            }
             return Ok(suji_ast::Pattern::Wildcard { span });
         }
 
+        if self.match_token(Token::Colon) {
+            // Type pattern: :number, :string, ...
+            let colon_span = self.previous().span.clone();
+            let name = match &self.peek().token {
+                Token::Identifier(name) => name.clone(),
+                Token::Nil => "nil".to_string(),
+                _ => {
+                    let current = self.peek();
+                    return Err(ParseError::ExpectedToken {
+                        expected: Token::Identifier(String::new()),
+                        found: current.token,
+                        span: current.span,
+                    });
+                }
+            };
+            let name_span = self.advance().span.clone();
+
+            if !TYPE_PATTERN_NAMES.contains(&name.as_str()) {
+                return Err(ParseError::Generic {
+                    message: format!(
+                        "Unknown type pattern ':{}' (expected one of: {})",
+                        name,
+                        TYPE_PATTERN_NAMES.join(", ")
+                    ),
+                });
+            }
+
+            let span = suji_lexer::Span::new(
+                colon_span.start,
+                name_span.end,
+                colon_span.line,
+                colon_span.column,
+            );
+            return Ok(suji_ast::Pattern::Type { name, span });
+        }
+
         if self.match_token(Token::LeftParen) {
             // Tuple pattern
             let span = self.previous().span.clone();
@@ -16,7 +61,7 @@ impl Parser {
 
             if !self.check(Token::RightParen) {
                 loop {
-                    patterns.push(self.parse_pattern()?);
+                    patterns.push(self.parse_tuple_element_pattern()?);
                     if !self.match_token(Token::Comma) {
                         break;
                     }
@@ -30,6 +75,10 @@ impl Parser {
             return Ok(suji_ast::Pattern::Tuple { patterns, span });
         }
 
+        if self.match_token(Token::LeftBracket) {
+            return self.parse_list_pattern();
+        }
+
         if self.match_token(Token::RegexStart) {
             // Regex pattern
             let span = self.previous().span.clone();
@@ -48,38 +97,35 @@ impl Parser {
             }
         }
 
-        if self.match_token(Token::Minus) {
-            let minus_span = self.previous().span.clone();
-            if let Token::Number(n) = &self.peek().token {
-                let n = n.clone();
-                let number_span = self.advance().span.clone();
-                // Prepend minus sign to create negative number
-                let negative_n = format!("-{}", n);
-                // Combine spans from minus to number
-                let combined_span = suji_lexer::Span::new(
-                    minus_span.start,
-                    number_span.end,
-                    minus_span.line,
-                    minus_span.column,
-                );
-                return Ok(suji_ast::Pattern::Literal {
-                    value: suji_ast::ValueLike::Number(negative_n),
-                    span: combined_span,
-                });
+        if self.check(Token::Minus) || matches!(self.peek().token, Token::Number(_)) {
+            let (n, span) = self.parse_number_pattern_literal()?;
+
+            // Range pattern: 1..10 or 1..=10
+            let inclusive = if self.match_token(Token::RangeInclusive) {
+                Some(true)
+            } else if self.match_token(Token::Range) {
+                Some(false)
             } else {
-                // Minus not followed by a number - error
-                let current = self.peek();
-                return Err(ParseError::ExpectedToken {
-                    expected: Token::Number(String::new()),
-                    found: current.token,
-                    span: current.span,
+                None
+            };
+
+            if let Some(inclusive) = inclusive {
+                let (end, end_span) = self.parse_number_pattern_literal()?;
+                let combined_span =
+                    suji_lexer::Span::new(span.start, end_span.end, span.line, span.column);
+                let bound = |text: &str| {
+                    suji_ast::Pattern::parse_range_bound(text).ok_or_else(|| ParseError::Generic {
+                        message: format!("Range pattern bound '{}' is out of range", text),
+                    })
+                };
+                return Ok(suji_ast::Pattern::Range {
+                    start: bound(&n)?,
+                    end: bound(&end)?,
+                    inclusive,
+                    span: combined_span,
                 });
             }
-        }
 
-        if let Token::Number(n) = &self.peek().token {
-            let n = n.clone();
-            let span = self.advance().span.clone();
             return Ok(suji_ast::Pattern::Literal {
                 value: suji_ast::ValueLike::Number(n),
                 span,
@@ -110,49 +156,52 @@ impl Parser {
             });
         }
 
-        if self.match_token(Token::StringStart) {
-            // String literal pattern
-            let span = self.previous().span.clone();
-            let mut parts = Vec::new();
-
-            while !self.check(Token::StringEnd) && !self.is_at_end() {
-                if let Token::StringText(text) = &self.peek().token {
-                    let text = text.clone();
-                    self.advance();
-                    parts.push(suji_ast::StringPart::Text(text));
-                } else if self.match_token(Token::InterpStart) {
-                    let expr = self.expression()?;
-                    parts.push(suji_ast::StringPart::Expr(expr));
-                    self.consume(Token::InterpEnd, "Expected '}' after string interpolation")?;
-                } else {
-                    let current = self.peek();
-                    return Err(ParseError::UnexpectedToken {
-                        token: current.token,
-                        span: current.span,
-                    });
-                }
-            }
-
-            self.consume(Token::StringEnd, "Expected end of string literal")?;
+        if self.check(Token::StringStart) {
+            let (text, span) = self.parse_string_pattern_text()?;
 
-            // For pattern matching, we only support simple string literals (no interpolation)
-            if parts.len() == 1
-                && let suji_ast::StringPart::Text(text) = &parts[0]
-            {
-                return Ok(suji_ast::Pattern::Literal {
-                    value: suji_ast::ValueLike::String(text.clone()),
-                    span,
+            // Prefix pattern: "go " + rest, optionally followed by + "suffix"
+            if self.match_token(Token::Plus) {
+                let rest = self.parse_affix_rest()?;
+                let suffix = match self.match_token(Token::Plus) {
+                    true => self.parse_string_pattern_text()?.0,
+                    false => String::new(),
+                };
+                let end = self.previous().span.clone();
+                return Ok(suji_ast::Pattern::StringAffix {
+                    prefix: text,
+                    rest,
+                    suffix,
+                    span: suji_lexer::Span::new(span.start, end.end, span.line, span.column),
                 });
             }
 
-            return Err(ParseError::Generic {
-                message: "String patterns cannot contain interpolation".to_string(),
+            return Ok(suji_ast::Pattern::Literal {
+                value: suji_ast::ValueLike::String(text),
+                span,
             });
         }
 
         if let Token::Identifier(name) = &self.peek().token {
             let name = name.clone();
             let span = self.advance().span.clone();
+
+            // Binding pattern: name @ pattern
+            if self.match_token(Token::At) {
+                let pattern = self.parse_pattern()?;
+                let span =
+                    suji_lexer::Span::new(span.start, pattern.span().end, span.line, span.column);
+                return Ok(suji_ast::Pattern::Binding {
+                    name,
+                    pattern: Box::new(pattern),
+                    span,
+                });
+            }
+
+            // Suffix pattern: rest + ".txt"
+            if self.match_token(Token::Plus) {
+                return self.parse_suffix_pattern(Some(name), span);
+            }
+
             return Ok(suji_ast::Pattern::Literal {
                 value: suji_ast::ValueLike::String(name),
                 span,
@@ -166,4 +215,213 @@ impl Parser {
             span: current.span,
         })
     }
+
+    /// Parse a list pattern after the `[`: [a, b], [first, ...rest] or [..._, last]
+    fn parse_list_pattern(&mut self) -> ParseResult<suji_ast::Pattern> {
+        let span = self.previous().span.clone();
+        let mut before = Vec::new();
+        let mut rest = None;
+        let mut after = Vec::new();
+
+        if !self.check(Token::RightBracket) {
+            loop {
+                if self.match_token(Token::Ellipsis) {
+                    let rest_span = self.previous().span.clone();
+                    if rest.is_some() {
+                        return Err(ParseError::Generic {
+                            message: "A list pattern can have at most one '...' rest".to_string(),
+                        });
+                    }
+                    let name = match &self.peek().token {
+                        Token::Underscore => None,
+                        Token::Identifier(name) => Some(name.clone()),
+                        _ => {
+                            return Err(ParseError::Generic {
+                                message: "Expected a name or '_' after '...' in a list pattern"
+                                    .to_string(),
+                            });
+                        }
+                    };
+                    self.advance();
+                    rest = Some(suji_ast::ListRest {
+                        name,
+                        span: rest_span,
+                    });
+                } else if rest.is_some() {
+                    after.push(self.parse_tuple_element_pattern()?);
+                } else {
+                    before.push(self.parse_tuple_element_pattern()?);
+                }
+
+                if !self.match_token(Token::Comma) || self.check(Token::RightBracket) {
+                    break;
+                }
+            }
+        }
+
+        self.consume(Token::RightBracket, "Expected ']' after list pattern")?;
+        Ok(suji_ast::Pattern::List {
+            before,
+            rest,
+            after,
+            span,
+        })
+    }
+
+    /// Parse a plain string literal in pattern position (no interpolation)
+    fn parse_string_pattern_text(&mut self) -> ParseResult<(String, suji_lexer::Span)> {
+        self.consume(Token::StringStart, "Expected a string literal")?;
+        let span = self.previous().span.clone();
+        let mut text = String::new();
+
+        while !self.check(Token::StringEnd) && !self.is_at_end() {
+            if let Token::StringText(part) = &self.peek().token {
+                text.push_str(part);
+                self.advance();
+            } else if self.check(Token::InterpStart) {
+                return Err(ParseError::Generic {
+                    message: "String patterns cannot contain interpolation".to_string(),
+                });
+            } else {
+                let current = self.peek();
+                return Err(ParseError::UnexpectedToken {
+                    token: current.token,
+                    span: current.span,
+                });
+            }
+        }
+
+        self.consume(Token::StringEnd, "Expected end of string literal")?;
+        Ok((text, span))
+    }
+
+    /// Parse the captured part of a string affix pattern after `+`: a name or `_`
+    fn parse_affix_rest(&mut self) -> ParseResult<Option<String>> {
+        if self.match_token(Token::Underscore) {
+            return Ok(None);
+        }
+        if let Token::Identifier(name) = &self.peek().token {
+            let name = name.clone();
+            self.advance();
+            return Ok(Some(name));
+        }
+        Err(ParseError::Generic {
+            message: "Expected a name or '_' after '+' in a string pattern".to_string(),
+        })
+    }
+
+    /// Parse the `"suffix"` of a `rest + "suffix"` pattern, after the `+`
+    fn parse_suffix_pattern(
+        &mut self,
+        rest: Option<String>,
+        span: suji_lexer::Span,
+    ) -> ParseResult<suji_ast::Pattern> {
+        if !self.check(Token::StringStart) {
+            return Err(ParseError::Generic {
+                message: "Expected a string literal after '+' in a string pattern".to_string(),
+            });
+        }
+        let (suffix, _) = self.parse_string_pattern_text()?;
+        let end = self.previous().span.clone();
+        Ok(suji_ast::Pattern::StringAffix {
+            prefix: String::new(),
+            rest,
+            suffix,
+            span: suji_lexer::Span::new(span.start, end.end, span.line, span.column),
+        })
+    }
+
+    /// Parse a tuple or list element pattern, allowing `|` alternatives: (1 | 2, _)
+    fn parse_tuple_element_pattern(&mut self) -> ParseResult<suji_ast::Pattern> {
+        let first = self.parse_pattern()?;
+        if !self.check(Token::Pipe) {
+            return Ok(first);
+        }
+
+        let span = first.span().clone();
+        let mut patterns = vec![first];
+        while self.match_token(Token::Pipe) {
+            patterns.push(self.parse_pattern()?);
+        }
+        Ok(suji_ast::Pattern::Or { patterns, span })
+    }
+
+    /// Reject patterns that bind a name twice, or whose alternatives bind different names
+    pub(super) fn validate_pattern_bindings(
+        &self,
+        alternatives: &[suji_ast::Pattern],
+    ) -> ParseResult<()> {
+        let mut expected: Option<Vec<&str>> = None;
+        for pattern in alternatives {
+            let mut names = pattern.bound_names();
+            names.sort_unstable();
+            if let Some(name) = names.windows(2).find(|w| w[0] == w[1]).map(|w| w[0]) {
+                return Err(ParseError::Generic {
+                    message: format!("'{}' is bound more than once in the same pattern", name),
+                });
+            }
+            match &expected {
+                Some(first) if *first != names => {
+                    return Err(ParseError::Generic {
+                        message: "All alternatives of a pattern must bind the same names"
+                            .to_string(),
+                    });
+                }
+                Some(_) => {}
+                None => expected = Some(names),
+            }
+            self.validate_nested_bindings(pattern)?;
+        }
+        Ok(())
+    }
+
+    fn validate_nested_bindings(&self, pattern: &suji_ast::Pattern) -> ParseResult<()> {
+        match pattern {
+            suji_ast::Pattern::Or { patterns, .. } => self.validate_pattern_bindings(patterns),
+            suji_ast::Pattern::Tuple { patterns, .. } => patterns
+                .iter()
+                .try_for_each(|p| self.validate_nested_bindings(p)),
+            suji_ast::Pattern::Binding { pattern, .. } => self.validate_nested_bindings(pattern),
+            suji_ast::Pattern::List { before, after, .. } => before
+                .iter()
+                .chain(after)
+                .try_for_each(|p| self.validate_nested_bindings(p)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse a numeric literal in pattern position, with an optional leading minus
+    fn parse_number_pattern_literal(&mut self) -> ParseResult<(String, suji_lexer::Span)> {
+        let minus_span = if self.match_token(Token::Minus) {
+            Some(self.previous().span.clone())
+        } else {
+            None
+        };
+
+        if let Token::Number(n) = &self.peek().token {
+            let n = n.clone();
+            let number_span = self.advance().span.clone();
+            return Ok(match minus_span {
+                // Prepend minus sign and combine spans from minus to number
+                Some(minus_span) => (
+                    format!("-{}", n),
+                    suji_lexer::Span::new(
+                        minus_span.start,
+                        number_span.end,
+                        minus_span.line,
+                        minus_span.column,
+                    ),
+                ),
+                None => (n, number_span),
+            });
+        }
+
+        // Minus (or range operator) not followed by a number - error
+        let current = self.peek();
+        Err(ParseError::ExpectedToken {
+            expected: Token::Number(String::new()),
+            found: current.token,
+            span: current.span,
+        })
+    }
 }
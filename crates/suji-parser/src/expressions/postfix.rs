@@ -3,31 +3,44 @@ use crate::{ParseError, ParseResult, Parser};
 use suji_ast::Expr;
 use suji_lexer::Token;
 
+/// Positional arguments and `name: value` arguments of a call
+type CallArguments = (Vec<Expr>, Vec<(String, Expr)>);
+
 impl Parser {
-    /// Parse a comma-separated argument list until the specified end token
-    /// Supports trailing commas and empty argument lists
-    fn parse_argument_list(&mut self, end_token: Token) -> ParseResult<Vec<Expr>> {
+    /// Parse a call's arguments up to `)`: positional ones, then `name: value` ones.
+    /// Supports trailing commas and empty argument lists. Method calls pass
+    /// `allow_named = false`, since methods only take positional arguments.
+    fn parse_argument_list(&mut self, allow_named: bool) -> ParseResult<CallArguments> {
         let mut args = Vec::new();
+        let mut named_args: Vec<(String, Expr)> = Vec::new();
 
         // Handle empty argument list
-        if !self.check(end_token.clone()) {
+        if !self.check(Token::RightParen) {
             loop {
-                // Parse each argument expression
-                args.push(self.expression()?);
-
-                // Check for comma separator
-                if !self.match_token(Token::Comma) {
-                    break;
+                if self.is_named_argument() {
+                    let (name, span) = self.consume_identifier()?;
+                    if !allow_named {
+                        return Err(ParseError::NamedArgumentInMethodCall { name, span });
+                    }
+                    self.advance(); // consume the colon
+                    named_args.push((name, self.expression()?));
+                } else if let Some((name, _)) = named_args.last() {
+                    return Err(ParseError::PositionalAfterNamed {
+                        name: name.clone(),
+                        span: self.peek().span.clone(),
+                    });
+                } else {
+                    args.push(self.expression()?);
                 }
 
                 // Allow trailing comma - break if we see the end token
-                if self.check(end_token.clone()) {
+                if !self.match_token(Token::Comma) || self.check(Token::RightParen) {
                     break;
                 }
             }
         }
 
-        Ok(args)
+        Ok((args, named_args))
     }
 
     /// Parse postfix expressions (calls, indexing, method calls, etc.)
@@ -52,7 +65,14 @@ impl Parser {
                 && self.expression_context != ExpressionContext::NoColonAccess
             {
                 // Map access by name (skip if in NoColonAccess context for slice parsing)
+                let colon_end = self.peek().span.end;
                 self.advance(); // consume the colon
+                if colon_end < self.peek().span.start {
+                    // `m: k` would read as a named argument inside a call
+                    return Err(ParseError::SpacedMapAccess {
+                        span: self.peek().span.clone(),
+                    });
+                }
                 expr = self.finish_map_access(expr)?;
             } else if self.match_token(Token::Increment) {
                 // Postfix increment
@@ -76,10 +96,9 @@ impl Parser {
         Ok(expr)
     }
 
-    /// Finish parsing a function call
+    /// Finish parsing a function call: positional arguments, then `name: value` ones
     pub(super) fn finish_call(&mut self, callee: Expr) -> ParseResult<Expr> {
-        // Use shared argument parser
-        let args = self.parse_argument_list(Token::RightParen)?;
+        let (args, named_args) = self.parse_argument_list(true)?;
 
         let span = self
             .consume(Token::RightParen, "Expected ')' after function arguments")?
@@ -88,10 +107,26 @@ impl Parser {
         Ok(Expr::Call {
             callee: Box::new(callee),
             args,
+            named_args,
             span,
         })
     }
 
+    /// Whether the next argument is `name: value`. The space after the colon is what
+    /// separates it from `map:key` access, which must be written without one.
+    fn is_named_argument(&self) -> bool {
+        let (Some(name), Some(colon), Some(value)) = (
+            self.tokens.get(self.current),
+            self.tokens.get(self.current + 1),
+            self.tokens.get(self.current + 2),
+        ) else {
+            return false;
+        };
+        matches!(name.token, Token::Identifier(_))
+            && colon.token == Token::Colon
+            && colon.span.end < value.span.start
+    }
+
     /// Finish parsing indexing or slicing
     pub(super) fn finish_index_or_slice(&mut self, target: Expr) -> ParseResult<Expr> {
         if self.match_token(Token::Colon) {
@@ -149,18 +184,20 @@ impl Parser {
 
     /// Finish parsing a method call
     pub(super) fn finish_method_call(&mut self, receiver: Expr) -> ParseResult<Expr> {
+        // `::` has already been consumed; the call's own span runs from it to `)`
+        let mut span = self.previous().span.clone();
         if let Token::Identifier(_) = &self.peek().token {
             let (method_name, _span) = self.consume_identifier()?;
 
             self.consume(Token::LeftParen, "Expected '(' after method name")?;
 
             // Use shared argument parser
-            let args = self.parse_argument_list(Token::RightParen)?;
+            let (args, _) = self.parse_argument_list(false)?;
 
-            let span = self
+            span.end = self
                 .consume(Token::RightParen, "Expected ')' after method arguments")?
                 .span
-                .clone();
+                .end;
             Ok(Expr::MethodCall {
                 target: Box::new(receiver),
                 method: method_name,
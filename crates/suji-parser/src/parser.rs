@@ -40,6 +40,14 @@ pub enum ParseError {
     InvalidAlias { span: Span },
     #[error("Multiple export statements found. Only one export statement is allowed per file.")]
     MultipleExports { span: Span },
+    #[error("Duplicate catch-all arms in match: {message}")]
+    DuplicateCatchAll { message: String, span: Span },
+    #[error("Positional arguments must come before named ones (found one after '{name}: ...')")]
+    PositionalAfterNamed { name: String, span: Span },
+    #[error("Methods only take positional arguments (found '{name}: ...')")]
+    NamedArgumentInMethodCall { name: String, span: Span },
+    #[error("Map access is written without a space after ':'")]
+    SpacedMapAccess { span: Span },
 }
 
 /// Main parser result type
@@ -52,6 +60,8 @@ pub struct Parser {
     pub(super) export_count: usize,
     // Current expression parsing context controlling postfix handling
     pub(super) expression_context: ExpressionContext,
+    // Token index where the latest expression statement starts
+    pub(super) statement_start: Option<usize>,
 }
 
 impl Parser {
@@ -61,6 +71,7 @@ impl Parser {
             current: 0,
             export_count: 0,
             expression_context: ExpressionContext::Default,
+            statement_start: None,
         };
         parser.skip_newlines_and_comments();
         parser
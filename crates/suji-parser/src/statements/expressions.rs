@@ -4,6 +4,7 @@ use suji_ast::Stmt;
 impl Parser {
     /// Parse expression statement (default case when no other statement type matches)
     pub(super) fn parse_expression_statement(&mut self) -> ParseResult<Stmt> {
+        self.statement_start = Some(self.current);
         let expr = self.expression()?;
         Ok(Stmt::Expr(expr))
     }
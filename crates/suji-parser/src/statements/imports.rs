@@ -8,6 +8,10 @@ impl Parser {
     pub(super) fn parse_import_statement(&mut self) -> ParseResult<Stmt> {
         let span = self.previous().span.clone();
 
+        if self.match_token(Token::StringStart) {
+            return self.parse_file_import(span);
+        }
+
         if let Token::Identifier(module_name) = &self.peek().token {
             let module_name = module_name.clone();
             let module_span = self.advance().span.clone();
@@ -18,6 +22,11 @@ impl Parser {
                 // after the first (module) name.
                 let (segments, _path_span) =
                     self.parse_colon_path_from(module_name.clone(), module_span, true)?;
+
+                // import module:{item, other} - the path stops at a ':' followed by '{'
+                if self.previous().token == Token::Colon && self.match_token(Token::LeftBrace) {
+                    return self.parse_import_items(segments.join(":"), span);
+                }
                 let (module_path, item_name) = segments
                     .split_last()
                     .map(|(last, rest)| (rest.join(":"), last.to_string()))
@@ -64,6 +73,76 @@ impl Parser {
         }
     }
 
+    /// Parse the item list after `module:{`: names separated by commas, up to `}`
+    fn parse_import_items(&mut self, module: String, span: suji_lexer::Span) -> ParseResult<Stmt> {
+        let mut names = Vec::new();
+        loop {
+            self.skip_newlines_and_comments();
+            if self.check(Token::RightBrace) {
+                break;
+            }
+            let (name, _name_span) = self.consume_identifier()?;
+            names.push(name);
+            self.skip_newlines_and_comments();
+            if !self.match_token(Token::Comma) {
+                break;
+            }
+        }
+        self.consume(Token::RightBrace, "Expected '}' after imported items")?;
+
+        if names.is_empty() {
+            return Err(ParseError::Generic {
+                message: format!("Expected at least one item name in import {}:{{}}", module),
+            });
+        }
+
+        Ok(Stmt::Import {
+            spec: suji_ast::ImportSpec::Items { module, names },
+            span,
+        })
+    }
+
+    /// Parse file import after the opening quote: import "path" as alias
+    fn parse_file_import(&mut self, span: suji_lexer::Span) -> ParseResult<Stmt> {
+        let path = match &self.peek().token {
+            Token::StringText(text) => {
+                let text = text.clone();
+                self.advance();
+                text
+            }
+            _ => {
+                return Err(ParseError::Generic {
+                    message: "File import path must be a plain string without interpolation"
+                        .to_string(),
+                });
+            }
+        };
+        if !self.match_token(Token::StringEnd) {
+            return Err(ParseError::Generic {
+                message: "File import path must be a plain string without interpolation"
+                    .to_string(),
+            });
+        }
+
+        if !self.match_token(Token::As) {
+            return Err(ParseError::Generic {
+                message: format!("File imports require an alias: import \"{}\" as name", path),
+            });
+        }
+        let (alias, _alias_span) = match self.consume_identifier() {
+            Ok(v) => v,
+            Err(_) => {
+                let current = self.peek();
+                return Err(ParseError::InvalidAlias { span: current.span });
+            }
+        };
+
+        Ok(Stmt::Import {
+            spec: suji_ast::ImportSpec::File { path, alias },
+            span,
+        })
+    }
+
     /// Parse export statement: export { name: expr, ... } | export <expr>
     pub(super) fn parse_export_statement(&mut self) -> ParseResult<Stmt> {
         let span = self.previous().span.clone();
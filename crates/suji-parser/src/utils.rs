@@ -43,8 +43,8 @@ impl Parser {
                 segments.push(segment);
                 saw_additional = true;
             } else {
-                // Trailing ':' without an identifier
-                if require_additional_segment && !saw_additional {
+                // Trailing ':' without an identifier; before '{' it opens an import item list
+                if require_additional_segment && !saw_additional && !self.check(Token::LeftBrace) {
                     let current = self.peek();
                     return Err(ParseError::InvalidImportPath { span: current.span });
                 }
@@ -7,7 +7,7 @@ use suji_interpreter::{AstInterpreter, eval_module_source_callback};
 use suji_lexer::Lexer;
 use suji_parser::{ParseError, Parser};
 use suji_runtime::{Executor, ModuleRegistry};
-use suji_stdlib::{setup_global_env, setup_module_registry};
+use suji_stdlib::{setup_global_env, setup_module_registry, to_pretty_json};
 use suji_values::{Env, RuntimeError, Value};
 
 /// REPL state and configuration
@@ -18,12 +18,15 @@ pub struct Repl {
     interpreter: AstInterpreter,
     input_buffer: String,
     line_number: usize,
+    /// Print lists and maps as indented multi-line output (toggled with `:pretty`)
+    pretty: bool,
 }
 
 impl Repl {
     /// Create a new REPL instance
     pub fn new() -> RustylineResult<Self> {
         let editor = DefaultEditor::new()?;
+        install_interrupt_handler();
 
         // Set up global environment with built-ins
         let env = Rc::new(Env::new());
@@ -44,6 +47,7 @@ impl Repl {
             interpreter,
             input_buffer: String::new(),
             line_number: 1,
+            pretty: false,
         })
     }
 
@@ -70,6 +74,10 @@ impl Repl {
                         self.print_help();
                         continue;
                     }
+                    if let Some(setting) = trimmed.strip_prefix(":pretty") {
+                        self.set_pretty(setting.trim());
+                        continue;
+                    }
 
                     // Add line to input buffer
                     if !self.input_buffer.is_empty() {
@@ -167,6 +175,7 @@ impl Repl {
         match parser.parse() {
             Ok(statements) => {
                 // Evaluate using execute_stmt for each statement
+                AstInterpreter::clear_interrupt();
                 let mut last_value = None;
                 for stmt in &statements {
                     match self.interpreter.execute_stmt(
@@ -178,6 +187,10 @@ impl Repl {
                             last_value = Some(value);
                         }
                         Ok(None) => {}
+                        Err(e) if matches!(e.without_span(), RuntimeError::Interrupted) => {
+                            println!("Interrupted");
+                            return;
+                        }
                         Err(e) => {
                             self.print_runtime_error(&e, input);
                             return;
@@ -189,7 +202,7 @@ impl Repl {
                 if let Some(value) = last_value
                     && !matches!(value, Value::Nil)
                 {
-                    println!("{}", value);
+                    println!("{}", self.format_result(&value));
                 }
             }
             Err(e) => {
@@ -198,6 +211,34 @@ impl Repl {
         }
     }
 
+    /// Format a result for display, expanding lists and maps when pretty mode is on
+    fn format_result(&self, value: &Value) -> String {
+        if self.pretty && matches!(value, Value::List(_) | Value::Map(_)) {
+            // Values JSON cannot represent (functions, tuple keys, ...) fall back to Display
+            if let Ok(pretty) = to_pretty_json(value) {
+                return pretty;
+            }
+        }
+        value.to_string()
+    }
+
+    /// Handle `:pretty on|off` (bare `:pretty` reports the current setting)
+    fn set_pretty(&mut self, setting: &str) {
+        match setting {
+            "on" => self.pretty = true,
+            "off" => self.pretty = false,
+            "" => {}
+            _ => {
+                println!("Usage: :pretty on|off");
+                return;
+            }
+        }
+        println!(
+            "Pretty printing is {}",
+            if self.pretty { "on" } else { "off" }
+        );
+    }
+
     /// Print a parse error with enhanced formatting
     fn print_parse_error(&self, error: &ParseError, input: &str) {
         let context = DiagnosticContext::new(input.to_string());
@@ -220,7 +261,9 @@ impl Repl {
     fn print_welcome(&self) {
         println!("{}", "SUJI Language REPL".fg(Color::Cyan));
         println!("Type expressions to evaluate them, or :help for commands");
-        println!("Use Ctrl+C to cancel current input, Ctrl+D or :quit to exit");
+        println!(
+            "Use Ctrl+C to cancel current input or stop a running evaluation, Ctrl+D or :quit to exit"
+        );
         println!();
     }
 
@@ -230,12 +273,31 @@ impl Repl {
         println!("  :help      - Show this help message");
         println!("  :quit      - Exit the REPL");
         println!("  :exit      - Exit the REPL");
+        println!("  :pretty on - Print lists and maps across multiple indented lines");
+        println!("  :pretty off - Print all results compactly (default)");
         println!();
         println!("Tips:");
         println!("  - Multi-line input is supported - keep typing until braces are balanced");
-        println!("  - Use Ctrl+C to cancel current input");
+        println!("  - Use Ctrl+C to cancel current input or stop a running evaluation");
         println!("  - Use Ctrl+D to exit");
         println!("  - Variable assignments persist between inputs");
         println!();
     }
 }
+
+/// Route SIGINT to the interpreter's interrupt flag so Ctrl+C stops a running
+/// evaluation instead of killing the REPL. rustyline swaps in its own handler while
+/// reading a line and restores this one afterwards.
+#[cfg(unix)]
+fn install_interrupt_handler() {
+    extern "C" fn on_sigint(_signal: libc::c_int) {
+        AstInterpreter::interrupt();
+    }
+    // SAFETY: the handler only stores to an atomic, which is async-signal-safe
+    unsafe {
+        libc::signal(libc::SIGINT, on_sigint as *const () as libc::sighandler_t);
+    }
+}
+
+#[cfg(not(unix))]
+fn install_interrupt_handler() {}
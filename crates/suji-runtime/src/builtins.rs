@@ -4,13 +4,28 @@ use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::RwLock;
-use suji_values::{RuntimeError, Value};
+use suji_values::methods::ClosureEvaluator;
+use suji_values::{NativeFn, RuntimeError, Value};
 
-/// Type for builtin function implementations
-pub type BuiltinFn = fn(&[Value]) -> Result<Value, RuntimeError>;
+/// Type for builtin function implementations.
+///
+/// A builtin receives the call's positional arguments already evaluated (it checks
+/// their count and types itself) and returns the result value, or a `RuntimeError`
+/// that is raised in the calling script. Builtins do not accept named arguments.
+pub type BuiltinFn = NativeFn;
+
+/// Type for builtins that call script functions, such as the `on_change` callback of
+/// os:watch. `call_fn` runs a function value with the caller's module registry.
+pub type CallbackBuiltinFn = fn(&[Value], ClosureEvaluator) -> Result<Value, RuntimeError>;
+
+#[derive(Clone, Copy)]
+enum Builtin {
+    Plain(BuiltinFn),
+    WithCallbacks(CallbackBuiltinFn),
+}
 
 /// Global registry of builtin functions
-static BUILTIN_REGISTRY: Lazy<RwLock<HashMap<String, BuiltinFn>>> =
+static BUILTIN_REGISTRY: Lazy<RwLock<HashMap<String, Builtin>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
 /// Register a builtin function
@@ -18,24 +33,49 @@ pub fn register_builtin(name: impl Into<String>, func: BuiltinFn) {
     let mut registry = BUILTIN_REGISTRY
         .write()
         .expect("Failed to acquire write lock on builtin registry");
-    registry.insert(name.into(), func);
+    registry.insert(name.into(), Builtin::Plain(func));
+}
+
+/// Register a builtin function that calls back into script functions
+pub fn register_callback_builtin(name: impl Into<String>, func: CallbackBuiltinFn) {
+    let mut registry = BUILTIN_REGISTRY
+        .write()
+        .expect("Failed to acquire write lock on builtin registry");
+    registry.insert(name.into(), Builtin::WithCallbacks(func));
 }
 
-/// Call a builtin function by name
+/// Call a builtin function by name, without a way to call script functions
 pub fn call_builtin(name: &str, args: &[Value]) -> Result<Value, RuntimeError> {
-    let registry = BUILTIN_REGISTRY
+    call_builtin_with_callbacks(name, args, &|_, _, _| {
+        Err(RuntimeError::InvalidOperation {
+            message: format!("Builtin function '{}' cannot call functions here", name),
+        })
+    })
+}
+
+/// Call a builtin function by name. `call_fn` is handed to builtins that call back
+/// into script functions.
+pub fn call_builtin_with_callbacks(
+    name: &str,
+    args: &[Value],
+    call_fn: ClosureEvaluator,
+) -> Result<Value, RuntimeError> {
+    // Copy the function out so callbacks can call other builtins without holding the lock
+    let builtin = BUILTIN_REGISTRY
         .read()
-        .expect("Failed to acquire read lock on builtin registry");
+        .expect("Failed to acquire read lock on builtin registry")
+        .get(name)
+        .copied();
 
-    if let Some(func) = registry.get(name) {
-        func(args)
-    } else {
-        Err(RuntimeError::MethodError {
+    match builtin {
+        Some(Builtin::Plain(func)) => func(args),
+        Some(Builtin::WithCallbacks(func)) => func(args, call_fn),
+        None => Err(RuntimeError::MethodError {
             message: format!(
                 "Builtin function '{}' not found. Ensure stdlib is initialized.",
                 name
             ),
-        })
+        }),
     }
 }
 
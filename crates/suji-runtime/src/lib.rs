@@ -7,12 +7,13 @@ pub use executor::Executor;
 // Module system
 pub mod module_registry;
 pub use module_registry::{
-    CacheKey, LoadGuard, ModuleLoadAction, ModuleRegistry, SourceEvaluator, VirtualStdResolver,
-    VirtualStdResult,
+    CacheKey, DataLoader, LoadGuard, ModuleLoadAction, ModuleRegistry, SourceEvaluator,
+    VirtualStdResolver, VirtualStdResult,
 };
 
 // Builtin function registry
 pub mod builtins;
 pub use builtins::{
-    BuiltinFn, call_builtin, create_std_module, list_builtins, register_builtin, setup_global_env,
+    BuiltinFn, CallbackBuiltinFn, call_builtin, call_builtin_with_callbacks, create_std_module,
+    list_builtins, register_builtin, register_callback_builtin, setup_global_env,
 };
@@ -1,4 +1,4 @@
-use crate::builtins::list_builtins;
+use crate::builtins::{BuiltinFn, list_builtins};
 use crate::executor::Executor;
 use indexmap::IndexMap;
 use std::cell::RefCell;
@@ -7,7 +7,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use suji_values::{
-    Env, EnvProxy, FunctionBody, FunctionValue, MapKey, ModuleHandle, RuntimeError,
+    Env, EnvProxy, FunctionBody, FunctionValue, MapKey, ModuleHandle, NativeFunction, RuntimeError,
     StreamProxyKind, Value,
 };
 
@@ -16,6 +16,11 @@ use suji_values::{
 pub type SourceEvaluator =
     fn(&dyn Executor, &str, Rc<Env>, &ModuleRegistry) -> Result<Value, RuntimeError>;
 
+/// Type for data file loader callback
+/// Takes a lowercase file extension and the file contents; returns None when the
+/// extension is not a data format, so the file is evaluated as source instead
+pub type DataLoader = fn(&str, &str) -> Option<Result<Value, RuntimeError>>;
+
 /// Result from virtual std resolution
 #[derive(Debug, Clone)]
 pub enum VirtualStdResult {
@@ -95,6 +100,9 @@ impl<'a> Drop for LoadGuard<'a> {
     }
 }
 
+/// Module names embedders cannot register modules or functions under
+const RESERVED_MODULES: [&str; 2] = ["std", "__builtins__"];
+
 /// Registry for managing modules in the SUJI language runtime
 #[derive(Clone)]
 pub struct ModuleRegistry {
@@ -110,6 +118,8 @@ pub struct ModuleRegistry {
     virtual_std_resolver: Option<VirtualStdResolver>,
     /// Source evaluator callback (parse-agnostic, set by runtime)
     source_evaluator: Option<SourceEvaluator>,
+    /// Optional data file loader for `import "file.json" as name` (set by suji-stdlib)
+    data_loader: Option<DataLoader>,
 }
 
 // Manual Debug implementation since function pointers don't impl Debug
@@ -122,6 +132,7 @@ impl std::fmt::Debug for ModuleRegistry {
             .field("dir_stack", &self.dir_stack)
             .field("virtual_std_resolver", &self.virtual_std_resolver.is_some())
             .field("source_evaluator", &self.source_evaluator.is_some())
+            .field("data_loader", &self.data_loader.is_some())
             .finish()
     }
 }
@@ -144,6 +155,7 @@ impl ModuleRegistry {
             dir_stack: RefCell::new(Vec::new()),
             virtual_std_resolver: None,
             source_evaluator: None,
+            data_loader: None,
         };
 
         // Register built-in modules
@@ -161,6 +173,11 @@ impl ModuleRegistry {
         self.source_evaluator = Some(evaluator);
     }
 
+    /// Set the data file loader callback
+    pub fn set_data_loader(&mut self, loader: DataLoader) {
+        self.data_loader = Some(loader);
+    }
+
     /// Create a clone of this registry with a custom override for the `std` module
     pub fn with_custom_std(&self, std_value: Value) -> Self {
         let mut new_registry = self.clone();
@@ -168,6 +185,77 @@ impl ModuleRegistry {
         new_registry
     }
 
+    /// Register `module` (usually a map of exported items) under `name`, so scripts can
+    /// `import name` or `import name:item`. Fails if `name` is already a registered
+    /// module or is reserved (`std`, `__builtins__`).
+    pub fn register_module(
+        &mut self,
+        name: impl Into<String>,
+        module: Value,
+    ) -> Result<(), RuntimeError> {
+        let name = name.into();
+        if RESERVED_MODULES.contains(&name.as_str()) || self.builtins.contains_key(&name) {
+            return Err(RuntimeError::InvalidOperation {
+                message: format!("Module '{}' is already registered", name),
+            });
+        }
+        self.builtins.insert(name, module);
+        Ok(())
+    }
+
+    /// Register a native function as `name` in the module `module`, creating the module
+    /// if needed, so scripts can call it after `import module:name`. The function lives
+    /// only in this registry; other registries can bind a different function under the
+    /// same name. Fails if the module already has an item called `name`, if the module
+    /// is reserved (`std`, `__builtins__`) or if it was registered as something other
+    /// than a map.
+    pub fn register_function(
+        &mut self,
+        module: &str,
+        name: &str,
+        func: BuiltinFn,
+    ) -> Result<(), RuntimeError> {
+        if RESERVED_MODULES.contains(&module) {
+            return Err(RuntimeError::InvalidOperation {
+                message: format!("Cannot register functions in the '{}' module", module),
+            });
+        }
+        let key = MapKey::String(name.to_string());
+        match self.builtins.get(module) {
+            Some(Value::Map(items)) if items.contains_key(&key) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!("Module '{}' already has an item '{}'", module, name),
+                });
+            }
+            Some(Value::Map(_)) | None => {}
+            Some(_) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!("Module '{}' is not a valid module (not a map)", module),
+                });
+            }
+        }
+
+        let wrapper = Value::Function(FunctionValue {
+            params: vec![], // Arity checking is left to the native function
+            body: FunctionBody::Native(NativeFunction {
+                name: format!("{}:{}", module, name),
+                func,
+            }),
+            env: Rc::new(Env::new()),
+        });
+        match self
+            .builtins
+            .entry(module.to_string())
+            .or_insert_with(|| Value::Map(IndexMap::new()))
+        {
+            Value::Map(items) => {
+                items.insert(key, wrapper);
+            }
+            _ => unreachable!("checked above that the module is a map"),
+        }
+        Ok(())
+    }
+
     /// Begin loading a module (returns guard for cycle detection)
     pub fn begin_load(&self, key: &CacheKey) -> Result<LoadGuard<'_>, RuntimeError> {
         {
@@ -873,6 +961,63 @@ impl ModuleRegistry {
         })
     }
 
+    /// Resolve a file import (`import "path" as name`) relative to the importer directory.
+    /// Data files recognized by the data loader are parsed into values; anything else is
+    /// evaluated as a source module.
+    pub fn resolve_file_import(
+        &self,
+        executor: &dyn Executor,
+        path: &str,
+    ) -> Result<Value, RuntimeError> {
+        let current_dir = {
+            let stack_ref = self.dir_stack.borrow();
+            match stack_ref.last() {
+                Some(d) => d.clone(),
+                None => PathBuf::from("."),
+            }
+        };
+        let mut file_path = current_dir.join(path);
+        // `import "./helpers" as helpers` names a source module without its extension
+        if !file_path.exists() && file_path.extension().is_none() {
+            file_path.set_extension("si");
+        }
+
+        let loader = match self.data_loader {
+            Some(loader) => loader,
+            None => return self.load_file_value(executor, &file_path),
+        };
+
+        let canonical =
+            fs::canonicalize(&file_path).map_err(|e| RuntimeError::InvalidOperation {
+                message: format!("Failed to import '{}': {}", file_path.display(), e),
+            })?;
+
+        if let Some(v) = self.file_cache.borrow().get(&canonical) {
+            return Ok(v.clone());
+        }
+
+        let extension = canonical
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase())
+            .unwrap_or_default();
+        let contents =
+            fs::read_to_string(&canonical).map_err(|e| RuntimeError::InvalidOperation {
+                message: format!("Failed to read '{}': {}", canonical.display(), e),
+            })?;
+
+        match loader(&extension, &contents) {
+            Some(result) => {
+                let value = result?;
+                self.file_cache
+                    .borrow_mut()
+                    .insert(canonical, value.clone());
+                Ok(value)
+            }
+            None => self.load_file_value(executor, &canonical),
+        }
+    }
+
     /// Load and evaluate a module file, caching by canonical absolute path
     fn load_file_value(&self, executor: &dyn Executor, path: &Path) -> Result<Value, RuntimeError> {
         let canonical = match fs::canonicalize(path) {
@@ -898,9 +1043,11 @@ impl ModuleRegistry {
             message: "Module evaluation callback not set. Call set_source_evaluator() on the registry.".to_string(),
         })?;
 
-        // Evaluate source
-        let value = self.with_dir(canonical.parent().unwrap_or(Path::new(".")), || {
-            let env = Rc::new(Env::new());
+        // Evaluate source; the module's env remembers its directory so imports that run
+        // later (inside functions called from other modules) still resolve from here
+        let dir = canonical.parent().unwrap_or(Path::new("."));
+        let value = self.with_dir(dir, || {
+            let env = Rc::new(Env::new_module(dir.to_path_buf()));
             source_eval(executor, &source, env, self)
         })?;
 
@@ -1182,4 +1329,74 @@ mod tests {
             assert_eq!(stack.len(), 0);
         });
     }
+
+    fn native_nil(_args: &[Value]) -> Result<Value, RuntimeError> {
+        Ok(Value::Nil)
+    }
+
+    fn native_true(_args: &[Value]) -> Result<Value, RuntimeError> {
+        Ok(Value::Boolean(true))
+    }
+
+    #[test]
+    fn test_register_function_and_module_collisions() {
+        let mut registry = ModuleRegistry::new();
+        registry
+            .register_function("test_native", "nil", native_nil)
+            .unwrap();
+        assert!(registry.has_module("test_native"));
+
+        let err = registry
+            .register_function("test_native", "nil", native_true)
+            .unwrap_err();
+        assert!(err.to_string().contains("already has an item 'nil'"));
+        assert!(
+            registry
+                .register_function("std", "nil", native_nil)
+                .is_err()
+        );
+        assert!(
+            registry
+                .register_module("test_native", Value::Map(IndexMap::new()))
+                .is_err()
+        );
+        assert!(
+            registry
+                .register_module("__builtins__", Value::Map(IndexMap::new()))
+                .is_err()
+        );
+
+        registry.register_module("test_number", Value::Nil).unwrap();
+        let err = registry
+            .register_function("test_number", "nil", native_nil)
+            .unwrap_err();
+        assert!(err.to_string().contains("not a map"));
+
+        // Another registry can bind a different function under the same name
+        let mut other = ModuleRegistry::new();
+        other
+            .register_function("test_native", "nil", native_true)
+            .unwrap();
+        assert_eq!(call_registered(&registry, "test_native", "nil"), Value::Nil);
+        assert_eq!(
+            call_registered(&other, "test_native", "nil"),
+            Value::Boolean(true)
+        );
+    }
+
+    fn call_registered(registry: &ModuleRegistry, module: &str, name: &str) -> Value {
+        let Some(Value::Map(items)) = registry.builtins.get(module) else {
+            panic!("module '{}' is not registered", module);
+        };
+        match items.get(&MapKey::String(name.to_string())) {
+            Some(Value::Function(FunctionValue {
+                body: FunctionBody::Native(native),
+                ..
+            })) => {
+                assert_eq!(native.name, format!("{}:{}", module, name));
+                (native.func)(&[]).unwrap()
+            }
+            other => panic!("expected a native function, got {:?}", other),
+        }
+    }
 }
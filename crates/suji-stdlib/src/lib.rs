@@ -2,4 +2,6 @@ pub mod runtime {
     pub mod builtins;
 }
 
-pub use runtime::builtins::{setup_global_env, setup_module_registry};
+pub use runtime::builtins::{
+    setup_global_env, setup_global_env_with_constants, setup_module_registry, to_pretty_json,
+};
@@ -13,3 +13,15 @@ pub fn one_string_arg<'a>(args: &'a [Value], fname: &str) -> Result<&'a str, Run
         }),
     }
 }
+
+/// Build a candidate path in the system temp directory with a random, process-unique name.
+/// Callers must create it atomically (create_new / create_dir) and retry on collision.
+pub fn random_temp_path(prefix: &str) -> std::path::PathBuf {
+    let name = format!(
+        "{}-{}-{:016x}",
+        prefix,
+        std::process::id(),
+        rand::random::<u64>()
+    );
+    std::env::temp_dir().join(name)
+}
@@ -0,0 +1,46 @@
+//! Data file loader for `import "file.json" as name`, dispatching on file extension
+
+use super::functions::{builtin_json_parse, builtin_toml_parse, builtin_yaml_parse};
+use suji_values::value::{RuntimeError, Value};
+
+/// Parse a data file into a value; returns None for extensions that are not data formats
+pub fn load_data_file(extension: &str, contents: &str) -> Option<Result<Value, RuntimeError>> {
+    let parse = match extension {
+        "json" => builtin_json_parse,
+        "yaml" | "yml" => builtin_yaml_parse,
+        "toml" => builtin_toml_parse,
+        _ => return None,
+    };
+    Some(parse(&[Value::String(contents.to_string())]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suji_values::value::MapKey;
+
+    #[test]
+    fn test_load_data_file_dispatch() {
+        let json = load_data_file("json", r#"{"name": "suji"}"#)
+            .unwrap()
+            .unwrap();
+        let toml = load_data_file("toml", "name = \"suji\"").unwrap().unwrap();
+        let yaml = load_data_file("yml", "name: suji").unwrap().unwrap();
+
+        for value in [json, toml, yaml] {
+            match value {
+                Value::Map(map) => assert_eq!(
+                    map.get(&MapKey::String("name".to_string())),
+                    Some(&Value::String("suji".to_string()))
+                ),
+                other => panic!("Expected map, got {:?}", other),
+            }
+        }
+
+        assert!(load_data_file("si", "x = 1").is_none());
+        assert!(matches!(
+            load_data_file("json", "{not json"),
+            Some(Err(RuntimeError::JsonParseError { .. }))
+        ));
+    }
+}
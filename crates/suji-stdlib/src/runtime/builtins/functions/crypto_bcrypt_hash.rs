@@ -0,0 +1,41 @@
+//! Built-in: crypto:bcrypt_hash(password, cost = 12) -> string.
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Hashes a password with bcrypt using a random salt. The result is a
+/// self-describing `$2b$` string that includes the cost and salt.
+pub fn builtin_crypto_bcrypt_hash(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "crypto:bcrypt_hash expects 1 or 2 arguments (password, cost)".to_string(),
+        });
+    }
+    let password = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "crypto:bcrypt_hash password must be a string".to_string(),
+            });
+        }
+    };
+    let cost = match args.get(1) {
+        None => bcrypt::DEFAULT_COST,
+        Some(Value::Number(n)) => n
+            .to_i64_checked()
+            .and_then(|c| u32::try_from(c).ok())
+            .ok_or_else(|| RuntimeError::InvalidOperation {
+                message: "crypto:bcrypt_hash cost must be an integer between 4 and 31".to_string(),
+            })?,
+        Some(_) => {
+            return Err(RuntimeError::TypeError {
+                message: "crypto:bcrypt_hash cost must be a number".to_string(),
+            });
+        }
+    };
+
+    bcrypt::hash(password, cost)
+        .map(Value::String)
+        .map_err(|e| RuntimeError::InvalidOperation {
+            message: format!("crypto:bcrypt_hash failed: {}", e),
+        })
+}
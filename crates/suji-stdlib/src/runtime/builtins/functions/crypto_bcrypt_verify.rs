@@ -0,0 +1,62 @@
+//! Built-in: crypto:bcrypt_verify(password, hash) -> bool.
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Checks a password against a bcrypt hash. The digest comparison runs in
+/// constant time; a malformed hash is an error rather than a mismatch.
+pub fn builtin_crypto_bcrypt_verify(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "crypto:bcrypt_verify expects 2 arguments (password, hash)".to_string(),
+        });
+    }
+    let (Value::String(password), Value::String(hash)) = (&args[0], &args[1]) else {
+        return Err(RuntimeError::TypeError {
+            message: "crypto:bcrypt_verify arguments must be strings".to_string(),
+        });
+    };
+
+    bcrypt::verify(password, hash)
+        .map(Value::Boolean)
+        .map_err(|e| RuntimeError::InvalidOperation {
+            message: format!("crypto:bcrypt_verify failed: {}", e),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::builtin_crypto_bcrypt_hash;
+    use super::*;
+    use suji_values::value::DecimalNumber;
+
+    fn s(v: &str) -> Value {
+        Value::String(v.to_string())
+    }
+
+    #[test]
+    fn test_bcrypt_hash_and_verify() {
+        let cost = Value::Number(DecimalNumber::from_i64(4));
+        let hash = builtin_crypto_bcrypt_hash(&[s("hunter2"), cost]).unwrap();
+        let Value::String(text) = &hash else {
+            panic!("Expected string hash");
+        };
+        assert!(text.starts_with("$2b$04$"));
+
+        assert_eq!(
+            builtin_crypto_bcrypt_verify(&[s("hunter2"), hash.clone()]).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            builtin_crypto_bcrypt_verify(&[s("hunter3"), hash]).unwrap(),
+            Value::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn test_bcrypt_rejects_bad_cost_and_hash() {
+        let cost = |c: i64| Value::Number(DecimalNumber::from_i64(c));
+        assert!(builtin_crypto_bcrypt_hash(&[s("pw"), cost(3)]).is_err());
+        assert!(builtin_crypto_bcrypt_hash(&[s("pw"), cost(32)]).is_err());
+        assert!(builtin_crypto_bcrypt_verify(&[s("pw"), s("not-a-hash")]).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Built-in: crypto:hmac(algorithm, key, message) -> hex string.
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use suji_values::value::{RuntimeError, Value};
+
+/// Hash algorithms accepted by crypto:hmac, by name
+const ALGORITHMS: &[&str] = &["sha1", "sha256", "sha512"];
+
+/// Computes an HMAC of `message` keyed with `key` using the named hash algorithm
+/// (`"sha1"`, `"sha256"` or `"sha512"`, in any case) and returns it hex-encoded.
+pub fn builtin_crypto_hmac(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 3 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "crypto:hmac expects 3 arguments (algorithm, key, message)".to_string(),
+        });
+    }
+    let algorithm = string_arg(&args[0], "crypto:hmac", "algorithm")?;
+    let key = string_arg(&args[1], "crypto:hmac", "key")?;
+    let message = string_arg(&args[2], "crypto:hmac", "message")?;
+    Ok(Value::String(hmac_hex(algorithm, key, message)?))
+}
+
+/// Hex-encoded HMAC of `message` with the named algorithm
+pub(super) fn hmac_hex(algorithm: &str, key: &str, message: &str) -> Result<String, RuntimeError> {
+    match algorithm.to_ascii_lowercase().as_str() {
+        "sha1" => mac_hex::<Hmac<Sha1>>(key, message),
+        "sha256" => mac_hex::<Hmac<Sha256>>(key, message),
+        "sha512" => mac_hex::<Hmac<Sha512>>(key, message),
+        _ => Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "crypto:hmac does not support the algorithm '{}' (supported: {})",
+                algorithm,
+                ALGORITHMS.join(", ")
+            ),
+        }),
+    }
+}
+
+fn mac_hex<M: Mac + hmac::digest::KeyInit>(
+    key: &str,
+    message: &str,
+) -> Result<String, RuntimeError> {
+    let mut mac =
+        <M as Mac>::new_from_slice(key.as_bytes()).map_err(|_| RuntimeError::InvalidOperation {
+            message: "invalid HMAC key".to_string(),
+        })?;
+    mac.update(message.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+pub(super) fn string_arg<'a>(
+    value: &'a Value,
+    fname: &str,
+    name: &str,
+) -> Result<&'a str, RuntimeError> {
+    match value {
+        Value::String(s) => Ok(s),
+        other => Err(RuntimeError::TypeError {
+            message: format!(
+                "{} {} must be a string, got {}",
+                fname,
+                name,
+                other.type_name()
+            ),
+        }),
+    }
+}
@@ -0,0 +1,16 @@
+//! Built-in: crypto:hmac_sha512(key, message) -> hex string.
+
+use super::crypto_hmac::{hmac_hex, string_arg};
+use suji_values::value::{RuntimeError, Value};
+
+/// Computes an HMAC-SHA512 of `message` keyed with `key` and returns it hex-encoded.
+pub fn builtin_crypto_hmac_sha512(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "crypto:hmac_sha512 expects 2 arguments".to_string(),
+        });
+    }
+    let key = string_arg(&args[0], "crypto:hmac_sha512", "key")?;
+    let message = string_arg(&args[1], "crypto:hmac_sha512", "message")?;
+    Ok(Value::String(hmac_hex("sha512", key, message)?))
+}
@@ -20,24 +20,9 @@ pub fn builtin_csv_generate(args: &[Value]) -> Result<Value, RuntimeError> {
         }
     };
 
-    let delimiter = if args.len() == 2 {
-        match &args[1] {
-            Value::String(d) => {
-                if d.len() != 1 {
-                    return Err(RuntimeError::TypeError {
-                        message: "delimiter must be a single character string".to_string(),
-                    });
-                }
-                d.chars().next().unwrap() as u8
-            }
-            _ => {
-                return Err(RuntimeError::TypeError {
-                    message: "delimiter must be a string".to_string(),
-                });
-            }
-        }
-    } else {
-        b','
+    let delimiter = match args.get(1) {
+        Some(delimiter) => delimiter_arg(delimiter)?,
+        None => b',',
     };
 
     // Handle empty input
@@ -45,11 +30,7 @@ pub fn builtin_csv_generate(args: &[Value]) -> Result<Value, RuntimeError> {
         return Ok(Value::String(String::new()));
     }
 
-    // Build CSV
-    let mut writer = csv::WriterBuilder::new()
-        .delimiter(delimiter)
-        .from_writer(vec![]);
-
+    let mut records = Vec::with_capacity(rows.len());
     for row_value in rows {
         let row = match row_value {
             Value::List(row) => row,
@@ -60,20 +41,55 @@ pub fn builtin_csv_generate(args: &[Value]) -> Result<Value, RuntimeError> {
                 });
             }
         };
+        records.push(record_fields(row, "csv:generate")?);
+    }
 
-        let record: Result<Vec<String>, RuntimeError> = row
-            .iter()
-            .map(|cell| match cell {
-                Value::String(s) => Ok(s.clone()),
-                other => Err(RuntimeError::CsvGenerateError {
-                    message: "csv:generate expects all cells to be strings".to_string(),
-                    value_type: other.type_name().to_string(),
-                }),
-            })
-            .collect();
+    Ok(Value::String(write_records(&records, delimiter)?))
+}
 
+/// Delimiter argument: a single-character string
+pub(super) fn delimiter_arg(value: &Value) -> Result<u8, RuntimeError> {
+    match value {
+        Value::String(d) => {
+            if d.len() != 1 {
+                return Err(RuntimeError::TypeError {
+                    message: "delimiter must be a single character string".to_string(),
+                });
+            }
+            Ok(d.as_bytes()[0])
+        }
+        _ => Err(RuntimeError::TypeError {
+            message: "delimiter must be a string".to_string(),
+        }),
+    }
+}
+
+/// Cells of one row; every cell must be a string
+pub(super) fn record_fields(row: &[Value], fname: &str) -> Result<Vec<String>, RuntimeError> {
+    row.iter()
+        .map(|cell| match cell {
+            Value::String(s) => Ok(s.clone()),
+            other => Err(RuntimeError::CsvGenerateError {
+                message: format!("{} expects all cells to be strings", fname),
+                value_type: other.type_name().to_string(),
+            }),
+        })
+        .collect()
+}
+
+/// Write records as CSV lines, each ending in `\n`; fields holding the delimiter,
+/// quotes or line breaks are quoted
+pub(super) fn write_records(
+    records: &[Vec<String>],
+    delimiter: u8,
+) -> Result<String, RuntimeError> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(vec![]);
+
+    for record in records {
         writer
-            .write_record(&record?)
+            .write_record(record)
             .map_err(|e| RuntimeError::CsvGenerateError {
                 message: format!("Failed to write CSV record: {}", e),
                 value_type: "List".to_string(),
@@ -87,12 +103,10 @@ pub fn builtin_csv_generate(args: &[Value]) -> Result<Value, RuntimeError> {
             value_type: "List".to_string(),
         })?;
 
-    let csv_string = String::from_utf8(bytes).map_err(|_| RuntimeError::CsvGenerateError {
+    String::from_utf8(bytes).map_err(|_| RuntimeError::CsvGenerateError {
         message: "CSV output is not valid UTF-8".to_string(),
         value_type: "List".to_string(),
-    })?;
-
-    Ok(Value::String(csv_string))
+    })
 }
 
 #[cfg(test)]
@@ -1,12 +1,16 @@
-//! Built-in: csv:parse(text, delimiter) -> list of lists.
+//! Built-in: csv:parse(text, delimiter, options) -> list of lists.
 
-use suji_values::value::{RuntimeError, Value};
+use indexmap::IndexMap;
+use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
 
 /// Parse CSV text to SUJI list of lists.
+///
+/// Fields are strings unless `options` sets `infer: true`, in which case each field
+/// goes through `infer_field`.
 pub fn builtin_csv_parse(args: &[Value]) -> Result<Value, RuntimeError> {
-    if args.is_empty() || args.len() > 2 {
+    if args.is_empty() || args.len() > 3 {
         return Err(RuntimeError::ArityMismatch {
-            message: "csv:parse() takes 1 or 2 arguments".to_string(),
+            message: "csv:parse() takes 1 to 3 arguments".to_string(),
         });
     }
 
@@ -19,24 +23,34 @@ pub fn builtin_csv_parse(args: &[Value]) -> Result<Value, RuntimeError> {
         }
     };
 
-    let delimiter = if args.len() == 2 {
-        match &args[1] {
-            Value::String(d) => {
-                if d.len() != 1 {
-                    return Err(RuntimeError::TypeError {
-                        message: "delimiter must be a single character string".to_string(),
-                    });
-                }
-                d.chars().next().unwrap() as u8
-            }
-            _ => {
+    let delimiter = match args.get(1) {
+        None | Some(Value::Nil) => b',',
+        Some(Value::String(d)) => {
+            if d.len() != 1 {
                 return Err(RuntimeError::TypeError {
-                    message: "delimiter must be a string".to_string(),
+                    message: "delimiter must be a single character string".to_string(),
                 });
             }
+            d.chars().next().unwrap() as u8
+        }
+        Some(_) => {
+            return Err(RuntimeError::TypeError {
+                message: "delimiter must be a string".to_string(),
+            });
+        }
+    };
+
+    let infer = match args.get(2) {
+        None | Some(Value::Nil) => false,
+        Some(Value::Map(options)) => infer_option(options)?,
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "csv:parse() options must be a map, got {}",
+                    other.type_name()
+                ),
+            });
         }
-    } else {
-        b','
     };
 
     // Handle empty input
@@ -59,7 +73,10 @@ pub fn builtin_csv_parse(args: &[Value]) -> Result<Value, RuntimeError> {
 
         let row: Vec<Value> = record
             .iter()
-            .map(|field| Value::String(field.to_string()))
+            .map(|field| match infer {
+                true => infer_field(field),
+                false => Value::String(field.to_string()),
+            })
             .collect();
         rows.push(Value::List(row));
     }
@@ -67,6 +84,53 @@ pub fn builtin_csv_parse(args: &[Value]) -> Result<Value, RuntimeError> {
     Ok(Value::List(rows))
 }
 
+fn infer_option(options: &IndexMap<MapKey, Value>) -> Result<bool, RuntimeError> {
+    let mut infer = false;
+    for (key, value) in options {
+        match (key, value) {
+            (MapKey::String(k), Value::Boolean(b)) if k == "infer" => infer = *b,
+            (MapKey::String(k), other) if k == "infer" => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "csv:parse() expects option 'infer' to be a boolean, got {}",
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "csv:parse() got unknown option '{}' (expected infer)",
+                        key.to_value()
+                    ),
+                });
+            }
+        }
+    }
+    Ok(infer)
+}
+
+/// Type a field for `infer: true`. Empty fields are nil, `true` and `false` are
+/// booleans, and a field becomes a number only if that number prints back as exactly
+/// the same text. Anything else stays a string: leading zeros (`"02134"`), a leading
+/// `+`, exponents, a bare `.5` or `5.`, `-0`, surrounding spaces and digits beyond the
+/// 28 a decimal holds. Trailing fraction zeros (`"2.50"`) are kept, so they round-trip.
+fn infer_field(field: &str) -> Value {
+    match field {
+        "" => return Value::Nil,
+        "true" => return Value::Boolean(true),
+        "false" => return Value::Boolean(false),
+        _ => {}
+    }
+
+    match DecimalNumber::parse(field) {
+        Ok(n) if n.inner().map_or_else(|| n.to_string(), |d| d.to_string()) == field => {
+            Value::Number(n)
+        }
+        _ => Value::String(field.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,11 +231,50 @@ mod tests {
         let result = builtin_csv_parse(&[
             Value::String("a,b".to_string()),
             Value::String(",".to_string()),
+            Value::Map(IndexMap::new()),
             Value::String("extra".to_string()),
         ]);
         assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
     }
 
+    #[test]
+    fn test_infer_field() {
+        let number = |text: &str| Value::Number(DecimalNumber::parse(text).unwrap());
+        let string = |text: &str| Value::String(text.to_string());
+
+        assert_eq!(infer_field(""), Value::Nil);
+        assert_eq!(infer_field("true"), Value::Boolean(true));
+        assert_eq!(infer_field("false"), Value::Boolean(false));
+        assert_eq!(infer_field("42"), number("42"));
+        assert_eq!(infer_field("-3.25"), number("-3.25"));
+        assert_eq!(infer_field("0"), number("0"));
+        assert_eq!(infer_field("0.5"), number("0.5"));
+        assert_eq!(infer_field("2.50"), number("2.50"));
+        // Integers beyond the decimal range are still exact numbers
+        assert_eq!(
+            infer_field("99999999999999999999999999999999999"),
+            number("99999999999999999999999999999999999")
+        );
+
+        for text in [
+            "02134",
+            "00",
+            "+1",
+            "1e5",
+            ".5",
+            "5.",
+            "1.2.3",
+            " 7",
+            "-",
+            "-0",
+            "True",
+            "NaN",
+            "0.12345678901234567890123456789012",
+        ] {
+            assert_eq!(infer_field(text), string(text), "{:?}", text);
+        }
+    }
+
     #[test]
     fn test_csv_parse_malformed_csv() {
         let result = builtin_csv_parse(&[Value::String("a,b\n\"unclosed".to_string())]);
@@ -0,0 +1,29 @@
+use crate::runtime::builtins::common::one_string_arg;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use suji_values::value::{RuntimeError, Value};
+
+/// URL-safe alphabet that accepts input with or without `=` padding
+const URL_SAFE_ANY_PAD: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+pub fn builtin_encoding_base64url_decode(args: &[Value]) -> Result<Value, RuntimeError> {
+    let s = one_string_arg(args, "encoding:base64url_decode")?;
+    use base64::Engine;
+    let bytes = URL_SAFE_ANY_PAD
+        .decode(s)
+        .map_err(|e| RuntimeError::TypeError {
+            message: match e {
+                base64::DecodeError::InvalidByte(offset, byte) => format!(
+                    "invalid base64url: unexpected character '{}' at offset {}",
+                    byte as char, offset
+                ),
+                other => format!("invalid base64url: {}", other),
+            },
+        })?;
+    let text = String::from_utf8(bytes).map_err(|_| RuntimeError::TypeError {
+        message: "decoded base64url is not valid UTF-8".to_string(),
+    })?;
+    Ok(Value::String(text))
+}
@@ -0,0 +1,33 @@
+use suji_values::value::{RuntimeError, Value};
+
+pub fn builtin_encoding_base64url_encode(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "encoding:base64url_encode expects 1 or 2 arguments".to_string(),
+        });
+    }
+    let s = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "argument must be string".to_string(),
+            });
+        }
+    };
+    let pad = match args.get(1) {
+        None => false,
+        Some(Value::Boolean(b)) => *b,
+        Some(_) => {
+            return Err(RuntimeError::TypeError {
+                message: "encoding:base64url_encode pad must be a boolean".to_string(),
+            });
+        }
+    };
+    use base64::Engine;
+    let encoded = if pad {
+        base64::engine::general_purpose::URL_SAFE.encode(s.as_bytes())
+    } else {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(s.as_bytes())
+    };
+    Ok(Value::String(encoded))
+}
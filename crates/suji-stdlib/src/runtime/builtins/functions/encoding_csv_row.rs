@@ -0,0 +1,70 @@
+//! Built-in: encoding:csv_row(fields, delimiter = ",") -> string (one CSV line).
+
+use super::csv_generate::{delimiter_arg, record_fields, write_records};
+use suji_values::value::{RuntimeError, Value};
+
+/// Formats a list of string fields as a single CSV line with no trailing newline.
+/// Fields are quoted exactly as csv:generate quotes them.
+pub fn builtin_encoding_csv_row(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (fields, delimiter) = match args {
+        [fields] => (fields, b','),
+        [fields, delimiter] => (fields, delimiter_arg(delimiter)?),
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "encoding:csv_row(fields, delimiter) expects 1 or 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let fields = match fields {
+        Value::List(fields) => fields,
+        other => {
+            return Err(RuntimeError::CsvGenerateError {
+                message: "encoding:csv_row expects a list of fields".to_string(),
+                value_type: other.type_name().to_string(),
+            });
+        }
+    };
+    if fields.is_empty() {
+        return Ok(Value::String(String::new()));
+    }
+
+    let record = record_fields(fields, "encoding:csv_row")?;
+    let mut line = write_records(&[record], delimiter)?;
+    line.pop(); // the record's `\n`
+    Ok(Value::String(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(items: &[&str]) -> Value {
+        Value::List(
+            items
+                .iter()
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_csv_row_quotes_like_csv_generate() {
+        let result = builtin_encoding_csv_row(&[fields(&["a", "b,c", "say \"hi\"", "x\ny"])]);
+        assert_eq!(
+            result.unwrap(),
+            Value::String("a,\"b,c\",\"say \"\"hi\"\"\",\"x\ny\"".to_string())
+        );
+
+        let result = builtin_encoding_csv_row(&[fields(&["a;b", "c"]), fields(&[";"])]);
+        assert!(matches!(result, Err(RuntimeError::TypeError { .. })));
+        let result =
+            builtin_encoding_csv_row(&[fields(&["a;b", "c"]), Value::String(";".to_string())]);
+        assert_eq!(result.unwrap(), Value::String("\"a;b\";c".to_string()));
+
+        assert_eq!(
+            builtin_encoding_csv_row(&[fields(&[])]).unwrap(),
+            Value::String(String::new())
+        );
+    }
+}
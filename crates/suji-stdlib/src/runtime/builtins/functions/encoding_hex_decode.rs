@@ -1,13 +1,85 @@
-use crate::runtime::builtins::common::one_string_arg;
 use suji_values::value::{RuntimeError, Value};
 
+/// encoding:hex_decode(encoded, tolerant = false). In tolerant mode whitespace, `:`
+/// separators and `0x` prefixes are skipped, so pasted dumps like "de ad:be ef" decode.
 pub fn builtin_encoding_hex_decode(args: &[Value]) -> Result<Value, RuntimeError> {
-    let s = one_string_arg(args, "encoding:hex_decode")?;
-    let bytes = hex::decode(s).map_err(|_| RuntimeError::TypeError {
-        message: "invalid hex".to_string(),
+    if args.is_empty() || args.len() > 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "encoding:hex_decode expects 1 or 2 arguments".to_string(),
+        });
+    }
+    let s = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "argument must be string".to_string(),
+            });
+        }
+    };
+    let tolerant = match args.get(1) {
+        None => false,
+        Some(Value::Boolean(b)) => *b,
+        Some(_) => {
+            return Err(RuntimeError::TypeError {
+                message: "encoding:hex_decode tolerant must be a boolean".to_string(),
+            });
+        }
+    };
+
+    let bytes = match tolerant {
+        true => hex::decode(strip_separators(s)?),
+        false => hex::decode(s),
+    }
+    .map_err(|e| RuntimeError::TypeError {
+        message: format!("invalid hex: {}", e),
     })?;
     let text = String::from_utf8(bytes).map_err(|_| RuntimeError::TypeError {
         message: "decoded hex is not valid UTF-8".to_string(),
     })?;
     Ok(Value::String(text))
 }
+
+/// Keep only the hex digits of each whitespace- or `:`-separated group, dropping a
+/// leading `0x`. Invalid characters are reported at their offset in the original input.
+fn strip_separators(s: &str) -> Result<String, RuntimeError> {
+    let mut digits = String::with_capacity(s.len());
+    let mut group_start = true;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        if c.is_whitespace() || c == ':' {
+            group_start = true;
+            continue;
+        }
+        if group_start
+            && c == '0'
+            && chars
+                .peek()
+                .is_some_and(|(_, next)| *next == 'x' || *next == 'X')
+        {
+            chars.next();
+            group_start = false;
+            continue;
+        }
+        if !c.is_ascii_hexdigit() {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "invalid hex: Invalid character {:?} at position {}",
+                    c, offset
+                ),
+            });
+        }
+        digits.push(c);
+        group_start = false;
+    }
+
+    if !digits.len().is_multiple_of(2) {
+        return Err(RuntimeError::TypeError {
+            message: format!(
+                "invalid hex: Odd number of digits ({}) after removing separators",
+                digits.len()
+            ),
+        });
+    }
+    Ok(digits)
+}
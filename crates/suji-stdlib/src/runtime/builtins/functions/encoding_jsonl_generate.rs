@@ -0,0 +1,36 @@
+//! Built-in: encoding:jsonl_generate(list) -> string (one compact JSON value per line).
+
+use super::super::json::suji_to_json_value;
+use suji_values::value::{RuntimeError, Value};
+
+/// Serialize a list of SUJI values as JSON Lines.
+pub fn builtin_encoding_jsonl_generate(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "encoding:jsonl_generate() takes exactly one argument".to_string(),
+        });
+    }
+
+    let items = match &args[0] {
+        Value::List(items) => items,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "encoding:jsonl_generate() argument must be a list".to_string(),
+            });
+        }
+    };
+
+    let mut output = String::new();
+    for item in items {
+        let json_value = suji_to_json_value(item)?;
+        let line =
+            serde_json::to_string(&json_value).map_err(|e| RuntimeError::JsonGenerateError {
+                message: format!("Failed to serialize to JSON: {}", e),
+                value_type: item.type_name().to_string(),
+            })?;
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    Ok(Value::String(output))
+}
@@ -0,0 +1,62 @@
+//! Built-in: encoding:jsonl_parse(string) -> list (one JSON value per non-blank line).
+
+use super::super::json::json_to_suji_value;
+use crate::runtime::builtins::common::one_string_arg;
+use suji_values::value::{RuntimeError, Value};
+
+/// Parse JSON Lines text into a list of SUJI values, skipping blank lines.
+pub fn builtin_encoding_jsonl_parse(args: &[Value]) -> Result<Value, RuntimeError> {
+    let text = one_string_arg(args, "encoding:jsonl_parse")?;
+
+    let mut values = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let json_value: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| RuntimeError::JsonParseError {
+                message: format!("Invalid JSON on line {}: {}", index + 1, e),
+                json_input: Some(line.to_string()),
+            })?;
+        values.push(json_to_suji_value(json_value)?);
+    }
+
+    Ok(Value::List(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonl_parse_skips_blank_lines() {
+        let input = "{\"a\": 1}\n\n  \n[true, null]\n";
+        let result = builtin_encoding_jsonl_parse(&[Value::String(input.to_string())]).unwrap();
+        if let Value::List(items) = result {
+            assert_eq!(items.len(), 2);
+            assert!(matches!(items[0], Value::Map(_)));
+            assert_eq!(
+                items[1],
+                Value::List(vec![Value::Boolean(true), Value::Nil])
+            );
+        } else {
+            panic!("Expected list");
+        }
+    }
+
+    #[test]
+    fn test_jsonl_parse_reports_line_number() {
+        let input = "{\"a\": 1}\n\n{bad}\n";
+        match builtin_encoding_jsonl_parse(&[Value::String(input.to_string())]) {
+            Err(RuntimeError::JsonParseError {
+                message,
+                json_input,
+            }) => {
+                assert!(message.contains("line 3"));
+                assert_eq!(json_input, Some("{bad}".to_string()));
+            }
+            other => panic!("Expected JsonParseError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! Built-in: env:args_parse(spec[, argv]) -> map (parse command-line flags and options).
+
+use indexmap::IndexMap;
+use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
+
+/// Key holding the positional arguments in the result map.
+const POSITIONAL_KEY: &str = "positional";
+
+#[derive(Clone, Copy, PartialEq)]
+enum OptionKind {
+    Bool,
+    String,
+    Number,
+}
+
+/// Parses arguments according to `spec`, a map of option name to type
+/// ("bool", "string" or "number"). `argv` is either a list of strings or an
+/// args map shaped like `std:env:args` (key "0" is the script path and is skipped).
+///
+/// Supports `--flag`, `--opt=value`, `--opt value`, `-v` (first letter of an
+/// option name), bundled short flags (`-vq`) and `--` to end option parsing.
+/// A token that parses as a number, such as `-5`, is positional rather than a flag.
+/// Dashes in long names map to underscores (`--dry-run` sets `dry_run`).
+/// The result maps every option to its value (bools default to false, others
+/// to nil) plus a `positional` list with the remaining arguments.
+pub fn builtin_env_args_parse(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "env:args_parse(spec, argv) expects 2 arguments".to_string(),
+        });
+    }
+
+    let spec = parse_spec(&args[0])?;
+    let argv = collect_argv(&args[1])?;
+
+    let mut result: IndexMap<MapKey, Value> = IndexMap::new();
+    for (name, kind) in &spec {
+        let default = match kind {
+            OptionKind::Bool => Value::Boolean(false),
+            _ => Value::Nil,
+        };
+        result.insert(MapKey::String(name.clone()), default);
+    }
+
+    let mut positional = Vec::new();
+    let mut iter = argv.into_iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            positional.extend(iter.by_ref().map(Value::String));
+            break;
+        }
+
+        if let Some(long) = arg.strip_prefix("--") {
+            let (raw_name, inline_value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (long, None),
+            };
+            let name = raw_name.replace('-', "_");
+            let kind = lookup(&spec, &name).ok_or_else(|| unknown_flag(&arg))?;
+
+            let value = match (kind, inline_value) {
+                (OptionKind::Bool, None) => Value::Boolean(true),
+                (OptionKind::Bool, Some(text)) => parse_bool(&name, &text)?,
+                (kind, Some(text)) => convert_value(&name, kind, text)?,
+                (kind, None) => {
+                    let text = iter.next().ok_or_else(|| missing_value(&arg))?;
+                    convert_value(&name, kind, text)?
+                }
+            };
+            result.insert(MapKey::String(name), value);
+        } else if DecimalNumber::parse(&arg).is_ok() {
+            positional.push(Value::String(arg));
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            let letters: Vec<char> = shorts.chars().collect();
+            for (i, letter) in letters.iter().enumerate() {
+                let name = resolve_short(&spec, *letter)?;
+                let kind = lookup(&spec, &name).unwrap_or(OptionKind::Bool);
+
+                if kind == OptionKind::Bool {
+                    result.insert(MapKey::String(name), Value::Boolean(true));
+                    continue;
+                }
+
+                // A valued short option must come last in a bundle and takes the next argument
+                if i != letters.len() - 1 {
+                    return Err(RuntimeError::InvalidOperation {
+                        message: format!(
+                            "Option '-{}' expects a value and must be last in '{}'",
+                            letter, arg
+                        ),
+                    });
+                }
+                let flag = format!("-{}", letter);
+                let text = iter.next().ok_or_else(|| missing_value(&flag))?;
+                let value = convert_value(&name, kind, text)?;
+                result.insert(MapKey::String(name), value);
+            }
+        } else {
+            positional.push(Value::String(arg));
+        }
+    }
+
+    result.insert(
+        MapKey::String(POSITIONAL_KEY.to_string()),
+        Value::List(positional),
+    );
+    Ok(Value::Map(result))
+}
+
+fn parse_spec(value: &Value) -> Result<Vec<(String, OptionKind)>, RuntimeError> {
+    let Value::Map(map) = value else {
+        return Err(RuntimeError::TypeError {
+            message: "env:args_parse expects spec to be a map".to_string(),
+        });
+    };
+
+    let mut spec = Vec::with_capacity(map.len());
+    for (key, kind) in map {
+        let MapKey::String(name) = key else {
+            return Err(RuntimeError::TypeError {
+                message: "env:args_parse spec keys must be strings".to_string(),
+            });
+        };
+        if name == POSITIONAL_KEY {
+            return Err(RuntimeError::InvalidOperation {
+                message: format!(
+                    "env:args_parse spec cannot define '{}' (reserved for positional arguments)",
+                    POSITIONAL_KEY
+                ),
+            });
+        }
+        let kind = match kind {
+            Value::String(s) if s == "bool" => OptionKind::Bool,
+            Value::String(s) if s == "string" => OptionKind::String,
+            Value::String(s) if s == "number" => OptionKind::Number,
+            other => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "env:args_parse spec type for '{}' must be \"bool\", \"string\" or \"number\", got {}",
+                        name, other
+                    ),
+                });
+            }
+        };
+        spec.push((name.clone(), kind));
+    }
+    Ok(spec)
+}
+
+fn collect_argv(value: &Value) -> Result<Vec<String>, RuntimeError> {
+    let items: Vec<&Value> = match value {
+        Value::List(items) => items.iter().collect(),
+        Value::Map(map) => map
+            .iter()
+            .filter(|(key, _)| !matches!(key, MapKey::String(k) if k == "0"))
+            .map(|(_, v)| v)
+            .collect(),
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "env:args_parse expects argv to be a list of strings".to_string(),
+            });
+        }
+    };
+
+    items
+        .into_iter()
+        .map(|item| match item {
+            Value::String(s) => Ok(s.clone()),
+            _ => Err(RuntimeError::TypeError {
+                message: "env:args_parse expects argv to be a list of strings".to_string(),
+            }),
+        })
+        .collect()
+}
+
+fn lookup(spec: &[(String, OptionKind)], name: &str) -> Option<OptionKind> {
+    spec.iter().find(|(n, _)| n == name).map(|(_, kind)| *kind)
+}
+
+fn resolve_short(spec: &[(String, OptionKind)], letter: char) -> Result<String, RuntimeError> {
+    let matches: Vec<&String> = spec
+        .iter()
+        .filter(|(name, _)| name.starts_with(letter))
+        .map(|(name, _)| name)
+        .collect();
+
+    match matches.as_slice() {
+        [name] => Ok((*name).clone()),
+        [] => Err(unknown_flag(&format!("-{}", letter))),
+        names => Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "Ambiguous flag '-{}' (matches {})",
+                letter,
+                names
+                    .iter()
+                    .map(|n| format!("--{}", n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }),
+    }
+}
+
+fn convert_value(name: &str, kind: OptionKind, text: String) -> Result<Value, RuntimeError> {
+    match kind {
+        OptionKind::Bool => parse_bool(name, &text),
+        OptionKind::String => Ok(Value::String(text)),
+        OptionKind::Number => {
+            DecimalNumber::parse(&text)
+                .map(Value::Number)
+                .map_err(|_| RuntimeError::TypeError {
+                    message: format!("Option '--{}' expects a number, got '{}'", name, text),
+                })
+        }
+    }
+}
+
+fn parse_bool(name: &str, text: &str) -> Result<Value, RuntimeError> {
+    match text {
+        "true" => Ok(Value::Boolean(true)),
+        "false" => Ok(Value::Boolean(false)),
+        _ => Err(RuntimeError::TypeError {
+            message: format!("Option '--{}' expects true or false, got '{}'", name, text),
+        }),
+    }
+}
+
+fn unknown_flag(flag: &str) -> RuntimeError {
+    RuntimeError::InvalidOperation {
+        message: format!("Unknown flag '{}'", flag),
+    }
+}
+
+fn missing_value(flag: &str) -> RuntimeError {
+    RuntimeError::InvalidOperation {
+        message: format!("Option '{}' expects a value", flag),
+    }
+}
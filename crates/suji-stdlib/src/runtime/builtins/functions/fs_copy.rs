@@ -0,0 +1,33 @@
+//! Built-in: fs:copy(src, dst) -> nil (copy a file).
+
+use std::fs;
+use suji_values::value::{RuntimeError, Value};
+
+/// Copies a file to a new location, overwriting dst if it exists. Returns nil on success.
+pub fn builtin_fs_copy(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (src, dst) = match args {
+        [Value::String(src), Value::String(dst)] => (src, dst),
+        [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "fs:copy expects src and dst to be strings".to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "fs:copy(src, dst) expects 2 arguments".to_string(),
+            });
+        }
+    };
+
+    if fs::metadata(src).is_ok_and(|m| m.is_dir()) {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!("Cannot copy directory '{}'; only files can be copied", src),
+        });
+    }
+
+    fs::copy(src, dst).map_err(|e| RuntimeError::InvalidOperation {
+        message: format!("Failed to copy '{}' to '{}': {}", src, dst, e),
+    })?;
+
+    Ok(Value::Nil)
+}
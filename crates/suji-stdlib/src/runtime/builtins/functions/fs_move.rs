@@ -0,0 +1,36 @@
+//! Built-in: fs:move(src, dst) -> nil (move or rename a file or directory).
+
+use std::fs;
+use suji_values::value::{RuntimeError, Value};
+
+/// Moves src to dst. Files that cannot be renamed (e.g. across filesystems)
+/// are copied and then removed. Returns nil on success.
+pub fn builtin_fs_move(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (src, dst) = match args {
+        [Value::String(src), Value::String(dst)] => (src, dst),
+        [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "fs:move expects src and dst to be strings".to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "fs:move(src, dst) expects 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let move_error = |e: std::io::Error| RuntimeError::InvalidOperation {
+        message: format!("Failed to move '{}' to '{}': {}", src, dst, e),
+    };
+
+    if let Err(rename_error) = fs::rename(src, dst) {
+        if !fs::metadata(src).is_ok_and(|m| m.is_file()) {
+            return Err(move_error(rename_error));
+        }
+        fs::copy(src, dst).map_err(move_error)?;
+        fs::remove_file(src).map_err(move_error)?;
+    }
+
+    Ok(Value::Nil)
+}
@@ -0,0 +1,123 @@
+//! Built-in: fs_read_dir(path, follow_symlinks) -> map (directory listing used by fs:walk).
+
+use indexmap::IndexMap;
+use std::fs;
+use std::path::Path;
+use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
+
+/// Lists the direct children of a directory, sorted by name, without failing on I/O errors.
+/// Returns `{entries, error, real_path}`: `error` is the message if the directory itself
+/// could not be read, and `real_path` is its canonical path (nil if unresolvable).
+/// Each entry is `{path, name, is_directory, is_file, is_symlink, size}`; an entry whose
+/// metadata cannot be read carries an `error` message instead. With follow_symlinks,
+/// symlinks are described by their targets and directories get a `real_path` so the
+/// caller can detect loops.
+pub fn builtin_fs_read_dir(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (dir, follow_symlinks) = match args {
+        [Value::String(dir)] => (dir, false),
+        [Value::String(dir), Value::Boolean(follow)] => (dir, *follow),
+        [_] | [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "fs:walk expects a string root and a boolean follow_symlinks flag"
+                    .to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "fs_read_dir(path[, follow_symlinks]) expects 1 or 2 arguments"
+                    .to_string(),
+            });
+        }
+    };
+
+    let mut listing = IndexMap::new();
+    let mut entries = Vec::new();
+    let mut error = Value::Nil;
+
+    match fs::read_dir(dir) {
+        Ok(read_dir) => {
+            let mut children: Vec<_> = read_dir.collect();
+            children.sort_by_key(|child| child.as_ref().ok().map(|c| c.file_name()));
+            for child in children {
+                match child {
+                    Ok(child) => entries.push(describe_entry(&child.path(), follow_symlinks)),
+                    Err(e) => {
+                        let mut entry = IndexMap::new();
+                        insert(&mut entry, "path", Value::String(dir.clone()));
+                        insert(&mut entry, "error", Value::String(e.to_string()));
+                        entries.push(Value::Map(entry));
+                    }
+                }
+            }
+        }
+        Err(e) => error = Value::String(format!("Failed to read '{}': {}", dir, e)),
+    }
+
+    insert(&mut listing, "entries", Value::List(entries));
+    insert(&mut listing, "error", error);
+    insert(&mut listing, "real_path", real_path(Path::new(dir)));
+    Ok(Value::Map(listing))
+}
+
+fn describe_entry(path: &Path, follow_symlinks: bool) -> Value {
+    let mut entry = IndexMap::new();
+    insert(
+        &mut entry,
+        "path",
+        Value::String(path.to_string_lossy().into_owned()),
+    );
+    insert(
+        &mut entry,
+        "name",
+        Value::String(
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        ),
+    );
+
+    let is_symlink = fs::symlink_metadata(path)
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false);
+    let metadata = if follow_symlinks {
+        fs::metadata(path)
+    } else {
+        fs::symlink_metadata(path)
+    };
+
+    match metadata {
+        Ok(metadata) => {
+            insert(
+                &mut entry,
+                "is_directory",
+                Value::Boolean(metadata.is_dir()),
+            );
+            insert(&mut entry, "is_file", Value::Boolean(metadata.is_file()));
+            insert(&mut entry, "is_symlink", Value::Boolean(is_symlink));
+            insert(
+                &mut entry,
+                "size",
+                Value::Number(DecimalNumber::from_u64(metadata.len())),
+            );
+            if follow_symlinks && metadata.is_dir() {
+                insert(&mut entry, "real_path", real_path(path));
+            }
+        }
+        Err(e) => {
+            insert(&mut entry, "is_symlink", Value::Boolean(is_symlink));
+            insert(&mut entry, "error", Value::String(e.to_string()));
+        }
+    }
+
+    Value::Map(entry)
+}
+
+fn real_path(path: &Path) -> Value {
+    fs::canonicalize(path)
+        .map(|p| Value::String(p.to_string_lossy().into_owned()))
+        .unwrap_or(Value::Nil)
+}
+
+fn insert(map: &mut IndexMap<MapKey, Value>, key: &str, value: Value) {
+    map.insert(MapKey::String(key.to_string()), value);
+}
@@ -0,0 +1,41 @@
+//! Built-in: fs:remove(path, recursive) -> nil (remove a file or directory).
+
+use std::fs;
+use suji_values::value::{RuntimeError, Value};
+
+/// Removes a file or an empty directory. With recursive = true, directories
+/// are removed together with their contents. Returns nil on success.
+pub fn builtin_fs_remove(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (path, recursive) = match args {
+        [Value::String(path)] => (path, false),
+        [Value::String(path), Value::Boolean(recursive)] => (path, *recursive),
+        [_] | [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "fs:remove expects a string path and a boolean recursive flag".to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "fs:remove(path[, recursive]) expects 1 or 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let metadata = fs::symlink_metadata(path).map_err(|e| RuntimeError::InvalidOperation {
+        message: format!("Failed to remove '{}': {}", path, e),
+    })?;
+
+    let result = if !metadata.is_dir() {
+        fs::remove_file(path)
+    } else if recursive {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_dir(path)
+    };
+
+    result.map_err(|e| RuntimeError::InvalidOperation {
+        message: format!("Failed to remove '{}': {}", path, e),
+    })?;
+
+    Ok(Value::Nil)
+}
@@ -0,0 +1,35 @@
+//! Built-in `std:io:memory` implementation.
+
+use std::rc::Rc;
+
+use suji_values::value::{RuntimeError, StreamHandle, Value};
+
+/// Create an in-memory stream that reads, writes and seeks like a file opened with "rw".
+/// Signature: `io:memory(initial = "")`. The stream starts at position 0, so writes
+/// overwrite `initial` from the start; `contents()` returns everything it holds.
+pub fn builtin_io_memory(args: &[Value]) -> Result<Value, RuntimeError> {
+    let initial = match args {
+        [] => String::new(),
+        [Value::String(initial)] => initial.clone(),
+        [other] => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "std:io:memory(initial) expects initial to be a string, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: format!(
+                    "std:io:memory(initial) expects 0 or 1 arguments, got {}",
+                    args.len()
+                ),
+            });
+        }
+    };
+
+    Ok(Value::Stream(Rc::new(StreamHandle::new_memory(
+        initial.into_bytes(),
+    ))))
+}
@@ -5,10 +5,18 @@ use std::cell::RefCell;
 use std::fs::OpenOptions;
 use std::rc::Rc;
 
-use suji_values::value::{RuntimeError, StreamBackend, StreamHandle, Value};
+use indexmap::IndexMap;
+use suji_values::IoContext;
+use suji_values::value::{
+    MapKey, RuntimeError, StreamBackend, StreamBuffering, StreamHandle, Value,
+};
 
-/// Open a file as a stream for reading and writing (binary mode).
-/// Signature: `io:open(path, create = false, truncate = false)`
+/// Open a file as a stream (binary mode).
+/// Signature: `io:open(path, create = false, truncate = false)`, `io:open(path, mode)` or
+/// `io:open(path, options)` where mode is "r", "w", "a" or "rw" and options is a map with
+/// `mode`, `create`, `truncate` and `buffering` ("none", "line", "block").
+/// Without a mode the file is opened read-only, unless `create` or `truncate` asks for
+/// write access, in which case it is opened for reading and writing.
 pub fn builtin_io_open(args: &[Value]) -> Result<Value, RuntimeError> {
     if args.is_empty() || args.len() > 3 {
         return Err(RuntimeError::ArityMismatch {
@@ -31,43 +39,46 @@ pub fn builtin_io_open(args: &[Value]) -> Result<Value, RuntimeError> {
         }
     };
 
-    let create = if args.len() >= 2 {
-        match &args[1] {
-            Value::Boolean(b) => *b,
-            other => {
-                return Err(RuntimeError::TypeError {
-                    message: format!(
-                        "std:io:open(path, create, truncate) expects create to be a boolean, got {}",
-                        other.type_name()
-                    ),
-                });
-            }
+    let options = match args.get(1) {
+        Some(Value::Map(map)) if args.len() == 2 => parse_open_options(map)?,
+        Some(Value::Map(_)) => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "std:io:open(path, options) expects no arguments after the options map"
+                    .to_string(),
+            });
+        }
+        Some(Value::String(mode)) if args.len() == 2 => OpenOptionsArg {
+            mode: Some(OpenMode::parse(mode)?),
+            ..OpenOptionsArg::default()
+        },
+        Some(Value::String(_)) => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "std:io:open(path, mode) expects no arguments after the mode".to_string(),
+            });
         }
-    } else {
-        false
+        _ => OpenOptionsArg {
+            create: bool_arg(args, 1, "create")?,
+            truncate: bool_arg(args, 2, "truncate")?,
+            ..OpenOptionsArg::default()
+        },
     };
 
-    let truncate = if args.len() >= 3 {
-        match &args[2] {
-            Value::Boolean(b) => *b,
-            other => {
-                return Err(RuntimeError::TypeError {
-                    message: format!(
-                        "std:io:open(path, create, truncate) expects truncate to be a boolean, got {}",
-                        other.type_name()
-                    ),
-                });
-            }
-        }
-    } else {
-        false
+    let mode = options.resolve_mode()?;
+    let mut open_options = OpenOptions::new();
+    match mode {
+        OpenMode::Read => open_options.read(true),
+        OpenMode::Write => open_options.write(true).create(true).truncate(true),
+        OpenMode::Append => open_options.append(true).create(true),
+        OpenMode::ReadWrite => open_options.read(true).write(true),
     };
+    if options.create {
+        open_options.create(true);
+    }
+    if options.truncate {
+        open_options.truncate(true);
+    }
 
-    let file = OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(create)
-        .truncate(truncate)
+    let file = open_options
         .open(&path)
         .map_err(|err| RuntimeError::StreamError {
             message: format!("Failed to open '{}': {}", path, err),
@@ -77,7 +88,151 @@ pub fn builtin_io_open(args: &[Value]) -> Result<Value, RuntimeError> {
         backend: StreamBackend::File(RefCell::new(file)),
         is_closed: Cell::new(false),
         name: path,
+        buffering: options.buffering,
+        read_only: mode == OpenMode::Read,
+        write_buffer: RefCell::new(Vec::new()),
     };
 
-    Ok(Value::Stream(Rc::new(handle)))
+    let handle = Rc::new(handle);
+    if handle.buffering != StreamBuffering::None {
+        IoContext::register_buffered_stream(&handle);
+    }
+    Ok(Value::Stream(handle))
+}
+
+/// Settings resolved from either positional flags, a mode string or an options map
+struct OpenOptionsArg {
+    mode: Option<OpenMode>,
+    create: bool,
+    truncate: bool,
+    buffering: StreamBuffering,
+}
+
+impl Default for OpenOptionsArg {
+    fn default() -> Self {
+        Self {
+            mode: None,
+            create: false,
+            truncate: false,
+            buffering: StreamBuffering::None,
+        }
+    }
+}
+
+impl OpenOptionsArg {
+    /// The explicit mode, or "r" when none was given. The `create` and `truncate` flags
+    /// need write access, so on their own they open the file for reading and writing.
+    fn resolve_mode(&self) -> Result<OpenMode, RuntimeError> {
+        match self.mode {
+            Some(OpenMode::Read) if self.create || self.truncate => {
+                Err(RuntimeError::InvalidOperation {
+                    message: "std:io:open cannot create or truncate a file opened with mode \"r\""
+                        .to_string(),
+                })
+            }
+            Some(mode) => Ok(mode),
+            None if self.create || self.truncate => Ok(OpenMode::ReadWrite),
+            None => Ok(OpenMode::Read),
+        }
+    }
+}
+
+/// Access mode for the opened file
+#[derive(Clone, Copy, PartialEq)]
+enum OpenMode {
+    /// "r": read only; writes raise a stream error
+    Read,
+    /// "w": write only, creating or truncating the file
+    Write,
+    /// "a": write only at the end of the file, creating it if missing
+    Append,
+    /// "rw": read and write an existing file
+    ReadWrite,
+}
+
+impl OpenMode {
+    fn parse(mode: &str) -> Result<Self, RuntimeError> {
+        match mode {
+            "r" => Ok(OpenMode::Read),
+            "w" => Ok(OpenMode::Write),
+            "a" => Ok(OpenMode::Append),
+            "rw" => Ok(OpenMode::ReadWrite),
+            _ => Err(RuntimeError::TypeError {
+                message: format!(
+                    "std:io:open mode must be \"r\", \"w\", \"a\" or \"rw\", got \"{}\"",
+                    mode
+                ),
+            }),
+        }
+    }
+}
+
+fn bool_arg(args: &[Value], index: usize, name: &str) -> Result<bool, RuntimeError> {
+    match args.get(index) {
+        None => Ok(false),
+        Some(Value::Boolean(b)) => Ok(*b),
+        Some(other) => Err(RuntimeError::TypeError {
+            message: format!(
+                "std:io:open(path, create, truncate) expects {} to be a boolean, got {}",
+                name,
+                other.type_name()
+            ),
+        }),
+    }
+}
+
+fn parse_open_options(map: &IndexMap<MapKey, Value>) -> Result<OpenOptionsArg, RuntimeError> {
+    let mut options = OpenOptionsArg::default();
+
+    for (key, value) in map {
+        match (key, value) {
+            (MapKey::String(k), Value::Boolean(b)) if k == "create" => options.create = *b,
+            (MapKey::String(k), Value::Boolean(b)) if k == "truncate" => options.truncate = *b,
+            (MapKey::String(k), Value::String(mode)) if k == "mode" => {
+                options.mode = Some(OpenMode::parse(mode)?)
+            }
+            (MapKey::String(k), Value::String(mode)) if k == "buffering" => {
+                options.buffering = match mode.as_str() {
+                    "none" => StreamBuffering::None,
+                    "line" => StreamBuffering::Line,
+                    "block" => StreamBuffering::Block,
+                    _ => {
+                        return Err(RuntimeError::TypeError {
+                            message: format!(
+                                "std:io:open buffering must be \"none\", \"line\" or \"block\", got \"{}\"",
+                                mode
+                            ),
+                        });
+                    }
+                };
+            }
+            (MapKey::String(k), other)
+                if k == "create" || k == "truncate" || k == "buffering" || k == "mode" =>
+            {
+                let expected = if k == "buffering" || k == "mode" {
+                    "a string"
+                } else {
+                    "a boolean"
+                };
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "std:io:open expects option '{}' to be {}, got {}",
+                        k,
+                        expected,
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "std:io:open got unknown option '{}' (expected mode, create, truncate or buffering)",
+                        key.to_value()
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(options)
 }
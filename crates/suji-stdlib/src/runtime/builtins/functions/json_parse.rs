@@ -1,13 +1,32 @@
-//! Built-in: json:parse(string) -> value.
+//! Built-in: json:parse(string, options = {}) -> value.
 
-use super::super::json::json_to_suji_value;
-use suji_values::value::{RuntimeError, Value};
+use super::super::json::{json_to_suji_value, json_to_suji_value_numeric_keys};
+use indexmap::IndexMap;
+use suji_values::value::{MapKey, RuntimeError, Value};
 
 /// Parse JSON string to SUJI value.
+///
+/// Object keys are strings unless `options` sets `parse_numeric_keys: true`, in which
+/// case keys in canonical number form (`"1"`, `"-2.5"`) become number keys again.
 pub fn builtin_json_parse(args: &[Value]) -> Result<Value, RuntimeError> {
-    if args.len() != 1 {
+    parse_with(args, "json:parse()", |json_string| {
+        serde_json::from_str(json_string).map_err(|e| RuntimeError::JsonParseError {
+            message: format!("Invalid JSON: {}", e),
+            json_input: Some(json_string.to_string()),
+        })
+    })
+}
+
+/// Shared by json:parse and json:parse_relaxed: check the arguments, parse the string
+/// with `parse`, and convert the result to a SUJI value.
+pub(super) fn parse_with(
+    args: &[Value],
+    name: &str,
+    parse: impl FnOnce(&str) -> Result<serde_json::Value, RuntimeError>,
+) -> Result<Value, RuntimeError> {
+    if args.is_empty() || args.len() > 2 {
         return Err(RuntimeError::ArityMismatch {
-            message: "json:parse() takes exactly one argument".to_string(),
+            message: format!("{} takes one or two arguments", name),
         });
     }
 
@@ -15,20 +34,62 @@ pub fn builtin_json_parse(args: &[Value]) -> Result<Value, RuntimeError> {
         Value::String(s) => s,
         _ => {
             return Err(RuntimeError::TypeError {
-                message: "json:parse() argument must be a string".to_string(),
+                message: format!("{} argument must be a string", name),
             });
         }
     };
 
-    // Parse JSON string
-    let json_value: serde_json::Value =
-        serde_json::from_str(json_string).map_err(|e| RuntimeError::JsonParseError {
-            message: format!("Invalid JSON: {}", e),
-            json_input: Some(json_string.clone()),
-        })?;
+    let numeric_keys = match args.get(1) {
+        None => false,
+        Some(Value::Map(options)) => parse_numeric_keys_option(options, name)?,
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!("{} options must be a map, got {}", name, other.type_name()),
+            });
+        }
+    };
+
+    let json_value = parse(json_string)?;
 
     // Convert JSON value to SUJI value
-    json_to_suji_value(json_value)
+    if numeric_keys {
+        json_to_suji_value_numeric_keys(json_value)
+    } else {
+        json_to_suji_value(json_value)
+    }
+}
+
+fn parse_numeric_keys_option(
+    options: &IndexMap<MapKey, Value>,
+    name: &str,
+) -> Result<bool, RuntimeError> {
+    let mut numeric_keys = false;
+    for (key, value) in options {
+        match (key, value) {
+            (MapKey::String(k), Value::Boolean(b)) if k == "parse_numeric_keys" => {
+                numeric_keys = *b
+            }
+            (MapKey::String(k), other) if k == "parse_numeric_keys" => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "{} expects option 'parse_numeric_keys' to be a boolean, got {}",
+                        name,
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "{} got unknown option '{}' (expected parse_numeric_keys)",
+                        name,
+                        key.to_value()
+                    ),
+                });
+            }
+        }
+    }
+    Ok(numeric_keys)
 }
 
 #[cfg(test)]
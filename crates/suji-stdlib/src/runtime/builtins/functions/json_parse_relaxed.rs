@@ -0,0 +1,11 @@
+//! Built-in: json:parse_relaxed(string, options = {}) -> value.
+
+use super::super::json::parse_relaxed_json;
+use super::json_parse::parse_with;
+use suji_values::value::{RuntimeError, Value};
+
+/// Parse "almost JSON" such as hand-edited config files: like json:parse, but `//` and
+/// `/* */` comments, trailing commas and unquoted object keys are accepted.
+pub fn builtin_json_parse_relaxed(args: &[Value]) -> Result<Value, RuntimeError> {
+    parse_with(args, "json:parse_relaxed()", parse_relaxed_json)
+}
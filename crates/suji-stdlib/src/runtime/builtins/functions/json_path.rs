@@ -0,0 +1,181 @@
+//! Built-in: json:path(value, path) -> list of matches (JSONPath subset).
+
+use suji_values::value::{MapKey, RuntimeError, Value};
+
+/// One step of a parsed path expression
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// `.name` or `["name"]`
+    Key(String),
+    /// `[2]` or `[-1]` (negative counts from the end)
+    Index(i64),
+    /// `[*]` or `.*`: every list item or map value
+    Wildcard,
+}
+
+/// Select values from parsed JSON with a JSONPath-style expression such as
+/// `$.users[0].name` or `$.items[*].id`. Returns a list of every match in document
+/// order; keys or indices that do not exist simply produce no match.
+pub fn builtin_json_path(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "json:path() takes exactly two arguments".to_string(),
+        });
+    }
+
+    let path = match &args[1] {
+        Value::String(path) => path,
+        other => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "json:path() expects the path to be a string, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+    };
+
+    let segments = parse_path(path)?;
+    let mut matches = vec![&args[0]];
+    for segment in &segments {
+        matches = matches
+            .into_iter()
+            .flat_map(|value| select(value, segment))
+            .collect();
+    }
+
+    Ok(Value::List(matches.into_iter().cloned().collect()))
+}
+
+fn select<'a>(value: &'a Value, segment: &Segment) -> Vec<&'a Value> {
+    match (segment, value) {
+        (Segment::Key(key), Value::Map(map)) => {
+            map.get(&MapKey::String(key.clone())).into_iter().collect()
+        }
+        (Segment::Index(index), Value::List(items)) => {
+            let index = match *index < 0 {
+                true => items.len() as i64 + index,
+                false => *index,
+            };
+            usize::try_from(index)
+                .ok()
+                .and_then(|i| items.get(i))
+                .into_iter()
+                .collect()
+        }
+        (Segment::Wildcard, Value::List(items)) => items.iter().collect(),
+        (Segment::Wildcard, Value::Map(map)) => map.values().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment>, RuntimeError> {
+    let chars: Vec<char> = path.chars().collect();
+    let error = |pos: usize, message: &str| RuntimeError::InvalidOperation {
+        message: format!(
+            "Invalid JSON path '{}': {} at position {}",
+            path, message, pos
+        ),
+    };
+
+    if chars.first() != Some(&'$') {
+        return Err(error(0, "path must start with '$'"));
+    }
+
+    let mut segments = Vec::new();
+    let mut pos = 1;
+    while pos < chars.len() {
+        match chars[pos] {
+            '.' => {
+                let start = pos + 1;
+                let end = chars[start..]
+                    .iter()
+                    .position(|c| *c == '.' || *c == '[')
+                    .map_or(chars.len(), |offset| start + offset);
+                let name: String = chars[start..end].iter().collect();
+                match name.as_str() {
+                    "" => return Err(error(start, "expected a key name after '.'")),
+                    "*" => segments.push(Segment::Wildcard),
+                    _ if name.contains(']') => {
+                        return Err(error(start, "unexpected ']' in key name"));
+                    }
+                    _ => segments.push(Segment::Key(name)),
+                }
+                pos = end;
+            }
+            '[' => {
+                let start = pos + 1;
+                let end = match chars[start..].iter().position(|c| *c == ']') {
+                    Some(offset) => start + offset,
+                    None => return Err(error(pos, "unclosed '['")),
+                };
+                let inner: String = chars[start..end].iter().collect();
+                segments.push(parse_bracket(inner.trim()).ok_or_else(|| {
+                    error(
+                        start,
+                        "expected an index, '*', or a quoted key inside '[...]'",
+                    )
+                })?);
+                pos = end + 1;
+            }
+            other => {
+                return Err(error(
+                    pos,
+                    &format!("unexpected '{}', expected '.' or '['", other),
+                ));
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+fn parse_bracket(inner: &str) -> Option<Segment> {
+    if inner == "*" {
+        return Some(Segment::Wildcard);
+    }
+    for quote in ['"', '\''] {
+        if inner.len() >= 2 && inner.starts_with(quote) && inner.ends_with(quote) {
+            return Some(Segment::Key(inner[1..inner.len() - 1].to_string()));
+        }
+    }
+    inner.parse::<i64>().ok().map(Segment::Index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path_segments() {
+        assert_eq!(
+            parse_path("$.users[0].name").unwrap(),
+            vec![
+                Segment::Key("users".to_string()),
+                Segment::Index(0),
+                Segment::Key("name".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_path("$['a.b'][*].*[-1]").unwrap(),
+            vec![
+                Segment::Key("a.b".to_string()),
+                Segment::Wildcard,
+                Segment::Wildcard,
+                Segment::Index(-1),
+            ]
+        );
+        assert_eq!(parse_path("$").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_path_rejects_invalid_expressions() {
+        for path in ["users", "$.", "$..a", "$[0", "$[x]", "$.a]", "$a"] {
+            assert!(
+                matches!(parse_path(path), Err(RuntimeError::InvalidOperation { .. })),
+                "{} should be rejected",
+                path
+            );
+        }
+    }
+}
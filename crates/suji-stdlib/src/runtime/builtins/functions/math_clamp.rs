@@ -0,0 +1,20 @@
+use crate::runtime::builtins::math::{from_decimal, to_decimal};
+use suji_values::value::{RuntimeError, Value};
+
+/// math:clamp(x, lo, hi) - x limited to the range [lo, hi]
+pub fn builtin_math_clamp(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 3 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:clamp expects 3 arguments".to_string(),
+        });
+    }
+    let x = to_decimal(&args[0], "x")?;
+    let lo = to_decimal(&args[1], "lo")?;
+    let hi = to_decimal(&args[2], "hi")?;
+    if lo > hi {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!("clamp requires lo <= hi, got lo = {} and hi = {}", lo, hi),
+        });
+    }
+    Ok(from_decimal(x.clamp(lo, hi)))
+}
@@ -0,0 +1,19 @@
+use crate::runtime::builtins::math::{checked_result, from_decimal, to_decimal};
+use suji_values::value::{RuntimeError, Value};
+
+/// math:lerp(a, b, t) - linear interpolation a + (b - a) * t; t is not clamped
+pub fn builtin_math_lerp(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 3 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:lerp expects 3 arguments".to_string(),
+        });
+    }
+    let a = to_decimal(&args[0], "a")?;
+    let b = to_decimal(&args[1], "b")?;
+    let t = to_decimal(&args[2], "t")?;
+    let y = b
+        .checked_sub(a)
+        .and_then(|span| span.checked_mul(t))
+        .and_then(|offset| a.checked_add(offset));
+    Ok(from_decimal(checked_result(y, "lerp")?))
+}
@@ -0,0 +1,32 @@
+use crate::runtime::builtins::math::{checked_result, from_decimal, to_decimal};
+use suji_values::value::{RuntimeError, Value};
+
+/// math:map_range(x, in_lo, in_hi, out_lo, out_hi) - x moved from one range to another
+/// by the same linear interpolation as lerp; x outside the input range is not clamped
+pub fn builtin_math_map_range(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 5 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:map_range expects 5 arguments".to_string(),
+        });
+    }
+    let x = to_decimal(&args[0], "x")?;
+    let in_lo = to_decimal(&args[1], "in_lo")?;
+    let in_hi = to_decimal(&args[2], "in_hi")?;
+    let out_lo = to_decimal(&args[3], "out_lo")?;
+    let out_hi = to_decimal(&args[4], "out_hi")?;
+    if in_lo == in_hi {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "map_range input range is empty (in_lo and in_hi are both {})",
+                in_lo
+            ),
+        });
+    }
+    // Scale before dividing so ranges like 0..3 map exactly where possible
+    let y = x
+        .checked_sub(in_lo)
+        .and_then(|offset| offset.checked_mul(out_hi.checked_sub(out_lo)?))
+        .and_then(|scaled| scaled.checked_div(in_hi.checked_sub(in_lo)?))
+        .and_then(|offset| out_lo.checked_add(offset));
+    Ok(from_decimal(checked_result(y, "map_range")?))
+}
@@ -0,0 +1,13 @@
+use crate::runtime::builtins::math::{from_decimal, mean, to_decimal_list};
+use suji_values::value::{RuntimeError, Value};
+
+/// math:mean(list) - arithmetic mean of a non-empty list of numbers
+pub fn builtin_math_mean(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:mean expects 1 argument".to_string(),
+        });
+    }
+    let values = to_decimal_list(&args[0], "mean")?;
+    Ok(from_decimal(mean(&values, "mean")?))
+}
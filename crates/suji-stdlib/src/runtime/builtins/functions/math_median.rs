@@ -0,0 +1,23 @@
+use crate::runtime::builtins::math::{checked_result, from_decimal, to_decimal_list};
+use rust_decimal::Decimal;
+use suji_values::value::{RuntimeError, Value};
+
+/// math:median(list) - middle value of a non-empty list of numbers; an even-length
+/// list gives the average of the two middle values
+pub fn builtin_math_median(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:median expects 1 argument".to_string(),
+        });
+    }
+    let mut values = to_decimal_list(&args[0], "median")?;
+    values.sort();
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        return Ok(from_decimal(values[middle]));
+    }
+    let y = values[middle - 1]
+        .checked_add(values[middle])
+        .and_then(|sum| sum.checked_div(Decimal::TWO));
+    Ok(from_decimal(checked_result(y, "median")?))
+}
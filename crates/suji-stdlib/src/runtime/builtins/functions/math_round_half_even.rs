@@ -0,0 +1,21 @@
+use suji_values::methods::digits_arg;
+use suji_values::value::{RuntimeError, Value};
+
+/// math:round_half_even(x, digits = 0) - banker's rounding on the exact decimal value
+pub fn builtin_math_round_half_even(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:round_half_even expects 1 or 2 arguments".to_string(),
+        });
+    }
+    let x = match &args[0] {
+        Value::Number(n) => n,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "x must be a number".to_string(),
+            });
+        }
+    };
+    let digits = digits_arg(args.get(1), "math:round_half_even")?;
+    Ok(Value::Number(x.round_half_even(digits)))
+}
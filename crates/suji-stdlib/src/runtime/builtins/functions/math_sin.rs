@@ -20,7 +20,6 @@ pub fn builtin_math_sin(args: &[Value]) -> Result<Value, RuntimeError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rust_decimal::prelude::ToPrimitive;
     #[test]
     fn test_sin_zero() {
         let v = builtin_math_sin(&[Value::Number(suji_values::value::DecimalNumber::from_i64(
@@ -28,7 +27,7 @@ mod tests {
         ))])
         .unwrap();
         if let Value::Number(n) = v {
-            assert!(n.inner().to_f64().unwrap().abs() < 1e-12);
+            assert!(n.to_f64().unwrap().abs() < 1e-12);
         } else {
             panic!()
         }
@@ -0,0 +1,16 @@
+use crate::runtime::builtins::math::{checked_result, from_decimal, to_decimal_list, variance};
+use rust_decimal::MathematicalOps;
+use suji_values::value::{RuntimeError, Value};
+
+/// math:stddev(list) - population standard deviation (square root of the variance)
+/// of a non-empty list of numbers
+pub fn builtin_math_stddev(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:stddev expects 1 argument".to_string(),
+        });
+    }
+    let values = to_decimal_list(&args[0], "stddev")?;
+    let y = variance(&values, "stddev")?.sqrt();
+    Ok(from_decimal(checked_result(y, "stddev")?))
+}
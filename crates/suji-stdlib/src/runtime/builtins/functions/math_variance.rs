@@ -0,0 +1,13 @@
+use crate::runtime::builtins::math::{from_decimal, to_decimal_list, variance};
+use suji_values::value::{RuntimeError, Value};
+
+/// math:variance(list) - population variance of a non-empty list of numbers
+pub fn builtin_math_variance(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "math:variance expects 1 argument".to_string(),
+        });
+    }
+    let values = to_decimal_list(&args[0], "variance")?;
+    Ok(from_decimal(variance(&values, "variance")?))
+}
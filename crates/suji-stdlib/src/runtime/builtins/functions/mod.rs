@@ -1,7 +1,12 @@
 //! Built-in function implementations.
 
+mod crypto_bcrypt_hash;
+mod crypto_bcrypt_verify;
+mod crypto_hmac;
 mod crypto_hmac_sha256;
+mod crypto_hmac_sha512;
 mod crypto_md5;
+mod crypto_pbkdf2;
 mod crypto_sha1;
 mod crypto_sha256;
 mod crypto_sha512;
@@ -9,52 +14,99 @@ mod csv_generate;
 mod csv_parse;
 mod encoding_base64_decode;
 mod encoding_base64_encode;
+mod encoding_base64url_decode;
+mod encoding_base64url_encode;
+mod encoding_csv_row;
 mod encoding_hex_decode;
 mod encoding_hex_encode;
+mod encoding_jsonl_generate;
+mod encoding_jsonl_parse;
 mod encoding_percent_decode;
 mod encoding_percent_encode;
+mod env_args_parse;
+mod fs_copy;
+mod fs_move;
+mod fs_read_dir;
+mod fs_remove;
+mod io_memory;
 mod io_open;
 mod json_generate;
 mod json_parse;
+mod json_parse_relaxed;
+mod json_path;
 mod math_acos;
 mod math_asin;
 mod math_atan;
 mod math_atan2;
+mod math_clamp;
 mod math_cos;
 mod math_exp;
+mod math_lerp;
 mod math_log;
 mod math_log10;
+mod math_map_range;
+mod math_mean;
+mod math_median;
+mod math_round_half_even;
 mod math_sin;
+mod math_stddev;
 mod math_tan;
+mod math_variance;
+mod os_chmod;
+mod os_chown;
+mod os_cpu_count;
+mod os_env_expand;
+mod os_exec;
 mod os_exit;
+mod os_getenv_or;
 mod os_gid;
 mod os_home_dir;
 mod os_hostname;
+mod os_interfaces;
+mod os_kill;
+mod os_mem_info;
 mod os_mkdir;
 mod os_name;
 mod os_pid;
 mod os_ppid;
+mod os_program_name;
 mod os_rm;
 mod os_rmdir;
 mod os_stat;
+mod os_temp_dir_create;
+mod os_temp_file;
 mod os_tmp_dir;
 mod os_uid;
 mod os_uptime_ms;
+mod os_watch;
 mod os_work_dir;
+mod print_format;
 mod random_random;
 mod random_seed;
+mod random_weighted_choice;
+mod time_deadline;
 mod time_format_iso;
 mod time_now;
 mod time_parse_iso;
 mod time_sleep;
+mod time_sleep_until;
+mod time_tick_next;
+mod time_tick_wait;
+mod time_to_local;
+mod time_to_zone;
 mod toml_generate;
 mod toml_parse;
 mod uuid_v5;
 mod yaml_generate;
 mod yaml_parse;
 
+pub use crypto_bcrypt_hash::builtin_crypto_bcrypt_hash;
+pub use crypto_bcrypt_verify::builtin_crypto_bcrypt_verify;
+pub use crypto_hmac::builtin_crypto_hmac;
 pub use crypto_hmac_sha256::builtin_crypto_hmac_sha256;
+pub use crypto_hmac_sha512::builtin_crypto_hmac_sha512;
 pub use crypto_md5::builtin_crypto_md5;
+pub use crypto_pbkdf2::builtin_crypto_pbkdf2;
 pub use crypto_sha1::builtin_crypto_sha1;
 pub use crypto_sha256::builtin_crypto_sha256;
 pub use crypto_sha512::builtin_crypto_sha512;
@@ -62,44 +114,86 @@ pub use csv_generate::builtin_csv_generate;
 pub use csv_parse::builtin_csv_parse;
 pub use encoding_base64_decode::builtin_encoding_base64_decode;
 pub use encoding_base64_encode::builtin_encoding_base64_encode;
+pub use encoding_base64url_decode::builtin_encoding_base64url_decode;
+pub use encoding_base64url_encode::builtin_encoding_base64url_encode;
+pub use encoding_csv_row::builtin_encoding_csv_row;
 pub use encoding_hex_decode::builtin_encoding_hex_decode;
 pub use encoding_hex_encode::builtin_encoding_hex_encode;
+pub use encoding_jsonl_generate::builtin_encoding_jsonl_generate;
+pub use encoding_jsonl_parse::builtin_encoding_jsonl_parse;
 pub use encoding_percent_decode::builtin_encoding_percent_decode;
 pub use encoding_percent_encode::builtin_encoding_percent_encode;
+pub use env_args_parse::builtin_env_args_parse;
+pub use fs_copy::builtin_fs_copy;
+pub use fs_move::builtin_fs_move;
+pub use fs_read_dir::builtin_fs_read_dir;
+pub use fs_remove::builtin_fs_remove;
+pub use io_memory::builtin_io_memory;
 pub use io_open::builtin_io_open;
 pub use json_generate::builtin_json_generate;
 pub use json_parse::builtin_json_parse;
+pub use json_parse_relaxed::builtin_json_parse_relaxed;
+pub use json_path::builtin_json_path;
 pub use math_acos::builtin_math_acos;
 pub use math_asin::builtin_math_asin;
 pub use math_atan::builtin_math_atan;
 pub use math_atan2::builtin_math_atan2;
+pub use math_clamp::builtin_math_clamp;
 pub use math_cos::builtin_math_cos;
 pub use math_exp::builtin_math_exp;
+pub use math_lerp::builtin_math_lerp;
 pub use math_log::builtin_math_log;
 pub use math_log10::builtin_math_log10;
+pub use math_map_range::builtin_math_map_range;
+pub use math_mean::builtin_math_mean;
+pub use math_median::builtin_math_median;
+pub use math_round_half_even::builtin_math_round_half_even;
 pub use math_sin::builtin_math_sin;
+pub use math_stddev::builtin_math_stddev;
 pub use math_tan::builtin_math_tan;
+pub use math_variance::builtin_math_variance;
+pub use os_chmod::builtin_os_chmod;
+pub use os_chown::builtin_os_chown;
+pub use os_cpu_count::builtin_os_cpu_count;
+pub use os_env_expand::builtin_os_env_expand;
+pub use os_exec::builtin_os_exec;
 pub use os_exit::builtin_os_exit;
+pub use os_getenv_or::builtin_os_getenv_or;
 pub use os_gid::builtin_os_gid;
 pub use os_home_dir::builtin_os_home_dir;
 pub use os_hostname::builtin_os_hostname;
+pub use os_interfaces::builtin_os_interfaces;
+pub use os_kill::builtin_os_kill;
+pub use os_mem_info::builtin_os_mem_info;
 pub use os_mkdir::builtin_os_mkdir;
 pub use os_name::builtin_os_name;
 pub use os_pid::builtin_os_pid;
 pub use os_ppid::builtin_os_ppid;
+pub use os_program_name::builtin_os_program_name;
 pub use os_rm::builtin_os_rm;
 pub use os_rmdir::builtin_os_rmdir;
 pub use os_stat::builtin_os_stat;
+pub use os_temp_dir_create::builtin_os_temp_dir_create;
+pub use os_temp_file::builtin_os_temp_file;
 pub use os_tmp_dir::builtin_os_tmp_dir;
 pub use os_uid::builtin_os_uid;
 pub use os_uptime_ms::builtin_os_uptime_ms;
+pub use os_watch::builtin_os_watch;
 pub use os_work_dir::builtin_os_work_dir;
+pub use print_format::builtin_print_format;
 pub use random_random::builtin_random_random;
 pub use random_seed::builtin_random_seed;
+pub use random_weighted_choice::builtin_random_weighted_choice;
+pub use time_deadline::builtin_time_deadline;
 pub use time_format_iso::builtin_time_format_iso;
 pub use time_now::builtin_time_now;
 pub use time_parse_iso::builtin_time_parse_iso;
 pub use time_sleep::builtin_time_sleep;
+pub use time_sleep_until::builtin_time_sleep_until;
+pub use time_tick_next::builtin_time_tick_next;
+pub use time_tick_wait::builtin_time_tick_wait;
+pub use time_to_local::builtin_time_to_local;
+pub use time_to_zone::builtin_time_to_zone;
 pub use toml_generate::builtin_toml_generate;
 pub use toml_parse::builtin_toml_parse;
 pub use uuid_v5::builtin_uuid_v5;
@@ -0,0 +1,102 @@
+//! Built-in: os:chmod(path, mode) -> nil (set permission bits).
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Sets the permission bits of `path`. `mode` is an octal string such as `"755"`,
+/// `"0644"` or `"0o700"`, or a number such as the `mode` field of `os:stat()`; file type
+/// bits above `0o7777` in a number are ignored.
+pub fn builtin_os_chmod(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:chmod(path, mode) expects 2 arguments".to_string(),
+        });
+    }
+
+    let path = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "os:chmod expects path to be a string".to_string(),
+            });
+        }
+    };
+    let mode = parse_mode(&args[1])?;
+
+    set_mode(path, mode)?;
+    Ok(Value::Nil)
+}
+
+fn parse_mode(value: &Value) -> Result<u32, RuntimeError> {
+    let invalid = || RuntimeError::InvalidOperation {
+        message: format!(
+            "os:chmod expects mode as an octal string like \"755\" or a non-negative integer, got {}",
+            value
+        ),
+    };
+    match value {
+        Value::String(s) => {
+            let digits = s.strip_prefix("0o").unwrap_or(s);
+            if digits.is_empty() || digits.len() > 4 {
+                return Err(invalid());
+            }
+            u32::from_str_radix(digits, 8).map_err(|_| invalid())
+        }
+        Value::Number(n) => n
+            .to_i64_checked()
+            .and_then(|m| u32::try_from(m).ok())
+            .map(|m| m & 0o7777)
+            .ok_or_else(invalid),
+        _ => Err(RuntimeError::TypeError {
+            message: "os:chmod expects mode to be a string or number".to_string(),
+        }),
+    }
+}
+
+#[cfg(unix)]
+fn set_mode(path: &str, mode: u32) -> Result<(), RuntimeError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).map_err(|e| {
+        RuntimeError::InvalidOperation {
+            message: format!("Failed to change mode of '{}': {}", path, e),
+        }
+    })
+}
+
+#[cfg(not(unix))]
+fn set_mode(path: &str, _mode: u32) -> Result<(), RuntimeError> {
+    Err(RuntimeError::InvalidOperation {
+        message: format!(
+            "Failed to change mode of '{}': os:chmod is only supported on Unix-like systems",
+            path
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suji_values::value::DecimalNumber;
+
+    #[test]
+    fn test_parse_mode() {
+        let text = |s: &str| Value::String(s.to_string());
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+
+        assert_eq!(parse_mode(&text("755")).unwrap(), 0o755);
+        assert_eq!(parse_mode(&text("0644")).unwrap(), 0o644);
+        assert_eq!(parse_mode(&text("0o700")).unwrap(), 0o700);
+        assert_eq!(parse_mode(&num(0o100755)).unwrap(), 0o755);
+
+        for bad in [text("789"), text(""), text("77777"), text("rwx"), num(-1)] {
+            assert!(matches!(
+                parse_mode(&bad),
+                Err(RuntimeError::InvalidOperation { .. })
+            ));
+        }
+        assert!(matches!(
+            parse_mode(&Value::Nil),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+//! Built-in: os:chown(path, uid, gid) -> nil (change owner and group).
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Changes the owner and group of `path`. Pass `nil` for `uid` or `gid` to leave it
+/// unchanged. Symlinks are followed. Unix-like systems only.
+pub fn builtin_os_chown(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 3 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:chown(path, uid, gid) expects 3 arguments".to_string(),
+        });
+    }
+
+    let path = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "os:chown expects path to be a string".to_string(),
+            });
+        }
+    };
+    let uid = id_arg(&args[1], "uid")?;
+    let gid = id_arg(&args[2], "gid")?;
+
+    set_owner(path, uid, gid)?;
+    Ok(Value::Nil)
+}
+
+fn id_arg(value: &Value, name: &str) -> Result<Option<u32>, RuntimeError> {
+    match value {
+        Value::Nil => Ok(None),
+        Value::Number(n) => n
+            .to_i64_checked()
+            .and_then(|id| u32::try_from(id).ok())
+            .map(Some)
+            .ok_or_else(|| RuntimeError::InvalidOperation {
+                message: format!(
+                    "os:chown expects {} to be a non-negative integer, got {}",
+                    name, n
+                ),
+            }),
+        _ => Err(RuntimeError::TypeError {
+            message: format!("os:chown expects {} to be a number or nil", name),
+        }),
+    }
+}
+
+#[cfg(unix)]
+fn set_owner(path: &str, uid: Option<u32>, gid: Option<u32>) -> Result<(), RuntimeError> {
+    std::os::unix::fs::chown(path, uid, gid).map_err(|e| RuntimeError::InvalidOperation {
+        message: format!("Failed to change owner of '{}': {}", path, e),
+    })
+}
+
+#[cfg(not(unix))]
+fn set_owner(path: &str, _uid: Option<u32>, _gid: Option<u32>) -> Result<(), RuntimeError> {
+    Err(RuntimeError::InvalidOperation {
+        message: format!(
+            "Failed to change owner of '{}': os:chown is only supported on Unix-like systems",
+            path
+        ),
+    })
+}
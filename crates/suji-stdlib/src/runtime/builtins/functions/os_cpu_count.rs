@@ -0,0 +1,24 @@
+//! Built-in: os:cpu_count() -> number | nil (logical CPU count).
+
+use suji_values::value::{DecimalNumber, RuntimeError, Value};
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
+
+/// Returns the number of logical CPUs, or nil when the platform cannot report it.
+pub fn builtin_os_cpu_count(args: &[Value]) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:cpu_count() takes no arguments".to_string(),
+        });
+    }
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return Ok(Value::Nil);
+    }
+
+    let sys =
+        System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()));
+    match sys.cpus().len() {
+        0 => Ok(Value::Nil),
+        count => Ok(Value::Number(DecimalNumber::from_usize(count))),
+    }
+}
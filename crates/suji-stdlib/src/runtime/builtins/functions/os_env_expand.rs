@@ -0,0 +1,135 @@
+//! Built-in: os:env_expand(text, keep_unknown = false) -> string (expand $VAR references).
+
+use suji_values::get_effective_env_var;
+use suji_values::value::{RuntimeError, Value};
+
+/// Replaces `$NAME` and `${NAME}` with environment variables, consulting the env
+/// overlay first so values assigned through `env:var` or loaded by dotenv are seen.
+/// Unknown variables expand to an empty string, or stay as written when
+/// `keep_unknown` is true. `\$` produces a literal `$`; any other `$` that does not
+/// start a reference (and an unclosed `${`) is kept as-is.
+pub fn builtin_os_env_expand(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (text, keep_unknown) = match args {
+        [Value::String(text)] => (text, false),
+        [Value::String(text), Value::Boolean(keep)] => (text, *keep),
+        [_] | [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "os:env_expand expects a string and a boolean keep_unknown flag"
+                    .to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "os:env_expand(text, keep_unknown) expects 1 or 2 arguments".to_string(),
+            });
+        }
+    };
+
+    Ok(Value::String(expand(
+        text,
+        keep_unknown,
+        get_effective_env_var,
+    )))
+}
+
+fn expand(text: &str, keep_unknown: bool, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['$', '\\']) {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if let Some(after) = rest.strip_prefix("\\$") {
+            out.push('$');
+            rest = after;
+            continue;
+        }
+        if rest.starts_with('\\') {
+            out.push('\\');
+            rest = &rest[1..];
+            continue;
+        }
+
+        // `rest` starts with `$`: find the name and how much text the reference spans
+        let (name, len) = match rest[1..].strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) if is_name(&braced[..end]) => (&braced[..end], end + 3),
+                _ => ("", 0),
+            },
+            None => {
+                let end = rest[1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .map_or(rest.len() - 1, |end| end);
+                match is_name(&rest[1..1 + end]) {
+                    true => (&rest[1..1 + end], end + 1),
+                    false => ("", 0),
+                }
+            }
+        };
+
+        if len == 0 {
+            out.push('$');
+            rest = &rest[1..];
+            continue;
+        }
+
+        match lookup(name) {
+            Some(value) => out.push_str(&value),
+            None if keep_unknown => out.push_str(&rest[..len]),
+            None => {}
+        }
+        rest = &rest[len..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Variable names: a letter or `_`, then letters, digits or `_`
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/ann".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_references() {
+        assert_eq!(
+            expand("$HOME/.config:${HOME}x", false, lookup),
+            "/home/ann/.config:/home/annx"
+        );
+        assert_eq!(expand("[$EMPTY]", false, lookup), "[]");
+        assert_eq!(expand("a $MISSING ${MISSING} b", false, lookup), "a   b");
+        assert_eq!(
+            expand("a $MISSING ${MISSING} b", true, lookup),
+            "a $MISSING ${MISSING} b"
+        );
+    }
+
+    #[test]
+    fn test_expand_keeps_literal_dollars() {
+        assert_eq!(expand("cost: \\$HOME", false, lookup), "cost: $HOME");
+        assert_eq!(
+            expand("$ $5 ${ ${1} ${HOME", false, lookup),
+            "$ $5 ${ ${1} ${HOME"
+        );
+        assert_eq!(expand("C:\\dir\\$HOME", false, lookup), "C:\\dir$HOME");
+        assert_eq!(expand("C:\\dir\\x", false, lookup), "C:\\dir\\x");
+        assert_eq!(expand("end$", false, lookup), "end$");
+    }
+}
@@ -0,0 +1,114 @@
+//! Built-in: os:exec(command, args = [], options = {}) -> string (stdout of a shell command).
+
+use indexmap::IndexMap;
+use suji_values::run_shell_with_input;
+use suji_values::value::{MapKey, RuntimeError, Value};
+
+/// Runs `command` through the shell with `args` appended as quoted words and returns its
+/// stdout, trimmed of one trailing newline like backtick commands.
+/// Options: `stdin` (string) is written to the child's stdin, which is then closed.
+pub fn builtin_os_exec(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.is_empty() || args.len() > 3 {
+        return Err(RuntimeError::ArityMismatch {
+            message: format!(
+                "os:exec(command[, args[, options]]) expects 1 to 3 arguments, got {}",
+                args.len()
+            ),
+        });
+    }
+
+    let mut command = match &args[0] {
+        Value::String(s) => s.clone(),
+        other => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "os:exec command must be a string, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+    };
+
+    match args.get(1) {
+        None => {}
+        Some(Value::List(items)) => {
+            for item in items {
+                let word = match item {
+                    Value::String(s) => s.clone(),
+                    Value::Number(n) => n.to_string(),
+                    other => {
+                        return Err(RuntimeError::TypeError {
+                            message: format!(
+                                "os:exec args must be strings or numbers, got {}",
+                                other.type_name()
+                            ),
+                        });
+                    }
+                };
+                command.push(' ');
+                command.push_str(&shell_quote(&word));
+            }
+        }
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!("os:exec args must be a list, got {}", other.type_name()),
+            });
+        }
+    }
+
+    let stdin = match args.get(2) {
+        None => None,
+        Some(Value::Map(map)) => stdin_option(map)?,
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!("os:exec options must be a map, got {}", other.type_name()),
+            });
+        }
+    };
+
+    run_shell_with_input(&command, stdin.as_deref()).map(Value::String)
+}
+
+fn stdin_option(map: &IndexMap<MapKey, Value>) -> Result<Option<String>, RuntimeError> {
+    let mut stdin = None;
+
+    for (key, value) in map {
+        match (key, value) {
+            (MapKey::String(k), Value::String(text)) if k == "stdin" => stdin = Some(text.clone()),
+            (MapKey::String(k), other) if k == "stdin" => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "os:exec expects option 'stdin' to be a string, got {}",
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "os:exec got unknown option '{}' (expected stdin)",
+                        key.to_value()
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(stdin)
+}
+
+/// Quote a word for POSIX shells: wrap in single quotes, escaping embedded ones
+fn shell_quote(word: &str) -> String {
+    format!("'{}'", word.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("plain"), "'plain'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+    }
+}
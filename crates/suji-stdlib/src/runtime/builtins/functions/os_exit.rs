@@ -1,8 +1,11 @@
 //! Built-in: os:exit(code) -> (terminates process, never returns).
 
+use std::io::Write;
+use suji_values::IoContext;
 use suji_values::value::{RuntimeError, Value};
 
-/// Terminates the process with the given exit code.
+/// Terminates the process with the given exit code, first writing out buffered
+/// file streams and stdout/stderr, since exiting skips the flush streams do on drop.
 pub fn builtin_os_exit(args: &[Value]) -> Result<Value, RuntimeError> {
     if args.len() != 1 {
         return Err(RuntimeError::ArityMismatch {
@@ -36,5 +39,8 @@ pub fn builtin_os_exit(args: &[Value]) -> Result<Value, RuntimeError> {
         }
     };
 
+    IoContext::flush_buffered_streams();
+    std::io::stdout().flush().ok();
+    std::io::stderr().flush().ok();
     std::process::exit(code);
 }
@@ -0,0 +1,28 @@
+//! Built-in: os:getenv_or(name, default) -> string (environment variable or fallback).
+
+use suji_values::get_effective_env_var;
+use suji_values::value::{RuntimeError, Value};
+
+/// Returns the value of an environment variable, or `default` when it is unset.
+/// Consults the env overlay first so values assigned through `env:var` are visible.
+pub fn builtin_os_getenv_or(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:getenv_or(name, default) expects 2 arguments".to_string(),
+        });
+    }
+
+    let name = match &args[0] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "os:getenv_or name must be a string".to_string(),
+            });
+        }
+    };
+
+    match get_effective_env_var(name) {
+        Some(value) => Ok(Value::String(value)),
+        None => Ok(args[1].clone()),
+    }
+}
@@ -0,0 +1,48 @@
+//! Built-in: os:interfaces() -> list ({name, address, is_loopback} per interface address).
+
+use indexmap::IndexMap;
+use suji_values::value::{MapKey, RuntimeError, Value};
+use sysinfo::Networks;
+
+/// Lists the addresses of the machine's network interfaces, one map per address,
+/// sorted by interface name. Returns an empty list when the platform cannot
+/// enumerate interfaces.
+pub fn builtin_os_interfaces(args: &[Value]) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:interfaces() takes no arguments".to_string(),
+        });
+    }
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return Ok(Value::List(Vec::new()));
+    }
+
+    let networks = Networks::new_with_refreshed_list();
+    let mut addresses: Vec<_> = networks
+        .iter()
+        .flat_map(|(name, data)| data.ip_networks().iter().map(move |ip| (name, ip.addr)))
+        .collect();
+    addresses.sort();
+
+    let interfaces = addresses
+        .into_iter()
+        .map(|(name, address)| {
+            let mut entry = IndexMap::new();
+            entry.insert(
+                MapKey::String("name".to_string()),
+                Value::String(name.clone()),
+            );
+            entry.insert(
+                MapKey::String("address".to_string()),
+                Value::String(address.to_string()),
+            );
+            entry.insert(
+                MapKey::String("is_loopback".to_string()),
+                Value::Boolean(address.is_loopback()),
+            );
+            Value::Map(entry)
+        })
+        .collect();
+    Ok(Value::List(interfaces))
+}
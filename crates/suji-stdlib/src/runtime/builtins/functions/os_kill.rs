@@ -0,0 +1,122 @@
+//! Built-in: os:kill(pid, signal = "TERM") -> true (send a signal to a process).
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Signal names accepted by os:kill, with their Unix numbers. Names may be given in any
+/// case and with or without the `SIG` prefix.
+#[cfg(unix)]
+const SIGNALS: &[(&str, libc::c_int)] = &[
+    ("HUP", libc::SIGHUP),
+    ("INT", libc::SIGINT),
+    ("QUIT", libc::SIGQUIT),
+    ("KILL", libc::SIGKILL),
+    ("USR1", libc::SIGUSR1),
+    ("USR2", libc::SIGUSR2),
+    ("TERM", libc::SIGTERM),
+    ("STOP", libc::SIGSTOP),
+    ("CONT", libc::SIGCONT),
+];
+
+/// Sends `signal` (a name such as `"TERM"` or a signal number) to the process `pid`.
+/// Returns true once the signal was delivered; a missing process or a permission
+/// failure raises with the OS message. On Windows every signal terminates the process.
+pub fn builtin_os_kill(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (pid, signal) = match args {
+        [pid] => (pid, None),
+        [pid, signal] => (pid, Some(signal)),
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "os:kill(pid, signal) expects 1 or 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let pid = match pid {
+        Value::Number(n) => n
+            .to_i64_checked()
+            .and_then(|pid| u32::try_from(pid).ok())
+            .filter(|pid| *pid > 0)
+            .ok_or_else(|| RuntimeError::InvalidOperation {
+                message: format!("os:kill expects pid to be a positive integer, got {}", n),
+            })?,
+        other => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "os:kill expects pid to be a number, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+    };
+
+    send_signal(pid, signal)?;
+    Ok(Value::Boolean(true))
+}
+
+#[cfg(unix)]
+fn send_signal(pid: u32, signal: Option<&Value>) -> Result<(), RuntimeError> {
+    let signal = match signal {
+        None => libc::SIGTERM,
+        Some(Value::String(name)) => {
+            let upper = name.to_ascii_uppercase();
+            let bare = upper.strip_prefix("SIG").unwrap_or(&upper);
+            SIGNALS
+                .iter()
+                .find(|(known, _)| *known == bare)
+                .map(|(_, number)| *number)
+                .ok_or_else(|| RuntimeError::InvalidOperation {
+                    message: format!("os:kill does not know the signal '{}'", name),
+                })?
+        }
+        Some(Value::Number(n)) => n
+            .to_i64_checked()
+            .and_then(|number| libc::c_int::try_from(number).ok())
+            .filter(|number| *number >= 0)
+            .ok_or_else(|| RuntimeError::InvalidOperation {
+                message: format!(
+                    "os:kill expects signal to be a non-negative integer, got {}",
+                    n
+                ),
+            })?,
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "os:kill expects signal to be a name or number, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+    };
+
+    let pid = libc::pid_t::try_from(pid).map_err(|_| RuntimeError::InvalidOperation {
+        message: format!("os:kill pid {} is out of range", pid),
+    })?;
+    if unsafe { libc::kill(pid, signal) } != 0 {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "Failed to signal process {}: {}",
+                pid,
+                std::io::Error::last_os_error()
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn send_signal(pid: u32, _signal: Option<&Value>) -> Result<(), RuntimeError> {
+    use sysinfo::{Pid, ProcessesToUpdate, System};
+
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    match system.process(pid) {
+        Some(process) if process.kill() => Ok(()),
+        Some(_) => Err(RuntimeError::InvalidOperation {
+            message: format!("Failed to terminate process {}", pid),
+        }),
+        None => Err(RuntimeError::InvalidOperation {
+            message: format!("Failed to terminate process {}: no such process", pid),
+        }),
+    }
+}
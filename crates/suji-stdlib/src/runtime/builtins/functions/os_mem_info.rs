@@ -0,0 +1,38 @@
+//! Built-in: os:mem_info() -> map | nil ({total, available} memory in bytes).
+
+use indexmap::IndexMap;
+use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
+
+/// Returns total and available physical memory in bytes, or nil when the
+/// platform cannot report it.
+pub fn builtin_os_mem_info(args: &[Value]) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:mem_info() takes no arguments".to_string(),
+        });
+    }
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return Ok(Value::Nil);
+    }
+
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing().with_memory(MemoryRefreshKind::nothing().with_ram()),
+    );
+    let total = sys.total_memory();
+    if total == 0 {
+        return Ok(Value::Nil);
+    }
+
+    let mut info = IndexMap::new();
+    info.insert(
+        MapKey::String("total".to_string()),
+        Value::Number(DecimalNumber::from_u64(total)),
+    );
+    info.insert(
+        MapKey::String("available".to_string()),
+        Value::Number(DecimalNumber::from_u64(sys.available_memory())),
+    );
+    Ok(Value::Map(info))
+}
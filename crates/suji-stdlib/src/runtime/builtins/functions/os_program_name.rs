@@ -0,0 +1,16 @@
+//! Built-in: os:program_name() -> string|nil (path of the running script).
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Returns the script path as passed to the interpreter, or nil when no script
+/// is running (e.g. in the REPL). Leading interpreter options are skipped.
+pub fn builtin_os_program_name(args: &[Value]) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:program_name() takes no arguments".to_string(),
+        });
+    }
+
+    let script = std::env::args().skip(1).find(|a| !a.starts_with('-'));
+    Ok(script.map(Value::String).unwrap_or(Value::Nil))
+}
@@ -0,0 +1,34 @@
+//! Built-in: os:temp_file() -> string (create a new empty file with a unique temp path).
+
+use crate::runtime::builtins::common::random_temp_path;
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
+use suji_values::value::{RuntimeError, Value};
+
+const MAX_ATTEMPTS: usize = 16;
+
+/// Atomically creates a fresh, empty file in the temp directory and returns its path.
+pub fn builtin_os_temp_file(args: &[Value]) -> Result<Value, RuntimeError> {
+    if !args.is_empty() {
+        return Err(RuntimeError::ArityMismatch {
+            message: "os:temp_file() takes no arguments".to_string(),
+        });
+    }
+
+    for _ in 0..MAX_ATTEMPTS {
+        let path = random_temp_path("suji");
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => return Ok(Value::String(path.to_string_lossy().to_string())),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!("Failed to create temp file '{}': {}", path.display(), e),
+                });
+            }
+        }
+    }
+
+    Err(RuntimeError::InvalidOperation {
+        message: "Failed to create a unique temp file".to_string(),
+    })
+}
@@ -0,0 +1,48 @@
+//! Built-in: os:watch(path, on_change) -> nil (calls on_change({path, kind}) for each change).
+
+use crate::runtime::builtins::watch::Watch;
+use indexmap::IndexMap;
+use std::time::Duration;
+use suji_interpreter::AstInterpreter;
+use suji_values::methods::ClosureEvaluator;
+use suji_values::value::{MapKey, RuntimeError, Value};
+
+/// How long to wait for changes before checking for Ctrl+C again
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Block until `on_change` returns false. The watch is dropped on every way out,
+/// including errors raised by `on_change` and interrupts.
+pub fn builtin_os_watch(args: &[Value], call_fn: ClosureEvaluator) -> Result<Value, RuntimeError> {
+    let (path, on_change) = match args {
+        [Value::String(path), Value::Function(on_change)] => (path, on_change),
+        [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "os:watch expects a path string and a function".to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "os:watch(path, on_change) expects 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let watch = Watch::start(path).map_err(|message| RuntimeError::InvalidOperation { message })?;
+    loop {
+        AstInterpreter::check_interrupt()?;
+        let changes = watch
+            .next(POLL_INTERVAL)
+            .map_err(|message| RuntimeError::InvalidOperation { message })?;
+        for (path, kind) in changes {
+            let mut event = IndexMap::new();
+            event.insert(MapKey::String("path".to_string()), Value::String(path));
+            event.insert(
+                MapKey::String("kind".to_string()),
+                Value::String(kind.to_string()),
+            );
+            if call_fn(on_change, vec![Value::Map(event)], None)? == Value::Boolean(false) {
+                return Ok(Value::Nil);
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Built-in: print_format(text, options, default_end) -> string (line written by print/println).
+
+use suji_values::value::{MapKey, RuntimeError, Value};
+
+/// Formats the output of `print`/`println` when called with an options map
+/// `{sep, end, out}`. A tuple `text` is printed as separate values joined by `sep`
+/// (default `" "`); anything else is printed as is. `end` (default `default_end`) is
+/// appended. `out` is only checked here; the caller writes to it.
+pub fn builtin_print_format(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (text, options, default_end) = match args {
+        [text, Value::Map(options), Value::String(default_end)] => (text, options, default_end),
+        [_, _, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "print_format expects a value, an options map and a default end"
+                    .to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "print_format(text, options, default_end) expects 3 arguments".to_string(),
+            });
+        }
+    };
+
+    let mut sep = " ";
+    let mut end = default_end.as_str();
+    for (key, value) in options {
+        match (key, value) {
+            (MapKey::String(key), Value::String(s)) if key == "sep" => sep = s,
+            (MapKey::String(key), Value::String(s)) if key == "end" => end = s,
+            (MapKey::String(key), Value::Nil | Value::Stream(_) | Value::StreamProxy(_))
+                if key == "out" => {}
+            (MapKey::String(key), other) if ["sep", "end", "out"].contains(&key.as_str()) => {
+                let expected = match key.as_str() {
+                    "out" => "a stream",
+                    _ => "a string",
+                };
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "print option '{}' must be {}, got {}",
+                        key,
+                        expected,
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!("Unknown print option '{}' (expected sep, end or out)", key),
+                });
+            }
+        }
+    }
+
+    let body = match text {
+        Value::Tuple(items) => items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .join(sep),
+        other => other.to_string(),
+    };
+    Ok(Value::String(body + end))
+}
@@ -0,0 +1,74 @@
+use crate::runtime::builtins::random::rng_f64;
+use rust_decimal::Decimal;
+use suji_values::value::{RuntimeError, Value};
+
+/// random:weighted_choice(items, weights) - pick one item with probability proportional
+/// to its weight. Uses the shared RNG, so results follow random:seed.
+pub fn builtin_random_weighted_choice(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (items, weights) = match args {
+        [Value::List(items), Value::List(weights)] => (items, weights),
+        [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "random:weighted_choice expects a list of items and a list of weights"
+                    .to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "random:weighted_choice expects 2 arguments".to_string(),
+            });
+        }
+    };
+
+    if items.len() != weights.len() {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "random:weighted_choice got {} items but {} weights",
+                items.len(),
+                weights.len()
+            ),
+        });
+    }
+
+    let mut cumulative = Vec::with_capacity(weights.len());
+    let mut total = Decimal::ZERO;
+    for (i, weight) in weights.iter().enumerate() {
+        let overflow = || RuntimeError::InvalidOperation {
+            message: "random:weighted_choice total weight overflowed".to_string(),
+        };
+        let weight = match weight {
+            Value::Number(n) if !n.is_negative() => n.inner().ok_or_else(overflow)?,
+            Value::Number(_) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!("random:weighted_choice weight at index {} is negative", i),
+                });
+            }
+            other => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "random:weighted_choice weights must be numbers, got {} at index {}",
+                        other.type_name(),
+                        i
+                    ),
+                });
+            }
+        };
+        total = total.checked_add(weight).ok_or_else(overflow)?;
+        cumulative.push(total);
+    }
+
+    if total.is_zero() {
+        return Err(RuntimeError::InvalidOperation {
+            message: "random:weighted_choice needs a positive total weight".to_string(),
+        });
+    }
+
+    // rng_f64 is in [0, 1), so the target is always below the total and the first
+    // running sum above it belongs to an item with a non-zero weight
+    let target = Decimal::try_from(rng_f64()).unwrap_or(Decimal::ZERO) * total;
+    let index = cumulative
+        .iter()
+        .position(|sum| *sum > target)
+        .unwrap_or(items.len() - 1);
+    Ok(items[index].clone())
+}
@@ -0,0 +1,34 @@
+use crate::runtime::builtins::time::map_now;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use suji_values::value::{RuntimeError, Value};
+
+/// time:deadline(seconds) -> time map `seconds` from now (wall clock, UTC).
+/// Fractional seconds are rounded to the nearest millisecond.
+pub fn builtin_time_deadline(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "time:deadline expects 1 argument".to_string(),
+        });
+    }
+    let seconds = match &args[0] {
+        Value::Number(n) => n.inner(),
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "time:deadline requires number argument".to_string(),
+            });
+        }
+    };
+    let out_of_range = || RuntimeError::InvalidOperation {
+        message: "time:deadline is out of range".to_string(),
+    };
+    let ms = seconds
+        .and_then(|seconds| seconds.checked_mul(Decimal::from(1000)))
+        .and_then(|ms| ms.round().to_i64())
+        .ok_or_else(out_of_range)?;
+    let deadline = chrono::TimeDelta::try_milliseconds(ms)
+        .and_then(|delta| chrono::Utc::now().checked_add_signed(delta))
+        .ok_or_else(out_of_range)?;
+    let iso = deadline.to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+    Ok(map_now(deadline.timestamp_millis(), iso, "Z".to_string()))
+}
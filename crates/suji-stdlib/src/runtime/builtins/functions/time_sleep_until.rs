@@ -0,0 +1,20 @@
+use crate::runtime::builtins::time::epoch_ms_arg;
+use suji_values::value::{RuntimeError, Value};
+
+/// time:sleep_until(time) -> nil, blocking until the given instant.
+///
+/// The remaining time is read from the wall clock once and then slept on the monotonic
+/// clock, so adjusting the system clock mid-sleep does not stretch it. Instants in the
+/// past return immediately.
+pub fn builtin_time_sleep_until(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "time:sleep_until expects 1 argument".to_string(),
+        });
+    }
+    let target = epoch_ms_arg(&args[0], "time:sleep_until")?;
+    if let Ok(remaining) = (target - chrono::Utc::now()).to_std() {
+        std::thread::sleep(remaining);
+    }
+    Ok(Value::Nil)
+}
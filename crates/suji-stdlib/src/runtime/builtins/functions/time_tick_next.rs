@@ -0,0 +1,51 @@
+//! Built-in: time_tick_next(previous, interval_seconds) -> number (schedule used by time:every).
+
+use crate::runtime::builtins::time::monotonic_ms;
+use suji_values::value::{DecimalNumber, RuntimeError, Value};
+
+/// Returns the next tick on the monotonic clock (see `time_tick_wait`). With `previous`
+/// nil the schedule starts now; otherwise ticks stay on the grid `previous + k * interval`
+/// so time spent between ticks does not shift later ones, and ticks that have already
+/// passed are skipped rather than fired back to back.
+pub fn builtin_time_tick_next(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "time_tick_next(previous, interval_seconds) expects 2 arguments".to_string(),
+        });
+    }
+
+    let interval_ms = match &args[1] {
+        Value::Number(n) => n
+            .mul(&DecimalNumber::from_i64(1000))
+            .ok()
+            .and_then(|ms| ms.round().to_i64_checked()),
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "time:every expects the interval in seconds as a number".to_string(),
+            });
+        }
+    }
+    .filter(|ms| *ms >= 1)
+    .ok_or_else(|| RuntimeError::InvalidOperation {
+        message: "time:every interval must be at least 0.001 seconds".to_string(),
+    })?;
+
+    let now = monotonic_ms();
+    let next = match &args[0] {
+        Value::Nil => now,
+        Value::Number(n) => {
+            let previous = n.to_i64_checked().ok_or_else(|| RuntimeError::TypeError {
+                message: "time_tick_next expects the previous tick as an integer".to_string(),
+            })?;
+            let missed = (now - previous).max(0) / interval_ms;
+            previous.saturating_add(interval_ms.saturating_mul(missed + 1))
+        }
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "time_tick_next expects the previous tick as a number or nil".to_string(),
+            });
+        }
+    };
+
+    Ok(Value::Number(DecimalNumber::from_i64(next)))
+}
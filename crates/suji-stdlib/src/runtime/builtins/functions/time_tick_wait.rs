@@ -0,0 +1,39 @@
+//! Built-in: time_tick_wait(tick, max_wait_ms) -> bool (sleep used by time:every).
+
+use crate::runtime::builtins::time::monotonic_ms;
+use std::time::Duration;
+use suji_values::value::{RuntimeError, Value};
+
+/// Sleeps until `tick` (from `time_tick_next`) or for at most `max_wait_ms`, whichever
+/// comes first, and returns whether the tick has been reached. The cap keeps time:every
+/// returning to the interpreter, so Ctrl+C can interrupt long intervals.
+pub fn builtin_time_tick_wait(args: &[Value]) -> Result<Value, RuntimeError> {
+    let (tick, max_wait) = match args {
+        [Value::Number(tick), Value::Number(max_wait)] => {
+            match (tick.to_i64_checked(), max_wait.to_i64_checked()) {
+                (Some(tick), Some(max_wait)) if max_wait >= 0 => (tick, max_wait),
+                _ => {
+                    return Err(RuntimeError::TypeError {
+                        message: "time_tick_wait expects integer milliseconds".to_string(),
+                    });
+                }
+            }
+        }
+        [_, _] => {
+            return Err(RuntimeError::TypeError {
+                message: "time_tick_wait expects a tick and a timeout in milliseconds".to_string(),
+            });
+        }
+        _ => {
+            return Err(RuntimeError::ArityMismatch {
+                message: "time_tick_wait(tick, max_wait_ms) expects 2 arguments".to_string(),
+            });
+        }
+    };
+
+    let remaining = tick - monotonic_ms();
+    if remaining > 0 {
+        std::thread::sleep(Duration::from_millis(remaining.min(max_wait) as u64));
+    }
+    Ok(Value::Boolean(monotonic_ms() >= tick))
+}
@@ -0,0 +1,16 @@
+//! Built-in: time:to_local(time) -> map (calendar fields in the local time zone).
+
+use crate::runtime::builtins::time::{epoch_ms_arg, map_zoned};
+use suji_values::value::{RuntimeError, Value};
+
+pub fn builtin_time_to_local(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 1 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "time:to_local expects 1 argument".to_string(),
+        });
+    }
+    let dt_utc = epoch_ms_arg(&args[0], "time:to_local")?;
+    let local = dt_utc.with_timezone(&chrono::Local);
+    let tz = local.offset().to_string();
+    Ok(map_zoned(&local, tz))
+}
@@ -0,0 +1,31 @@
+//! Built-in: time:to_zone(time, zone) -> map (calendar fields in an IANA time zone).
+
+use crate::runtime::builtins::time::{epoch_ms_arg, map_zoned};
+use suji_values::value::{RuntimeError, Value};
+
+pub fn builtin_time_to_zone(args: &[Value]) -> Result<Value, RuntimeError> {
+    if args.len() != 2 {
+        return Err(RuntimeError::ArityMismatch {
+            message: "time:to_zone expects 2 arguments".to_string(),
+        });
+    }
+    let dt_utc = epoch_ms_arg(&args[0], "time:to_zone")?;
+    let name = match &args[1] {
+        Value::String(s) => s,
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: "time:to_zone requires a zone name string".to_string(),
+            });
+        }
+    };
+    let zone: chrono_tz::Tz = name.parse().map_err(|_| RuntimeError::InvalidOperation {
+        message: format!(
+            "Invalid time zone name '{}': expected an IANA zone such as \"America/New_York\"",
+            name
+        ),
+    })?;
+    Ok(map_zoned(
+        &dt_utc.with_timezone(&zone),
+        zone.name().to_string(),
+    ))
+}
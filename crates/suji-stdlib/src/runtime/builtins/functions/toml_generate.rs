@@ -1,46 +1,120 @@
-//! Built-in: toml:generate(value) -> string.
+//! Built-in: toml:generate(value, options = {}) -> string.
 
-use super::super::toml::suji_to_toml_value;
-use suji_values::value::{RuntimeError, Value};
+use super::super::toml::{suji_to_toml_value, to_string_with_inline};
+use indexmap::IndexMap;
+use suji_values::value::{MapKey, RuntimeError, Value};
 
 /// Convert SUJI value to TOML string.
+///
+/// Nested maps become `[table]` sections and lists of maps `[[array]]` sections unless
+/// `options:inline` lists their paths, in which case they are written inline.
 pub fn builtin_toml_generate(args: &[Value]) -> Result<Value, RuntimeError> {
-    if args.len() != 1 {
+    if args.is_empty() || args.len() > 2 {
         return Err(RuntimeError::ArityMismatch {
-            message: "toml:generate() takes exactly one argument".to_string(),
+            message: "toml:generate() takes one or two arguments".to_string(),
         });
     }
 
     let suji_value = &args[0];
+    let inline = match args.get(1) {
+        None | Some(Value::Nil) => None,
+        Some(Value::Map(options)) => Some(inline_option(options)?),
+        Some(other) => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "toml:generate() options must be a map, got {}",
+                    other.type_name()
+                ),
+            });
+        }
+    };
 
     // Convert SUJI value to TOML value
     let toml_value = suji_to_toml_value(suji_value)?;
 
-    // Generate TOML string
     // TOML requires a table structure, so we wrap non-table values in a table
-    let toml_string = match toml_value {
-        toml::Value::Table(_) => {
-            toml::to_string(&toml_value).map_err(|e| RuntimeError::TomlGenerateError {
-                message: format!("TOML generation failed: {}", e),
-                value_type: suji_value.type_name().to_string(),
-            })?
-        }
+    let table = match toml_value {
+        toml::Value::Table(table) => table,
         _ => {
             // Wrap non-table values in a table with a "value" key
             let mut table = toml::map::Map::new();
             table.insert("value".to_string(), toml_value);
-            toml::to_string(&toml::Value::Table(table)).map_err(|e| {
-                RuntimeError::TomlGenerateError {
-                    message: format!("TOML generation failed: {}", e),
-                    value_type: suji_value.type_name().to_string(),
-                }
-            })?
+            table
+        }
+    };
+
+    // Generate TOML string
+    let toml_string = match inline.filter(|paths| !paths.is_empty()) {
+        None => toml::to_string(&table).map_err(|e| RuntimeError::TomlGenerateError {
+            message: format!("TOML generation failed: {}", e),
+            value_type: suji_value.type_name().to_string(),
+        })?,
+        Some(paths) => {
+            let (toml_string, unused) = to_string_with_inline(&table, &paths);
+            if let Some(&index) = unused.first() {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "toml:generate() inline path '{}' does not name a map or a list of maps",
+                        paths[index].join(".")
+                    ),
+                });
+            }
+            toml_string
         }
     };
 
     Ok(Value::String(toml_string))
 }
 
+/// Paths listed by `options:inline`, each a dotted string or a list of keys
+fn inline_option(options: &IndexMap<MapKey, Value>) -> Result<Vec<Vec<String>>, RuntimeError> {
+    let mut paths = Vec::new();
+    for (key, value) in options {
+        match (key, value) {
+            (MapKey::String(k), Value::List(items)) if k == "inline" => {
+                for item in items {
+                    paths.push(inline_path(item)?);
+                }
+            }
+            (MapKey::String(k), other) if k == "inline" => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "toml:generate() expects option 'inline' to be a list, got {}",
+                        other.type_name()
+                    ),
+                });
+            }
+            (key, _) => {
+                return Err(RuntimeError::InvalidOperation {
+                    message: format!(
+                        "toml:generate() got unknown option '{}' (expected inline)",
+                        key.to_value()
+                    ),
+                });
+            }
+        }
+    }
+    Ok(paths)
+}
+
+fn inline_path(item: &Value) -> Result<Vec<String>, RuntimeError> {
+    let keys = match item {
+        Value::String(path) => Some(path.split('.').map(str::to_string).collect()),
+        Value::List(keys) => keys
+            .iter()
+            .map(|key| match key {
+                Value::String(key) => Some(key.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    };
+    keys.ok_or_else(|| RuntimeError::TypeError {
+        message: "toml:generate() inline paths must be dotted strings or lists of key strings"
+            .to_string(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +207,39 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_toml_generate_inline_option() {
+        let string = |s: &str| Value::String(s.to_string());
+        let key = |s: &str| MapKey::String(s.to_string());
+        let mut point = IndexMap::new();
+        point.insert(key("x"), Value::Number(DecimalNumber::from_i64(1)));
+        let mut config = IndexMap::new();
+        config.insert(key("point"), Value::Map(point));
+        let config = Value::Map(config);
+        let options = |paths: Value| {
+            let mut options = IndexMap::new();
+            options.insert(key("inline"), paths);
+            Value::Map(options)
+        };
+
+        let result =
+            builtin_toml_generate(&[config.clone(), options(Value::List(vec![string("point")]))]);
+        assert_eq!(result.unwrap(), string("point = { x = 1 }\n"));
+
+        // Without paths the regular section layout is kept
+        let result = builtin_toml_generate(&[config.clone(), options(Value::List(vec![]))]);
+        assert_eq!(result.unwrap(), string("[point]\nx = 1\n"));
+
+        for bad in [
+            options(Value::List(vec![string("point.x")])),
+            options(string("point")),
+            options(Value::List(vec![Value::Nil])),
+            Value::List(vec![]),
+        ] {
+            assert!(builtin_toml_generate(&[config.clone(), bad]).is_err());
+        }
+    }
+
     #[test]
     fn test_toml_generate_wrong_argument_count() {
         let result = builtin_toml_generate(&[]);
@@ -140,6 +247,7 @@ mod tests {
 
         let result = builtin_toml_generate(&[
             Value::String("a".to_string()),
+            Value::Nil,
             Value::String("b".to_string()),
         ]);
         assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
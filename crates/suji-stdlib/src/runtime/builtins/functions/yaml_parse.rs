@@ -1,6 +1,6 @@
 //! Built-in: yaml:parse(string) -> value.
 
-use super::super::yaml::yaml_to_suji_value;
+use super::super::yaml::{check_aliases, yaml_to_suji_value};
 use suji_values::value::{RuntimeError, Value};
 
 /// Parse YAML string to SUJI value.
@@ -20,6 +20,9 @@ pub fn builtin_yaml_parse(args: &[Value]) -> Result<Value, RuntimeError> {
         }
     };
 
+    // Reject recursive or runaway aliases before the loader expands them
+    check_aliases(yaml_string)?;
+
     // Parse YAML string
     let yaml_value: yaml_rust2::Yaml = yaml_rust2::YamlLoader::load_from_str(yaml_string)
         .map_err(|e| RuntimeError::YamlParseError {
@@ -116,6 +119,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_yaml_parse_resolves_aliases() {
+        let yaml = "base: &base\n  host: localhost\n  port: 80\ndev: *base\nports: [&p 1, *p]";
+        let result = builtin_yaml_parse(&[Value::String(yaml.to_string())]).unwrap();
+        let Value::Map(map) = result else {
+            panic!("Expected map");
+        };
+        let key = |k: &str| suji_values::value::MapKey::String(k.to_string());
+        assert_eq!(map.get(&key("dev")), map.get(&key("base")));
+        assert_eq!(
+            map.get(&key("ports")),
+            Some(&Value::List(vec![
+                Value::Number(DecimalNumber::from_i64(1)),
+                Value::Number(DecimalNumber::from_i64(1)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_yaml_parse_rejects_recursive_alias() {
+        let result = builtin_yaml_parse(&[Value::String("a: &a\n  b: *a".to_string())]);
+        assert!(
+            matches!(result, Err(RuntimeError::YamlParseError { message, .. }) if message.contains("recursive"))
+        );
+
+        let result = builtin_yaml_parse(&[Value::String("a: *missing".to_string())]);
+        assert!(matches!(result, Err(RuntimeError::YamlParseError { .. })));
+    }
+
+    #[test]
+    fn test_yaml_parse_rejects_alias_expansion_bomb() {
+        let mut yaml = String::from("l0: &l0 [x, x, x, x, x, x, x, x, x, x]\n");
+        for level in 1..8 {
+            let prev = format!("*l{}", level - 1);
+            let items = [prev.as_str(); 10].join(", ");
+            yaml.push_str(&format!("l{level}: &l{level} [{items}]\n"));
+        }
+        let result = builtin_yaml_parse(&[Value::String(yaml)]);
+        assert!(
+            matches!(result, Err(RuntimeError::YamlParseError { message, .. }) if message.contains("expand"))
+        );
+    }
+
     #[test]
     fn test_yaml_parse_wrong_argument_type() {
         let result = builtin_yaml_parse(&[Value::Number(DecimalNumber::from_i64(42))]);
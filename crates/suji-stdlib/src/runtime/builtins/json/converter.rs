@@ -2,12 +2,23 @@
 
 use super::types::JsonError;
 use indexmap::IndexMap;
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde_json::{Map as JsonMap, Value as JsonValue};
-use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
+use std::str::FromStr;
+use suji_values::value::{DecimalNumber, MapKey, OrderedDecimal, RuntimeError, Value};
 
-/// Convert JSON value to SUJI value
+/// Convert JSON value to SUJI value. Object keys always become strings.
 pub fn json_to_suji_value(json_value: JsonValue) -> Result<Value, RuntimeError> {
+    convert_json(json_value, false)
+}
+
+/// Convert JSON value to SUJI value, turning object keys written in canonical
+/// number form (as produced by `suji_to_json_value`) back into number keys.
+pub fn json_to_suji_value_numeric_keys(json_value: JsonValue) -> Result<Value, RuntimeError> {
+    convert_json(json_value, true)
+}
+
+fn convert_json(json_value: JsonValue, numeric_keys: bool) -> Result<Value, RuntimeError> {
     match json_value {
         JsonValue::Null => Ok(Value::Nil),
         JsonValue::Bool(b) => Ok(Value::Boolean(b)),
@@ -30,15 +41,18 @@ pub fn json_to_suji_value(json_value: JsonValue) -> Result<Value, RuntimeError>
         JsonValue::Array(arr) => {
             let mut suji_array = Vec::new();
             for item in arr {
-                suji_array.push(json_to_suji_value(item)?);
+                suji_array.push(convert_json(item, numeric_keys)?);
             }
             Ok(Value::List(suji_array))
         }
         JsonValue::Object(obj) => {
             let mut suji_map = IndexMap::new();
             for (key, value) in obj {
-                let suji_key = MapKey::String(key);
-                let suji_value = json_to_suji_value(value)?;
+                let suji_key = match numeric_keys {
+                    true => parse_numeric_key(key),
+                    false => MapKey::String(key),
+                };
+                let suji_value = convert_json(value, numeric_keys)?;
                 suji_map.insert(suji_key, suji_value);
             }
             Ok(Value::Map(suji_map))
@@ -63,8 +77,7 @@ pub fn suji_to_json_value(suji_value: &Value) -> Result<JsonValue, RuntimeError>
                         .into()
                     })
             } else {
-                n.inner()
-                    .to_f64()
+                n.to_f64()
                     .and_then(serde_json::Number::from_f64)
                     .map(JsonValue::Number)
                     .ok_or_else(|| {
@@ -89,8 +102,15 @@ pub fn suji_to_json_value(suji_value: &Value) -> Result<JsonValue, RuntimeError>
             for (key, value) in map {
                 let key_str = match key {
                     MapKey::String(s) => s.clone(),
-                    MapKey::Number(n) => n.0.to_string(),
-                    MapKey::Boolean(b) => b.to_string(),
+                    MapKey::Number(n) => numeric_key_string(n),
+                    MapKey::Boolean(_) => {
+                        return Err(JsonError::GenerateError {
+                            message: "Map keys with booleans cannot be converted to JSON"
+                                .to_string(),
+                            value_type: "map with boolean keys".to_string(),
+                        }
+                        .into());
+                    }
                     MapKey::Tuple(_) => {
                         return Err(JsonError::GenerateError {
                             message: "Map keys with tuples cannot be converted to JSON".to_string(),
@@ -140,3 +160,78 @@ pub fn suji_to_json_value(suji_value: &Value) -> Result<JsonValue, RuntimeError>
         .into()),
     }
 }
+
+/// Canonical string form of a number key: no trailing zeros, no exponent
+/// (`1.50` and `1.5` both become `"1.5"`).
+fn numeric_key_string(n: &OrderedDecimal) -> String {
+    n.0.normalize().to_string()
+}
+
+/// Turn a JSON object key back into a number key when it is exactly the
+/// canonical form of a number; anything else (`"01"`, `"1.50"`, `" 1"`) stays a string.
+fn parse_numeric_key(key: String) -> MapKey {
+    match Decimal::from_str(&key) {
+        Ok(n) if numeric_key_string(&OrderedDecimal::new(n)) == key => {
+            MapKey::Number(OrderedDecimal::new(n))
+        }
+        _ => MapKey::String(key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number_key(n: i64) -> MapKey {
+        MapKey::Number(OrderedDecimal::new(Decimal::from(n)))
+    }
+
+    #[test]
+    fn test_numeric_keys_stringify_canonically() {
+        let mut map = IndexMap::new();
+        map.insert(number_key(1), Value::Nil);
+        map.insert(
+            MapKey::Number(OrderedDecimal::new(Decimal::from_str("2.50").unwrap())),
+            Value::Nil,
+        );
+        let json = suji_to_json_value(&Value::Map(map)).unwrap();
+        assert_eq!(json.to_string(), r#"{"1":null,"2.5":null}"#);
+    }
+
+    #[test]
+    fn test_boolean_and_tuple_keys_are_rejected() {
+        for key in [MapKey::Boolean(true), MapKey::Tuple(vec![number_key(1)])] {
+            let mut map = IndexMap::new();
+            map.insert(key, Value::Nil);
+            assert!(matches!(
+                suji_to_json_value(&Value::Map(map)),
+                Err(RuntimeError::JsonGenerateError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_numeric_keys_only_converts_canonical_numbers() {
+        let json: JsonValue =
+            serde_json::from_str(r#"{"1":1,"-2.5":2,"01":3,"1.50":4,"x":{"7":5}}"#).unwrap();
+
+        let Value::Map(plain) = json_to_suji_value(json.clone()).unwrap() else {
+            panic!("Expected map");
+        };
+        assert!(plain.keys().all(|k| matches!(k, MapKey::String(_))));
+
+        let Value::Map(map) = json_to_suji_value_numeric_keys(json).unwrap() else {
+            panic!("Expected map");
+        };
+        assert!(map.contains_key(&number_key(1)));
+        assert!(map.contains_key(&MapKey::Number(OrderedDecimal::new(
+            Decimal::from_str("-2.5").unwrap()
+        ))));
+        assert!(map.contains_key(&MapKey::String("01".to_string())));
+        assert!(map.contains_key(&MapKey::String("1.50".to_string())));
+        let Some(Value::Map(nested)) = map.get(&MapKey::String("x".to_string())) else {
+            panic!("Expected nested map");
+        };
+        assert!(nested.contains_key(&number_key(7)));
+    }
+}
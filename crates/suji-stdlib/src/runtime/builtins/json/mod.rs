@@ -1,6 +1,37 @@
 //! JSON conversion utilities.
 
 mod converter;
+mod relaxed;
 mod types;
 
-pub use converter::{json_to_suji_value, suji_to_json_value};
+pub use converter::{json_to_suji_value, json_to_suji_value_numeric_keys, suji_to_json_value};
+pub use relaxed::parse_relaxed_json;
+
+use suji_values::value::{RuntimeError, Value};
+
+/// Render a SUJI value as indented, multi-line JSON.
+pub fn to_pretty_json(value: &Value) -> Result<String, RuntimeError> {
+    let json_value = suji_to_json_value(value)?;
+    serde_json::to_string_pretty(&json_value).map_err(|e| RuntimeError::JsonGenerateError {
+        message: format!("Failed to serialize to JSON: {}", e),
+        value_type: value.type_name().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use suji_values::value::DecimalNumber;
+
+    #[test]
+    fn test_to_pretty_json_indents_nested_values() {
+        let value = Value::List(vec![
+            Value::Number(DecimalNumber::from_i64(1)),
+            Value::List(vec![Value::String("a".to_string())]),
+        ]);
+        assert_eq!(
+            to_pretty_json(&value).unwrap(),
+            "[\n  1,\n  [\n    \"a\"\n  ]\n]"
+        );
+    }
+}
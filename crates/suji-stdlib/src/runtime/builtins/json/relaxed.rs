@@ -0,0 +1,261 @@
+//! Relaxed ("JSON5-lite") input: `//` and `/* */` comments, trailing commas and
+//! unquoted object keys. The input is rewritten into strict JSON for serde_json while
+//! keeping track of where each byte came from, so errors point at the original text.
+
+use suji_values::value::RuntimeError;
+
+/// Parse relaxed JSON into a serde value. Syntax errors are reported as
+/// `JsonParseError` with the line and column in `input`.
+pub fn parse_relaxed_json(input: &str) -> Result<serde_json::Value, RuntimeError> {
+    let uncommented = strip_comments(input)?;
+    let (strict, origins) = quote_keys_and_drop_trailing_commas(&uncommented);
+
+    serde_json::from_str(&strict).map_err(|e| {
+        let offset = offset_of(&strict, e.line(), e.column())
+            .and_then(|offset| origins.get(offset).copied())
+            .unwrap_or(input.len());
+        let (line, column) = line_and_column(input, offset);
+        let message = e.to_string();
+        let reason = message
+            .rsplit_once(" at line ")
+            .map_or(message.as_str(), |(reason, _)| reason);
+        parse_error(
+            input,
+            format!("{} at line {} column {}", reason, line, column),
+        )
+    })
+}
+
+/// Replace comments with spaces (newlines are kept), so byte offsets do not move
+fn strip_comments(input: &str) -> Result<String, RuntimeError> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    let mut in_string = false;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if in_string {
+            out.push(b);
+            match b {
+                b'\\' if i + 1 < bytes.len() => {
+                    out.push(bytes[i + 1]);
+                    i += 1;
+                }
+                b'"' => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        match (b, bytes.get(i + 1)) {
+            (b'"', _) => {
+                in_string = true;
+                out.push(b);
+                i += 1;
+            }
+            (b'/', Some(b'/')) => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    out.push(b' ');
+                    i += 1;
+                }
+            }
+            (b'/', Some(b'*')) => {
+                let start = i;
+                out.extend_from_slice(b"  ");
+                i += 2;
+                loop {
+                    match (bytes.get(i), bytes.get(i + 1)) {
+                        (Some(b'*'), Some(b'/')) => {
+                            out.extend_from_slice(b"  ");
+                            i += 2;
+                            break;
+                        }
+                        (Some(b'\n'), _) => out.push(b'\n'),
+                        (Some(_), _) => out.push(b' '),
+                        (None, _) => {
+                            let (line, column) = line_and_column(input, start);
+                            return Err(parse_error(
+                                input,
+                                format!(
+                                    "unterminated block comment at line {} column {}",
+                                    line, column
+                                ),
+                            ));
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            _ => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    // Only ASCII bytes were replaced and whole comments are blanked, so this stays UTF-8
+    Ok(String::from_utf8(out).expect("comment stripping keeps UTF-8 intact"))
+}
+
+/// Quote bare identifier keys and drop commas before `}` or `]`. Returns the strict text
+/// and, for each of its bytes, the offset of the byte in `input` it came from.
+fn quote_keys_and_drop_trailing_commas(input: &str) -> (String, Vec<usize>) {
+    let bytes = input.as_bytes();
+    let mut out = String::with_capacity(input.len());
+    let mut origins = Vec::with_capacity(input.len());
+    // Open brackets, to know whether a `,` is followed by an object key
+    let mut stack = Vec::new();
+    let mut expect_key = false;
+    let mut i = 0;
+
+    let mut push = |out: &mut String, text: &str, origin: usize| {
+        out.push_str(text);
+        origins.extend(std::iter::repeat_n(origin, text.len()));
+    };
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'"' => {
+                let end = string_end(bytes, i);
+                push(&mut out, &input[i..end], i);
+                expect_key = false;
+                i = end;
+                continue;
+            }
+            b'{' | b'[' => {
+                stack.push(b);
+                expect_key = b == b'{';
+            }
+            b'}' | b']' => {
+                stack.pop();
+                expect_key = false;
+            }
+            b',' => {
+                let next = bytes[i + 1..]
+                    .iter()
+                    .position(|c| !c.is_ascii_whitespace())
+                    .map(|offset| bytes[i + 1 + offset]);
+                if matches!(next, Some(b'}') | Some(b']')) {
+                    push(&mut out, " ", i);
+                    i += 1;
+                    continue;
+                }
+                expect_key = stack.last() == Some(&b'{');
+            }
+            _ if expect_key && is_identifier_start(b) => {
+                let end = bytes[i..]
+                    .iter()
+                    .position(|c| !is_identifier_char(*c))
+                    .map_or(bytes.len(), |offset| i + offset);
+                push(&mut out, "\"", i);
+                push(&mut out, &input[i..end], i);
+                push(&mut out, "\"", end - 1);
+                expect_key = false;
+                i = end;
+                continue;
+            }
+            _ if b.is_ascii_whitespace() => {}
+            _ => expect_key = false,
+        }
+
+        // Copy one whole character so multibyte text is never split
+        let len = input[i..].chars().next().map_or(1, char::len_utf8);
+        push(&mut out, &input[i..i + len], i);
+        i += len;
+    }
+
+    (out, origins)
+}
+
+/// Offset just past the string literal starting at `start` (or the end of input)
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn is_identifier_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b == b'$'
+}
+
+fn is_identifier_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+/// Byte offset of a 1-based line and column as reported by serde_json
+fn offset_of(text: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start = match line {
+        0 | 1 => 0,
+        _ => text
+            .match_indices('\n')
+            .nth(line - 2)
+            .map(|(index, _)| index + 1)?,
+    };
+    Some((line_start + column.saturating_sub(1)).min(text.len()))
+}
+
+/// 1-based line and character column of a byte offset
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+fn parse_error(input: &str, message: String) -> RuntimeError {
+    RuntimeError::JsonParseError {
+        message: format!("Invalid JSON: {}", message),
+        json_input: Some(input.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_relaxed_json_accepts_comments_trailing_commas_and_bare_keys() {
+        let input = r#"{
+            // server settings
+            host: "localhost", /* inline */ port: 8080,
+            "url": "http://example.com/*not a comment*/",
+            tags: ["a", "b",],
+            $meta: { _id: 1, },
+        }"#;
+        assert_eq!(
+            parse_relaxed_json(input).unwrap(),
+            json!({
+                "host": "localhost",
+                "port": 8080,
+                "url": "http://example.com/*not a comment*/",
+                "tags": ["a", "b"],
+                "$meta": { "_id": 1 },
+            })
+        );
+    }
+
+    #[test]
+    fn test_relaxed_json_reports_positions_in_the_original_input() {
+        let err = parse_relaxed_json("{\n  name: \"x\",\n  age: @,\n}").unwrap_err();
+        let RuntimeError::JsonParseError { message, .. } = err else {
+            panic!("Expected JsonParseError");
+        };
+        assert!(message.ends_with("at line 3 column 8"), "{}", message);
+
+        let err = parse_relaxed_json("[1, /* open").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unterminated block comment at line 1 column 5")
+        );
+    }
+}
@@ -1,16 +1,14 @@
 use rust_decimal::Decimal;
-use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::prelude::FromPrimitive;
 use suji_values::value::{DecimalNumber, RuntimeError, Value};
 
 pub fn to_f64(arg: &Value, name: &str) -> Result<f64, RuntimeError> {
     match arg {
-        Value::Number(n) => {
-            n.inner()
-                .to_f64()
-                .ok_or_else(|| RuntimeError::InvalidNumberConversion {
-                    message: format!("{} cannot be represented as f64", name),
-                })
-        }
+        Value::Number(n) => n
+            .to_f64()
+            .ok_or_else(|| RuntimeError::InvalidNumberConversion {
+                message: format!("{} cannot be represented as f64", name),
+            }),
         _ => Err(RuntimeError::TypeError {
             message: format!("{} must be a number", name),
         }),
@@ -26,12 +24,14 @@ pub fn from_f64(v: f64) -> Result<Value, RuntimeError> {
     let dec = rust_decimal::Decimal::from_f64(v).ok_or_else(|| RuntimeError::InvalidOperation {
         message: "cannot convert result to decimal".to_string(),
     })?;
-    Ok(Value::Number(DecimalNumber(dec)))
+    Ok(Value::Number(DecimalNumber::from_decimal(dec)))
 }
 
 pub fn to_decimal(arg: &Value, name: &str) -> Result<Decimal, RuntimeError> {
     match arg {
-        Value::Number(n) => Ok(n.inner()),
+        Value::Number(n) => n.inner().ok_or_else(|| RuntimeError::InvalidOperation {
+            message: format!("{} is outside the decimal range", name),
+        }),
         _ => Err(RuntimeError::TypeError {
             message: format!("{} must be a number", name),
         }),
@@ -39,7 +39,14 @@ pub fn to_decimal(arg: &Value, name: &str) -> Result<Decimal, RuntimeError> {
 }
 
 pub fn from_decimal(v: Decimal) -> Value {
-    Value::Number(DecimalNumber(v))
+    Value::Number(DecimalNumber::from_decimal(v))
+}
+
+/// Unwrap the result of checked decimal arithmetic, reporting overflow as an error
+pub fn checked_result(v: Option<Decimal>, fname: &str) -> Result<Decimal, RuntimeError> {
+    v.ok_or_else(|| RuntimeError::InvalidOperation {
+        message: format!("{} result is out of range", fname),
+    })
 }
 
 pub fn ensure_in_unit_interval(x: f64, fname: &str) -> Result<(), RuntimeError> {
@@ -59,3 +66,64 @@ pub fn ensure_positive_decimal(x: Decimal, fname: &str) -> Result<(), RuntimeErr
     }
     Ok(())
 }
+
+/// The numbers of a non-empty list argument, for the aggregate functions
+pub fn to_decimal_list(arg: &Value, fname: &str) -> Result<Vec<Decimal>, RuntimeError> {
+    let items = match arg {
+        Value::List(items) => items,
+        other => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "math:{} expects a list of numbers, got {}",
+                    fname,
+                    other.type_name()
+                ),
+            });
+        }
+    };
+    if items.is_empty() {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!("math:{} of an empty list is undefined", fname),
+        });
+    }
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| match item {
+            Value::Number(n) => n.inner().ok_or_else(|| RuntimeError::InvalidOperation {
+                message: format!(
+                    "math:{} element {} is outside the decimal range",
+                    fname, index
+                ),
+            }),
+            other => Err(RuntimeError::TypeError {
+                message: format!(
+                    "math:{} expects a list of numbers, but element {} is a {}",
+                    fname,
+                    index,
+                    other.type_name()
+                ),
+            }),
+        })
+        .collect()
+}
+
+/// Arithmetic mean of a non-empty list of numbers
+pub fn mean(values: &[Decimal], fname: &str) -> Result<Decimal, RuntimeError> {
+    let sum = values
+        .iter()
+        .try_fold(Decimal::ZERO, |sum, value| sum.checked_add(*value));
+    let count = Decimal::from(values.len());
+    checked_result(sum.and_then(|sum| sum.checked_div(count)), fname)
+}
+
+/// Population variance (mean squared distance from the mean) of a non-empty list
+pub fn variance(values: &[Decimal], fname: &str) -> Result<Decimal, RuntimeError> {
+    let mean = mean(values, fname)?;
+    let squares = values.iter().try_fold(Decimal::ZERO, |sum, value| {
+        let distance = value.checked_sub(mean)?;
+        sum.checked_add(distance.checked_mul(distance)?)
+    });
+    let count = Decimal::from(values.len());
+    checked_result(squares.and_then(|sum| sum.checked_div(count)), fname)
+}
@@ -1,6 +1,7 @@
 //! Builtins: functions and modules used by the runtime.
 
 mod common;
+mod data_loader;
 mod functions;
 mod json;
 mod math;
@@ -10,10 +11,12 @@ mod time;
 mod toml;
 mod virtual_std;
 mod virtual_std_adapter;
+pub mod watch;
 mod yaml;
 
 use functions::*;
-use suji_values::Env;
+pub use json::to_pretty_json;
+use suji_values::{Env, Value};
 
 /// Setup the global environment with built-in functions
 pub fn setup_global_env(_env: &Env) {
@@ -21,6 +24,19 @@ pub fn setup_global_env(_env: &Env) {
     register_all_builtins();
 }
 
+/// Setup the global environment like `setup_global_env` and bind each of `constants`
+/// as a read-only variable, so embedders can pass configuration into a script without
+/// the script reassigning or mutating it
+pub fn setup_global_env_with_constants(
+    env: &Env,
+    constants: impl IntoIterator<Item = (String, Value)>,
+) {
+    setup_global_env(env);
+    for (name, value) in constants {
+        env.define_constant(&name, value);
+    }
+}
+
 /// Setup the module registry with virtual std resolver
 /// Should be called before any module loading that depends on std
 pub fn setup_module_registry(registry: &mut suji_runtime::ModuleRegistry) {
@@ -32,6 +48,9 @@ pub fn setup_module_registry(registry: &mut suji_runtime::ModuleRegistry) {
 
     // Set up source evaluator
     registry.set_source_evaluator(suji_interpreter::eval_module_source_callback);
+
+    // Set up data file loader for `import "file.json" as name`
+    registry.set_data_loader(data_loader::load_data_file);
 }
 
 /// Register all builtin function implementations with the runtime
@@ -40,10 +59,15 @@ pub fn register_all_builtins() {
 
     // Register JSON functions
     register_builtin("json_parse", builtin_json_parse as suji_runtime::BuiltinFn);
+    register_builtin(
+        "json_parse_relaxed",
+        builtin_json_parse_relaxed as suji_runtime::BuiltinFn,
+    );
     register_builtin(
         "json_generate",
         builtin_json_generate as suji_runtime::BuiltinFn,
     );
+    register_builtin("json_path", builtin_json_path as suji_runtime::BuiltinFn);
 
     // Register YAML functions
     register_builtin("yaml_parse", builtin_yaml_parse as suji_runtime::BuiltinFn);
@@ -61,6 +85,13 @@ pub fn register_all_builtins() {
 
     // Register IO functions
     register_builtin("io_open", builtin_io_open as suji_runtime::BuiltinFn);
+    register_builtin("io_memory", builtin_io_memory as suji_runtime::BuiltinFn);
+
+    // Register print functions
+    register_builtin(
+        "print_format",
+        builtin_print_format as suji_runtime::BuiltinFn,
+    );
 
     // Register random functions
     register_builtin(
@@ -71,10 +102,30 @@ pub fn register_all_builtins() {
         "random_seed",
         builtin_random_seed as suji_runtime::BuiltinFn,
     );
+    register_builtin(
+        "random_weighted_choice",
+        builtin_random_weighted_choice as suji_runtime::BuiltinFn,
+    );
 
     // Register time functions
     register_builtin("time_now", builtin_time_now as suji_runtime::BuiltinFn);
     register_builtin("time_sleep", builtin_time_sleep as suji_runtime::BuiltinFn);
+    register_builtin(
+        "time_sleep_until",
+        builtin_time_sleep_until as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "time_tick_next",
+        builtin_time_tick_next as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "time_tick_wait",
+        builtin_time_tick_wait as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "time_deadline",
+        builtin_time_deadline as suji_runtime::BuiltinFn,
+    );
     register_builtin(
         "time_parse_iso",
         builtin_time_parse_iso as suji_runtime::BuiltinFn,
@@ -83,6 +134,14 @@ pub fn register_all_builtins() {
         "time_format_iso",
         builtin_time_format_iso as suji_runtime::BuiltinFn,
     );
+    register_builtin(
+        "time_to_local",
+        builtin_time_to_local as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "time_to_zone",
+        builtin_time_to_zone as suji_runtime::BuiltinFn,
+    );
 
     // Register uuid functions (v5 only; v4 is SUJI)
     register_builtin("uuid_v5", builtin_uuid_v5 as suji_runtime::BuiltinFn);
@@ -96,6 +155,14 @@ pub fn register_all_builtins() {
         "encoding_base64_decode",
         builtin_encoding_base64_decode as suji_runtime::BuiltinFn,
     );
+    register_builtin(
+        "encoding_base64url_encode",
+        builtin_encoding_base64url_encode as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "encoding_base64url_decode",
+        builtin_encoding_base64url_decode as suji_runtime::BuiltinFn,
+    );
     register_builtin(
         "encoding_hex_encode",
         builtin_encoding_hex_encode as suji_runtime::BuiltinFn,
@@ -112,6 +179,18 @@ pub fn register_all_builtins() {
         "encoding_percent_decode",
         builtin_encoding_percent_decode as suji_runtime::BuiltinFn,
     );
+    register_builtin(
+        "encoding_jsonl_parse",
+        builtin_encoding_jsonl_parse as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "encoding_jsonl_generate",
+        builtin_encoding_jsonl_generate as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "encoding_csv_row",
+        builtin_encoding_csv_row as suji_runtime::BuiltinFn,
+    );
 
     // Register math functions
     register_builtin("math_sin", builtin_math_sin as suji_runtime::BuiltinFn);
@@ -124,6 +203,29 @@ pub fn register_all_builtins() {
     register_builtin("math_log", builtin_math_log as suji_runtime::BuiltinFn);
     register_builtin("math_log10", builtin_math_log10 as suji_runtime::BuiltinFn);
     register_builtin("math_exp", builtin_math_exp as suji_runtime::BuiltinFn);
+    register_builtin("math_clamp", builtin_math_clamp as suji_runtime::BuiltinFn);
+    register_builtin("math_lerp", builtin_math_lerp as suji_runtime::BuiltinFn);
+    register_builtin(
+        "math_map_range",
+        builtin_math_map_range as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "math_round_half_even",
+        builtin_math_round_half_even as suji_runtime::BuiltinFn,
+    );
+    register_builtin("math_mean", builtin_math_mean as suji_runtime::BuiltinFn);
+    register_builtin(
+        "math_median",
+        builtin_math_median as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "math_variance",
+        builtin_math_variance as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "math_stddev",
+        builtin_math_stddev as suji_runtime::BuiltinFn,
+    );
 
     // Register crypto functions
     register_builtin("crypto_md5", builtin_crypto_md5 as suji_runtime::BuiltinFn);
@@ -143,6 +245,26 @@ pub fn register_all_builtins() {
         "crypto_hmac_sha256",
         builtin_crypto_hmac_sha256 as suji_runtime::BuiltinFn,
     );
+    register_builtin(
+        "crypto_hmac_sha512",
+        builtin_crypto_hmac_sha512 as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "crypto_hmac",
+        builtin_crypto_hmac as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "crypto_pbkdf2",
+        builtin_crypto_pbkdf2 as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "crypto_bcrypt_hash",
+        builtin_crypto_bcrypt_hash as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "crypto_bcrypt_verify",
+        builtin_crypto_bcrypt_verify as suji_runtime::BuiltinFn,
+    );
 
     // Register CSV functions
     register_builtin("csv_parse", builtin_csv_parse as suji_runtime::BuiltinFn);
@@ -151,6 +273,21 @@ pub fn register_all_builtins() {
         builtin_csv_generate as suji_runtime::BuiltinFn,
     );
 
+    // Register env functions
+    register_builtin(
+        "env_args_parse",
+        builtin_env_args_parse as suji_runtime::BuiltinFn,
+    );
+
+    // Register filesystem functions
+    register_builtin("fs_copy", builtin_fs_copy as suji_runtime::BuiltinFn);
+    register_builtin("fs_move", builtin_fs_move as suji_runtime::BuiltinFn);
+    register_builtin("fs_remove", builtin_fs_remove as suji_runtime::BuiltinFn);
+    register_builtin(
+        "fs_read_dir",
+        builtin_fs_read_dir as suji_runtime::BuiltinFn,
+    );
+
     // Register OS functions
     register_builtin("os_name", builtin_os_name as suji_runtime::BuiltinFn);
     register_builtin(
@@ -163,6 +300,14 @@ pub fn register_all_builtins() {
         builtin_os_uptime_ms as suji_runtime::BuiltinFn,
     );
     register_builtin("os_tmp_dir", builtin_os_tmp_dir as suji_runtime::BuiltinFn);
+    register_builtin(
+        "os_temp_file",
+        builtin_os_temp_file as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_temp_dir_create",
+        builtin_os_temp_dir_create as suji_runtime::BuiltinFn,
+    );
     register_builtin(
         "os_home_dir",
         builtin_os_home_dir as suji_runtime::BuiltinFn,
@@ -174,9 +319,41 @@ pub fn register_all_builtins() {
     register_builtin("os_exit", builtin_os_exit as suji_runtime::BuiltinFn);
     register_builtin("os_pid", builtin_os_pid as suji_runtime::BuiltinFn);
     register_builtin("os_ppid", builtin_os_ppid as suji_runtime::BuiltinFn);
+    register_builtin("os_kill", builtin_os_kill as suji_runtime::BuiltinFn);
     register_builtin("os_rm", builtin_os_rm as suji_runtime::BuiltinFn);
     register_builtin("os_rmdir", builtin_os_rmdir as suji_runtime::BuiltinFn);
     register_builtin("os_stat", builtin_os_stat as suji_runtime::BuiltinFn);
+    register_builtin("os_chmod", builtin_os_chmod as suji_runtime::BuiltinFn);
+    register_builtin("os_chown", builtin_os_chown as suji_runtime::BuiltinFn);
+    suji_runtime::register_callback_builtin(
+        "os_watch",
+        builtin_os_watch as suji_runtime::CallbackBuiltinFn,
+    );
     register_builtin("os_uid", builtin_os_uid as suji_runtime::BuiltinFn);
     register_builtin("os_gid", builtin_os_gid as suji_runtime::BuiltinFn);
+    register_builtin(
+        "os_cpu_count",
+        builtin_os_cpu_count as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_mem_info",
+        builtin_os_mem_info as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_interfaces",
+        builtin_os_interfaces as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_getenv_or",
+        builtin_os_getenv_or as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_env_expand",
+        builtin_os_env_expand as suji_runtime::BuiltinFn,
+    );
+    register_builtin(
+        "os_program_name",
+        builtin_os_program_name as suji_runtime::BuiltinFn,
+    );
+    register_builtin("os_exec", builtin_os_exec as suji_runtime::BuiltinFn);
 }
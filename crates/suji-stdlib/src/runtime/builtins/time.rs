@@ -1,5 +1,7 @@
 use indexmap::IndexMap;
-use suji_values::value::{DecimalNumber, MapKey, Value};
+use once_cell::sync::Lazy;
+use std::time::Instant;
+use suji_values::value::{DecimalNumber, MapKey, RuntimeError, Value};
 
 pub fn map_now(epoch_ms: i64, iso: String, tz: String) -> Value {
     let mut map = IndexMap::new();
@@ -21,3 +23,89 @@ pub fn map_epoch_tz(epoch_ms: i64, tz: String) -> Value {
     map.insert(MapKey::String("tz".to_string()), Value::String(tz));
     Value::Map(map)
 }
+
+/// Broken-down calendar components of `dt`, labelled with the zone name `tz`
+pub fn map_zoned<Tz: chrono::TimeZone>(dt: &chrono::DateTime<Tz>, tz: String) -> Value {
+    use chrono::{Datelike, Offset, Timelike};
+
+    let offset = dt.offset().fix();
+    let number = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+    let mut map = IndexMap::new();
+    map.insert(
+        MapKey::String("epoch_ms".to_string()),
+        number(dt.timestamp_millis()),
+    );
+    map.insert(
+        MapKey::String("iso".to_string()),
+        Value::String(dt.to_rfc3339_opts(chrono::SecondsFormat::Millis, false)),
+    );
+    map.insert(MapKey::String("tz".to_string()), Value::String(tz));
+    map.insert(
+        MapKey::String("offset".to_string()),
+        Value::String(offset.to_string()),
+    );
+    map.insert(
+        MapKey::String("offset_seconds".to_string()),
+        number(offset.local_minus_utc() as i64),
+    );
+    map.insert(MapKey::String("year".to_string()), number(dt.year() as i64));
+    map.insert(
+        MapKey::String("month".to_string()),
+        number(dt.month() as i64),
+    );
+    map.insert(MapKey::String("day".to_string()), number(dt.day() as i64));
+    map.insert(MapKey::String("hour".to_string()), number(dt.hour() as i64));
+    map.insert(
+        MapKey::String("minute".to_string()),
+        number(dt.minute() as i64),
+    );
+    map.insert(
+        MapKey::String("second".to_string()),
+        number(dt.second() as i64),
+    );
+    map.insert(
+        MapKey::String("millisecond".to_string()),
+        number((dt.nanosecond() / 1_000_000).min(999) as i64),
+    );
+    Value::Map(map)
+}
+
+/// Read a timestamp argument: epoch milliseconds or a time map with an `epoch_ms` key
+pub fn epoch_ms_arg(
+    value: &Value,
+    func: &str,
+) -> Result<chrono::DateTime<chrono::Utc>, RuntimeError> {
+    let number = match value {
+        Value::Number(n) => n,
+        Value::Map(map) => match map.get(&MapKey::String("epoch_ms".to_string())) {
+            Some(Value::Number(n)) => n,
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    message: format!("{} requires a map with a numeric epoch_ms", func),
+                });
+            }
+        },
+        _ => {
+            return Err(RuntimeError::TypeError {
+                message: format!("{} requires epoch_ms as a number or time map", func),
+            });
+        }
+    };
+    let epoch_ms = number
+        .to_i64_checked()
+        .ok_or_else(|| RuntimeError::TypeError {
+            message: "epoch_ms must be an integer".to_string(),
+        })?;
+    chrono::DateTime::from_timestamp_millis(epoch_ms).ok_or_else(|| {
+        RuntimeError::InvalidOperation {
+            message: "invalid epoch".to_string(),
+        }
+    })
+}
+
+/// Milliseconds on a monotonic clock that starts with the process. Unlike epoch
+/// milliseconds it never jumps when the system clock is adjusted.
+pub fn monotonic_ms() -> i64 {
+    static START: Lazy<Instant> = Lazy::new(Instant::now);
+    START.elapsed().as_millis() as i64
+}
@@ -2,5 +2,7 @@
 
 mod converter;
 mod types;
+mod writer;
 
 pub use converter::{suji_to_toml_value, toml_to_suji_value};
+pub use writer::to_string_with_inline;
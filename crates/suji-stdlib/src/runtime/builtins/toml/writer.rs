@@ -0,0 +1,172 @@
+//! TOML output with chosen tables written inline.
+
+use std::collections::HashSet;
+use toml::{Table, Value};
+
+/// Write `table` as a TOML document laid out like `toml::to_string`, except that the
+/// tables and arrays of tables found at the `inline` paths (key lists from the root;
+/// array elements share their array's path) are written as values: `point = { x = 1 }`
+/// instead of a `[point]` section and `items = [{ id = 1 }]` instead of `[[items]]`.
+///
+/// Returns the document and the indices of the `inline` paths that matched nothing.
+pub fn to_string_with_inline(table: &Table, inline: &[Vec<String>]) -> (String, Vec<usize>) {
+    let mut writer = Writer {
+        out: String::new(),
+        inline,
+        used: HashSet::new(),
+    };
+    writer.table(&mut Vec::new(), table);
+
+    let unused = (0..inline.len())
+        .filter(|index| !writer.used.contains(index))
+        .collect();
+    (writer.out, unused)
+}
+
+struct Writer<'a> {
+    out: String,
+    inline: &'a [Vec<String>],
+    used: HashSet<usize>,
+}
+
+impl Writer<'_> {
+    /// Write the plain keys of `table`, then one section per nested table
+    fn table(&mut self, path: &mut Vec<String>, table: &Table) {
+        let mut sections = Vec::new();
+        for (key, value) in table {
+            path.push(key.clone());
+            if self.is_section(path, value) {
+                sections.push((key, value));
+            } else {
+                self.out
+                    .push_str(&format!("{} = {}\n", key_repr(key), value));
+            }
+            path.pop();
+        }
+
+        for (key, value) in sections {
+            path.push(key.clone());
+            match value {
+                Value::Table(inner) => {
+                    // Like toml::to_string, a table holding only sections gets no header
+                    let has_values = inner.is_empty()
+                        || inner.iter().any(|(key, value)| {
+                            path.push(key.clone());
+                            let section = self.is_section(path, value);
+                            path.pop();
+                            !section
+                        });
+                    if has_values {
+                        self.header(&format!("[{}]", path_repr(path)));
+                    }
+                    self.table(path, inner);
+                }
+                Value::Array(items) => {
+                    for item in items {
+                        self.header(&format!("[[{}]]", path_repr(path)));
+                        if let Value::Table(inner) = item {
+                            self.table(path, inner);
+                        }
+                    }
+                }
+                _ => unreachable!("only tables and arrays of tables become sections"),
+            }
+            path.pop();
+        }
+    }
+
+    /// Whether `value` at `path` gets its own `[...]` / `[[...]]` section(s)
+    fn is_section(&mut self, path: &[String], value: &Value) -> bool {
+        let table_like = match value {
+            Value::Table(_) => true,
+            Value::Array(items) => {
+                !items.is_empty() && items.iter().all(|item| matches!(item, Value::Table(_)))
+            }
+            _ => false,
+        };
+        if !table_like {
+            return false;
+        }
+        match self.inline.iter().position(|inline| inline == path) {
+            Some(index) => {
+                self.used.insert(index);
+                false
+            }
+            None => true,
+        }
+    }
+
+    fn header(&mut self, header: &str) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out.push_str(header);
+        self.out.push('\n');
+    }
+}
+
+fn path_repr(path: &[String]) -> String {
+    path.iter()
+        .map(|key| key_repr(key))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// A key as written in TOML: bare when possible, quoted otherwise
+fn key_repr(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    match bare {
+        true => key.to_string(),
+        false => Value::String(key.to_string()).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_matches_toml_layout_without_inline_paths() {
+        let text = r#"
+            title = "x"
+            "odd key" = 1
+            empty = {}
+            deep = { a = { b = { c = 1 } } }
+            servers = [{ ip = "1", ports = [1, 2] }, { ip = "2", meta = { rack = 3 } }]
+        "#;
+        let table: Table = text.parse().unwrap();
+        let (written, unused) = to_string_with_inline(&table, &[]);
+        assert_eq!(written, toml::to_string(&table).unwrap());
+        assert!(unused.is_empty());
+    }
+
+    #[test]
+    fn test_writes_chosen_tables_inline() {
+        let text = r#"
+            name = "app"
+            point = { x = 1, y = 2 }
+            servers = [{ ip = "1", meta = { rack = 3 } }]
+            owner = { name = "a" }
+        "#;
+        let table: Table = text.parse().unwrap();
+        let inline = [
+            path(&["point"]),
+            path(&["servers", "meta"]),
+            path(&["missing"]),
+        ];
+        let (written, unused) = to_string_with_inline(&table, &inline);
+        assert_eq!(
+            written,
+            "name = \"app\"\npoint = { x = 1, y = 2 }\n\n[owner]\nname = \"a\"\n\n[[servers]]\nip = \"1\"\nmeta = { rack = 3 }\n"
+        );
+        assert_eq!(unused, vec![2]);
+        assert_eq!(written.parse::<Table>().unwrap(), table);
+    }
+}
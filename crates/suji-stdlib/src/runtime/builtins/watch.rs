@@ -0,0 +1,81 @@
+//! File watchers backing os:watch. A watch is released when its `Watch` is dropped.
+
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::cell::Cell;
+use std::path::Path;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::time::Duration;
+
+thread_local! {
+    /// Watches currently open on this thread
+    static ACTIVE_WATCHES: Cell<usize> = const { Cell::new(0) };
+}
+
+pub struct Watch {
+    /// Dropping the watcher stops the notifications
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+}
+
+impl Watch {
+    /// Start watching `path` (recursively for directories)
+    pub fn start(path: &str) -> Result<Self, String> {
+        let (sender, events) = channel();
+        let mut watcher = notify::recommended_watcher(sender)
+            .map_err(|e| format!("Failed to create a watcher for '{}': {}", path, e))?;
+        watcher
+            .watch(Path::new(path), RecursiveMode::Recursive)
+            .map_err(|e| format!("Failed to watch '{}': {}", path, e))?;
+
+        ACTIVE_WATCHES.with(|count| count.set(count.get() + 1));
+        Ok(Self {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Wait up to `timeout` for changes and return them as (path, kind) pairs, together
+    /// with any further changes that are already queued. Returns an empty list on timeout.
+    pub fn next(&self, timeout: Duration) -> Result<Vec<(String, &'static str)>, String> {
+        let mut changes = Vec::new();
+        let mut next = match self.events.recv_timeout(timeout) {
+            Ok(event) => Some(event),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => return Err("File watcher stopped".to_string()),
+        };
+        while let Some(event) = next {
+            let event = event.map_err(|e| format!("File watcher error: {}", e))?;
+            if let Some(kind) = event_kind(&event.kind) {
+                for path in &event.paths {
+                    changes.push((path.to_string_lossy().into_owned(), kind));
+                }
+            }
+            next = self.events.try_recv().ok();
+        }
+        Ok(changes)
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        ACTIVE_WATCHES.with(|count| count.set(count.get() - 1));
+    }
+}
+
+/// Number of watches open on the current thread
+pub fn active_watches() -> usize {
+    ACTIVE_WATCHES.with(Cell::get)
+}
+
+/// Name of a change kind as seen by scripts; access events are not reported
+fn event_kind(kind: &EventKind) -> Option<&'static str> {
+    match kind {
+        EventKind::Access(_) => None,
+        EventKind::Create(_) => Some("create"),
+        EventKind::Modify(ModifyKind::Name(_)) => Some("rename"),
+        EventKind::Modify(_) => Some("modify"),
+        EventKind::Remove(_) => Some("remove"),
+        EventKind::Any | EventKind::Other => Some("other"),
+    }
+}
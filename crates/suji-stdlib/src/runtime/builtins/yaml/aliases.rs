@@ -0,0 +1,96 @@
+//! YAML anchor/alias validation.
+//!
+//! The YAML loader resolves `*alias` by copying the anchored node, so a small document
+//! can expand into an enormous value ("billion laughs"). This pass walks the parser
+//! events before loading and rejects aliases that would expand past a fixed budget, as
+//! well as aliases whose anchor is not complete yet (a node that refers to itself).
+
+use std::collections::HashMap;
+
+use super::types::YamlError;
+use suji_values::value::RuntimeError;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::Marker;
+
+/// Maximum number of nodes that aliases may add to a document once expanded
+pub const MAX_ALIAS_EXPANSION: u64 = 100_000;
+
+/// Check that every alias refers to a completed anchor and that expanding all aliases
+/// stays within `MAX_ALIAS_EXPANSION` nodes. Syntax errors are left to the loader.
+pub fn check_aliases(input: &str) -> Result<(), RuntimeError> {
+    let mut checker = AliasChecker::default();
+    // Scan errors are reported by the loader, which sees the same input next
+    let _ = Parser::new_from_str(input).load(&mut checker, true);
+
+    match checker.error {
+        Some(message) => Err(YamlError::ParseError {
+            message,
+            yaml_input: Some(input.to_string()),
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+#[derive(Default)]
+struct AliasChecker {
+    /// Expanded node count of each completed anchor, by anchor id
+    anchors: HashMap<usize, u64>,
+    /// Open collections as (anchor id, expanded node count so far)
+    open: Vec<(usize, u64)>,
+    /// Nodes added by alias expansion so far
+    expanded: u64,
+    error: Option<String>,
+}
+
+impl AliasChecker {
+    fn close_node(&mut self, anchor: usize, size: u64) {
+        if anchor > 0 {
+            self.anchors.insert(anchor, size);
+        }
+        if let Some((_, parent_size)) = self.open.last_mut() {
+            *parent_size = parent_size.saturating_add(size);
+        }
+    }
+}
+
+impl MarkedEventReceiver for AliasChecker {
+    fn on_event(&mut self, ev: Event, mark: Marker) {
+        if self.error.is_some() {
+            return;
+        }
+
+        match ev {
+            Event::Scalar(_, _, anchor, _) => self.close_node(anchor, 1),
+            Event::SequenceStart(anchor, _) | Event::MappingStart(anchor, _) => {
+                self.open.push((anchor, 1))
+            }
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some((anchor, size)) = self.open.pop() {
+                    self.close_node(anchor, size);
+                }
+            }
+            Event::Alias(id) => match self.anchors.get(&id).copied() {
+                Some(size) => {
+                    self.expanded = self.expanded.saturating_add(size);
+                    if self.expanded > MAX_ALIAS_EXPANSION {
+                        self.error = Some(format!(
+                            "YAML aliases expand to more than {} nodes (line {})",
+                            MAX_ALIAS_EXPANSION,
+                            mark.line()
+                        ));
+                        return;
+                    }
+                    self.close_node(0, size);
+                }
+                None => {
+                    self.error = Some(format!(
+                        "YAML alias at line {} refers to an anchor that encloses it or is not defined; recursive aliases are not supported",
+                        mark.line()
+                    ));
+                }
+            },
+            _ => {}
+        }
+    }
+}
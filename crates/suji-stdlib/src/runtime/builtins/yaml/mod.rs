@@ -1,6 +1,8 @@
 //! YAML conversion utilities.
 
+mod aliases;
 mod converter;
 mod types;
 
+pub use aliases::check_aliases;
 pub use converter::{suji_to_yaml_value, yaml_to_suji_value};
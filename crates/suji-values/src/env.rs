@@ -1,6 +1,8 @@
 use super::value::{RuntimeError, Value};
 use indexmap::IndexMap;
 use std::cell::RefCell;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 /// Lexically-scoped environment for variable bindings
@@ -10,6 +12,10 @@ pub struct Env {
     parent: Option<Rc<Env>>,
     /// Variable bindings in this scope
     bindings: RefCell<IndexMap<String, Value>>,
+    /// Names in `bindings` that are read-only (see `define_constant`)
+    constants: RefCell<HashSet<String>>,
+    /// Directory of the module file this root environment evaluates (see `new_module`)
+    module_dir: Option<PathBuf>,
 }
 
 impl Env {
@@ -18,6 +24,17 @@ impl Env {
         Env {
             parent: None,
             bindings: RefCell::new(IndexMap::new()),
+            constants: RefCell::new(HashSet::new()),
+            module_dir: None,
+        }
+    }
+
+    /// Create a root environment for a module file in `dir`. Imports evaluated in it, or
+    /// in any scope below it, resolve relative to `dir`.
+    pub fn new_module(dir: PathBuf) -> Self {
+        Env {
+            module_dir: Some(dir),
+            ..Env::new()
         }
     }
 
@@ -26,6 +43,8 @@ impl Env {
         Env {
             parent: Some(parent),
             bindings: RefCell::new(IndexMap::new()),
+            constants: RefCell::new(HashSet::new()),
+            module_dir: None,
         }
     }
 
@@ -34,6 +53,44 @@ impl Env {
         self.bindings.borrow_mut().insert(name.to_string(), value);
     }
 
+    /// Define a variable in the current scope like `define_or_set`, but refuse to
+    /// replace a constant of this scope
+    pub fn define(&self, name: &str, value: Value) -> Result<(), RuntimeError> {
+        if self.constants.borrow().contains(name) {
+            return Err(constant_error(name));
+        }
+        self.define_or_set(name, value);
+        Ok(())
+    }
+
+    /// Define a read-only binding in the current scope. Assigning to it, mutating it
+    /// in place or redefining it in the same scope raises an error; child scopes may
+    /// still shadow it with parameters, loop variables or `let`.
+    pub fn define_constant(&self, name: &str, value: Value) {
+        self.define_or_set(name, value);
+        self.constants.borrow_mut().insert(name.to_string());
+    }
+
+    /// Check whether `name` resolves to a constant (the nearest binding wins)
+    pub fn is_constant(&self, name: &str) -> bool {
+        if self.bindings.borrow().contains_key(name) {
+            return self.constants.borrow().contains(name);
+        }
+
+        match self.parent {
+            Some(ref parent) => parent.is_constant(name),
+            None => false,
+        }
+    }
+
+    /// Directory of the module this scope belongs to, if it was loaded from a file
+    pub fn module_dir(&self) -> Option<&Path> {
+        match self.parent {
+            Some(ref parent) => parent.module_dir(),
+            None => self.module_dir.as_deref(),
+        }
+    }
+
     /// Get a variable value, searching up the parent chain
     pub fn get(&self, name: &str) -> Result<Value, RuntimeError> {
         // First check current scope
@@ -54,29 +111,33 @@ impl Env {
 
     /// Try to set an existing variable somewhere in the parent chain.
     /// Returns true if the variable was found and updated, false otherwise.
-    fn try_set_in_chain(&self, name: &str, value: &Value) -> bool {
+    fn try_set_in_chain(&self, name: &str, value: &Value) -> Result<bool, RuntimeError> {
         if self.bindings.borrow().contains_key(name) {
+            if self.constants.borrow().contains(name) {
+                return Err(constant_error(name));
+            }
             self.bindings
                 .borrow_mut()
                 .insert(name.to_string(), value.clone());
-            return true;
+            return Ok(true);
         }
 
         if let Some(ref parent) = self.parent {
             return parent.try_set_in_chain(name, value);
         }
 
-        false
+        Ok(false)
     }
 
-    /// Set an existing variable, searching up the parent chain
-    /// If the variable doesn't exist anywhere, define it in the current scope
+    /// Set an existing variable, searching up the parent chain.
+    /// Fails if the variable is not defined anywhere (`let` defines new ones) or is a constant.
     pub fn set_existing(&self, name: &str, value: Value) -> Result<(), RuntimeError> {
-        if !self.try_set_in_chain(name, &value) {
-            // Variable doesn't exist anywhere, define in current scope
-            self.bindings.borrow_mut().insert(name.to_string(), value);
+        match self.try_set_in_chain(name, &value)? {
+            true => Ok(()),
+            false => Err(RuntimeError::UndefinedVariable {
+                name: name.to_string(),
+            }),
         }
-        Ok(())
     }
 
     /// Check if a variable exists in this environment or any parent
@@ -115,6 +176,36 @@ impl Env {
     pub fn binding_count(&self) -> usize {
         self.bindings.borrow().len()
     }
+
+    /// Capture the bindings of the current scope so they can be rolled back with `restore`.
+    /// Parent scopes are not captured.
+    pub fn snapshot(&self) -> EnvSnapshot {
+        EnvSnapshot {
+            bindings: self.bindings.borrow().clone(),
+            constants: self.constants.borrow().clone(),
+        }
+    }
+
+    /// Roll the current scope back to `snapshot`: bindings added since the snapshot are
+    /// removed and reassigned ones get their captured values back. Values that share
+    /// state (streams, closures' captured environments) are not rewound.
+    pub fn restore(&self, snapshot: EnvSnapshot) {
+        *self.bindings.borrow_mut() = snapshot.bindings;
+        *self.constants.borrow_mut() = snapshot.constants;
+    }
+}
+
+fn constant_error(name: &str) -> RuntimeError {
+    RuntimeError::InvalidOperation {
+        message: format!("Cannot assign to constant '{}'", name),
+    }
+}
+
+/// Bindings of one scope captured by `Env::snapshot`
+#[derive(Debug, Clone)]
+pub struct EnvSnapshot {
+    bindings: IndexMap<String, Value>,
+    constants: HashSet<String>,
 }
 
 impl Default for Env {
@@ -196,6 +287,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_snapshot_and_restore() {
+        let parent_env = Rc::new(Env::new());
+        parent_env.define_or_set("kept", Value::Number(DecimalNumber::from_i64(1)));
+        let env = Env::new_child(parent_env.clone());
+        env.define_or_set("x", Value::Number(DecimalNumber::from_i64(1)));
+
+        let snapshot = env.snapshot();
+        env.define_or_set("x", Value::Number(DecimalNumber::from_i64(2)));
+        env.define_or_set("added", Value::Boolean(true));
+        env.restore(snapshot);
+
+        assert_eq!(
+            env.get("x").unwrap(),
+            Value::Number(DecimalNumber::from_i64(1))
+        );
+        assert!(!env.contains("added"));
+        assert_eq!(env.local_names(), vec!["x".to_string()]);
+        assert!(env.contains("kept"));
+    }
+
     #[test]
     fn test_set_existing() {
         let parent_env = Rc::new(Env::new());
@@ -212,17 +324,38 @@ mod tests {
             Value::Number(DecimalNumber::from_i64(100))
         );
 
-        // Set non-existing variable creates it in current scope
-        child_env
-            .set_existing("y", Value::Number(DecimalNumber::from_i64(2)))
-            .unwrap();
-        assert_eq!(
-            child_env.get("y").unwrap(),
-            Value::Number(DecimalNumber::from_i64(2))
-        );
+        // Setting a variable that was never defined fails and defines nothing
         assert!(matches!(
-            parent_env.get("y"),
+            child_env.set_existing("y", Value::Number(DecimalNumber::from_i64(2))),
             Err(RuntimeError::UndefinedVariable { .. })
         ));
+        assert!(!child_env.contains("y"));
+    }
+
+    #[test]
+    fn test_constants_are_read_only() {
+        let env = Rc::new(Env::new());
+        env.define_constant("LIMIT", Value::Number(DecimalNumber::from_i64(10)));
+        let child_env = Env::new_child(env.clone());
+
+        assert!(env.is_constant("LIMIT"));
+        assert!(child_env.is_constant("LIMIT"));
+        assert!(matches!(
+            child_env.set_existing("LIMIT", Value::Nil),
+            Err(RuntimeError::InvalidOperation { .. })
+        ));
+        assert!(env.define("LIMIT", Value::Nil).is_err());
+        assert_eq!(
+            env.get("LIMIT").unwrap(),
+            Value::Number(DecimalNumber::from_i64(10))
+        );
+
+        // A child scope may shadow the constant with a binding of its own
+        child_env.define("LIMIT", Value::Nil).unwrap();
+        assert!(!child_env.is_constant("LIMIT"));
+        child_env
+            .set_existing("LIMIT", Value::Boolean(true))
+            .unwrap();
+        assert!(env.is_constant("LIMIT"));
     }
 }
@@ -2,11 +2,14 @@
 
 use super::value::StreamHandle;
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 thread_local! {
     /// Thread-local IO context for stream redirection
     static IO_CONTEXT: RefCell<IoContext> = RefCell::new(IoContext::default());
+
+    /// Streams that hold buffered writes, so they can be flushed before the process exits
+    static BUFFERED_STREAMS: RefCell<Vec<Weak<StreamHandle>>> = const { RefCell::new(Vec::new()) };
 }
 
 /// IO context holding optional stream overrides for stdin, stdout, stderr
@@ -102,6 +105,26 @@ impl IoContext {
         result
     }
 
+    /// Track a stream that buffers writes. Dropping a stream flushes it, but exiting
+    /// the process skips destructors, so `flush_buffered_streams` must reach it too.
+    pub fn register_buffered_stream(stream: &Rc<StreamHandle>) {
+        BUFFERED_STREAMS.with(|streams| {
+            let mut streams = streams.borrow_mut();
+            streams.retain(|stream| stream.strong_count() > 0);
+            streams.push(Rc::downgrade(stream));
+        });
+    }
+
+    /// Write out the buffers of every registered stream that is still alive.
+    /// Best effort, like the flush on drop: failures are ignored.
+    pub fn flush_buffered_streams() {
+        let streams: Vec<Rc<StreamHandle>> = BUFFERED_STREAMS
+            .with(|streams| streams.borrow().iter().filter_map(Weak::upgrade).collect());
+        for stream in streams {
+            stream.flush_write_buffer().ok();
+        }
+    }
+
     /// Clear all overrides (reset to defaults)
     ///
     /// This is mainly useful for testing or cleanup scenarios.
@@ -6,7 +6,7 @@ pub use value::*;
 
 // Environment for variable bindings
 pub mod env;
-pub use env::Env;
+pub use env::{Env, EnvSnapshot};
 
 // Environment variable overlays for shell commands
 pub mod env_overlay;
@@ -19,7 +19,7 @@ pub fn call_boolean_method(
                 Ok(Value::String(b.to_string()))
             }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -71,7 +71,8 @@ pub fn eval_closure<'a>(
     }
 }
 
-/// Handle type-checking methods (is_number, is_bool, is_string, etc.)
+/// Handle methods every value has: type checks (is_number, is_bool, is_string, etc.)
+/// and to_bool()
 pub fn call_type_checking_method(
     method: &str,
     receiver: &Value,
@@ -84,6 +85,10 @@ pub fn call_type_checking_method(
         });
     }
 
+    if method == "to_bool" {
+        return Ok(Value::Boolean(receiver.is_truthy()));
+    }
+
     // Determine which type we're checking for
     let expected_type = match method {
         "is_number" => Some("number"),
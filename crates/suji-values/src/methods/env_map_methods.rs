@@ -166,7 +166,7 @@ pub fn call_env_map_method(
                 }
             }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::InvalidOperation {
@@ -1,15 +1,15 @@
 // No executor needed
-use super::super::value::{RuntimeError, Value};
+use super::super::value::{FunctionBody, FunctionValue, PartialFunction, RuntimeError, Value};
 use super::common::{ValueRef, call_type_checking_method};
 
-/// Function methods: to_string()
+/// Function methods: to_string(), partial(args...), source()
 pub fn call_function_method(
     receiver: ValueRef,
     method: &str,
     args: Vec<Value>,
 ) -> Result<Value, RuntimeError> {
     match receiver.get() {
-        Value::Function(_) => match method {
+        Value::Function(func) => match method {
             "to_string" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
@@ -18,8 +18,17 @@ pub fn call_function_method(
                 }
                 Ok(Value::String("<function>".to_string()))
             }
+            "partial" => partial(func, args),
+            "source" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "source() takes no arguments".to_string(),
+                    });
+                }
+                function_source(func).map(Value::String)
+            }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -30,6 +39,81 @@ pub fn call_function_method(
     }
 }
 
+/// Source text of a function; a partial prints as its target followed by the bound arguments,
+/// e.g. `(|a, b| a + b)::partial(1)`
+fn function_source(func: &FunctionValue) -> Result<String, RuntimeError> {
+    match &func.body {
+        FunctionBody::Ast(body) => Ok(suji_ast::function_to_source(
+            func.params
+                .iter()
+                .map(|param| (param.name.as_str(), param.default.as_ref())),
+            body,
+        )),
+        FunctionBody::Partial(partial) => {
+            let args: Vec<String> = partial
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    Value::String(s) => format!("{:?}", s),
+                    other => other.to_string(),
+                })
+                .collect();
+            Ok(format!(
+                "({})::partial({})",
+                function_source(&partial.target)?,
+                args.join(", ")
+            ))
+        }
+        _ => Err(RuntimeError::InvalidOperation {
+            message: "source() is not available for builtin functions".to_string(),
+        }),
+    }
+}
+
+/// Pre-bind leading arguments, returning a function that takes the remaining parameters.
+///
+/// The result keeps the original function and the bound values; a call passes them ahead of
+/// its own arguments, so defaults of the remaining parameters still see the bound ones and
+/// arity checks are left to the call. Partials of partials bind onto the same target.
+fn partial(func: &FunctionValue, args: Vec<Value>) -> Result<Value, RuntimeError> {
+    if matches!(
+        func.body,
+        FunctionBody::Builtin(_) | FunctionBody::Native(_)
+    ) {
+        return Err(RuntimeError::InvalidOperation {
+            message: "partial() requires a user-defined function; wrap builtins in a closure first"
+                .to_string(),
+        });
+    }
+    if args.len() > func.params.len() {
+        return Err(RuntimeError::ArityMismatch {
+            message: format!(
+                "partial() got {} arguments but the function takes {}",
+                args.len(),
+                func.params.len()
+            ),
+        });
+    }
+
+    let params = func.params[args.len()..].to_vec();
+    let partial = match &func.body {
+        FunctionBody::Partial(inner) => PartialFunction {
+            target: inner.target.clone(),
+            args: inner.args.iter().cloned().chain(args).collect(),
+        },
+        _ => PartialFunction {
+            target: Box::new(func.clone()),
+            args,
+        },
+    };
+
+    Ok(Value::Function(FunctionValue {
+        params,
+        body: FunctionBody::Partial(partial),
+        env: func.env.clone(),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::value::FunctionValue;
@@ -89,4 +173,78 @@ mod tests {
         let result = call_function_method(receiver, "invalid_method", vec![]);
         assert!(matches!(result, Err(RuntimeError::MethodError { .. })));
     }
+
+    #[test]
+    fn test_function_partial() {
+        let params = vec![
+            crate::value::ParamSpec {
+                name: "a".to_string(),
+                default: None,
+            },
+            crate::value::ParamSpec {
+                name: "b".to_string(),
+                default: None,
+            },
+        ];
+        let func_val = Value::Function(FunctionValue {
+            params,
+            body: FunctionBody::Ast(Stmt::Expr(Expr::Return {
+                values: Vec::new(),
+                span: Span::default(),
+            })),
+            env: Rc::new(crate::env::Env::new()),
+        });
+
+        let result = call_function_method(
+            ValueRef::Immutable(&func_val),
+            "partial",
+            vec![Value::Number(DecimalNumber::from_i64(1))],
+        )
+        .unwrap();
+        let Value::Function(partial) = result else {
+            panic!("Expected function");
+        };
+        assert_eq!(partial.params.len(), 1);
+        assert_eq!(partial.params[0].name, "b");
+        let FunctionBody::Partial(bound) = &partial.body else {
+            panic!("Expected a partial application");
+        };
+        assert_eq!(bound.args, vec![Value::Number(DecimalNumber::from_i64(1))]);
+
+        // Binding onto a partial extends the same target instead of nesting
+        let result = call_function_method(
+            ValueRef::Immutable(&Value::Function(partial)),
+            "partial",
+            vec![Value::Nil],
+        )
+        .unwrap();
+        let Value::Function(FunctionValue {
+            params,
+            body: FunctionBody::Partial(bound),
+            ..
+        }) = result
+        else {
+            panic!("Expected a partial application");
+        };
+        assert!(params.is_empty());
+        assert_eq!(Value::Function(*bound.target), func_val);
+        assert_eq!(bound.args.len(), 2);
+
+        let too_many = call_function_method(
+            ValueRef::Immutable(&func_val),
+            "partial",
+            vec![Value::Nil, Value::Nil, Value::Nil],
+        );
+        assert!(matches!(too_many, Err(RuntimeError::ArityMismatch { .. })));
+
+        let builtin = Value::Function(FunctionValue {
+            params: vec![],
+            body: FunctionBody::Builtin("println"),
+            env: Rc::new(crate::env::Env::new()),
+        });
+        assert!(matches!(
+            call_function_method(ValueRef::Immutable(&builtin), "partial", vec![Value::Nil]),
+            Err(RuntimeError::InvalidOperation { .. })
+        ));
+    }
 }
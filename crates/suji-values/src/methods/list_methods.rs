@@ -1,9 +1,14 @@
-use super::super::value::{DecimalNumber, RuntimeError, Value};
+use super::super::value::{DecimalNumber, MapKey, RuntimeError, Value};
 use super::common::{ClosureEvaluator, ValueRef, call_type_checking_method, eval_closure};
+use super::number_methods::{MAX_BYTE_WIDTH, byte_order_arg, signed_arg};
+use num_bigint::{BigInt, Sign};
 
-/// List methods: push(item), pop(), length(), join(separator=" "), index_of(), filter(), map(), fold(), sum(), product()
+/// Maximum list nesting `flatten_deep()` will descend before raising an error
+const MAX_FLATTEN_DEPTH: usize = 1000;
+
+/// List methods: push(item), pop(), length(), is_empty(), join(separator=""), index_of(), filter(), map(), fold(), fold_right(), reduce(), min_by(), max_by(), sum(), product(), flatten(), flatten_deep(), from_bytes(endian="big", signed=false)
 ///
-/// For methods that need to call closures (filter, map, fold), a callback function is provided
+/// For methods that need to call closures (filter, map, fold, min_by, max_by), a callback function is provided
 /// to evaluate the closure without depending on a specific Executor implementation.
 pub fn call_list_method<'a>(
     call_closure_fn: Option<ClosureEvaluator<'a>>,
@@ -56,9 +61,24 @@ pub fn call_list_method<'a>(
                 unreachable!()
             }
         }
+        "is_empty" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "is_empty() takes no arguments".to_string(),
+                });
+            }
+
+            if let Value::List(items) = receiver.get() {
+                Ok(Value::Boolean(items.is_empty()))
+            } else {
+                unreachable!()
+            }
+        }
         "join" => {
+            // Elements are converted with their display form, so nested lists
+            // and maps join as `[1, 2]` / `{k: v}` and strings stay unquoted.
             let separator = if args.is_empty() {
-                " ".to_string()
+                String::new()
             } else if args.len() == 1 {
                 match &args[0] {
                     Value::String(sep) => sep.clone(),
@@ -112,7 +132,9 @@ pub fn call_list_method<'a>(
                 let closure = &args[0];
                 let mut filtered = Vec::new();
                 for item in items {
-                    if eval_closure(call_fn, closure, vec![item.clone()], None)?.is_truthy() {
+                    if eval_closure(call_fn, closure, vec![item.clone()], None)?
+                        .as_condition("filter() predicate result")?
+                    {
                         filtered.push(item.clone());
                     }
                 }
@@ -162,6 +184,54 @@ pub fn call_list_method<'a>(
                 unreachable!()
             }
         }
+        "fold_right" => {
+            if args.len() != 2 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "fold_right() takes exactly two arguments".to_string(),
+                });
+            }
+            let call_fn = call_closure_fn.ok_or_else(|| RuntimeError::MethodError {
+                message: "fold_right() requires closure evaluation support".to_string(),
+            })?;
+            if let Value::List(items) = receiver.get() {
+                let mut accumulator = args[0].clone();
+                let closure = &args[1];
+                for item in items.iter().rev() {
+                    accumulator =
+                        eval_closure(call_fn, closure, vec![accumulator, item.clone()], None)?;
+                }
+                Ok(accumulator)
+            } else {
+                unreachable!()
+            }
+        }
+        "reduce" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "reduce() takes exactly one argument".to_string(),
+                });
+            }
+            let call_fn = call_closure_fn.ok_or_else(|| RuntimeError::MethodError {
+                message: "reduce() requires closure evaluation support".to_string(),
+            })?;
+            if let Value::List(items) = receiver.get() {
+                let (first, rest) =
+                    items
+                        .split_first()
+                        .ok_or_else(|| RuntimeError::InvalidOperation {
+                            message: "reduce() called on empty list".to_string(),
+                        })?;
+                let mut accumulator = first.clone();
+                let closure = &args[0];
+                for item in rest {
+                    accumulator =
+                        eval_closure(call_fn, closure, vec![accumulator, item.clone()], None)?;
+                }
+                Ok(accumulator)
+            } else {
+                unreachable!()
+            }
+        }
         "sum" => {
             if !args.is_empty() {
                 return Err(RuntimeError::ArityMismatch {
@@ -172,7 +242,11 @@ pub fn call_list_method<'a>(
                 let mut sum = DecimalNumber::from_i64(0);
                 for item in items {
                     match item {
-                        Value::Number(n) => sum = sum.add(n),
+                        Value::Number(n) => {
+                            sum = sum.add(n).map_err(|e| RuntimeError::InvalidOperation {
+                                message: e.to_string(),
+                            })?
+                        }
                         _ => {
                             return Err(RuntimeError::TypeError {
                                 message: "sum() can only be called on lists of numbers".to_string(),
@@ -195,7 +269,12 @@ pub fn call_list_method<'a>(
                 let mut product = DecimalNumber::from_i64(1);
                 for item in items {
                     match item {
-                        Value::Number(n) => product = product.mul(n),
+                        Value::Number(n) => {
+                            product =
+                                product.mul(n).map_err(|e| RuntimeError::InvalidOperation {
+                                    message: e.to_string(),
+                                })?
+                        }
                         _ => {
                             return Err(RuntimeError::TypeError {
                                 message: "product() can only be called on lists of numbers"
@@ -249,6 +328,19 @@ pub fn call_list_method<'a>(
                 unreachable!()
             }
         }
+        "sort_by_keys" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "sort_by_keys() takes exactly one argument".to_string(),
+                });
+            }
+            let keys = sort_keys(&args[0])?;
+            if let Value::List(items) = receiver.get() {
+                sort_by_keys(items, &keys)
+            } else {
+                unreachable!()
+            }
+        }
         "min" => {
             if !args.is_empty() {
                 return Err(RuntimeError::ArityMismatch {
@@ -321,6 +413,63 @@ pub fn call_list_method<'a>(
                 unreachable!()
             }
         }
+        "min_by" | "max_by" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: format!("{}() takes exactly one argument", method),
+                });
+            }
+            let call_fn = call_closure_fn.ok_or_else(|| RuntimeError::MethodError {
+                message: format!("{}() requires closure evaluation support", method),
+            })?;
+            if let Value::List(items) = receiver.get() {
+                let wanted = match method {
+                    "min_by" => std::cmp::Ordering::Less,
+                    _ => std::cmp::Ordering::Greater,
+                };
+                extreme_by(call_fn, items, &args[0], method, wanted)
+            } else {
+                unreachable!()
+            }
+        }
+        "dedup_consecutive" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "dedup_consecutive() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                let mut deduped = items.clone();
+                deduped.dedup();
+                Ok(Value::List(deduped))
+            } else {
+                unreachable!()
+            }
+        }
+        "dedup_consecutive_by" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "dedup_consecutive_by() takes exactly one argument".to_string(),
+                });
+            }
+            let call_fn = call_closure_fn.ok_or_else(|| RuntimeError::MethodError {
+                message: "dedup_consecutive_by() requires closure evaluation support".to_string(),
+            })?;
+            if let Value::List(items) = receiver.get() {
+                let mut deduped = Vec::with_capacity(items.len());
+                let mut previous_key = None;
+                for item in items {
+                    let key = eval_closure(call_fn, &args[0], vec![item.clone()], None)?;
+                    if previous_key.as_ref() != Some(&key) {
+                        deduped.push(item.clone());
+                    }
+                    previous_key = Some(key);
+                }
+                Ok(Value::List(deduped))
+            } else {
+                unreachable!()
+            }
+        }
         "first" => {
             let default_value = match args.len() {
                 0 => Value::Nil,
@@ -373,7 +522,9 @@ pub fn call_list_method<'a>(
                 for item in items {
                     match item {
                         Value::Number(n) => {
-                            sum = sum.add(n);
+                            sum = sum.add(n).map_err(|e| RuntimeError::InvalidOperation {
+                                message: e.to_string(),
+                            })?;
                             count += 1;
                         }
                         _ => {
@@ -396,6 +547,149 @@ pub fn call_list_method<'a>(
                 unreachable!()
             }
         }
+        "window" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "window() takes exactly one argument".to_string(),
+                });
+            }
+            let size = match &args[0] {
+                Value::Number(n) if n.is_integer() => n.to_i64_checked().filter(|&n| n > 0),
+                _ => None,
+            }
+            .ok_or_else(|| RuntimeError::TypeError {
+                message: "window() size must be a positive integer".to_string(),
+            })? as usize;
+
+            if let Value::List(items) = receiver.get() {
+                Ok(Value::List(
+                    items
+                        .windows(size)
+                        .map(|window| Value::List(window.to_vec()))
+                        .collect(),
+                ))
+            } else {
+                unreachable!()
+            }
+        }
+        "flatten" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "flatten() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                let mut flat = Vec::with_capacity(items.len());
+                for item in items {
+                    match item {
+                        Value::List(inner) => flat.extend(inner.iter().cloned()),
+                        other => flat.push(other.clone()),
+                    }
+                }
+                Ok(Value::List(flat))
+            } else {
+                unreachable!()
+            }
+        }
+        "flatten_deep" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "flatten_deep() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                let mut flat = Vec::with_capacity(items.len());
+                flatten_into(items, 1, &mut flat)?;
+                Ok(Value::List(flat))
+            } else {
+                unreachable!()
+            }
+        }
+        "rotate" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "rotate() takes exactly one argument".to_string(),
+                });
+            }
+            let shift = match &args[0] {
+                Value::Number(n) if n.is_integer() => n.to_i64_checked(),
+                _ => None,
+            }
+            .ok_or_else(|| RuntimeError::TypeError {
+                message: "rotate() amount must be an integer".to_string(),
+            })?;
+
+            if let Value::List(items) = receiver.get() {
+                let mut rotated = items.clone();
+                if !rotated.is_empty() {
+                    // Positive amounts rotate left, negative ones right
+                    let mid = shift.rem_euclid(rotated.len() as i64) as usize;
+                    rotated.rotate_left(mid);
+                }
+                Ok(Value::List(rotated))
+            } else {
+                unreachable!()
+            }
+        }
+        "transpose" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "transpose() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                transpose(items)
+            } else {
+                unreachable!()
+            }
+        }
+        "from_bytes" => {
+            if args.len() > 2 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "from_bytes() takes at most 2 arguments (endian, signed)".to_string(),
+                });
+            }
+            let big_endian = byte_order_arg("from_bytes", args.first())?;
+            let signed = signed_arg("from_bytes", args.get(1))?;
+            if let Value::List(items) = receiver.get() {
+                bytes_to_number(items, big_endian, signed)
+            } else {
+                unreachable!()
+            }
+        }
+        "clone" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "clone() takes no arguments".to_string(),
+                });
+            }
+            // Lists hold their elements by value, so this is already a deep copy
+            Ok(receiver.get().clone())
+        }
+        "to_map" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "to_map() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                pairs_to_map(items)
+            } else {
+                unreachable!()
+            }
+        }
+        "frequency" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "frequency() takes no arguments".to_string(),
+                });
+            }
+            if let Value::List(items) = receiver.get() {
+                frequency(items)
+            } else {
+                unreachable!()
+            }
+        }
         "to_string" => {
             if !args.is_empty() {
                 return Err(RuntimeError::ArityMismatch {
@@ -409,7 +703,7 @@ pub fn call_list_method<'a>(
             }
         }
         "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-        | "is_function" | "is_tuple" | "is_regex" => {
+        | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
             call_type_checking_method(method, receiver.get(), args)
         }
         _ => Err(RuntimeError::MethodError {
@@ -418,6 +712,268 @@ pub fn call_list_method<'a>(
     }
 }
 
+/// Element whose closure-computed key orders as `wanted` against every other key;
+/// ties keep the first occurrence.
+fn extreme_by(
+    call_fn: ClosureEvaluator<'_>,
+    items: &[Value],
+    closure: &Value,
+    method: &str,
+    wanted: std::cmp::Ordering,
+) -> Result<Value, RuntimeError> {
+    let mut best: Option<(&Value, Value)> = None;
+    for item in items {
+        let key = eval_closure(call_fn, closure, vec![item.clone()], None)?;
+        best = match best {
+            None => Some((item, key)),
+            Some((best_item, best_key)) => match key.partial_cmp(&best_key) {
+                Some(ordering) if ordering == wanted => Some((item, key)),
+                Some(_) => Some((best_item, best_key)),
+                None => {
+                    return Err(RuntimeError::TypeError {
+                        message: format!(
+                            "{}() keys must be comparable, got {} and {}",
+                            method,
+                            best_key.type_name(),
+                            key.type_name()
+                        ),
+                    });
+                }
+            },
+        };
+    }
+    best.map(|(item, _)| item.clone())
+        .ok_or_else(|| RuntimeError::MethodError {
+            message: format!("{}() called on empty list", method),
+        })
+}
+
+/// Swap rows and columns of a list of equally long lists.
+fn transpose(rows: &[Value]) -> Result<Value, RuntimeError> {
+    let mut inner = Vec::with_capacity(rows.len());
+    for (index, row) in rows.iter().enumerate() {
+        match row {
+            Value::List(items) => inner.push(items),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "transpose() expects a list of lists, but element {} is {}",
+                        index,
+                        other.type_name()
+                    ),
+                });
+            }
+        }
+    }
+
+    let width = inner.first().map_or(0, |row| row.len());
+    if let Some((index, row)) = inner.iter().enumerate().find(|(_, row)| row.len() != width) {
+        return Err(RuntimeError::MethodError {
+            message: format!(
+                "transpose() requires inner lists of equal length: row 0 has {} elements but row {} has {}",
+                width,
+                index,
+                row.len()
+            ),
+        });
+    }
+
+    Ok(Value::List(
+        (0..width)
+            .map(|column| Value::List(inner.iter().map(|row| row[column].clone()).collect()))
+            .collect(),
+    ))
+}
+
+/// Build a map from `(key, value)` tuples or `[key, value]` lists, the inverse of
+/// `map::to_list()`. Later pairs overwrite earlier ones with the same key.
+fn pairs_to_map(items: &[Value]) -> Result<Value, RuntimeError> {
+    let mut map = indexmap::IndexMap::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let (key, value) = match item {
+            Value::Tuple(pair) | Value::List(pair) if pair.len() == 2 => (&pair[0], &pair[1]),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "to_map() expects [key, value] pairs, but element {} is {}",
+                        index, other
+                    ),
+                });
+            }
+        };
+        let key = key
+            .clone()
+            .try_into_map_key()
+            .map_err(|_| RuntimeError::InvalidKeyType {
+                message: format!(
+                    "to_map() element {} has a {} key, which cannot be used as a map key",
+                    index,
+                    key.type_name()
+                ),
+            })?;
+        map.insert(key, value.clone());
+    }
+    Ok(Value::Map(map))
+}
+
+/// Count each distinct element, keyed by the element in order of first appearance.
+fn frequency(items: &[Value]) -> Result<Value, RuntimeError> {
+    let mut counts: indexmap::IndexMap<MapKey, usize> = indexmap::IndexMap::new();
+    for (index, item) in items.iter().enumerate() {
+        let key = item
+            .clone()
+            .try_into_map_key()
+            .map_err(|_| RuntimeError::InvalidKeyType {
+                message: format!(
+                    "frequency() element {} is a {}, which cannot be used as a map key",
+                    index,
+                    item.type_name()
+                ),
+            })?;
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    Ok(Value::Map(
+        counts
+            .into_iter()
+            .map(|(key, count)| (key, Value::Number(DecimalNumber::from_usize(count))))
+            .collect(),
+    ))
+}
+
+/// Parse the `sort_by_keys()` argument: a list whose elements are a field (ascending)
+/// or a `(field, "asc" | "desc")` tuple. Returns (field, descending) pairs.
+fn sort_keys(spec: &Value) -> Result<Vec<(MapKey, bool)>, RuntimeError> {
+    let Value::List(entries) = spec else {
+        return Err(RuntimeError::TypeError {
+            message: format!(
+                "sort_by_keys() expects a list of fields, got {}",
+                spec.type_name()
+            ),
+        });
+    };
+
+    entries
+        .iter()
+        .map(|entry| match entry {
+            Value::Tuple(pair) if pair.len() == 2 => {
+                let descending = match &pair[1] {
+                    Value::String(order) if order == "asc" => false,
+                    Value::String(order) if order == "desc" => true,
+                    other => {
+                        return Err(RuntimeError::InvalidOperation {
+                            message: format!(
+                                "sort_by_keys() order must be \"asc\" or \"desc\", got {}",
+                                other
+                            ),
+                        });
+                    }
+                };
+                Ok((pair[0].clone().try_into_map_key()?, descending))
+            }
+            field => Ok((field.clone().try_into_map_key()?, false)),
+        })
+        .collect()
+}
+
+/// Stable sort of a list of maps by several fields in priority order; missing fields
+/// compare as nil, which sorts before any other value.
+fn sort_by_keys(items: &[Value], keys: &[(MapKey, bool)]) -> Result<Value, RuntimeError> {
+    let mut rows = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        match item {
+            Value::Map(map) => rows.push((item, map)),
+            other => {
+                return Err(RuntimeError::TypeError {
+                    message: format!(
+                        "sort_by_keys() expects a list of maps, but element {} is {}",
+                        index,
+                        other.type_name()
+                    ),
+                });
+            }
+        }
+    }
+
+    rows.sort_by(|(_, a), (_, b)| {
+        keys.iter()
+            .map(|(key, descending)| {
+                let ordering = a
+                    .get(key)
+                    .unwrap_or(&Value::Nil)
+                    .sort_cmp(b.get(key).unwrap_or(&Value::Nil));
+                match descending {
+                    true => ordering.reverse(),
+                    false => ordering,
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    Ok(Value::List(
+        rows.into_iter().map(|(item, _)| item.clone()).collect(),
+    ))
+}
+
+/// Interpret a list of byte values (0-255) as an integer, the inverse of `to_bytes()`
+fn bytes_to_number(items: &[Value], big_endian: bool, signed: bool) -> Result<Value, RuntimeError> {
+    if items.is_empty() || items.len() > MAX_BYTE_WIDTH {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "from_bytes() requires 1 to {} bytes, got {}",
+                MAX_BYTE_WIDTH,
+                items.len()
+            ),
+        });
+    }
+
+    let bytes = items
+        .iter()
+        .map(|item| {
+            match item {
+                Value::Number(n) => n.to_i64_checked().and_then(|b| u8::try_from(b).ok()),
+                _ => None,
+            }
+            .ok_or_else(|| RuntimeError::InvalidOperation {
+                message: format!(
+                    "from_bytes() items must be integers from 0 to 255, got {}",
+                    item
+                ),
+            })
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    let value = match (big_endian, signed) {
+        (true, true) => BigInt::from_signed_bytes_be(&bytes),
+        (true, false) => BigInt::from_bytes_be(Sign::Plus, &bytes),
+        (false, true) => BigInt::from_signed_bytes_le(&bytes),
+        (false, false) => BigInt::from_bytes_le(Sign::Plus, &bytes),
+    };
+    DecimalNumber::from_big_integer(value)
+        .map(Value::Number)
+        .map_err(|message| RuntimeError::InvalidOperation {
+            message: message.to_string(),
+        })
+}
+
+/// Append the non-list leaves of `items` to `out`, descending into nested lists.
+fn flatten_into(items: &[Value], depth: usize, out: &mut Vec<Value>) -> Result<(), RuntimeError> {
+    if depth > MAX_FLATTEN_DEPTH {
+        return Err(RuntimeError::InvalidOperation {
+            message: format!(
+                "flatten_deep() exceeded the maximum nesting depth of {}",
+                MAX_FLATTEN_DEPTH
+            ),
+        });
+    }
+    for item in items {
+        match item {
+            Value::List(inner) => flatten_into(inner, depth + 1, out)?,
+            other => out.push(other.clone()),
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::common::ValueRef;
@@ -477,7 +1033,396 @@ mod tests {
         // Join with default separator
         let receiver2 = ValueRef::Immutable(&list);
         let result2 = call_list_method(None, receiver2, "join", vec![]).unwrap();
-        assert_eq!(result2, Value::String("a b c".to_string()));
+        assert_eq!(result2, Value::String("abc".to_string()));
+    }
+
+    #[test]
+    fn test_list_join_mixed_elements() {
+        let list = Value::List(vec![
+            Value::String("a".to_string()),
+            Value::Number(DecimalNumber::from_i64(1)),
+            Value::Boolean(true),
+            Value::Nil,
+            Value::List(vec![
+                Value::Number(DecimalNumber::from_i64(1)),
+                Value::Number(DecimalNumber::from_i64(2)),
+            ]),
+        ]);
+        let receiver = ValueRef::Immutable(&list);
+
+        let result = call_list_method(
+            None,
+            receiver,
+            "join",
+            vec![Value::String(", ".to_string())],
+        )
+        .unwrap();
+        assert_eq!(result, Value::String("a, 1, true, nil, [1, 2]".to_string()));
+    }
+
+    #[test]
+    fn test_list_window() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let list = Value::List(vec![num(1), num(2), num(3), num(4)]);
+
+        let result =
+            call_list_method(None, ValueRef::Immutable(&list), "window", vec![num(2)]).unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![
+                Value::List(vec![num(1), num(2)]),
+                Value::List(vec![num(2), num(3)]),
+                Value::List(vec![num(3), num(4)]),
+            ])
+        );
+
+        // Shorter than the window yields no windows
+        let result =
+            call_list_method(None, ValueRef::Immutable(&list), "window", vec![num(5)]).unwrap();
+        assert_eq!(result, Value::List(vec![]));
+
+        for bad in [
+            num(0),
+            num(-1),
+            Value::Number(DecimalNumber::parse("1.5").unwrap()),
+        ] {
+            let result = call_list_method(None, ValueRef::Immutable(&list), "window", vec![bad]);
+            assert!(matches!(result, Err(RuntimeError::TypeError { .. })));
+        }
+    }
+
+    #[test]
+    fn test_list_flatten_and_flatten_deep() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let text = Value::String("x".to_string());
+        let tuple = Value::Tuple(vec![num(9), num(10)]);
+        // [1, [2, [3, [4]]], "x", (9, 10), []]
+        let list = Value::List(vec![
+            num(1),
+            Value::List(vec![
+                num(2),
+                Value::List(vec![num(3), Value::List(vec![num(4)])]),
+            ]),
+            text.clone(),
+            tuple.clone(),
+            Value::List(vec![]),
+        ]);
+
+        let result = call_list_method(None, ValueRef::Immutable(&list), "flatten", vec![]).unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![
+                num(1),
+                num(2),
+                Value::List(vec![num(3), Value::List(vec![num(4)])]),
+                text.clone(),
+                tuple.clone(),
+            ])
+        );
+
+        // Non-list elements such as tuples are left intact
+        let result =
+            call_list_method(None, ValueRef::Immutable(&list), "flatten_deep", vec![]).unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![num(1), num(2), num(3), num(4), text, tuple])
+        );
+
+        let result = call_list_method(None, ValueRef::Immutable(&list), "flatten", vec![num(1)]);
+        assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
+    }
+
+    #[test]
+    fn test_list_is_empty() {
+        let is_empty = |list: Value| {
+            call_list_method(None, ValueRef::Immutable(&list), "is_empty", vec![]).unwrap()
+        };
+        assert_eq!(is_empty(Value::List(vec![])), Value::Boolean(true));
+        assert_eq!(
+            is_empty(Value::List(vec![Value::Nil])),
+            Value::Boolean(false)
+        );
+
+        let list = Value::List(vec![]);
+        assert!(matches!(
+            call_list_method(
+                None,
+                ValueRef::Immutable(&list),
+                "is_empty",
+                vec![Value::Nil]
+            ),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_clone_is_independent() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let original = Value::List(vec![num(1), Value::List(vec![num(2)])]);
+        let mut copy =
+            call_list_method(None, ValueRef::Immutable(&original), "clone", vec![]).unwrap();
+        assert_eq!(copy, original);
+
+        call_list_method(None, ValueRef::Mutable(&mut copy), "push", vec![num(3)]).unwrap();
+        assert_eq!(
+            original,
+            Value::List(vec![num(1), Value::List(vec![num(2)])])
+        );
+        assert!(matches!(
+            call_list_method(None, ValueRef::Immutable(&original), "clone", vec![num(1)]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_to_map() {
+        let text = |s: &str| Value::String(s.to_string());
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let to_map = |items: Vec<Value>| {
+            let list = Value::List(items);
+            call_list_method(None, ValueRef::Immutable(&list), "to_map", vec![])
+        };
+
+        let result = to_map(vec![
+            Value::Tuple(vec![text("a"), num(1)]),
+            Value::List(vec![num(2), text("two")]),
+            Value::Tuple(vec![text("a"), num(3)]),
+        ])
+        .unwrap();
+        let mut expected = indexmap::IndexMap::new();
+        expected.insert(MapKey::String("a".to_string()), num(3));
+        expected.insert(MapKey::from_value(num(2)).unwrap(), text("two"));
+        assert_eq!(result, Value::Map(expected));
+        assert_eq!(
+            to_map(vec![]).unwrap(),
+            Value::Map(indexmap::IndexMap::new())
+        );
+
+        assert!(matches!(
+            to_map(vec![Value::List(vec![text("a")])]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            to_map(vec![text("ab")]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            to_map(vec![Value::Tuple(vec![Value::Nil, num(1)])]),
+            Err(RuntimeError::InvalidKeyType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_sort_by_keys() {
+        let text = |s: &str| Value::String(s.to_string());
+        let person = |last: &str, first: &str, age: Option<i64>| {
+            let mut map = indexmap::IndexMap::new();
+            map.insert(MapKey::String("last".to_string()), text(last));
+            map.insert(MapKey::String("first".to_string()), text(first));
+            if let Some(age) = age {
+                map.insert(
+                    MapKey::String("age".to_string()),
+                    Value::Number(DecimalNumber::from_i64(age)),
+                );
+            }
+            Value::Map(map)
+        };
+        let ann = person("Lee", "Ann", Some(40));
+        let bob = person("Kim", "Bob", None);
+        let cat = person("Lee", "Cat", Some(30));
+        let list = Value::List(vec![ann.clone(), bob.clone(), cat.clone()]);
+        let sort = |keys: Vec<Value>| {
+            call_list_method(
+                None,
+                ValueRef::Immutable(&list),
+                "sort_by_keys",
+                vec![Value::List(keys)],
+            )
+        };
+
+        assert_eq!(
+            sort(vec![text("last"), text("first")]).unwrap(),
+            Value::List(vec![bob.clone(), ann.clone(), cat.clone()])
+        );
+        assert_eq!(
+            sort(vec![
+                text("last"),
+                Value::Tuple(vec![text("first"), text("desc")])
+            ])
+            .unwrap(),
+            Value::List(vec![bob.clone(), cat.clone(), ann.clone()])
+        );
+        // Missing fields sort as nil, before any value
+        assert_eq!(
+            sort(vec![text("age")]).unwrap(),
+            Value::List(vec![bob.clone(), cat.clone(), ann.clone()])
+        );
+        assert_eq!(
+            sort(vec![Value::Tuple(vec![text("age"), text("desc")])]).unwrap(),
+            Value::List(vec![ann, cat, bob])
+        );
+
+        // A field holding both numbers and strings sorts numbers first without panicking
+        let rows: Vec<Value> = (0..33)
+            .map(|i| {
+                let mut map = indexmap::IndexMap::new();
+                let id = match i % 3 {
+                    0 => text(&format!("id-{}", 32 - i)),
+                    _ => Value::Number(DecimalNumber::from_i64(32 - i)),
+                };
+                map.insert(MapKey::String("id".to_string()), id);
+                Value::Map(map)
+            })
+            .collect();
+        let mixed = Value::List(rows);
+        let sorted = call_list_method(
+            None,
+            ValueRef::Immutable(&mixed),
+            "sort_by_keys",
+            vec![Value::List(vec![text("id")])],
+        )
+        .unwrap();
+        let Value::List(sorted) = sorted else {
+            panic!("expected a list");
+        };
+        let ids: Vec<Value> = sorted
+            .iter()
+            .map(|row| match row {
+                Value::Map(map) => map[&MapKey::String("id".to_string())].clone(),
+                _ => panic!("expected a map"),
+            })
+            .collect();
+        assert_eq!(ids.len(), 33);
+        assert_eq!(ids[0], Value::Number(DecimalNumber::from_i64(0)));
+        assert_eq!(ids[21], Value::Number(DecimalNumber::from_i64(31)));
+        assert_eq!(ids[22], text("id-11"));
+        assert_eq!(ids[32], text("id-8"));
+
+        assert!(matches!(
+            sort(vec![Value::Tuple(vec![text("age"), text("down")])]),
+            Err(RuntimeError::InvalidOperation { .. })
+        ));
+        let not_maps = Value::List(vec![text("x")]);
+        assert!(matches!(
+            call_list_method(
+                None,
+                ValueRef::Immutable(&not_maps),
+                "sort_by_keys",
+                vec![Value::List(vec![text("age")])]
+            ),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_rotate() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let list = Value::List(vec![num(1), num(2), num(3), num(4)]);
+        let rotate = |list: &Value, n: Value| {
+            call_list_method(None, ValueRef::Immutable(list), "rotate", vec![n])
+        };
+
+        assert_eq!(
+            rotate(&list, num(1)).unwrap(),
+            Value::List(vec![num(2), num(3), num(4), num(1)])
+        );
+        assert_eq!(
+            rotate(&list, num(-1)).unwrap(),
+            Value::List(vec![num(4), num(1), num(2), num(3)])
+        );
+        assert_eq!(
+            rotate(&list, num(6)).unwrap(),
+            Value::List(vec![num(3), num(4), num(1), num(2)])
+        );
+        assert_eq!(rotate(&list, num(-8)).unwrap(), list);
+        assert_eq!(
+            rotate(&Value::List(vec![]), num(3)).unwrap(),
+            Value::List(vec![])
+        );
+
+        let fraction = Value::Number(DecimalNumber::parse("1.5").unwrap());
+        assert!(matches!(
+            rotate(&list, fraction),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_transpose() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let row = |ns: &[i64]| Value::List(ns.iter().map(|&n| num(n)).collect());
+
+        let matrix = Value::List(vec![row(&[1, 2, 3]), row(&[4, 5, 6])]);
+        let result =
+            call_list_method(None, ValueRef::Immutable(&matrix), "transpose", vec![]).unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![row(&[1, 4]), row(&[2, 5]), row(&[3, 6])])
+        );
+
+        let empty = Value::List(vec![]);
+        let result =
+            call_list_method(None, ValueRef::Immutable(&empty), "transpose", vec![]).unwrap();
+        assert_eq!(result, Value::List(vec![]));
+
+        let ragged = Value::List(vec![row(&[1, 2]), row(&[3])]);
+        let result = call_list_method(None, ValueRef::Immutable(&ragged), "transpose", vec![]);
+        assert!(
+            matches!(result, Err(RuntimeError::MethodError { message }) if message.contains("row 1 has 1"))
+        );
+
+        let mixed = Value::List(vec![row(&[1]), num(2)]);
+        let result = call_list_method(None, ValueRef::Immutable(&mixed), "transpose", vec![]);
+        assert!(matches!(result, Err(RuntimeError::TypeError { .. })));
+    }
+
+    #[test]
+    fn test_list_flatten_deep_depth_limit() {
+        let nest = |levels: usize| {
+            let mut value = Value::List(vec![Value::Nil]);
+            for _ in 1..levels {
+                value = Value::List(vec![value]);
+            }
+            value
+        };
+
+        let at_limit = nest(MAX_FLATTEN_DEPTH);
+        let result =
+            call_list_method(None, ValueRef::Immutable(&at_limit), "flatten_deep", vec![]).unwrap();
+        assert_eq!(result, Value::List(vec![Value::Nil]));
+
+        let too_deep = nest(MAX_FLATTEN_DEPTH + 1);
+        let result = call_list_method(None, ValueRef::Immutable(&too_deep), "flatten_deep", vec![]);
+        assert!(matches!(result, Err(RuntimeError::InvalidOperation { .. })));
+    }
+
+    #[test]
+    fn test_list_from_bytes() {
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let bytes = |items: &[i64]| Value::List(items.iter().map(|b| num(*b)).collect::<Vec<_>>());
+        let from = |list: &Value, args: Vec<Value>| {
+            call_list_method(None, ValueRef::Immutable(list), "from_bytes", args)
+        };
+        let text = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(from(&bytes(&[1, 2]), vec![]).unwrap(), num(258));
+        assert_eq!(
+            from(&bytes(&[2, 1, 0, 0]), vec![text("little")]).unwrap(),
+            num(258)
+        );
+        assert_eq!(
+            from(&bytes(&[255, 254]), vec![text("big"), Value::Boolean(true)]).unwrap(),
+            num(-2)
+        );
+        assert_eq!(from(&bytes(&[255, 254]), vec![]).unwrap(), num(65534));
+
+        assert!(from(&bytes(&[]), vec![]).is_err());
+        assert!(from(&bytes(&[256]), vec![]).is_err());
+        // Values beyond the decimal range come back as big integers
+        assert_eq!(
+            from(&bytes(&[255; 16]), vec![]).unwrap().to_string(),
+            "340282366920938463463374607431768211455"
+        );
     }
 
     #[test]
@@ -1,7 +1,9 @@
-use super::super::value::{DecimalNumber, RuntimeError, Value};
+use super::super::value::{DecimalNumber, MapKey, RuntimeError, Value};
 use super::common::{ValueRef, call_type_checking_method};
+use indexmap::IndexMap;
+use std::collections::HashSet;
 
-/// Map methods: delete(key), contains(key), keys(), values(), to_list(), length(), get(key, default=nil), merge(other_map)
+/// Map methods: delete(key), contains(key), keys(), values(), to_list(), length(), is_empty(), get(key, default=nil), deep_get(path, default=nil), deep_set(path, value), merge(other_map), pick(keys), omit(keys)
 pub fn call_map_method(
     mut receiver: ValueRef,
     method: &str,
@@ -66,6 +68,15 @@ pub fn call_map_method(
                 unreachable!()
             }
         }
+        "clone" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "clone() takes no arguments".to_string(),
+                });
+            }
+            // Maps hold their contents by value, so this is already a deep copy
+            Ok(receiver.get().clone())
+        }
         "to_list" => {
             if !args.is_empty() {
                 return Err(RuntimeError::MapMethodError {
@@ -96,6 +107,19 @@ pub fn call_map_method(
                 unreachable!()
             }
         }
+        "is_empty" => {
+            if !args.is_empty() {
+                return Err(RuntimeError::MapMethodError {
+                    method: "is_empty".to_string(),
+                    message: "is_empty() takes no arguments".to_string(),
+                });
+            }
+            if let Value::Map(map_data) = receiver.get() {
+                Ok(Value::Boolean(map_data.is_empty()))
+            } else {
+                unreachable!()
+            }
+        }
         "get" => {
             if args.is_empty() || args.len() > 2 {
                 return Err(RuntimeError::ArityMismatch {
@@ -116,6 +140,55 @@ pub fn call_map_method(
                 unreachable!()
             }
         }
+        "deep_get" => {
+            if args.is_empty() || args.len() > 2 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "deep_get() takes one or two arguments".to_string(),
+                });
+            }
+
+            let path = path_segments("deep_get", &args[0])?;
+            let default = args.get(1).cloned().unwrap_or(Value::Nil);
+
+            let mut current = receiver.get();
+            for segment in path {
+                let next = match current {
+                    Value::Map(map_data) => segment
+                        .clone()
+                        .try_into_map_key()
+                        .ok()
+                        .and_then(|key| map_data.get(&key)),
+                    Value::List(items) => list_position(segment, items.len()).map(|i| &items[i]),
+                    _ => None,
+                };
+                match next {
+                    Some(value) => current = value,
+                    None => return Ok(default),
+                }
+            }
+            Ok(current.clone())
+        }
+        "deep_set" => {
+            if args.len() != 2 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: "deep_set() takes exactly two arguments".to_string(),
+                });
+            }
+
+            let mut args = args.into_iter();
+            let path_value = args.next().unwrap();
+            let value = args.next().unwrap();
+            let path = path_segments("deep_set", &path_value)?;
+            if path.is_empty() {
+                return Err(RuntimeError::InvalidOperation {
+                    message: "deep_set() path must not be empty".to_string(),
+                });
+            }
+
+            let map = receiver.get_mut()?;
+            set_path(map, path, value)?;
+            Ok(map.clone())
+        }
         "merge" => {
             if args.len() != 1 {
                 return Err(RuntimeError::ArityMismatch {
@@ -142,6 +215,27 @@ pub fn call_map_method(
                 unreachable!()
             }
         }
+        "pick" | "omit" => {
+            if args.len() != 1 {
+                return Err(RuntimeError::ArityMismatch {
+                    message: format!("{}() takes exactly one argument", method),
+                });
+            }
+
+            let keys = key_list(method, &args[0])?;
+            let keep = method == "pick";
+            if let Value::Map(map_data) = receiver.get() {
+                Ok(Value::Map(
+                    map_data
+                        .iter()
+                        .filter(|(key, _)| keys.contains(*key) == keep)
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect(),
+                ))
+            } else {
+                unreachable!()
+            }
+        }
         "to_string" => {
             if !args.is_empty() {
                 return Err(RuntimeError::ArityMismatch {
@@ -155,7 +249,7 @@ pub fn call_map_method(
             }
         }
         "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-        | "is_function" | "is_tuple" | "is_regex" => {
+        | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
             call_type_checking_method(method, receiver.get(), args)
         }
         _ => Err(RuntimeError::MethodError {
@@ -164,12 +258,108 @@ pub fn call_map_method(
     }
 }
 
+/// Read the `pick`/`omit` argument: a list of values that can be map keys.
+fn key_list(method: &str, keys: &Value) -> Result<HashSet<MapKey>, RuntimeError> {
+    let Value::List(items) = keys else {
+        return Err(RuntimeError::TypeError {
+            message: format!(
+                "{}() expects a list of keys, got {}",
+                method,
+                keys.type_name()
+            ),
+        });
+    };
+    items
+        .iter()
+        .map(|key| key.clone().try_into_map_key())
+        .collect()
+}
+
+/// Validate a `deep_get`/`deep_set` path: a list of string keys or numbers.
+fn path_segments<'a>(method: &str, path: &'a Value) -> Result<&'a [Value], RuntimeError> {
+    let Value::List(segments) = path else {
+        return Err(RuntimeError::TypeError {
+            message: format!("{}() path must be a list", method),
+        });
+    };
+    if let Some(bad) = segments
+        .iter()
+        .find(|segment| !matches!(segment, Value::String(_) | Value::Number(_)))
+    {
+        return Err(RuntimeError::TypeError {
+            message: format!(
+                "{}() path segments must be strings or numbers, got {}",
+                method,
+                bad.type_name()
+            ),
+        });
+    }
+    Ok(segments)
+}
+
+/// Resolve a numeric path segment to a list position; negative values count from the end.
+fn list_position(segment: &Value, len: usize) -> Option<usize> {
+    let Value::Number(n) = segment else {
+        return None;
+    };
+    let index = n.to_i64_checked()?;
+    let position = if index < 0 { len as i64 + index } else { index };
+    (0..len as i64)
+        .contains(&position)
+        .then_some(position as usize)
+}
+
+/// Assign `value` at `path` inside `container`, creating maps for missing or nil
+/// intermediate entries. List segments must refer to existing elements.
+fn set_path(container: &mut Value, path: &[Value], value: Value) -> Result<(), RuntimeError> {
+    let (segment, rest) = path.split_first().expect("path is non-empty");
+
+    let slot = match container {
+        Value::Map(map_data) => {
+            let key = segment.clone().try_into_map_key()?;
+            map_data.entry(key).or_insert(Value::Nil)
+        }
+        Value::List(items) => {
+            let len = items.len();
+            let position = list_position(segment, len).ok_or_else(|| match segment {
+                Value::Number(n) => RuntimeError::IndexOutOfBounds {
+                    message: format!(
+                        "deep_set() index {} out of bounds for list of length {}",
+                        n, len
+                    ),
+                },
+                other => RuntimeError::TypeError {
+                    message: format!("deep_set() cannot index a list with {}", other),
+                },
+            })?;
+            &mut items[position]
+        }
+        other => {
+            return Err(RuntimeError::TypeError {
+                message: format!(
+                    "deep_set() cannot set '{}' inside {}",
+                    segment,
+                    other.type_name()
+                ),
+            });
+        }
+    };
+
+    if rest.is_empty() {
+        *slot = value;
+        return Ok(());
+    }
+    if matches!(slot, Value::Nil) {
+        *slot = Value::Map(IndexMap::new());
+    }
+    set_path(slot, rest, value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::value::{DecimalNumber, MapKey, OrderedDecimal};
     use super::super::common::ValueRef;
     use super::*;
-    use indexmap::IndexMap;
 
     #[test]
     fn test_map_delete() {
@@ -205,6 +395,23 @@ mod tests {
         assert_eq!(result2, Value::Boolean(false));
     }
 
+    #[test]
+    fn test_map_clone_arity() {
+        let mut map_data = IndexMap::new();
+        map_data.insert(
+            MapKey::String("a".to_string()),
+            Value::Number(DecimalNumber::from_i64(1)),
+        );
+        let map = Value::Map(map_data);
+
+        let copy = call_map_method(ValueRef::Immutable(&map), "clone", vec![]).unwrap();
+        assert_eq!(copy, map);
+        assert!(matches!(
+            call_map_method(ValueRef::Immutable(&map), "clone", vec![Value::Nil]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
     #[test]
     fn test_map_contains_string_keys() {
         let mut map_data = IndexMap::new();
@@ -534,6 +741,18 @@ mod tests {
         let receiver4 = ValueRef::Immutable(&empty_map);
         let length_result = call_map_method(receiver4, "length", vec![]).unwrap();
         assert_eq!(length_result, Value::Number(DecimalNumber::from_i64(0)));
+
+        // Test is_empty() on empty and non-empty maps
+        let receiver5 = ValueRef::Immutable(&empty_map);
+        let is_empty_result = call_map_method(receiver5, "is_empty", vec![]).unwrap();
+        assert_eq!(is_empty_result, Value::Boolean(true));
+
+        let mut map_data = IndexMap::new();
+        map_data.insert(MapKey::String("a".to_string()), Value::Nil);
+        let map = Value::Map(map_data);
+        let receiver6 = ValueRef::Immutable(&map);
+        let is_empty_result = call_map_method(receiver6, "is_empty", vec![]).unwrap();
+        assert_eq!(is_empty_result, Value::Boolean(false));
     }
 
     #[test]
@@ -1031,6 +1250,67 @@ mod tests {
         assert!(matches!(result, Err(RuntimeError::MethodError { .. })));
     }
 
+    #[test]
+    fn test_map_pick_and_omit() {
+        let mut map_data = IndexMap::new();
+        for (key, n) in [("a", 1), ("b", 2), ("c", 3)] {
+            map_data.insert(
+                MapKey::String(key.to_string()),
+                Value::Number(DecimalNumber::from_i64(n)),
+            );
+        }
+        let map = Value::Map(map_data);
+        let keys = |names: &[&str]| {
+            Value::List(
+                names
+                    .iter()
+                    .map(|name| Value::String(name.to_string()))
+                    .collect(),
+            )
+        };
+        let key_order = |value: Value| -> Vec<String> {
+            match value {
+                Value::Map(map_data) => map_data.keys().map(|key| key.to_string()).collect(),
+                other => panic!("Expected map, got {:?}", other),
+            }
+        };
+
+        // Order follows the map, not the key list; missing keys are skipped
+        let picked = call_map_method(
+            ValueRef::Immutable(&map),
+            "pick",
+            vec![keys(&["c", "zzz", "a"])],
+        )
+        .unwrap();
+        assert_eq!(key_order(picked), ["a", "c"]);
+
+        let omitted =
+            call_map_method(ValueRef::Immutable(&map), "omit", vec![keys(&["b", "zzz"])]).unwrap();
+        assert_eq!(key_order(omitted), ["a", "c"]);
+
+        // The receiver is left untouched
+        assert_eq!(key_order(map.clone()), ["a", "b", "c"]);
+
+        let two_args = call_map_method(
+            ValueRef::Immutable(&map),
+            "pick",
+            vec![keys(&["a"]), Value::Nil],
+        );
+        assert!(matches!(two_args, Err(RuntimeError::ArityMismatch { .. })));
+        let bad_key = call_map_method(
+            ValueRef::Immutable(&map),
+            "omit",
+            vec![Value::List(vec![Value::List(vec![])])],
+        );
+        assert!(matches!(bad_key, Err(RuntimeError::InvalidKeyType { .. })));
+        let string_arg = call_map_method(
+            ValueRef::Immutable(&map),
+            "pick",
+            vec![Value::String("a".to_string())],
+        );
+        assert!(matches!(string_arg, Err(RuntimeError::TypeError { .. })));
+    }
+
     #[test]
     fn test_map_to_string() {
         let mut map_data = IndexMap::new();
@@ -1076,4 +1356,82 @@ mod tests {
         );
         assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
     }
+
+    #[test]
+    fn test_map_deep_get_and_deep_set() {
+        let key = |k: &str| Value::String(k.to_string());
+        let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+
+        let mut map = Value::Map(IndexMap::new());
+        let path = Value::List(vec![key("a"), key("b"), key("c")]);
+        let result = call_map_method(
+            ValueRef::Mutable(&mut map),
+            "deep_set",
+            vec![path.clone(), num(1)],
+        )
+        .unwrap();
+        assert_eq!(result, map);
+
+        // Intermediate maps were created
+        let inner = call_map_method(
+            ValueRef::Immutable(&map),
+            "deep_get",
+            vec![Value::List(vec![key("a"), key("b")])],
+        )
+        .unwrap();
+        assert_eq!(
+            inner,
+            Value::Map(IndexMap::from([(MapKey::String("c".to_string()), num(1))]))
+        );
+        assert_eq!(
+            call_map_method(ValueRef::Immutable(&map), "deep_get", vec![path]).unwrap(),
+            num(1)
+        );
+
+        // Numeric segments index into lists, negative values from the end
+        let mut map = Value::Map(IndexMap::from([(
+            MapKey::String("xs".to_string()),
+            Value::List(vec![num(1), num(2), num(3)]),
+        )]));
+        call_map_method(
+            ValueRef::Mutable(&mut map),
+            "deep_set",
+            vec![Value::List(vec![key("xs"), num(-1)]), num(30)],
+        )
+        .unwrap();
+        assert_eq!(
+            call_map_method(
+                ValueRef::Immutable(&map),
+                "deep_get",
+                vec![Value::List(vec![key("xs"), num(2)])]
+            )
+            .unwrap(),
+            num(30)
+        );
+
+        // Missing paths give nil or the default
+        let missing = Value::List(vec![key("xs"), num(9)]);
+        assert_eq!(
+            call_map_method(ValueRef::Immutable(&map), "deep_get", vec![missing.clone()]).unwrap(),
+            Value::Nil
+        );
+        assert_eq!(
+            call_map_method(ValueRef::Immutable(&map), "deep_get", vec![missing, num(0)]).unwrap(),
+            num(0)
+        );
+
+        // Out-of-range list segments and bad path types are errors
+        assert!(matches!(
+            call_map_method(
+                ValueRef::Mutable(&mut map),
+                "deep_set",
+                vec![Value::List(vec![key("xs"), num(5)]), num(0)],
+            ),
+            Err(RuntimeError::IndexOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            call_map_method(ValueRef::Immutable(&map), "deep_get", vec![key("xs")]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
 }
@@ -2,6 +2,7 @@
 use super::value::{RuntimeError, Value};
 
 pub use common::{ClosureEvaluator, ValueRef};
+pub use number_methods::digits_arg;
 
 mod boolean_methods;
 mod common;
@@ -33,7 +34,9 @@ pub fn call_method<'a>(
     args: Vec<Value>,
 ) -> Result<Value, RuntimeError> {
     match receiver.get() {
-        Value::String(_) => string_methods::call_string_method(receiver, method, args),
+        Value::String(_) => {
+            string_methods::call_string_method(call_closure_fn, receiver, method, args)
+        }
         Value::Number(_) => number_methods::call_number_method(receiver, method, args),
         Value::Boolean(_) => boolean_methods::call_boolean_method(receiver, method, args),
         Value::List(_) => list_methods::call_list_method(call_closure_fn, receiver, method, args),
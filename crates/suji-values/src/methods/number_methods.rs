@@ -1,8 +1,10 @@
 // No executor needed
-use super::super::value::{RuntimeError, Value};
+use super::super::value::{DecimalNumber, RuntimeError, Value};
 use super::common::{ValueRef, call_type_checking_method};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
-/// Number methods: to_string(), is_int(), abs(), ceil(), floor(), round(), sqrt(), pow(), min(), max()
+/// Number methods: to_string(), is_int(), abs(), ceil(), floor(), round(), round_even(digits=0), sqrt(), pow(), min(), max(), to_duration_string(), to_ordinal(), to_words(), to_bytes(width, endian="big", signed=false)
 pub fn call_number_method(
     receiver: ValueRef,
     method: &str,
@@ -34,6 +36,14 @@ pub fn call_number_method(
                 }
                 Ok(Value::Number(n.abs()))
             }
+            "sign" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "sign() takes no arguments".to_string(),
+                    });
+                }
+                Ok(Value::Number(n.sign()))
+            }
             "ceil" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
@@ -58,6 +68,24 @@ pub fn call_number_method(
                 }
                 Ok(Value::Number(n.round()))
             }
+            "round_even" => {
+                if args.len() > 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "round_even() takes at most one argument (digits)".to_string(),
+                    });
+                }
+                let digits = digits_arg(args.first(), "round_even()")?;
+                Ok(Value::Number(n.round_half_even(digits)))
+            }
+            "to_fixed" => {
+                if args.len() > 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "to_fixed() takes at most one argument (digits)".to_string(),
+                    });
+                }
+                let digits = digits_arg(args.first(), "to_fixed()")?;
+                Ok(Value::String(n.to_fixed(digits)))
+            }
             "sqrt" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
@@ -115,8 +143,127 @@ pub fn call_number_method(
                     }),
                 }
             }
+            "to_duration_string" => {
+                let unit = match args.as_slice() {
+                    [] => "s",
+                    [Value::String(unit)] => unit.as_str(),
+                    [_] => {
+                        return Err(RuntimeError::TypeError {
+                            message: "to_duration_string() unit must be a string".to_string(),
+                        });
+                    }
+                    _ => {
+                        return Err(RuntimeError::ArityMismatch {
+                            message: "to_duration_string() takes at most one argument (unit)"
+                                .to_string(),
+                        });
+                    }
+                };
+                let out_of_range = || RuntimeError::InvalidOperation {
+                    message: "to_duration_string() value is out of range".to_string(),
+                };
+                let millis = match unit {
+                    "s" => n
+                        .inner()
+                        .and_then(|d| d.checked_mul(Decimal::from(1000)))
+                        .ok_or_else(out_of_range)?,
+                    "ms" => n.inner().ok_or_else(out_of_range)?,
+                    _ => {
+                        return Err(RuntimeError::InvalidOperation {
+                            message: format!(
+                                "to_duration_string() unit must be \"s\" or \"ms\", got \"{}\"",
+                                unit
+                            ),
+                        });
+                    }
+                };
+                let millis = millis.trunc().to_i128().ok_or_else(out_of_range)?;
+                Ok(Value::String(format_duration_ms(millis)))
+            }
+            "to_ordinal" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "to_ordinal() takes no arguments".to_string(),
+                    });
+                }
+                let value = whole_number(n, "to_ordinal")?;
+                Ok(Value::String(ordinal(value)))
+            }
+            "to_words" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "to_words() takes no arguments".to_string(),
+                    });
+                }
+                let value = whole_number(n, "to_words")?;
+                if value.unsigned_abs() > MAX_WORDS {
+                    return Err(RuntimeError::InvalidOperation {
+                        message: format!(
+                            "to_words() supports magnitudes up to {}, got {}",
+                            MAX_WORDS, n
+                        ),
+                    });
+                }
+                Ok(Value::String(number_words(value)))
+            }
+            "to_bytes" => {
+                if args.is_empty() || args.len() > 3 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "to_bytes() takes 1 to 3 arguments (width, endian, signed)"
+                            .to_string(),
+                    });
+                }
+                let width = match &args[0] {
+                    Value::Number(w) => w
+                        .to_i64_checked()
+                        .filter(|w| (1..=MAX_BYTE_WIDTH as i64).contains(w))
+                        .map(|w| w as usize),
+                    _ => None,
+                }
+                .ok_or_else(|| RuntimeError::InvalidOperation {
+                    message: format!(
+                        "to_bytes() width must be an integer from 1 to {}",
+                        MAX_BYTE_WIDTH
+                    ),
+                })?;
+                let big_endian = byte_order_arg("to_bytes", args.get(1))?;
+                let signed = signed_arg("to_bytes", args.get(2))?;
+                let value = n
+                    .to_big_integer()
+                    .ok_or_else(|| RuntimeError::InvalidOperation {
+                        message: "to_bytes() requires an integer".to_string(),
+                    })?;
+
+                // Minimal big-endian bytes, then widened with the sign byte
+                let minimal = match signed {
+                    true => Some(value.to_signed_bytes_be()),
+                    false if n.is_negative() => None,
+                    false => Some(value.to_bytes_be().1),
+                }
+                .filter(|bytes| bytes.len() <= width)
+                .ok_or_else(|| RuntimeError::InvalidOperation {
+                    message: format!(
+                        "to_bytes() value {} does not fit in {} {} byte(s)",
+                        n,
+                        width,
+                        if signed { "signed" } else { "unsigned" }
+                    ),
+                })?;
+                let fill = if n.is_negative() { 0xFF } else { 0 };
+                let mut bytes = vec![fill; width - minimal.len()];
+                bytes.extend(minimal);
+                if !big_endian {
+                    bytes.reverse();
+                }
+                Ok(Value::List(
+                    bytes
+                        .into_iter()
+                        .map(|b| Value::Number(DecimalNumber::from_u64(b as u64)))
+                        .collect(),
+                ))
+            }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -127,6 +274,176 @@ pub fn call_number_method(
     }
 }
 
+/// Widest integer `to_bytes()` / `from_bytes()` handle, in bytes (128 bits)
+pub(super) const MAX_BYTE_WIDTH: usize = 16;
+
+/// Parse an optional `"big"` / `"little"` argument; returns true for big-endian (the default)
+pub(super) fn byte_order_arg(method: &str, arg: Option<&Value>) -> Result<bool, RuntimeError> {
+    match arg {
+        None => Ok(true),
+        Some(Value::String(order)) if order == "big" => Ok(true),
+        Some(Value::String(order)) if order == "little" => Ok(false),
+        Some(_) => Err(RuntimeError::InvalidOperation {
+            message: format!("{}() endian must be \"big\" or \"little\"", method),
+        }),
+    }
+}
+
+/// Parse an optional signedness flag (default false)
+pub(super) fn signed_arg(method: &str, arg: Option<&Value>) -> Result<bool, RuntimeError> {
+    match arg {
+        None => Ok(false),
+        Some(Value::Boolean(b)) => Ok(*b),
+        Some(_) => Err(RuntimeError::TypeError {
+            message: format!("{}() signed flag must be a boolean", method),
+        }),
+    }
+}
+
+/// Read an optional decimal-places argument, 0 when omitted: a whole number from 0 to 28
+/// (the decimal scale limit). `name` prefixes the error messages, e.g. `to_fixed()`
+pub fn digits_arg(arg: Option<&Value>, name: &str) -> Result<u32, RuntimeError> {
+    match arg {
+        None => Ok(0),
+        Some(Value::Number(digits)) => digits
+            .to_i64_checked()
+            .filter(|d| digits.is_integer() && (0..=28).contains(d))
+            .map(|d| d as u32)
+            .ok_or_else(|| RuntimeError::TypeError {
+                message: format!("{} digits must be an integer from 0 to 28", name),
+            }),
+        Some(_) => Err(RuntimeError::TypeError {
+            message: format!("{} digits must be a number", name),
+        }),
+    }
+}
+
+/// Format a millisecond count as a compact duration like `1h 1m 1s` (zero parts omitted).
+fn format_duration_ms(millis: i128) -> String {
+    if millis == 0 {
+        return "0s".to_string();
+    }
+
+    const UNITS: [(i128, &str); 5] = [
+        (86_400_000, "d"),
+        (3_600_000, "h"),
+        (60_000, "m"),
+        (1_000, "s"),
+        (1, "ms"),
+    ];
+
+    let mut remaining = millis.abs();
+    let mut parts = Vec::new();
+    for (size, suffix) in UNITS {
+        let count = remaining / size;
+        remaining %= size;
+        if count > 0 {
+            parts.push(format!("{}{}", count, suffix));
+        }
+    }
+
+    let sign = if millis < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(" "))
+}
+
+/// Largest magnitude `to_words()` spells out (just under one quadrillion)
+const MAX_WORDS: u64 = 999_999_999_999_999;
+
+/// The receiver as an i64, or an error naming `method` when it is not a whole number
+fn whole_number(n: &DecimalNumber, method: &str) -> Result<i64, RuntimeError> {
+    n.to_i64_checked()
+        .filter(|_| n.is_integer())
+        .ok_or_else(|| RuntimeError::InvalidOperation {
+            message: format!("{}() requires an integer, got {}", method, n),
+        })
+}
+
+/// English ordinal: `1st`, `2nd`, `3rd`, `4th`, with `11th`–`13th` as exceptions
+fn ordinal(value: i64) -> String {
+    let last_two = value.unsigned_abs() % 100;
+    let suffix = match (last_two, last_two % 10) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", value, suffix)
+}
+
+/// English words for an integer: `-1021` → `minus one thousand twenty-one`
+fn number_words(value: i64) -> String {
+    const SCALES: [(u64, &str); 4] = [
+        (1_000_000_000_000, "trillion"),
+        (1_000_000_000, "billion"),
+        (1_000_000, "million"),
+        (1_000, "thousand"),
+    ];
+
+    if value == 0 {
+        return "zero".to_string();
+    }
+
+    let mut remaining = value.unsigned_abs();
+    let mut parts = Vec::new();
+    if value < 0 {
+        parts.push("minus".to_string());
+    }
+    for (size, name) in SCALES {
+        let count = remaining / size;
+        remaining %= size;
+        if count > 0 {
+            parts.push(format!("{} {}", hundreds_words(count), name));
+        }
+    }
+    if remaining > 0 {
+        parts.push(hundreds_words(remaining));
+    }
+    parts.join(" ")
+}
+
+/// Words for 1 to 999
+fn hundreds_words(value: u64) -> String {
+    const ONES: [&str; 20] = [
+        "",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    let (hundreds, rest) = (value / 100, (value % 100) as usize);
+    let rest = match rest {
+        0 => String::new(),
+        1..=19 => ONES[rest].to_string(),
+        _ if rest % 10 == 0 => TENS[rest / 10].to_string(),
+        _ => format!("{}-{}", TENS[rest / 10], ONES[rest % 10]),
+    };
+    match (hundreds, rest.is_empty()) {
+        (0, _) => rest,
+        (_, true) => format!("{} hundred", ONES[hundreds as usize]),
+        (_, false) => format!("{} hundred {}", ONES[hundreds as usize], rest),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::value::DecimalNumber;
@@ -146,6 +463,62 @@ mod tests {
         assert_eq!(result2, Value::String("2.5".to_string()));
     }
 
+    #[test]
+    fn test_number_abs_and_sign() {
+        let call = |s: &str, method: &str| {
+            let value = Value::Number(DecimalNumber::parse(s).unwrap());
+            call_number_method(ValueRef::Immutable(&value), method, vec![]).unwrap()
+        };
+        let num = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+
+        assert_eq!(call("-2.50", "abs"), num("2.50"));
+        assert_eq!(call("3", "abs"), num("3"));
+        assert_eq!(call("-2.5", "sign"), num("-1"));
+        assert_eq!(call("0.001", "sign"), num("1"));
+        assert_eq!(call("0", "sign"), num("0"));
+        assert_eq!(call("-0.0", "sign"), num("0"));
+    }
+
+    #[test]
+    fn test_number_to_bytes() {
+        let bytes = |n: i64, args: Vec<Value>| {
+            let value = Value::Number(DecimalNumber::from_i64(n));
+            call_number_method(ValueRef::Immutable(&value), "to_bytes", args)
+        };
+        let list = |items: &[i64]| {
+            Value::List(
+                items
+                    .iter()
+                    .map(|b| Value::Number(DecimalNumber::from_i64(*b)))
+                    .collect(),
+            )
+        };
+        let width = |w: i64| Value::Number(DecimalNumber::from_i64(w));
+        let text = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(bytes(258, vec![width(2)]).unwrap(), list(&[1, 2]));
+        assert_eq!(
+            bytes(258, vec![width(4), text("little")]).unwrap(),
+            list(&[2, 1, 0, 0])
+        );
+        assert_eq!(
+            bytes(-2, vec![width(2), text("big"), Value::Boolean(true)]).unwrap(),
+            list(&[255, 254])
+        );
+
+        let max = Value::Number("340282366920938463463374607431768211455".parse().unwrap());
+        assert_eq!(
+            call_number_method(ValueRef::Immutable(&max), "to_bytes", vec![width(16)]).unwrap(),
+            list(&[255; 16])
+        );
+
+        assert!(bytes(256, vec![width(1)]).is_err());
+        assert!(bytes(-1, vec![width(1)]).is_err());
+        assert!(bytes(128, vec![width(1), text("big"), Value::Boolean(true)]).is_err());
+        assert!(bytes(1, vec![width(17)]).is_err());
+        assert!(bytes(1, vec![width(1), text("middle")]).is_err());
+    }
+
     #[test]
     fn test_number_is_int() {
         let n = Value::Number(DecimalNumber::from_i64(42));
@@ -211,6 +584,93 @@ mod tests {
         assert_eq!(result2, Value::Number(DecimalNumber::from_i64(4)));
     }
 
+    #[test]
+    fn test_number_to_fixed() {
+        let to_fixed = |input: &str, args: Vec<Value>| {
+            let n = Value::Number(DecimalNumber::parse(input).unwrap());
+            call_number_method(ValueRef::Immutable(&n), "to_fixed", args)
+        };
+        let num = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+        let string = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(to_fixed("5", vec![num("2")]).unwrap(), string("5.00"));
+        assert_eq!(to_fixed("2.675", vec![num("2")]).unwrap(), string("2.68"));
+        assert_eq!(to_fixed("-2.5", vec![]).unwrap(), string("-3"));
+        assert_eq!(
+            to_fixed("1.23456", vec![num("3")]).unwrap(),
+            string("1.235")
+        );
+        assert_eq!(to_fixed("-0.001", vec![num("2")]).unwrap(), string("0.00"));
+        assert_eq!(to_fixed("10.5", vec![num("4")]).unwrap(), string("10.5000"));
+        assert_eq!(
+            to_fixed("79228162514264337593543950335", vec![num("2")]).unwrap(),
+            string("79228162514264337593543950335.00")
+        );
+        assert_eq!(
+            to_fixed("123456789012345678901234567", vec![num("5")]).unwrap(),
+            string("123456789012345678901234567.00000")
+        );
+        assert_eq!(
+            to_fixed("1234567890123456789012345.678", vec![num("4")]).unwrap(),
+            string("1234567890123456789012345.6780")
+        );
+        assert_eq!(
+            to_fixed("1267650600228229401496703205376", vec![num("1")]).unwrap(),
+            string("1267650600228229401496703205376.0")
+        );
+
+        assert!(matches!(
+            to_fixed("1", vec![num("-1")]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            to_fixed("1", vec![num("1"), num("2")]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_number_round_even() {
+        let round_even = |input: &str, args: Vec<Value>| {
+            let n = Value::Number(DecimalNumber::parse(input).unwrap());
+            call_number_method(ValueRef::Immutable(&n), "round_even", args)
+        };
+        let num = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+
+        assert_eq!(round_even("2.5", vec![]).unwrap(), num("2"));
+        assert_eq!(round_even("3.5", vec![]).unwrap(), num("4"));
+        assert_eq!(round_even("-2.5", vec![]).unwrap(), num("-2"));
+        assert_eq!(round_even("1.005", vec![num("2")]).unwrap(), num("1.00"));
+        assert_eq!(round_even("1.015", vec![num("2")]).unwrap(), num("1.02"));
+
+        assert!(matches!(
+            round_even("1.5", vec![num("0.5")]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            round_even("1.5", vec![num("1"), num("2")]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_digits_arg() {
+        let num = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+        assert_eq!(digits_arg(None, "to_fixed()").unwrap(), 0);
+        assert_eq!(digits_arg(Some(&num("28")), "to_fixed()").unwrap(), 28);
+
+        let err = digits_arg(Some(&num("29")), "to_fixed()").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Type error: to_fixed() digits must be an integer from 0 to 28"
+        );
+        let err = digits_arg(Some(&Value::Nil), "math:round_half_even").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Type error: math:round_half_even digits must be a number"
+        );
+    }
+
     #[test]
     fn test_number_sqrt() {
         let n = Value::Number(DecimalNumber::from_i64(16));
@@ -348,4 +808,97 @@ mod tests {
             call_number_method(receiver, "min", vec![Value::String("invalid".to_string())]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_number_to_duration_string() {
+        let duration = |n: &str, args: Vec<Value>| {
+            let value = Value::Number(DecimalNumber::parse(n).unwrap());
+            call_number_method(ValueRef::Immutable(&value), "to_duration_string", args).unwrap()
+        };
+        let ms = || vec![Value::String("ms".to_string())];
+
+        assert_eq!(
+            duration("3661", vec![]),
+            Value::String("1h 1m 1s".to_string())
+        );
+        assert_eq!(duration("0", vec![]), Value::String("0s".to_string()));
+        assert_eq!(
+            duration("90061", vec![]),
+            Value::String("1d 1h 1m 1s".to_string())
+        );
+        assert_eq!(
+            duration("1.5", vec![]),
+            Value::String("1s 500ms".to_string())
+        );
+        assert_eq!(
+            duration("1500", ms()),
+            Value::String("1s 500ms".to_string())
+        );
+        assert_eq!(duration("-120", vec![]), Value::String("-2m".to_string()));
+
+        let value = Value::Number(DecimalNumber::from_i64(1));
+        let result = call_number_method(
+            ValueRef::Immutable(&value),
+            "to_duration_string",
+            vec![Value::String("h".to_string())],
+        );
+        assert!(matches!(result, Err(RuntimeError::InvalidOperation { .. })));
+
+        let value = Value::Number(DecimalNumber::parse("79228162514264337593543950335").unwrap());
+        let result = call_number_method(ValueRef::Immutable(&value), "to_duration_string", vec![]);
+        assert!(matches!(result, Err(RuntimeError::InvalidOperation { .. })));
+    }
+
+    #[test]
+    fn test_number_to_ordinal_and_words() {
+        let call = |n: &str, method: &str| {
+            let value = Value::Number(DecimalNumber::parse(n).unwrap());
+            call_number_method(ValueRef::Immutable(&value), method, vec![])
+        };
+        let text = |s: &str| Value::String(s.to_string());
+
+        for (n, expected) in [
+            ("1", "1st"),
+            ("2", "2nd"),
+            ("3", "3rd"),
+            ("4", "4th"),
+            ("11", "11th"),
+            ("12", "12th"),
+            ("13", "13th"),
+            ("21", "21st"),
+            ("102", "102nd"),
+            ("111", "111th"),
+            ("0", "0th"),
+            ("-1", "-1st"),
+        ] {
+            assert_eq!(call(n, "to_ordinal").unwrap(), text(expected));
+        }
+
+        for (n, expected) in [
+            ("0", "zero"),
+            ("7", "seven"),
+            ("13", "thirteen"),
+            ("21", "twenty-one"),
+            ("40", "forty"),
+            ("100", "one hundred"),
+            ("115", "one hundred fifteen"),
+            ("1021", "one thousand twenty-one"),
+            ("-3000000", "minus three million"),
+            (
+                "999999999999999",
+                "nine hundred ninety-nine trillion nine hundred ninety-nine billion nine hundred ninety-nine million nine hundred ninety-nine thousand nine hundred ninety-nine",
+            ),
+        ] {
+            assert_eq!(call(n, "to_words").unwrap(), text(expected));
+        }
+
+        assert!(matches!(
+            call("1000000000000000", "to_words"),
+            Err(RuntimeError::InvalidOperation { .. })
+        ));
+        assert!(matches!(
+            call("1.5", "to_ordinal"),
+            Err(RuntimeError::InvalidOperation { .. })
+        ));
+    }
 }
@@ -2,7 +2,7 @@
 use super::super::value::{RuntimeError, Value};
 use super::common::{ValueRef, call_type_checking_method};
 
-/// Regex methods: to_string()
+/// Regex methods: to_string(), split(text), split_keep(text)
 pub fn call_regex_method(
     receiver: ValueRef,
     method: &str,
@@ -18,8 +18,40 @@ pub fn call_regex_method(
                 }
                 Ok(Value::String(format!("/{}/", regex.as_str())))
             }
+            "split" | "split_keep" => {
+                if args.len() != 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: format!("{}() takes exactly one argument", method),
+                    });
+                }
+                let Value::String(text) = &args[0] else {
+                    return Err(RuntimeError::TypeError {
+                        message: format!("{}() argument must be a string", method),
+                    });
+                };
+
+                // The regex iterators always advance past empty matches, so patterns
+                // like // or /x*/ terminate
+                let parts: Vec<Value> = if method == "split" {
+                    regex
+                        .split(text)
+                        .map(|part| Value::String(part.to_string()))
+                        .collect()
+                } else {
+                    let mut parts = Vec::new();
+                    let mut last = 0;
+                    for m in regex.find_iter(text) {
+                        parts.push(Value::String(text[last..m.start()].to_string()));
+                        parts.push(Value::String(m.as_str().to_string()));
+                        last = m.end();
+                    }
+                    parts.push(Value::String(text[last..].to_string()));
+                    parts
+                };
+                Ok(Value::List(parts))
+            }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -76,4 +108,47 @@ mod tests {
         let result = call_regex_method(receiver, "invalid_method", vec![]);
         assert!(matches!(result, Err(RuntimeError::MethodError { .. })));
     }
+
+    #[test]
+    fn test_regex_split_and_split_keep() {
+        let strings = |items: &[&str]| {
+            Value::List(items.iter().map(|s| Value::String(s.to_string())).collect())
+        };
+        let call = |pattern: &str, method: &str, text: &str| {
+            let regex_val = Value::Regex(Regex::new(pattern).unwrap());
+            call_regex_method(
+                ValueRef::Immutable(&regex_val),
+                method,
+                vec![Value::String(text.to_string())],
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            call(r"\s*[,;]\s*", "split", "a , b;c"),
+            strings(&["a", "b", "c"])
+        );
+        assert_eq!(
+            call(r"[+-]", "split_keep", "1+2-3"),
+            strings(&["1", "+", "2", "-", "3"])
+        );
+        // Leading and trailing delimiters produce empty edges
+        assert_eq!(call(",", "split", ",a,"), strings(&["", "a", ""]));
+        assert_eq!(call("x", "split_keep", "abc"), strings(&["abc"]));
+
+        // Empty matches split between characters without looping forever
+        assert_eq!(call("", "split", "ab"), strings(&["", "a", "b", ""]));
+        assert_eq!(
+            call("", "split_keep", "ab"),
+            strings(&["", "", "a", "", "b", "", ""])
+        );
+
+        let regex_val = Value::Regex(Regex::new(",").unwrap());
+        let result = call_regex_method(
+            ValueRef::Immutable(&regex_val),
+            "split",
+            vec![Value::Number(DecimalNumber::from_i64(1))],
+        );
+        assert!(matches!(result, Err(RuntimeError::TypeError { .. })));
+    }
 }
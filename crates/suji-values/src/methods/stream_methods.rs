@@ -1,9 +1,11 @@
 // No executor needed
 use super::super::io_context::IoContext;
-use super::super::value::{DecimalNumber, RuntimeError, StreamBackend, StreamHandle, Value};
+use super::super::value::{
+    DecimalNumber, RuntimeError, StreamBackend, StreamBuffering, StreamHandle, Value,
+};
 use super::common::{ValueRef, call_type_checking_method};
 use std::io::IsTerminal;
-use std::io::{BufRead, Read, Write};
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
 use std::rc::Rc;
 
 /// Resolve a Value to a concrete StreamHandle (handles both Stream and StreamProxy)
@@ -25,7 +27,7 @@ fn resolve_stream(value: &Value) -> Result<Rc<StreamHandle>, RuntimeError> {
     }
 }
 
-/// Stream methods: read(chunk_kb=8), write(text), read_all(), read_lines(), close(), to_string()
+/// Stream methods: read(chunk_kb=8), write(text), flush(), read_all(), read_lines(), seek(offset, whence), tell(), contents(), close(), to_string()
 pub fn call_stream_method(
     receiver: ValueRef,
     method: &str,
@@ -43,6 +45,11 @@ pub fn call_stream_method(
             });
         }
 
+        // Reads must observe earlier buffered writes to the same file
+        if method.starts_with("read") {
+            flush_write_buffer(&stream_handle)?;
+        }
+
         match method {
             "read" => {
                 // Optional arg: chunk_kb (default 8)
@@ -76,13 +83,13 @@ pub fn call_stream_method(
 
                 // Convert chunk_kb to bytes (multiply by 1024)
                 let kb_1024 = DecimalNumber::from_i64(1024);
-                let chunk_bytes = chunk_kb.mul(&kb_1024);
-                let chunk_bytes_i64 =
-                    chunk_bytes
-                        .to_i64_checked()
-                        .ok_or_else(|| RuntimeError::StreamError {
-                            message: "chunk size too large".to_string(),
-                        })?;
+                let chunk_bytes_i64 = chunk_kb
+                    .mul(&kb_1024)
+                    .ok()
+                    .and_then(|bytes| bytes.to_i64_checked())
+                    .ok_or_else(|| RuntimeError::StreamError {
+                        message: "chunk size too large".to_string(),
+                    })?;
                 stream_read_chunk(&stream_handle, chunk_bytes_i64 as usize)
             }
             "read_line" => {
@@ -187,15 +194,109 @@ pub fn call_stream_method(
 
                 Ok(Value::Boolean(is_tty))
             }
+            "flush" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "stream::flush() takes no arguments".to_string(),
+                    });
+                }
+
+                if !stream_handle.is_writable() {
+                    return Err(RuntimeError::StreamError {
+                        message: format!("Cannot flush read-only stream: {}", stream_handle.name),
+                    });
+                }
+
+                stream_flush(&stream_handle)
+            }
+            "seek" => {
+                let (offset, whence) = match args.as_slice() {
+                    [Value::Number(offset)] => (offset, "start"),
+                    [Value::Number(offset), Value::String(whence)] => (offset, whence.as_str()),
+                    [_] | [_, _] => {
+                        return Err(RuntimeError::TypeError {
+                            message: "stream::seek(offset, whence) expects a number and a string"
+                                .to_string(),
+                        });
+                    }
+                    _ => {
+                        return Err(RuntimeError::ArityMismatch {
+                            message:
+                                "stream::seek(offset, whence=\"start\") takes 1 or 2 arguments"
+                                    .to_string(),
+                        });
+                    }
+                };
+
+                let offset = offset
+                    .to_i64_checked()
+                    .ok_or_else(|| RuntimeError::StreamError {
+                        message: format!("Seek offset must be an integer, got {}", offset),
+                    })?;
+                let target = match whence {
+                    "start" => u64::try_from(offset).map(SeekFrom::Start).map_err(|_| {
+                        RuntimeError::StreamError {
+                            message: format!(
+                                "Cannot seek before the start of a stream: {}",
+                                offset
+                            ),
+                        }
+                    })?,
+                    "current" => SeekFrom::Current(offset),
+                    "end" => SeekFrom::End(offset),
+                    other => {
+                        return Err(RuntimeError::StreamError {
+                            message: format!(
+                                "Invalid seek whence '{}', expected \"start\", \"current\" or \"end\"",
+                                other
+                            ),
+                        });
+                    }
+                };
+
+                flush_write_buffer(&stream_handle)?;
+                stream_seek(&stream_handle, target)
+            }
+            "tell" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "stream::tell() takes no arguments".to_string(),
+                    });
+                }
+                flush_write_buffer(&stream_handle)?;
+                stream_seek(&stream_handle, SeekFrom::Current(0))
+            }
             "close" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
                         message: "stream::close() takes no arguments".to_string(),
                     });
                 }
+                flush_write_buffer(&stream_handle)?;
                 stream_handle.is_closed.set(true);
                 Ok(Value::Nil)
             }
+            "contents" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "stream::contents() takes no arguments".to_string(),
+                    });
+                }
+                let bytes =
+                    stream_handle
+                        .memory_contents()
+                        .ok_or_else(|| RuntimeError::StreamError {
+                            message: format!(
+                                "contents() is only available on in-memory streams, not {}",
+                                stream_handle.name
+                            ),
+                        })?;
+                String::from_utf8(bytes)
+                    .map(Value::String)
+                    .map_err(|_| RuntimeError::StreamError {
+                        message: "Stream contents are not valid UTF-8".to_string(),
+                    })
+            }
             "to_string" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
@@ -205,7 +306,7 @@ pub fn call_stream_method(
                 Ok(Value::String(format!("<stream:{}>", stream_handle.name)))
             }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -263,7 +364,7 @@ fn stream_read_chunk(
                 }),
             }
         }
-        StreamBackend::MemoryReadable(cursor_ref) => {
+        StreamBackend::MemoryReadable(cursor_ref) | StreamBackend::Memory(cursor_ref) => {
             let mut cursor = cursor_ref.borrow_mut();
             let mut buffer = vec![0u8; chunk_size];
 
@@ -374,7 +475,7 @@ fn stream_read_line(stream_handle: &StreamHandle) -> Result<Value, RuntimeError>
                 }),
             }
         }
-        StreamBackend::MemoryReadable(cursor_ref) => {
+        StreamBackend::MemoryReadable(cursor_ref) | StreamBackend::Memory(cursor_ref) => {
             let mut cursor = cursor_ref.borrow_mut();
             let mut line = String::new();
             match cursor.read_line(&mut line) {
@@ -421,6 +522,11 @@ fn stream_read_line(stream_handle: &StreamHandle) -> Result<Value, RuntimeError>
 
 /// Write text to a stream
 fn stream_write(stream_handle: &StreamHandle, text: &str) -> Result<Value, RuntimeError> {
+    if stream_handle.read_only {
+        return Err(RuntimeError::StreamError {
+            message: format!("Cannot write to read-only stream: {}", stream_handle.name),
+        });
+    }
     let bytes = text.as_bytes();
 
     match &stream_handle.backend {
@@ -449,6 +555,21 @@ fn stream_write(stream_handle: &StreamHandle, text: &str) -> Result<Value, Runti
             }
         }
         StreamBackend::File(file_ref) => {
+            if stream_handle.buffering != StreamBuffering::None {
+                let should_flush = {
+                    let mut pending = stream_handle.write_buffer.borrow_mut();
+                    pending.extend_from_slice(bytes);
+                    match stream_handle.buffering {
+                        StreamBuffering::Line => bytes.contains(&b'\n'),
+                        _ => pending.len() >= StreamBuffering::BLOCK_SIZE,
+                    }
+                };
+                if should_flush {
+                    flush_write_buffer(stream_handle)?;
+                }
+                return Ok(Value::Number(DecimalNumber::from_usize(bytes.len())));
+            }
+
             let mut file = file_ref.borrow_mut();
             match file.write_all(bytes) {
                 Ok(()) => {
@@ -465,6 +586,16 @@ fn stream_write(stream_handle: &StreamHandle, text: &str) -> Result<Value, Runti
             buffer.extend_from_slice(bytes);
             Ok(Value::Number(DecimalNumber::from_usize(bytes.len())))
         }
+        StreamBackend::Memory(cursor_ref) => {
+            // Overwrites from the current position and grows the buffer like a file
+            cursor_ref
+                .borrow_mut()
+                .write_all(bytes)
+                .map_err(|e| RuntimeError::StreamError {
+                    message: format!("Failed to write to stream: {}", e),
+                })?;
+            Ok(Value::Number(DecimalNumber::from_usize(bytes.len())))
+        }
         #[cfg(test)]
         StreamBackend::TestWritable(buffer_ref) => {
             let mut buffer = buffer_ref.borrow_mut();
@@ -477,6 +608,58 @@ fn stream_write(stream_handle: &StreamHandle, text: &str) -> Result<Value, Runti
     }
 }
 
+/// Pass buffered writes to the backend, mapping I/O failures to stream errors
+fn flush_write_buffer(stream_handle: &StreamHandle) -> Result<(), RuntimeError> {
+    stream_handle
+        .flush_write_buffer()
+        .map_err(|e| RuntimeError::StreamError {
+            message: format!("Failed to flush stream {}: {}", stream_handle.name, e),
+        })
+}
+
+/// Flush buffered writes and the backend itself
+fn stream_flush(stream_handle: &StreamHandle) -> Result<Value, RuntimeError> {
+    flush_write_buffer(stream_handle)?;
+
+    let result = match &stream_handle.backend {
+        StreamBackend::Stdout(stdout_ref) => stdout_ref.borrow_mut().flush(),
+        StreamBackend::Stderr(stderr_ref) => stderr_ref.borrow_mut().flush(),
+        StreamBackend::File(file_ref) => file_ref.borrow_mut().flush(),
+        // In-memory streams have nothing to flush
+        _ => Ok(()),
+    };
+
+    result.map_err(|e| RuntimeError::StreamError {
+        message: format!("Failed to flush stream {}: {}", stream_handle.name, e),
+    })?;
+    Ok(Value::Nil)
+}
+
+/// Move the position of a file or in-memory stream and return the new
+/// position; seeking by `Current(0)` reports the position without moving it
+fn stream_seek(stream_handle: &StreamHandle, target: SeekFrom) -> Result<Value, RuntimeError> {
+    let result = match &stream_handle.backend {
+        StreamBackend::File(file_ref) => file_ref.borrow_mut().seek(target),
+        StreamBackend::MemoryReadable(cursor_ref) | StreamBackend::Memory(cursor_ref) => {
+            cursor_ref.borrow_mut().seek(target)
+        }
+        #[cfg(test)]
+        StreamBackend::TestReadable(cursor_ref) => cursor_ref.borrow_mut().seek(target),
+        _ => {
+            return Err(RuntimeError::StreamError {
+                message: format!("Stream is not seekable: {}", stream_handle.name),
+            });
+        }
+    };
+
+    match result {
+        Ok(position) => Ok(Value::Number(DecimalNumber::from_u64(position))),
+        Err(e) => Err(RuntimeError::StreamError {
+            message: format!("Failed to seek stream {}: {}", stream_handle.name, e),
+        }),
+    }
+}
+
 /// Read all content from a stream
 fn stream_read_all(stream_handle: &StreamHandle) -> Result<Value, RuntimeError> {
     match &stream_handle.backend {
@@ -502,7 +685,7 @@ fn stream_read_all(stream_handle: &StreamHandle) -> Result<Value, RuntimeError>
                 }),
             }
         }
-        StreamBackend::MemoryReadable(cursor_ref) => {
+        StreamBackend::MemoryReadable(cursor_ref) | StreamBackend::Memory(cursor_ref) => {
             let mut cursor = cursor_ref.borrow_mut();
             let mut content = String::new();
 
@@ -579,7 +762,7 @@ fn stream_read_lines(stream_handle: &StreamHandle) -> Result<Value, RuntimeError
 
             Ok(Value::List(lines))
         }
-        StreamBackend::MemoryReadable(cursor_ref) => {
+        StreamBackend::MemoryReadable(cursor_ref) | StreamBackend::Memory(cursor_ref) => {
             let mut cursor = cursor_ref.borrow_mut();
             let mut lines = Vec::new();
 
@@ -674,6 +857,42 @@ mod tests {
         assert_eq!(stream.get_test_output(), Some("hello".to_string()));
     }
 
+    #[test]
+    fn test_stream_seek_and_tell() {
+        let stream = Value::Stream(Rc::new(StreamHandle::new_test_readable("0123456789")));
+        let call = |method: &str, args: Vec<Value>| {
+            call_stream_method(ValueRef::Immutable(&stream), method, args)
+        };
+        let number = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let string = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(call("seek", vec![number(4)]).unwrap(), number(4));
+        assert_eq!(call("read", vec![]).unwrap(), string("456789"));
+        assert_eq!(call("tell", vec![]).unwrap(), number(10));
+        assert_eq!(
+            call("seek", vec![number(-3), string("end")]).unwrap(),
+            number(7)
+        );
+        assert_eq!(
+            call("seek", vec![number(-5), string("current")]).unwrap(),
+            number(2)
+        );
+        assert_eq!(call("read_line", vec![]).unwrap(), string("23456789"));
+
+        assert!(matches!(
+            call("seek", vec![number(-1)]),
+            Err(RuntimeError::StreamError { .. })
+        ));
+        assert!(matches!(
+            call("seek", vec![number(0), string("middle")]),
+            Err(RuntimeError::StreamError { .. })
+        ));
+
+        let stdin = Value::Stream(Rc::new(StreamHandle::new_stdin()));
+        let result = call_stream_method(ValueRef::Immutable(&stdin), "tell", vec![]);
+        assert!(matches!(result, Err(RuntimeError::StreamError { .. })));
+    }
+
     #[test]
     fn test_stream_read_all() {
         let stream = Rc::new(StreamHandle::new_test_readable("line1\nline2\nline3"));
@@ -794,6 +1013,17 @@ mod tests {
         assert!(matches!(result, Err(RuntimeError::StreamError { .. })));
     }
 
+    #[test]
+    fn test_stream_flush() {
+        let writable = Value::Stream(Rc::new(StreamHandle::new_test_writable()));
+        let result = call_stream_method(ValueRef::Immutable(&writable), "flush", vec![]).unwrap();
+        assert_eq!(result, Value::Nil);
+
+        let readable = Value::Stream(Rc::new(StreamHandle::new_test_readable("test")));
+        let result = call_stream_method(ValueRef::Immutable(&readable), "flush", vec![]);
+        assert!(matches!(result, Err(RuntimeError::StreamError { .. })));
+    }
+
     #[test]
     fn test_stream_to_string() {
         let stream = Rc::new(StreamHandle::new_test_readable("test"));
@@ -822,6 +1052,17 @@ mod tests {
         assert!(matches!(result, Err(RuntimeError::StreamError { .. })));
     }
 
+    #[test]
+    fn test_stream_write_read_only_errors() {
+        let mut handle = StreamHandle::new_test_writable();
+        handle.read_only = true;
+        let stream_value = Value::Stream(Rc::new(handle));
+
+        let receiver = ValueRef::Immutable(&stream_value);
+        let result = call_stream_method(receiver, "write", vec![Value::String("x".to_string())]);
+        assert!(matches!(result, Err(RuntimeError::StreamError { .. })));
+    }
+
     #[test]
     fn test_stream_arity_errors() {
         let stream = Rc::new(StreamHandle::new_test_readable("test"));
@@ -1,9 +1,10 @@
+use super::super::regex::compile_regex;
 use super::super::value::{DecimalNumber, RuntimeError, Value};
-use super::common::{ValueRef, call_type_checking_method};
-use rust_decimal::Decimal;
+use super::common::{ClosureEvaluator, ValueRef, call_type_checking_method, eval_closure};
 
-/// String methods: length(), split(separator=" "), to_number(), to_list(), index_of(), to_string()
-pub fn call_string_method(
+/// String methods: length(), is_empty(), byte_len(), split(separator=" "), to_number(), to_list(), index_of(), truncate(n, ellipsis="…"), split_at(n), lines(), lines_with_numbers(), scan(regex), each_match(regex, fn), levenshtein(other), similarity(other), hex_dump(), to_string()
+pub fn call_string_method<'a>(
+    call_closure_fn: Option<ClosureEvaluator<'a>>,
     receiver: ValueRef,
     method: &str,
     args: Vec<Value>,
@@ -18,6 +19,30 @@ pub fn call_string_method(
                 }
                 Ok(Value::Number(DecimalNumber::from_usize(s.chars().count())))
             }
+            "is_empty" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "is_empty() takes no arguments".to_string(),
+                    });
+                }
+                Ok(Value::Boolean(s.is_empty()))
+            }
+            "is_blank" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "is_blank() takes no arguments".to_string(),
+                    });
+                }
+                Ok(Value::Boolean(s.chars().all(char::is_whitespace)))
+            }
+            "byte_len" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "byte_len() takes no arguments".to_string(),
+                    });
+                }
+                Ok(Value::Number(DecimalNumber::from_usize(s.len())))
+            }
             "split" => {
                 let separator = if args.is_empty() {
                     " ".to_string()
@@ -71,9 +96,10 @@ pub fn call_string_method(
                 }
                 match &args[0] {
                     Value::String(substring) => {
+                        // Report a character index so it lines up with length() and s[i]
                         let index = s
                             .find(substring)
-                            .map(DecimalNumber::from_usize)
+                            .map(|byte| DecimalNumber::from_usize(s[..byte].chars().count()))
                             .unwrap_or_else(|| DecimalNumber::from_i64(-1));
                         Ok(Value::Number(index))
                     }
@@ -189,7 +215,7 @@ pub fn call_string_method(
                 }
                 match &args[0] {
                     Value::Number(count) => {
-                        if count.inner() < Decimal::ZERO {
+                        if count.is_negative() {
                             return Err(RuntimeError::TypeError {
                                 message: "repeat() count must be non-negative".to_string(),
                             });
@@ -220,6 +246,183 @@ pub fn call_string_method(
                     }),
                 }
             }
+            "to_snake_case" | "to_camel_case" | "to_pascal_case" | "to_kebab_case"
+            | "to_title_case" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: format!("{}() takes no arguments", method),
+                    });
+                }
+                let words = split_case_words(s);
+                let converted = match method {
+                    "to_snake_case" => words.join("_"),
+                    "to_kebab_case" => words.join("-"),
+                    "to_title_case" => words
+                        .iter()
+                        .map(|w| capitalize(w))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    "to_pascal_case" => words.iter().map(|w| capitalize(w)).collect(),
+                    _ => words
+                        .iter()
+                        .enumerate()
+                        .map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+                        .collect(),
+                };
+                Ok(Value::String(converted))
+            }
+            "truncate" => {
+                if args.is_empty() || args.len() > 2 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "truncate() takes one or two arguments".to_string(),
+                    });
+                }
+                let limit = match &args[0] {
+                    Value::Number(n) => n
+                        .to_i64_checked()
+                        .and_then(|n| usize::try_from(n).ok())
+                        .ok_or_else(|| RuntimeError::TypeError {
+                            message: "truncate() length must be a non-negative integer".to_string(),
+                        })?,
+                    _ => {
+                        return Err(RuntimeError::TypeError {
+                            message: "truncate() length must be a number".to_string(),
+                        });
+                    }
+                };
+                let ellipsis = match args.get(1) {
+                    None => "…",
+                    Some(Value::String(ellipsis)) => ellipsis.as_str(),
+                    Some(_) => {
+                        return Err(RuntimeError::TypeError {
+                            message: "truncate() ellipsis must be a string".to_string(),
+                        });
+                    }
+                };
+                Ok(Value::String(truncate(s, limit, ellipsis)))
+            }
+            "split_at" => {
+                if args.len() != 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "split_at() takes exactly one argument".to_string(),
+                    });
+                }
+                let index = match &args[0] {
+                    Value::Number(n) => {
+                        n.to_i64_checked().ok_or_else(|| RuntimeError::TypeError {
+                            message: "split_at() index must be an integer".to_string(),
+                        })?
+                    }
+                    _ => {
+                        return Err(RuntimeError::TypeError {
+                            message: "split_at() index must be a number".to_string(),
+                        });
+                    }
+                };
+                let (before, after) = split_at(s, index);
+                Ok(Value::Tuple(vec![
+                    Value::String(before.to_string()),
+                    Value::String(after.to_string()),
+                ]))
+            }
+            "lines" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "lines() takes no arguments".to_string(),
+                    });
+                }
+                let lines = split_lines(s)
+                    .into_iter()
+                    .map(|line| Value::String(line.to_string()))
+                    .collect();
+                Ok(Value::List(lines))
+            }
+            "lines_with_numbers" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "lines_with_numbers() takes no arguments".to_string(),
+                    });
+                }
+                let pairs: Vec<Value> = split_lines(s)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        Value::List(vec![
+                            Value::Number(DecimalNumber::from_usize(i + 1)),
+                            Value::String(line.to_string()),
+                        ])
+                    })
+                    .collect();
+                Ok(Value::List(pairs))
+            }
+            "scan" => {
+                if args.len() != 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "scan() takes exactly one argument".to_string(),
+                    });
+                }
+                let regex = regex_arg("scan", &args[0])?;
+                let matches = regex
+                    .captures_iter(s)
+                    .map(|caps| match_groups(&regex, &caps))
+                    .collect();
+                Ok(Value::List(matches))
+            }
+            "each_match" => {
+                if args.len() != 2 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "each_match() takes exactly two arguments".to_string(),
+                    });
+                }
+                let regex = regex_arg("each_match", &args[0])?;
+                let call_fn = call_closure_fn.ok_or_else(|| RuntimeError::MethodError {
+                    message: "each_match() requires closure evaluation support".to_string(),
+                })?;
+                // captures_iter steps past empty matches, so patterns like /x*/ terminate
+                for caps in regex.captures_iter(s) {
+                    eval_closure(call_fn, &args[1], vec![match_groups(&regex, &caps)], None)?;
+                }
+                Ok(Value::Nil)
+            }
+            "levenshtein" | "similarity" => {
+                if args.len() != 1 {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: format!("{}() takes exactly one argument", method),
+                    });
+                }
+                let Value::String(other) = &args[0] else {
+                    return Err(RuntimeError::TypeError {
+                        message: format!("{}() argument must be a string", method),
+                    });
+                };
+                let distance = levenshtein(s, other);
+                if method == "levenshtein" {
+                    return Ok(Value::Number(DecimalNumber::from_usize(distance)));
+                }
+                let longest = s.chars().count().max(other.chars().count());
+                if longest == 0 {
+                    return Ok(Value::Number(DecimalNumber::from_i64(1)));
+                }
+                let ratio = DecimalNumber::from_usize(distance)
+                    .div(&DecimalNumber::from_usize(longest))
+                    .map_err(|e| RuntimeError::InvalidOperation {
+                        message: e.to_string(),
+                    })?;
+                DecimalNumber::from_i64(1)
+                    .sub(&ratio)
+                    .map(Value::Number)
+                    .map_err(|e| RuntimeError::InvalidOperation {
+                        message: e.to_string(),
+                    })
+            }
+            "hex_dump" => {
+                if !args.is_empty() {
+                    return Err(RuntimeError::ArityMismatch {
+                        message: "hex_dump() takes no arguments".to_string(),
+                    });
+                }
+                Ok(Value::String(hex_dump(s.as_bytes())))
+            }
             "to_string" => {
                 if !args.is_empty() {
                     return Err(RuntimeError::ArityMismatch {
@@ -229,7 +432,7 @@ pub fn call_string_method(
                 Ok(Value::String(s.clone()))
             }
             "is_number" | "is_bool" | "is_string" | "is_list" | "is_map" | "is_stream"
-            | "is_function" | "is_tuple" | "is_regex" => {
+            | "is_function" | "is_tuple" | "is_regex" | "to_bool" => {
                 call_type_checking_method(method, receiver.get(), args)
             }
             _ => Err(RuntimeError::MethodError {
@@ -240,6 +443,159 @@ pub fn call_string_method(
     }
 }
 
+/// Split text into lines on `\n` or `\r\n`, without the line terminators.
+/// A trailing newline does not produce an extra empty line.
+fn split_lines(s: &str) -> Vec<&str> {
+    s.lines().collect()
+}
+
+/// Shorten `s` to at most `limit` characters, ending in `ellipsis` when anything was cut.
+/// The ellipsis counts toward the limit (and is itself cut if it is longer than `limit`).
+fn truncate(s: &str, limit: usize, ellipsis: &str) -> String {
+    if s.chars().count() <= limit {
+        return s.to_string();
+    }
+    let ellipsis_len = ellipsis.chars().count();
+    if ellipsis_len >= limit {
+        return ellipsis.chars().take(limit).collect();
+    }
+    let mut truncated: String = s.chars().take(limit - ellipsis_len).collect();
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Split at character `index`; negative indexes count from the end and
+/// out-of-range ones clamp to the start or end.
+fn split_at(s: &str, index: i64) -> (&str, &str) {
+    let chars = s.chars().count() as i64;
+    let index = match index < 0 {
+        true => (chars + index).max(0),
+        false => index.min(chars),
+    };
+    let byte = s
+        .char_indices()
+        .nth(index as usize)
+        .map_or(s.len(), |(byte, _)| byte);
+    s.split_at(byte)
+}
+
+/// Format bytes like `xxd`: an 8-digit hex offset, 16 bytes per row in 2-byte groups,
+/// and an ASCII gutter where non-printable bytes show as `.`. Rows are joined with `\n`.
+fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk
+                .chunks(2)
+                .map(|pair| pair.iter().map(|b| format!("{:02x}", b)).collect())
+                .collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| match b {
+                    0x20..=0x7e => b as char,
+                    _ => '.',
+                })
+                .collect();
+            format!("{:08x}: {:<39}  {}", row * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Edit distance between two strings, counted in Unicode scalar values.
+/// Insertions, deletions and substitutions each cost 1.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Split a string into lowercase words for case conversion.
+/// Words break on non-alphanumeric characters, lower-to-upper transitions (`fooBar`)
+/// and at the end of an acronym (`JSONParser` -> `json`, `parser`).
+fn split_case_words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.extend(c.to_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Uppercase the first character of an already-lowercased word
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Regex argument of scan() and each_match(): a regex or a pattern string
+fn regex_arg(method: &str, value: &Value) -> Result<regex::Regex, RuntimeError> {
+    match value {
+        Value::Regex(regex) => Ok(regex.clone()),
+        Value::String(pattern) => compile_regex(pattern),
+        other => Err(RuntimeError::TypeError {
+            message: format!(
+                "{}() argument must be a regex or pattern string, got {}",
+                method,
+                other.type_name()
+            ),
+        }),
+    }
+}
+
+/// One match as a list: its capture groups, or the whole match when the pattern has
+/// no groups. Groups that did not participate are nil.
+fn match_groups(regex: &regex::Regex, caps: &regex::Captures) -> Value {
+    let first_group = match regex.captures_len() {
+        1 => 0,
+        _ => 1,
+    };
+    Value::List(
+        (first_group..caps.len())
+            .map(|i| {
+                caps.get(i)
+                    .map_or(Value::Nil, |m| Value::String(m.as_str().to_string()))
+            })
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::common::ValueRef;
@@ -249,18 +605,81 @@ mod tests {
     fn test_string_length() {
         let s = Value::String("hello".to_string());
         let receiver = ValueRef::Immutable(&s);
-        let result = call_string_method(receiver, "length", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "length", vec![]).unwrap();
         assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
     }
 
+    #[test]
+    fn test_string_is_empty() {
+        for (text, expected) in [("", true), (" ", false), ("abc", false)] {
+            let s = Value::String(text.to_string());
+            let result =
+                call_string_method(None, ValueRef::Immutable(&s), "is_empty", vec![]).unwrap();
+            assert_eq!(result, Value::Boolean(expected), "is_empty of {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_string_is_blank() {
+        for (text, expected) in [
+            ("", true),
+            (" \t\r\n", true),
+            ("\u{00A0}\u{2003}\u{3000}", true),
+            (" x ", false),
+            ("\u{200B}", false),
+        ] {
+            let s = Value::String(text.to_string());
+            let result =
+                call_string_method(None, ValueRef::Immutable(&s), "is_blank", vec![]).unwrap();
+            assert_eq!(result, Value::Boolean(expected), "is_blank of {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_string_length_counts_chars_and_byte_len_counts_bytes() {
+        let number = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        for (text, chars, bytes) in [("hello", 5, 5), ("café", 4, 5), ("hi 🚀", 4, 7), ("", 0, 0)]
+        {
+            let s = Value::String(text.to_string());
+            let length =
+                call_string_method(None, ValueRef::Immutable(&s), "length", vec![]).unwrap();
+            let byte_len =
+                call_string_method(None, ValueRef::Immutable(&s), "byte_len", vec![]).unwrap();
+            assert_eq!(length, number(chars), "length of {:?}", text);
+            assert_eq!(byte_len, number(bytes), "byte_len of {:?}", text);
+        }
+
+        let s = Value::String("abc".to_string());
+        let result = call_string_method(None, ValueRef::Immutable(&s), "byte_len", vec![number(1)]);
+        assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
+    }
+
+    #[test]
+    fn test_string_index_of_counts_chars() {
+        let s = Value::String("🚀 café bar".to_string());
+        let result = call_string_method(
+            None,
+            ValueRef::Immutable(&s),
+            "index_of",
+            vec![Value::String("bar".to_string())],
+        )
+        .unwrap();
+        assert_eq!(result, Value::Number(DecimalNumber::from_i64(7)));
+    }
+
     #[test]
     fn test_string_split() {
         let s = Value::String("a,b,c".to_string());
         let receiver = ValueRef::Immutable(&s);
 
         // Split with custom separator
-        let result =
-            call_string_method(receiver, "split", vec![Value::String(",".to_string())]).unwrap();
+        let result = call_string_method(
+            None,
+            receiver,
+            "split",
+            vec![Value::String(",".to_string())],
+        )
+        .unwrap();
         if let Value::List(items) = result {
             assert_eq!(items.len(), 3);
             assert_eq!(items[0], Value::String("a".to_string()));
@@ -273,7 +692,7 @@ mod tests {
         // Split with default separator
         let s2 = Value::String("hello world".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "split", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "split", vec![]).unwrap();
         if let Value::List(items) = result2 {
             assert_eq!(items.len(), 2);
             assert_eq!(items[0], Value::String("hello".to_string()));
@@ -287,12 +706,12 @@ mod tests {
     fn test_string_to_number() {
         let s = Value::String("123".to_string());
         let receiver = ValueRef::Immutable(&s);
-        let result = call_string_method(receiver, "to_number", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "to_number", vec![]).unwrap();
         assert_eq!(result, Value::Number(DecimalNumber::from_i64(123)));
 
         let s2 = Value::String("123.45".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "to_number", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "to_number", vec![]).unwrap();
         assert_eq!(
             result2,
             Value::Number(DecimalNumber::parse("123.45").expect("valid number"))
@@ -300,7 +719,7 @@ mod tests {
 
         let s3 = Value::String("invalid".to_string());
         let receiver3 = ValueRef::Immutable(&s3);
-        let result3 = call_string_method(receiver3, "to_number", vec![]);
+        let result3 = call_string_method(None, receiver3, "to_number", vec![]);
         assert!(matches!(
             result3,
             Err(RuntimeError::InvalidNumberConversion { .. })
@@ -311,7 +730,7 @@ mod tests {
     fn test_string_to_list() {
         let s = Value::String("hello".to_string());
         let receiver = ValueRef::Immutable(&s);
-        let result = call_string_method(receiver, "to_list", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "to_list", vec![]).unwrap();
 
         if let Value::List(chars) = result {
             assert_eq!(chars.len(), 5);
@@ -331,6 +750,7 @@ mod tests {
         let receiver = ValueRef::Immutable(&s);
 
         let result = call_string_method(
+            None,
             receiver,
             "index_of",
             vec![Value::String("world".to_string())],
@@ -340,6 +760,7 @@ mod tests {
 
         let receiver2 = ValueRef::Immutable(&s);
         let result2 = call_string_method(
+            None,
             receiver2,
             "index_of",
             vec![Value::String("xyz".to_string())],
@@ -355,6 +776,7 @@ mod tests {
 
         // Test contains with existing substring
         let result = call_string_method(
+            None,
             receiver,
             "contains",
             vec![Value::String("world".to_string())],
@@ -365,6 +787,7 @@ mod tests {
         // Test contains with non-existing substring
         let receiver2 = ValueRef::Immutable(&s);
         let result2 = call_string_method(
+            None,
             receiver2,
             "contains",
             vec![Value::String("xyz".to_string())],
@@ -375,6 +798,7 @@ mod tests {
         // Test error case - wrong argument type
         let receiver3 = ValueRef::Immutable(&s);
         let result3 = call_string_method(
+            None,
             receiver3,
             "contains",
             vec![Value::Number(DecimalNumber::from_i64(42))],
@@ -383,7 +807,7 @@ mod tests {
 
         // Test error case - wrong number of arguments
         let receiver4 = ValueRef::Immutable(&s);
-        let result4 = call_string_method(receiver4, "contains", vec![]);
+        let result4 = call_string_method(None, receiver4, "contains", vec![]);
         assert!(matches!(result4, Err(RuntimeError::ArityMismatch { .. })));
     }
 
@@ -394,6 +818,7 @@ mod tests {
 
         // Test starts_with with matching prefix
         let result = call_string_method(
+            None,
             receiver,
             "starts_with",
             vec![Value::String("hello".to_string())],
@@ -404,6 +829,7 @@ mod tests {
         // Test starts_with with non-matching prefix
         let receiver2 = ValueRef::Immutable(&s);
         let result2 = call_string_method(
+            None,
             receiver2,
             "starts_with",
             vec![Value::String("world".to_string())],
@@ -414,6 +840,7 @@ mod tests {
         // Test error case - wrong argument type
         let receiver3 = ValueRef::Immutable(&s);
         let result3 = call_string_method(
+            None,
             receiver3,
             "starts_with",
             vec![Value::Number(DecimalNumber::from_i64(42))],
@@ -428,6 +855,7 @@ mod tests {
 
         // Test ends_with with matching suffix
         let result = call_string_method(
+            None,
             receiver,
             "ends_with",
             vec![Value::String("world".to_string())],
@@ -438,6 +866,7 @@ mod tests {
         // Test ends_with with non-matching suffix
         let receiver2 = ValueRef::Immutable(&s);
         let result2 = call_string_method(
+            None,
             receiver2,
             "ends_with",
             vec![Value::String("hello".to_string())],
@@ -448,6 +877,7 @@ mod tests {
         // Test error case - wrong argument type
         let receiver3 = ValueRef::Immutable(&s);
         let result3 = call_string_method(
+            None,
             receiver3,
             "ends_with",
             vec![Value::Number(DecimalNumber::from_i64(42))],
@@ -462,6 +892,7 @@ mod tests {
 
         // Test replace with single occurrence
         let result = call_string_method(
+            None,
             receiver,
             "replace",
             vec![
@@ -476,6 +907,7 @@ mod tests {
         let s2 = Value::String("hello hello world".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
         let result2 = call_string_method(
+            None,
             receiver2,
             "replace",
             vec![
@@ -489,6 +921,7 @@ mod tests {
         // Test error case - wrong argument types
         let receiver3 = ValueRef::Immutable(&s);
         let result3 = call_string_method(
+            None,
             receiver3,
             "replace",
             vec![
@@ -501,6 +934,7 @@ mod tests {
         // Test error case - wrong number of arguments
         let receiver4 = ValueRef::Immutable(&s);
         let result4 = call_string_method(
+            None,
             receiver4,
             "replace",
             vec![Value::String("test".to_string())],
@@ -513,25 +947,25 @@ mod tests {
         let s = Value::String("  hello world  ".to_string());
         let receiver = ValueRef::Immutable(&s);
 
-        let result = call_string_method(receiver, "trim", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "trim", vec![]).unwrap();
         assert_eq!(result, Value::String("hello world".to_string()));
 
         // Test with only leading whitespace
         let s2 = Value::String("  hello".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "trim", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "trim", vec![]).unwrap();
         assert_eq!(result2, Value::String("hello".to_string()));
 
         // Test with only trailing whitespace
         let s3 = Value::String("hello  ".to_string());
         let receiver3 = ValueRef::Immutable(&s3);
-        let result3 = call_string_method(receiver3, "trim", vec![]).unwrap();
+        let result3 = call_string_method(None, receiver3, "trim", vec![]).unwrap();
         assert_eq!(result3, Value::String("hello".to_string()));
 
         // Test with no whitespace
         let s4 = Value::String("hello".to_string());
         let receiver4 = ValueRef::Immutable(&s4);
-        let result4 = call_string_method(receiver4, "trim", vec![]).unwrap();
+        let result4 = call_string_method(None, receiver4, "trim", vec![]).unwrap();
         assert_eq!(result4, Value::String("hello".to_string()));
     }
 
@@ -540,42 +974,64 @@ mod tests {
         // Test trimming zeros
         let s1 = Value::String("000123000".to_string());
         let receiver1 = ValueRef::Immutable(&s1);
-        let result1 =
-            call_string_method(receiver1, "trim", vec![Value::String("0".to_string())]).unwrap();
+        let result1 = call_string_method(
+            None,
+            receiver1,
+            "trim",
+            vec![Value::String("0".to_string())],
+        )
+        .unwrap();
         assert_eq!(result1, Value::String("123".to_string()));
 
         // Test trimming asterisks (middle ones should remain)
         let s2 = Value::String("***hello***world***".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 =
-            call_string_method(receiver2, "trim", vec![Value::String("*".to_string())]).unwrap();
+        let result2 = call_string_method(
+            None,
+            receiver2,
+            "trim",
+            vec![Value::String("*".to_string())],
+        )
+        .unwrap();
         assert_eq!(result2, Value::String("hello***world".to_string()));
 
         // Test with empty char set (no-op)
         let s3 = Value::String("  hello  ".to_string());
         let receiver3 = ValueRef::Immutable(&s3);
         let result3 =
-            call_string_method(receiver3, "trim", vec![Value::String("".to_string())]).unwrap();
+            call_string_method(None, receiver3, "trim", vec![Value::String("".to_string())])
+                .unwrap();
         assert_eq!(result3, Value::String("  hello  ".to_string()));
 
         // Test with multiple chars in set
         let s4 = Value::String("abbaHELLOabba".to_string());
         let receiver4 = ValueRef::Immutable(&s4);
-        let result4 =
-            call_string_method(receiver4, "trim", vec![Value::String("ab".to_string())]).unwrap();
+        let result4 = call_string_method(
+            None,
+            receiver4,
+            "trim",
+            vec![Value::String("ab".to_string())],
+        )
+        .unwrap();
         assert_eq!(result4, Value::String("HELLO".to_string()));
 
         // Test with all characters trimmed
         let s5 = Value::String("aaaa".to_string());
         let receiver5 = ValueRef::Immutable(&s5);
-        let result5 =
-            call_string_method(receiver5, "trim", vec![Value::String("a".to_string())]).unwrap();
+        let result5 = call_string_method(
+            None,
+            receiver5,
+            "trim",
+            vec![Value::String("a".to_string())],
+        )
+        .unwrap();
         assert_eq!(result5, Value::String("".to_string()));
 
         // Test error case - non-string argument
         let s6 = Value::String("test".to_string());
         let receiver6 = ValueRef::Immutable(&s6);
         let result6 = call_string_method(
+            None,
             receiver6,
             "trim",
             vec![Value::Number(DecimalNumber::from_i64(123))],
@@ -585,6 +1041,7 @@ mod tests {
         // Test error case - too many arguments
         let receiver7 = ValueRef::Immutable(&s6);
         let result7 = call_string_method(
+            None,
             receiver7,
             "trim",
             vec![
@@ -600,13 +1057,13 @@ mod tests {
         let s = Value::String("hello world".to_string());
         let receiver = ValueRef::Immutable(&s);
 
-        let result = call_string_method(receiver, "upper", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "upper", vec![]).unwrap();
         assert_eq!(result, Value::String("HELLO WORLD".to_string()));
 
         // Test with mixed case
         let s2 = Value::String("Hello World".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "upper", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "upper", vec![]).unwrap();
         assert_eq!(result2, Value::String("HELLO WORLD".to_string()));
     }
 
@@ -615,13 +1072,13 @@ mod tests {
         let s = Value::String("HELLO WORLD".to_string());
         let receiver = ValueRef::Immutable(&s);
 
-        let result = call_string_method(receiver, "lower", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "lower", vec![]).unwrap();
         assert_eq!(result, Value::String("hello world".to_string()));
 
         // Test with mixed case
         let s2 = Value::String("Hello World".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "lower", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "lower", vec![]).unwrap();
         assert_eq!(result2, Value::String("hello world".to_string()));
     }
 
@@ -630,19 +1087,19 @@ mod tests {
         let s = Value::String("hello".to_string());
         let receiver = ValueRef::Immutable(&s);
 
-        let result = call_string_method(receiver, "reverse", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "reverse", vec![]).unwrap();
         assert_eq!(result, Value::String("olleh".to_string()));
 
         // Test with empty string
         let s2 = Value::String("".to_string());
         let receiver2 = ValueRef::Immutable(&s2);
-        let result2 = call_string_method(receiver2, "reverse", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "reverse", vec![]).unwrap();
         assert_eq!(result2, Value::String("".to_string()));
 
         // Test with single character
         let s3 = Value::String("a".to_string());
         let receiver3 = ValueRef::Immutable(&s3);
-        let result3 = call_string_method(receiver3, "reverse", vec![]).unwrap();
+        let result3 = call_string_method(None, receiver3, "reverse", vec![]).unwrap();
         assert_eq!(result3, Value::String("a".to_string()));
     }
 
@@ -652,20 +1109,32 @@ mod tests {
 
         // Test upper() with arguments (should fail)
         let receiver1 = ValueRef::Immutable(&s);
-        let result1 =
-            call_string_method(receiver1, "upper", vec![Value::String("arg".to_string())]);
+        let result1 = call_string_method(
+            None,
+            receiver1,
+            "upper",
+            vec![Value::String("arg".to_string())],
+        );
         assert!(matches!(result1, Err(RuntimeError::ArityMismatch { .. })));
 
         // Test lower() with arguments (should fail)
         let receiver2 = ValueRef::Immutable(&s);
-        let result2 =
-            call_string_method(receiver2, "lower", vec![Value::String("arg".to_string())]);
+        let result2 = call_string_method(
+            None,
+            receiver2,
+            "lower",
+            vec![Value::String("arg".to_string())],
+        );
         assert!(matches!(result2, Err(RuntimeError::ArityMismatch { .. })));
 
         // Test reverse() with arguments (should fail)
         let receiver3 = ValueRef::Immutable(&s);
-        let result3 =
-            call_string_method(receiver3, "reverse", vec![Value::String("arg".to_string())]);
+        let result3 = call_string_method(
+            None,
+            receiver3,
+            "reverse",
+            vec![Value::String("arg".to_string())],
+        );
         assert!(matches!(result3, Err(RuntimeError::ArityMismatch { .. })));
     }
 
@@ -676,6 +1145,7 @@ mod tests {
 
         // Test repeat with positive count
         let result = call_string_method(
+            None,
             receiver,
             "repeat",
             vec![Value::Number(DecimalNumber::from_i64(3))],
@@ -686,6 +1156,7 @@ mod tests {
         // Test repeat with zero count
         let receiver2 = ValueRef::Immutable(&s);
         let result2 = call_string_method(
+            None,
             receiver2,
             "repeat",
             vec![Value::Number(DecimalNumber::from_i64(0))],
@@ -696,6 +1167,7 @@ mod tests {
         // Test repeat with one count
         let receiver3 = ValueRef::Immutable(&s);
         let result3 = call_string_method(
+            None,
             receiver3,
             "repeat",
             vec![Value::Number(DecimalNumber::from_i64(1))],
@@ -706,6 +1178,7 @@ mod tests {
         // Test error case - negative count
         let receiver4 = ValueRef::Immutable(&s);
         let result4 = call_string_method(
+            None,
             receiver4,
             "repeat",
             vec![Value::Number(DecimalNumber::from_i64(-1))],
@@ -715,6 +1188,7 @@ mod tests {
         // Test error case - non-finite count
         let receiver5 = ValueRef::Immutable(&s);
         let result5 = call_string_method(
+            None,
             receiver5,
             "repeat",
             vec![Value::Number(
@@ -725,12 +1199,17 @@ mod tests {
 
         // Test error case - wrong argument type
         let receiver6 = ValueRef::Immutable(&s);
-        let result6 = call_string_method(receiver6, "repeat", vec![Value::String("3".to_string())]);
+        let result6 = call_string_method(
+            None,
+            receiver6,
+            "repeat",
+            vec![Value::String("3".to_string())],
+        );
         assert!(matches!(result6, Err(RuntimeError::TypeError { .. })));
 
         // Test error case - wrong number of arguments
         let receiver7 = ValueRef::Immutable(&s);
-        let result7 = call_string_method(receiver7, "repeat", vec![]);
+        let result7 = call_string_method(None, receiver7, "repeat", vec![]);
         assert!(matches!(result7, Err(RuntimeError::ArityMismatch { .. })));
     }
 
@@ -738,19 +1217,19 @@ mod tests {
     fn test_string_to_string() {
         let s = Value::String("hello world".to_string());
         let receiver = ValueRef::Immutable(&s);
-        let result = call_string_method(receiver, "to_string", vec![]).unwrap();
+        let result = call_string_method(None, receiver, "to_string", vec![]).unwrap();
         assert_eq!(result, Value::String("hello world".to_string()));
 
         // Test with empty string
         let empty = Value::String("".to_string());
         let receiver2 = ValueRef::Immutable(&empty);
-        let result2 = call_string_method(receiver2, "to_string", vec![]).unwrap();
+        let result2 = call_string_method(None, receiver2, "to_string", vec![]).unwrap();
         assert_eq!(result2, Value::String("".to_string()));
 
         // Test with special characters
         let special = Value::String("Hello, 世界! 🌍".to_string());
         let receiver3 = ValueRef::Immutable(&special);
-        let result3 = call_string_method(receiver3, "to_string", vec![]).unwrap();
+        let result3 = call_string_method(None, receiver3, "to_string", vec![]).unwrap();
         assert_eq!(result3, Value::String("Hello, 世界! 🌍".to_string()));
     }
 
@@ -759,10 +1238,301 @@ mod tests {
         let s = Value::String("test".to_string());
         let receiver = ValueRef::Immutable(&s);
         let result = call_string_method(
+            None,
             receiver,
             "to_string",
             vec![Value::Number(DecimalNumber::from_i64(1))],
         );
         assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
     }
+
+    #[test]
+    fn test_string_case_conversions() {
+        let convert = |input: &str, method: &str| {
+            let s = Value::String(input.to_string());
+            call_string_method(None, ValueRef::Immutable(&s), method, vec![]).unwrap()
+        };
+
+        assert_eq!(
+            convert("fooBar", "to_snake_case"),
+            Value::String("foo_bar".to_string())
+        );
+        assert_eq!(
+            convert("parseJSON", "to_snake_case"),
+            Value::String("parse_json".to_string())
+        );
+        assert_eq!(
+            convert("JSONParser v2", "to_kebab_case"),
+            Value::String("json-parser-v2".to_string())
+        );
+        assert_eq!(
+            convert("user_id", "to_camel_case"),
+            Value::String("userId".to_string())
+        );
+        assert_eq!(
+            convert("http-request", "to_pascal_case"),
+            Value::String("HttpRequest".to_string())
+        );
+        assert_eq!(
+            convert("hello_worldFoo", "to_title_case"),
+            Value::String("Hello World Foo".to_string())
+        );
+        assert_eq!(convert("", "to_snake_case"), Value::String(String::new()));
+
+        let s = Value::String("x".to_string());
+        let result = call_string_method(
+            None,
+            ValueRef::Immutable(&s),
+            "to_snake_case",
+            vec![Value::Nil],
+        );
+        assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
+    }
+
+    #[test]
+    fn test_string_lines() {
+        let s = Value::String("alpha\r\nbeta\n\ngamma".to_string());
+        let result = call_string_method(None, ValueRef::Immutable(&s), "lines", vec![]).unwrap();
+        assert_eq!(
+            result,
+            Value::List(vec![
+                Value::String("alpha".to_string()),
+                Value::String("beta".to_string()),
+                Value::String(String::new()),
+                Value::String("gamma".to_string()),
+            ])
+        );
+
+        let result = call_string_method(None, ValueRef::Immutable(&s), "lines", vec![Value::Nil]);
+        assert!(matches!(result, Err(RuntimeError::ArityMismatch { .. })));
+    }
+
+    #[test]
+    fn test_string_lines_with_numbers() {
+        let s = Value::String("first\r\nsecond\n\nfourth\n".to_string());
+        let result =
+            call_string_method(None, ValueRef::Immutable(&s), "lines_with_numbers", vec![])
+                .unwrap();
+
+        let pair = |n: i64, line: &str| {
+            Value::List(vec![
+                Value::Number(DecimalNumber::from_i64(n)),
+                Value::String(line.to_string()),
+            ])
+        };
+        assert_eq!(
+            result,
+            Value::List(vec![
+                pair(1, "first"),
+                pair(2, "second"),
+                pair(3, ""),
+                pair(4, "fourth"),
+            ])
+        );
+
+        let empty = Value::String(String::new());
+        let result = call_string_method(
+            None,
+            ValueRef::Immutable(&empty),
+            "lines_with_numbers",
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(result, Value::List(vec![]));
+    }
+
+    #[test]
+    fn test_string_scan() {
+        let scan = |text: &str, pattern: Value| {
+            let s = Value::String(text.to_string());
+            call_string_method(None, ValueRef::Immutable(&s), "scan", vec![pattern])
+        };
+        let regex = |pattern: &str| Value::Regex(regex::Regex::new(pattern).unwrap());
+        let text = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(
+            scan("a=1, b=2", regex(r"(\w+)=(\d+)")).unwrap(),
+            Value::List(vec![
+                Value::List(vec![text("a"), text("1")]),
+                Value::List(vec![text("b"), text("2")]),
+            ])
+        );
+        // Groups that did not take part in a match are nil
+        assert_eq!(
+            scan("x1 y", regex(r"([a-z])(\d)?")).unwrap(),
+            Value::List(vec![
+                Value::List(vec![text("x"), text("1")]),
+                Value::List(vec![text("y"), Value::Nil]),
+            ])
+        );
+        // Without groups each match is a one-element list; pattern strings are compiled
+        assert_eq!(
+            scan("10 and 20", text(r"\d+")).unwrap(),
+            Value::List(vec![
+                Value::List(vec![text("10")]),
+                Value::List(vec![text("20")]),
+            ])
+        );
+        assert_eq!(scan("abc", regex(r"\d")).unwrap(), Value::List(vec![]));
+
+        assert!(matches!(
+            scan("abc", text("(")),
+            Err(RuntimeError::RegexError { .. })
+        ));
+        assert!(matches!(
+            scan("abc", Value::Nil),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_string_split_at() {
+        let split_at = |text: &str, index: i64| {
+            let s = Value::String(text.to_string());
+            let args = vec![Value::Number(DecimalNumber::from_i64(index))];
+            call_string_method(None, ValueRef::Immutable(&s), "split_at", args)
+        };
+        let pair = |before: &str, after: &str| {
+            Value::Tuple(vec![
+                Value::String(before.to_string()),
+                Value::String(after.to_string()),
+            ])
+        };
+
+        assert_eq!(split_at("hello", 2).unwrap(), pair("he", "llo"));
+        assert_eq!(split_at("hello", -2).unwrap(), pair("hel", "lo"));
+        assert_eq!(split_at("日本語", 1).unwrap(), pair("日", "本語"));
+        // Out-of-range indexes clamp
+        assert_eq!(split_at("hello", 9).unwrap(), pair("hello", ""));
+        assert_eq!(split_at("hello", -9).unwrap(), pair("", "hello"));
+        assert_eq!(split_at("", 0).unwrap(), pair("", ""));
+
+        let s = Value::String("hello".to_string());
+        let half = Value::Number(DecimalNumber::parse("1.5").unwrap());
+        assert!(matches!(
+            call_string_method(None, ValueRef::Immutable(&s), "split_at", vec![half]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_string_truncate() {
+        let truncate = |text: &str, args: Vec<Value>| {
+            let s = Value::String(text.to_string());
+            call_string_method(None, ValueRef::Immutable(&s), "truncate", args)
+        };
+        let number = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+        let text = |s: &str| Value::String(s.to_string());
+
+        assert_eq!(
+            truncate("hello world", vec![number(8)]).unwrap(),
+            text("hello w…")
+        );
+        assert_eq!(
+            truncate("hello world", vec![number(8), text("...")]).unwrap(),
+            text("hello...")
+        );
+        // Nothing is cut, so no ellipsis is added
+        assert_eq!(truncate("hello", vec![number(5)]).unwrap(), text("hello"));
+        // Counted in characters, never splitting a multibyte one
+        assert_eq!(
+            truncate("日本語テキスト", vec![number(4)]).unwrap(),
+            text("日本語…")
+        );
+        assert_eq!(
+            truncate("hello", vec![number(2), text("...")]).unwrap(),
+            text("..")
+        );
+        assert_eq!(truncate("hello", vec![number(0)]).unwrap(), text(""));
+
+        assert!(matches!(
+            truncate("hello", vec![number(-1)]),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            truncate("hello", vec![]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_string_hex_dump() {
+        let dump = |text: &str| {
+            let s = Value::String(text.to_string());
+            call_string_method(None, ValueRef::Immutable(&s), "hex_dump", vec![]).unwrap()
+        };
+
+        assert_eq!(
+            dump("hello world\n"),
+            Value::String(
+                "00000000: 6865 6c6c 6f20 776f 726c 640a            hello world.".to_string()
+            )
+        );
+        assert_eq!(
+            dump("0123456789abcdef\té"),
+            Value::String(
+                [
+                    "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef",
+                    "00000010: 09c3 a9                                  ...",
+                ]
+                .join("\n")
+            )
+        );
+        assert_eq!(dump(""), Value::String(String::new()));
+    }
+
+    #[test]
+    fn test_string_levenshtein_and_similarity() {
+        let distance = |a: &str, b: &str| {
+            let s = Value::String(a.to_string());
+            call_string_method(
+                None,
+                ValueRef::Immutable(&s),
+                "levenshtein",
+                vec![Value::String(b.to_string())],
+            )
+            .unwrap()
+        };
+        let number = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+
+        assert_eq!(distance("kitten", "sitting"), number(3));
+        assert_eq!(distance("flaw", "lawn"), number(2));
+        assert_eq!(distance("", "abc"), number(3));
+        assert_eq!(distance("same", "same"), number(0));
+        // Counted per Unicode scalar value, not per byte
+        assert_eq!(distance("café", "cafe"), number(1));
+        assert_eq!(distance("日本語", "日本"), number(1));
+
+        let similarity = |a: &str, b: &str| {
+            let s = Value::String(a.to_string());
+            call_string_method(
+                None,
+                ValueRef::Immutable(&s),
+                "similarity",
+                vec![Value::String(b.to_string())],
+            )
+            .unwrap()
+        };
+        assert_eq!(similarity("", ""), number(1));
+        assert_eq!(similarity("abc", "xyz"), number(0));
+        assert_eq!(
+            similarity("test", "tent"),
+            Value::Number(DecimalNumber::parse("0.75").unwrap())
+        );
+
+        let s = Value::String("abc".to_string());
+        assert!(matches!(
+            call_string_method(
+                None,
+                ValueRef::Immutable(&s),
+                "levenshtein",
+                vec![number(1)]
+            ),
+            Err(RuntimeError::TypeError { .. })
+        ));
+        assert!(matches!(
+            call_string_method(None, ValueRef::Immutable(&s), "similarity", vec![]),
+            Err(RuntimeError::ArityMismatch { .. })
+        ));
+    }
 }
@@ -156,6 +156,36 @@ pub fn expand_range_inclusive_values(
     expand_range_inclusive(start, end)
 }
 
+/// Check whether a number falls within a range, using the same direction and
+/// end-inclusivity rules as range expansion:
+/// - start..end (ascending) covers start <= n < end
+/// - start..end (descending) covers end < n <= start
+/// - start..=end covers everything between the two bounds, both included
+///
+/// Unlike expansion, containment is numeric, so non-integer values can match.
+pub fn range_contains(
+    value: &DecimalNumber,
+    start: &DecimalNumber,
+    end: &DecimalNumber,
+    inclusive: bool,
+) -> bool {
+    let (low, high) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+
+    if inclusive {
+        return low <= value && value <= high;
+    }
+
+    if start <= end {
+        start <= value && value < end
+    } else {
+        end < value && value <= start
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -538,6 +568,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_range_contains() {
+        let n = |v: &str| DecimalNumber::parse(v).unwrap();
+
+        // Ascending exclusive: 1..10
+        assert!(range_contains(&n("1"), &n("1"), &n("10"), false));
+        assert!(range_contains(&n("9.5"), &n("1"), &n("10"), false));
+        assert!(!range_contains(&n("10"), &n("1"), &n("10"), false));
+        assert!(!range_contains(&n("0"), &n("1"), &n("10"), false));
+
+        // Ascending inclusive: 1..=10
+        assert!(range_contains(&n("10"), &n("1"), &n("10"), true));
+
+        // Descending exclusive: 5..0 covers 5 down to (but excluding) 0
+        assert!(range_contains(&n("5"), &n("5"), &n("0"), false));
+        assert!(!range_contains(&n("0"), &n("5"), &n("0"), false));
+
+        // Descending inclusive: 5..=0
+        assert!(range_contains(&n("0"), &n("5"), &n("0"), true));
+
+        // Empty range: 3..3
+        assert!(!range_contains(&n("3"), &n("3"), &n("3"), false));
+        assert!(range_contains(&n("3"), &n("3"), &n("3"), true));
+    }
+
     #[test]
     fn test_descending_range_size_limit() {
         // This should exceed our limit of 1,000,000 elements
@@ -48,6 +48,19 @@ pub fn run_shell(command: &str) -> Result<String, RuntimeError> {
     Ok(result)
 }
 
+/// Execute a shell command with optional stdin text and return stdout as UTF-8 (trims trailing newline)
+pub fn run_shell_with_input(command: &str, input: Option<&str>) -> Result<String, RuntimeError> {
+    let stdout = run_shell_bytes_with_input(command, input.map(|text| text.as_bytes().to_vec()))?;
+    let stdout = String::from_utf8(stdout).map_err(|err| RuntimeError::ShellError {
+        message: format!("Shell command output is not valid UTF-8: {}", err),
+    })?;
+
+    Ok(match stdout.strip_suffix('\n') {
+        Some(trimmed) => trimmed.to_string(),
+        None => stdout,
+    })
+}
+
 /// Execute a shell command template and return Value::String
 pub fn execute_shell_template(command: &str) -> Result<Value, RuntimeError> {
     let output = run_shell(command)?;
@@ -78,21 +91,13 @@ pub fn run_shell_bytes_with_input(
             message: format!("Failed to execute shell command '{}': {}", command, err),
         })?;
 
-    // If we have input, write it to child's stdin then drop the handle
-    if let Some(bytes) = input {
-        if let Some(stdin) = child.stdin.as_mut() {
-            stdin
-                .write_all(&bytes)
-                .map_err(|err| RuntimeError::ShellError {
-                    message: format!(
-                        "Failed to write to stdin of shell command '{}': {}",
-                        command, err
-                    ),
-                })?;
-        }
-        // Explicitly drop stdin to signal EOF
-        drop(child.stdin.take());
-    }
+    // Feed input from a separate thread so a child that writes more output than the
+    // pipe buffer holds before draining its stdin cannot deadlock us; dropping the
+    // handle when the writer finishes signals EOF
+    let writer = match (input, child.stdin.take()) {
+        (Some(bytes), Some(mut stdin)) => Some(std::thread::spawn(move || stdin.write_all(&bytes))),
+        _ => None,
+    };
 
     // Wait for the child and collect output
     let output = child
@@ -101,6 +106,18 @@ pub fn run_shell_bytes_with_input(
             message: format!("Failed to wait for shell command '{}': {}", command, err),
         })?;
 
+    if let Some(writer) = writer {
+        let written = writer.join().map_err(|_| RuntimeError::ShellError {
+            message: format!("Failed to write to stdin of shell command '{}'", command),
+        })?;
+        written.map_err(|err| RuntimeError::ShellError {
+            message: format!(
+                "Failed to write to stdin of shell command '{}': {}",
+                command, err
+            ),
+        })?;
+    }
+
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         let exit_code = output.status.code().unwrap_or(-1);
@@ -217,6 +234,26 @@ mod tests {
         assert_eq!(result.unwrap(), "test");
     }
 
+    #[test]
+    fn test_input_is_piped_to_stdin() {
+        let result = run_shell_with_input("grep foo", Some("foo 1\nbar\nfoo 2\n"));
+        assert_eq!(result.unwrap(), "foo 1\nfoo 2");
+    }
+
+    #[test]
+    fn test_large_input_does_not_deadlock() {
+        let input = "x".repeat(1 << 20);
+        let result = run_shell_bytes_with_input("cat", Some(input.clone().into_bytes()));
+        assert_eq!(result.unwrap().len(), input.len());
+    }
+
+    #[test]
+    fn test_broken_pipe_is_shell_error() {
+        let input = "x".repeat(1 << 20);
+        let result = run_shell_bytes_with_input("true", Some(input.into_bytes()));
+        assert!(matches!(result, Err(RuntimeError::ShellError { .. })));
+    }
+
     #[test]
     fn test_unicode_output() {
         let result = run_shell("echo 'café 🚀'");
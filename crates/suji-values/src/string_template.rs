@@ -1,5 +1,6 @@
 use super::value::{RuntimeError, Value};
 use suji_ast::StringPart;
+use suji_lexer::{FormatAlign, FormatSpec};
 
 /// Evaluate a string template by processing its parts
 pub fn evaluate_string_template<F>(
@@ -20,18 +21,81 @@ where
                 let value = eval_expr(expr)?;
                 result.push_str(&value.to_string());
             }
+            StringPart::Formatted(expr, spec) => {
+                let value = eval_expr(expr)?;
+                result.push_str(&format_with_spec(&value, spec));
+            }
         }
     }
 
     Ok(result)
 }
 
+/// Render a value according to an interpolation format spec.
+/// Numbers honour sign, zero padding and fixed precision and align right by default;
+/// other values use their display form (precision truncates) and align left.
+pub fn format_with_spec(value: &Value, spec: &FormatSpec) -> String {
+    let (body, is_number) = match value {
+        Value::Number(n) => {
+            let mut text = match spec.precision {
+                Some(precision) => match n.inner() {
+                    Some(d) => format!("{:.*}", precision, d),
+                    None => n.to_fixed(precision as u32),
+                },
+                None => n.to_string(),
+            };
+            if spec.sign_plus && !text.starts_with('-') {
+                text.insert(0, '+');
+            }
+            (text, true)
+        }
+        other => {
+            let text = other.to_string();
+            let text = match spec.precision {
+                Some(precision) => text.chars().take(precision).collect(),
+                None => text,
+            };
+            (text, false)
+        }
+    };
+
+    let width = spec.width.unwrap_or(0);
+    let len = body.chars().count();
+    if len >= width {
+        return body;
+    }
+    let padding = width - len;
+
+    if is_number && spec.zero_pad && spec.align.is_none() {
+        let (sign, digits) = match body.strip_prefix(['+', '-']) {
+            Some(rest) => (&body[..1], rest),
+            None => ("", body.as_str()),
+        };
+        return format!("{}{}{}", sign, "0".repeat(padding), digits);
+    }
+
+    let align = spec.align.unwrap_or(if is_number {
+        FormatAlign::Right
+    } else {
+        FormatAlign::Left
+    });
+    let fill = |count: usize| spec.fill.to_string().repeat(count);
+    match align {
+        FormatAlign::Left => format!("{}{}", body, fill(padding)),
+        FormatAlign::Right => format!("{}{}", fill(padding), body),
+        FormatAlign::Center => {
+            let left = padding / 2;
+            format!("{}{}{}", fill(left), body, fill(padding - left))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::value::DecimalNumber;
     use suji_ast::{Expr, Literal};
-    use suji_lexer::Span;
+    use suji_lexer::{FormatSpec, Span};
 
     fn dummy_evaluator(expr: &suji_ast::Expr) -> Result<Value, RuntimeError> {
         // Simple evaluator for testing
@@ -168,4 +232,20 @@ mod tests {
         let result = evaluate_string_template(&parts, dummy_evaluator).unwrap();
         assert_eq!(result, "Alice");
     }
+
+    #[test]
+    fn test_format_with_spec() {
+        let spec = |text: &str| FormatSpec::parse(text).unwrap();
+        let pi = Value::Number(DecimalNumber::parse("3.14159").unwrap());
+        let n = Value::Number(DecimalNumber::from_i64(-42));
+        let s = Value::String("abc".to_string());
+
+        assert_eq!(format_with_spec(&pi, &spec(".2")), "3.14");
+        assert_eq!(format_with_spec(&pi, &spec("+8.1")), "    +3.1");
+        assert_eq!(format_with_spec(&n, &spec("06")), "-00042");
+        assert_eq!(format_with_spec(&n, &spec("<5")), "-42  ");
+        assert_eq!(format_with_spec(&s, &spec("5")), "abc  ");
+        assert_eq!(format_with_spec(&s, &spec("-^7")), "--abc--");
+        assert_eq!(format_with_spec(&s, &spec(".2")), "ab");
+    }
 }
@@ -40,6 +40,29 @@ impl PartialOrd for Value {
     }
 }
 
+impl Value {
+    /// Total ordering used when sorting by extracted keys. Values are ranked by type
+    /// first (nil, booleans, numbers, strings, then everything else), so nil sorts
+    /// before every other value and mixed types never contradict each other. Numbers,
+    /// strings and booleans compare by value; other values of the same rank compare as
+    /// equal so the sort keeps their original order.
+    pub fn sort_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        fn rank(value: &Value) -> u8 {
+            match value {
+                Value::Nil => 0,
+                Value::Boolean(_) => 1,
+                Value::Number(_) => 2,
+                Value::String(_) => 3,
+                _ => 4,
+            }
+        }
+
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| self.partial_cmp(other).unwrap_or(std::cmp::Ordering::Equal))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::types::{DecimalNumber, FunctionBody, MapKey};
@@ -237,4 +260,36 @@ mod tests {
         assert_eq!(Value::List(vec![]).partial_cmp(&Value::List(vec![])), None);
         assert_eq!(Value::Nil.partial_cmp(&Value::Nil), None);
     }
+
+    #[test]
+    fn test_value_sort_cmp() {
+        use std::cmp::Ordering;
+
+        let one = Value::Number(DecimalNumber::from_i64(1));
+        assert_eq!(Value::Nil.sort_cmp(&one), Ordering::Less);
+        assert_eq!(one.sort_cmp(&Value::Nil), Ordering::Greater);
+        assert_eq!(Value::Nil.sort_cmp(&Value::Nil), Ordering::Equal);
+        assert_eq!(
+            one.sort_cmp(&Value::Number(DecimalNumber::from_i64(2))),
+            Ordering::Less
+        );
+        // Mixed types order by type rank, never by value
+        assert_eq!(
+            one.sort_cmp(&Value::String("1".to_string())),
+            Ordering::Less
+        );
+        assert_eq!(
+            Value::String("0".to_string()).sort_cmp(&one),
+            Ordering::Greater
+        );
+        assert_eq!(Value::Boolean(true).sort_cmp(&one), Ordering::Less);
+        assert_eq!(
+            Value::List(vec![]).sort_cmp(&Value::String("z".to_string())),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Value::List(vec![one.clone()]).sort_cmp(&Value::List(vec![])),
+            Ordering::Equal
+        );
+    }
 }
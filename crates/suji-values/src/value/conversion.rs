@@ -22,15 +22,37 @@ impl Value {
         }
     }
 
-    /// Check if this value is truthy (only true for Boolean(true))
+    /// Check if this value is truthy, as reported by `to_bool()`: `nil` and `false` are
+    /// falsy and every other value (including `0`, `""` and empty collections) is truthy
     pub fn is_truthy(&self) -> bool {
-        matches!(self, Value::Boolean(true))
+        !matches!(self, Value::Nil | Value::Boolean(false))
+    }
+
+    /// Read this value as a condition (`&&`, `||`, conditional match arms, filter
+    /// predicates). Conditions never coerce: anything but a boolean is a TypeError,
+    /// and `what` names the condition in the message.
+    pub fn as_condition(&self, what: &str) -> Result<bool, RuntimeError> {
+        match self {
+            Value::Boolean(b) => Ok(*b),
+            other => Err(RuntimeError::TypeError {
+                message: format!(
+                    "{} must be a boolean, got {} (use to_bool() to convert)",
+                    what,
+                    other.type_name()
+                ),
+            }),
+        }
     }
 
     /// Try to convert this value to a MapKey
     pub fn try_into_map_key(self) -> Result<MapKey, RuntimeError> {
         match self {
-            Value::Number(n) => Ok(MapKey::Number(OrderedDecimal::new(n.0))),
+            Value::Number(n) => match n.inner() {
+                Some(d) => Ok(MapKey::Number(OrderedDecimal::new(d))),
+                None => Err(RuntimeError::InvalidKeyType {
+                    message: "numbers beyond the decimal range (about ±7.9e28) cannot be used as map keys".to_string(),
+                }),
+            },
             Value::Boolean(b) => Ok(MapKey::Boolean(b)),
             Value::String(s) => Ok(MapKey::String(s)),
             Value::Tuple(items) => {
@@ -51,7 +73,9 @@ impl MapKey {
     /// Convert MapKey back to a Value
     pub fn to_value(&self) -> Value {
         match self {
-            MapKey::Number(ordered_decimal) => Value::Number(DecimalNumber(ordered_decimal.0)),
+            MapKey::Number(ordered_decimal) => {
+                Value::Number(DecimalNumber::from_decimal(ordered_decimal.0))
+            }
             MapKey::Boolean(b) => Value::Boolean(*b),
             MapKey::String(s) => Value::String(s.clone()),
             MapKey::Tuple(items) => Value::Tuple(items.iter().map(|k| k.to_value()).collect()),
@@ -95,20 +119,38 @@ mod tests {
 
     #[test]
     fn test_is_truthy() {
-        // Only Boolean(true) is truthy
-        assert!(Value::Boolean(true).is_truthy());
-
-        // Everything else is falsy
+        // Only nil and false are falsy
         assert!(!Value::Boolean(false).is_truthy());
-        assert!(!Value::Number(DecimalNumber::from_i64(42)).is_truthy());
-        assert!(!Value::Number(DecimalNumber::from_i64(0)).is_truthy());
-        assert!(!Value::String("hello".to_string()).is_truthy());
-        assert!(!Value::String("".to_string()).is_truthy());
-        assert!(!Value::List(vec![]).is_truthy());
-        assert!(!Value::List(vec![Value::Number(DecimalNumber::from_i64(1))]).is_truthy());
-        assert!(!Value::Map(indexmap::IndexMap::new()).is_truthy());
-        assert!(!Value::Tuple(vec![]).is_truthy());
         assert!(!Value::Nil.is_truthy());
+
+        // Everything else is truthy, including zero and empty values
+        assert!(Value::Boolean(true).is_truthy());
+        assert!(Value::Number(DecimalNumber::from_i64(42)).is_truthy());
+        assert!(Value::Number(DecimalNumber::from_i64(0)).is_truthy());
+        assert!(Value::String("hello".to_string()).is_truthy());
+        assert!(Value::String("".to_string()).is_truthy());
+        assert!(Value::List(vec![]).is_truthy());
+        assert!(Value::List(vec![Value::Number(DecimalNumber::from_i64(1))]).is_truthy());
+        assert!(Value::Map(indexmap::IndexMap::new()).is_truthy());
+        assert!(Value::Tuple(vec![]).is_truthy());
+    }
+
+    #[test]
+    fn test_as_condition_only_accepts_booleans() {
+        assert!(Value::Boolean(true).as_condition("Test").unwrap());
+        assert!(!Value::Boolean(false).as_condition("Test").unwrap());
+
+        let err = Value::Nil.as_condition("Match condition").unwrap_err();
+        assert!(matches!(err, RuntimeError::TypeError { .. }));
+        assert!(
+            err.to_string()
+                .contains("Match condition must be a boolean, got nil")
+        );
+        assert!(
+            Value::Number(DecimalNumber::from_i64(1))
+                .as_condition("Test")
+                .is_err()
+        );
     }
 
     #[test]
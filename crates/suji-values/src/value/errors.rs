@@ -144,6 +144,10 @@ pub enum RuntimeError {
     #[error("Invalid destructuring target: {message}")]
     DestructureInvalidTarget { message: String },
 
+    /// Evaluation was stopped by a user interrupt (Ctrl+C)
+    #[error("Interrupted")]
+    Interrupted,
+
     /// Runtime error with source location span
     #[error("{error}")]
     WithSpan {
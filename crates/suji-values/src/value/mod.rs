@@ -1,7 +1,8 @@
 // Core types
 pub use types::{
     BytecodeFunction, ControlFlow, DecimalNumber, FunctionBody, FunctionValue, MapKey,
-    ModuleHandle, OrderedDecimal, ParamSpec, StreamBackend, StreamHandle, StreamProxyKind, Value,
+    ModuleHandle, NativeFn, NativeFunction, OrderedDecimal, ParamSpec, PartialFunction,
+    StreamBackend, StreamBuffering, StreamHandle, StreamProxyKind, Value,
 };
 
 // Environment overlay types and functions
@@ -1,4 +1,5 @@
 use indexmap::IndexMap;
+use num_bigint::BigInt;
 use regex::Regex;
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy;
@@ -100,75 +101,206 @@ pub enum StreamProxyKind {
     Stderr,
 }
 
-/// Decimal number wrapper for precise base-10 arithmetic
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct DecimalNumber(pub Decimal);
+/// Error for arithmetic whose result does not fit in a number
+const NUMBER_OVERFLOW: &str =
+    "Number overflow: result is outside the supported range (about ±7.9e28 for fractions)";
+
+/// Largest integer arithmetic may produce, in bits (about 315,000 decimal digits)
+const MAX_BIG_INTEGER_BITS: u64 = 1 << 20;
+
+/// Number value for precise base-10 arithmetic. A 128-bit decimal (28 significant
+/// digits) that transparently becomes a big integer when an integer result leaves the
+/// decimal range, so integer arithmetic such as factorials stays exact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecimalNumber(NumberRepr);
+
+/// `Big` only ever holds integers outside the decimal range, so each number has
+/// exactly one representation and the derived equality is value equality.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NumberRepr {
+    Decimal(Decimal),
+    Big(BigInt),
+}
+
+/// 10^exp as a big integer
+fn pow10(exp: u32) -> BigInt {
+    BigInt::from(10).pow(exp)
+}
 
 impl DecimalNumber {
     pub fn parse(s: &str) -> Result<Self, rust_decimal::Error> {
-        Decimal::from_str(s).map(DecimalNumber)
+        // Scientific notation (1e10, 1.5e-3) needs the dedicated parser
+        if s.contains(['e', 'E']) {
+            return Decimal::from_scientific(s).map(|d| DecimalNumber::from_decimal(d.normalize()));
+        }
+        s.parse()
+    }
+
+    /// Wrap a decimal value
+    pub fn from_decimal(d: Decimal) -> Self {
+        DecimalNumber(NumberRepr::Decimal(d))
     }
 
     pub fn from_i64(n: i64) -> Self {
-        DecimalNumber(Decimal::from(n))
+        DecimalNumber::from_decimal(Decimal::from(n))
     }
 
     /// Create a decimal from an unsigned integer
     pub fn from_u64(n: u64) -> Self {
-        DecimalNumber(Decimal::from(n))
+        DecimalNumber::from_decimal(Decimal::from(n))
     }
 
     /// Create a decimal from a usize
     pub fn from_usize(n: usize) -> Self {
-        DecimalNumber(Decimal::from(n))
+        DecimalNumber::from_decimal(Decimal::from(n))
+    }
+
+    /// Create a number from an integer of any size, kept as a decimal when it fits
+    pub fn from_big_integer(n: BigInt) -> Result<Self, &'static str> {
+        if let Some(d) = n
+            .to_i128()
+            .and_then(|n| Decimal::try_from_i128_with_scale(n, 0).ok())
+        {
+            return Ok(DecimalNumber::from_decimal(d));
+        }
+        if n.bits() > MAX_BIG_INTEGER_BITS {
+            return Err(NUMBER_OVERFLOW);
+        }
+        Ok(DecimalNumber(NumberRepr::Big(n)))
     }
 
     /// Check if this decimal represents an integer (no fractional part)
     pub fn is_integer(&self) -> bool {
-        self.0.fract() == Decimal::ZERO
+        match &self.0 {
+            NumberRepr::Decimal(d) => d.fract() == Decimal::ZERO,
+            NumberRepr::Big(_) => true,
+        }
     }
 
     /// Convert to i64 if possible (integer and within range)
     pub fn to_i64_checked(&self) -> Option<i64> {
-        if self.is_integer() {
-            self.0.to_i64()
-        } else {
-            None
+        match &self.0 {
+            NumberRepr::Decimal(d) if self.is_integer() => d.to_i64(),
+            _ => None,
+        }
+    }
+
+    /// The value as a big integer, if it is an integer
+    pub fn to_big_integer(&self) -> Option<BigInt> {
+        match &self.0 {
+            NumberRepr::Decimal(d) if self.is_integer() => d.to_i128().map(BigInt::from),
+            NumberRepr::Decimal(_) => None,
+            NumberRepr::Big(n) => Some(n.clone()),
+        }
+    }
+
+    /// Get the underlying decimal; `None` for an integer beyond the decimal range
+    pub fn inner(&self) -> Option<Decimal> {
+        match &self.0 {
+            NumberRepr::Decimal(d) => Some(*d),
+            NumberRepr::Big(_) => None,
         }
     }
 
-    /// Get the underlying decimal
-    pub fn inner(&self) -> Decimal {
-        self.0
+    /// Nearest f64, if there is a finite one
+    pub fn to_f64(&self) -> Option<f64> {
+        match &self.0 {
+            NumberRepr::Decimal(d) => d.to_f64(),
+            NumberRepr::Big(n) => n.to_f64().filter(|f| f.is_finite()),
+        }
     }
 
-    /// Arithmetic operations
-    pub fn add(&self, other: &DecimalNumber) -> DecimalNumber {
-        DecimalNumber(self.0 + other.0)
+    /// Exact value as `mantissa / 10^scale`
+    fn to_ratio(&self) -> (BigInt, u32) {
+        match &self.0 {
+            NumberRepr::Decimal(d) => (BigInt::from(d.mantissa()), d.scale()),
+            NumberRepr::Big(n) => (n.clone(), 0),
+        }
+    }
+
+    /// The number `num / den` for a positive `den`. Whole results become big integers when
+    /// needed; fractional ones are rounded to a decimal and must fit the decimal range.
+    fn from_fraction(num: BigInt, den: BigInt) -> Result<Self, &'static str> {
+        let whole = &num / &den;
+        let remainder = num - &whole * &den;
+        if remainder.is_zero() {
+            return DecimalNumber::from_big_integer(whole);
+        }
+        let Some(whole) = DecimalNumber::from_big_integer(whole)?.inner() else {
+            return Err(NUMBER_OVERFLOW);
+        };
+        let fraction = (remainder * pow10(28) / den)
+            .to_i128()
+            .and_then(|n| Decimal::try_from_i128_with_scale(n, 28).ok())
+            .ok_or(NUMBER_OVERFLOW)?;
+        whole
+            .checked_add(fraction)
+            .map(DecimalNumber::from_decimal)
+            .ok_or(NUMBER_OVERFLOW)
+    }
+
+    /// Arithmetic operations. Decimal results beyond the decimal range (about ±7.9e28)
+    /// continue exactly as big integers when they are whole, and are an error otherwise.
+    pub fn add(&self, other: &DecimalNumber) -> Result<DecimalNumber, &'static str> {
+        if let (NumberRepr::Decimal(a), NumberRepr::Decimal(b)) = (&self.0, &other.0)
+            && let Some(sum) = a.checked_add(*b)
+        {
+            return Ok(DecimalNumber::from_decimal(sum));
+        }
+        let ((a, a_scale), (b, b_scale)) = (self.to_ratio(), other.to_ratio());
+        let scale = a_scale.max(b_scale);
+        DecimalNumber::from_fraction(
+            a * pow10(scale - a_scale) + b * pow10(scale - b_scale),
+            pow10(scale),
+        )
     }
 
-    pub fn sub(&self, other: &DecimalNumber) -> DecimalNumber {
-        DecimalNumber(self.0 - other.0)
+    pub fn sub(&self, other: &DecimalNumber) -> Result<DecimalNumber, &'static str> {
+        self.add(&-other.clone())
     }
 
-    pub fn mul(&self, other: &DecimalNumber) -> DecimalNumber {
-        DecimalNumber(self.0 * other.0)
+    pub fn mul(&self, other: &DecimalNumber) -> Result<DecimalNumber, &'static str> {
+        if let (NumberRepr::Decimal(a), NumberRepr::Decimal(b)) = (&self.0, &other.0)
+            && let Some(product) = a.checked_mul(*b)
+        {
+            return Ok(DecimalNumber::from_decimal(product));
+        }
+        let ((a, a_scale), (b, b_scale)) = (self.to_ratio(), other.to_ratio());
+        DecimalNumber::from_fraction(a * b, pow10(a_scale + b_scale))
     }
 
     pub fn div(&self, other: &DecimalNumber) -> Result<DecimalNumber, &'static str> {
-        if other.0 == Decimal::ZERO {
-            Err("Division by zero")
-        } else {
-            Ok(DecimalNumber(self.0 / other.0))
+        if other.is_zero() {
+            return Err("Division by zero");
+        }
+        if let (NumberRepr::Decimal(a), NumberRepr::Decimal(b)) = (&self.0, &other.0)
+            && let Some(quotient) = a.checked_div(*b)
+        {
+            return Ok(DecimalNumber::from_decimal(quotient));
+        }
+        // (a / 10^sa) / (b / 10^sb) = (a * 10^sb) / (b * 10^sa)
+        let ((a, a_scale), (b, b_scale)) = (self.to_ratio(), other.to_ratio());
+        let (num, den) = (a * pow10(b_scale), b * pow10(a_scale));
+        match den.is_negative() {
+            true => DecimalNumber::from_fraction(-num, -den),
+            false => DecimalNumber::from_fraction(num, den),
         }
     }
 
     pub fn rem(&self, other: &DecimalNumber) -> Result<DecimalNumber, &'static str> {
-        if other.0 == Decimal::ZERO {
-            Err("Modulo by zero")
-        } else {
-            Ok(DecimalNumber(self.0 % other.0))
+        if other.is_zero() {
+            return Err("Modulo by zero");
         }
+        if let (NumberRepr::Decimal(a), NumberRepr::Decimal(b)) = (&self.0, &other.0) {
+            return Ok(DecimalNumber::from_decimal(a % b));
+        }
+        // Like decimal `%`, the remainder takes the sign of the dividend
+        let ((a, a_scale), (b, b_scale)) = (self.to_ratio(), other.to_ratio());
+        let scale = a_scale.max(b_scale);
+        DecimalNumber::from_fraction(
+            (a * pow10(scale - a_scale)) % (b * pow10(scale - b_scale)),
+            pow10(scale),
+        )
     }
 
     /// Power operation (integer exponent only for determinism)
@@ -182,60 +314,154 @@ impl DecimalNumber {
             return Err("Negative exponents not supported");
         }
 
-        // Manual power implementation using repeated multiplication
+        if let Some(result) = self.decimal_pow(exp as u64) {
+            return Ok(DecimalNumber::from_decimal(result));
+        }
+
+        // A whole base keeps going as a big integer; a fractional one cannot become whole
+        if !self.is_integer() {
+            return Err(NUMBER_OVERFLOW);
+        }
+        let (base, _) = self.to_ratio();
+        let exp = u32::try_from(exp).map_err(|_| NUMBER_OVERFLOW)?;
+        if base.bits().saturating_mul(u64::from(exp)) > MAX_BIG_INTEGER_BITS + 64 {
+            return Err(NUMBER_OVERFLOW);
+        }
+        DecimalNumber::from_big_integer(base.pow(exp))
+    }
+
+    /// `self ^ exp` in decimal arithmetic, or `None` if it leaves the decimal range
+    fn decimal_pow(&self, mut exp: u64) -> Option<Decimal> {
+        let NumberRepr::Decimal(mut base) = self.0 else {
+            return None;
+        };
         let mut result = Decimal::ONE;
-        let mut base = self.0;
-        let mut exp = exp as u64;
 
         // Fast exponentiation by squaring
         while exp > 0 {
             if exp % 2 == 1 {
-                result *= base;
+                result = result.checked_mul(base)?;
             }
-            base *= base;
             exp /= 2;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
         }
 
-        Ok(DecimalNumber(result))
+        Some(result)
+    }
+
+    /// Whether the number is zero
+    pub fn is_zero(&self) -> bool {
+        match &self.0 {
+            NumberRepr::Decimal(d) => d.is_zero(),
+            NumberRepr::Big(_) => false,
+        }
+    }
+
+    /// Whether the number is below zero; negative zero is not
+    pub fn is_negative(&self) -> bool {
+        match &self.0 {
+            NumberRepr::Decimal(d) => !d.is_zero() && d.is_sign_negative(),
+            NumberRepr::Big(n) => n.is_negative(),
+        }
+    }
+
+    /// Apply a decimal operation that cannot change a big integer (rounding and the like)
+    fn map_decimal(&self, f: impl FnOnce(Decimal) -> Decimal) -> DecimalNumber {
+        match &self.0 {
+            NumberRepr::Decimal(d) => DecimalNumber::from_decimal(f(*d)),
+            NumberRepr::Big(_) => self.clone(),
+        }
     }
 
     /// Mathematical functions
     pub fn abs(&self) -> DecimalNumber {
-        DecimalNumber(self.0.abs())
+        match &self.0 {
+            NumberRepr::Decimal(d) => DecimalNumber::from_decimal(d.abs()),
+            NumberRepr::Big(n) => DecimalNumber(NumberRepr::Big(n.abs())),
+        }
+    }
+
+    /// -1, 0 or 1 depending on the sign; negative zero counts as 0
+    pub fn sign(&self) -> DecimalNumber {
+        match (self.is_zero(), self.is_negative()) {
+            (true, _) => DecimalNumber::from_i64(0),
+            (false, true) => DecimalNumber::from_i64(-1),
+            (false, false) => DecimalNumber::from_i64(1),
+        }
     }
 
     pub fn ceil(&self) -> DecimalNumber {
-        DecimalNumber(self.0.ceil())
+        self.map_decimal(|d| d.ceil())
     }
 
     pub fn floor(&self) -> DecimalNumber {
-        DecimalNumber(self.0.floor())
+        self.map_decimal(|d| d.floor())
     }
 
     pub fn round(&self) -> DecimalNumber {
-        DecimalNumber(
-            self.0
-                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero),
-        )
+        self.map_decimal(|d| d.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero))
+    }
+
+    /// Round to `digits` decimal places, sending halfway cases to the nearest even digit
+    /// (banker's rounding: 2.5 -> 2, 3.5 -> 4)
+    pub fn round_half_even(&self, digits: u32) -> DecimalNumber {
+        self.map_decimal(|d| {
+            d.round_dp_with_strategy(digits, RoundingStrategy::MidpointNearestEven)
+        })
+    }
+
+    /// Format with exactly `digits` decimal places, rounding halfway cases away from zero
+    /// and padding with zeros (5 -> "5.00"). A result that rounds to zero has no sign.
+    pub fn to_fixed(&self, digits: u32) -> String {
+        let (mut text, scale) = match &self.0 {
+            NumberRepr::Decimal(d) => {
+                let mut fixed =
+                    d.round_dp_with_strategy(digits, RoundingStrategy::MidpointAwayFromZero);
+                if fixed.is_zero() {
+                    fixed.set_sign_positive(true);
+                }
+                (fixed.to_string(), fixed.scale())
+            }
+            NumberRepr::Big(n) => (n.to_string(), 0),
+        };
+
+        // Pad as text: a decimal near its 28-digit limit cannot take on more scale
+        if digits > scale {
+            if scale == 0 {
+                text.push('.');
+            }
+            text.push_str(&"0".repeat((digits - scale) as usize));
+        }
+        text
     }
 
     /// Square root using Newton's method with fixed precision
     pub fn sqrt(&self) -> Result<DecimalNumber, &'static str> {
-        if self.0 < Decimal::ZERO {
+        if self.is_negative() {
             return Err("Square root of negative number");
         }
 
-        if self.0 == Decimal::ZERO {
-            return Ok(DecimalNumber(Decimal::ZERO));
+        let value = match &self.0 {
+            NumberRepr::Decimal(d) => *d,
+            // Integer square root of n * 10^56 carries 28 fractional digits
+            NumberRepr::Big(n) => {
+                return DecimalNumber::from_fraction((n * pow10(56)).sqrt(), pow10(28));
+            }
+        };
+
+        if value == Decimal::ZERO {
+            return Ok(DecimalNumber::from_decimal(Decimal::ZERO));
         }
 
         // Newton's method: x_{n+1} = (x_n + a/x_n) / 2
-        let mut x = self.0;
+        let mut x = value;
         let two = Decimal::from(2);
 
         // Iterate until convergence (max 50 iterations for safety)
         for _ in 0..50 {
-            let x_next = (x + self.0 / x) / two;
+            let x_next = (x + value / x) / two;
             if (x - x_next).abs() < Decimal::new(1, 28) {
                 // Very small epsilon
                 break;
@@ -243,33 +469,74 @@ impl DecimalNumber {
             x = x_next;
         }
 
-        Ok(DecimalNumber(x))
+        Ok(DecimalNumber::from_decimal(x))
     }
 
     pub fn min(&self, other: &DecimalNumber) -> DecimalNumber {
-        DecimalNumber(self.0.min(other.0))
+        std::cmp::min(self, other).clone()
     }
 
     pub fn max(&self, other: &DecimalNumber) -> DecimalNumber {
-        DecimalNumber(self.0.max(other.0))
+        std::cmp::max(self, other).clone()
+    }
+}
+
+impl From<Decimal> for DecimalNumber {
+    fn from(d: Decimal) -> Self {
+        DecimalNumber::from_decimal(d)
+    }
+}
+
+impl PartialOrd for DecimalNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecimalNumber {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        match (&self.0, &other.0) {
+            (NumberRepr::Decimal(a), NumberRepr::Decimal(b)) => a.cmp(b),
+            (NumberRepr::Big(a), NumberRepr::Big(b)) => a.cmp(b),
+            // A big integer lies beyond every decimal, so its sign decides
+            (NumberRepr::Big(a), NumberRepr::Decimal(_)) => match a.is_negative() {
+                true => Ordering::Less,
+                false => Ordering::Greater,
+            },
+            (NumberRepr::Decimal(_), NumberRepr::Big(b)) => match b.is_negative() {
+                true => Ordering::Greater,
+                false => Ordering::Less,
+            },
+        }
     }
 }
 
 impl FromStr for DecimalNumber {
     type Err = rust_decimal::Error;
 
+    /// Integers too large for a decimal parse as big integers
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Decimal::from_str(s).map(DecimalNumber)
+        Decimal::from_str(s)
+            .map(DecimalNumber::from_decimal)
+            .or_else(|err| {
+                let digits = s.strip_prefix('-').unwrap_or(s);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(err);
+                }
+                let n = BigInt::from_str(s).map_err(|_| err.clone())?;
+                DecimalNumber::from_big_integer(n).map_err(|_| err)
+            })
     }
 }
 
 impl fmt::Display for DecimalNumber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Display integers without decimal point
-        if self.is_integer() {
-            write!(f, "{}", self.0.trunc())
-        } else {
-            write!(f, "{}", self.0)
+        match &self.0 {
+            // Display integers without decimal point
+            NumberRepr::Decimal(d) if self.is_integer() => write!(f, "{}", d.trunc()),
+            NumberRepr::Decimal(d) => write!(f, "{}", d),
+            NumberRepr::Big(n) => write!(f, "{}", n),
         }
     }
 }
@@ -278,7 +545,10 @@ impl Neg for DecimalNumber {
     type Output = DecimalNumber;
 
     fn neg(self) -> Self::Output {
-        DecimalNumber(-self.0)
+        match self.0 {
+            NumberRepr::Decimal(d) => DecimalNumber::from_decimal(-d),
+            NumberRepr::Big(n) => DecimalNumber(NumberRepr::Big(-n)),
+        }
     }
 }
 
@@ -335,6 +605,46 @@ pub enum FunctionBody {
     Bytecode(Arc<BytecodeFunction>),
     /// Builtin function reference
     Builtin(&'static str),
+    /// Native function registered by an embedder
+    Native(NativeFunction),
+    /// Partial application of another function to leading arguments
+    Partial(PartialFunction),
+}
+
+/// Function with its leading arguments already bound by `partial()`; calling it calls
+/// `target` with `args` followed by the call's own arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFunction {
+    /// The function being applied, never itself a partial
+    pub target: Box<FunctionValue>,
+    /// Bound leading arguments
+    pub args: Vec<Value>,
+}
+
+/// Signature of a native function: it receives the evaluated positional arguments and
+/// returns the result, or an error that is raised in the calling script
+pub type NativeFn = fn(&[Value]) -> Result<Value, super::RuntimeError>;
+
+/// Native function held by the function value itself rather than looked up by name
+/// in the global builtin table
+#[derive(Clone)]
+pub struct NativeFunction {
+    /// Qualified name used in error messages, e.g. `app:greet`
+    pub name: String,
+    /// The function to call
+    pub func: NativeFn,
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NativeFunction").field(&self.name).finish()
+    }
+}
+
+impl PartialEq for NativeFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && std::ptr::fn_addr_eq(self.func, other.func)
+    }
 }
 
 /// Placeholder for bytecode representation
@@ -363,8 +673,13 @@ impl FunctionValue {
             FunctionBody::Bytecode(_) => Err(super::RuntimeError::InvalidOperation {
                 message: "Cannot execute bytecode function in AST interpreter".to_string(),
             }),
-            FunctionBody::Builtin(_) => Err(super::RuntimeError::InvalidOperation {
-                message: "Cannot get AST body from builtin function".to_string(),
+            FunctionBody::Builtin(_) | FunctionBody::Native(_) => {
+                Err(super::RuntimeError::InvalidOperation {
+                    message: "Cannot get AST body from builtin function".to_string(),
+                })
+            }
+            FunctionBody::Partial(_) => Err(super::RuntimeError::InvalidOperation {
+                message: "Cannot get AST body from a partial application".to_string(),
             }),
         }
     }
@@ -417,6 +732,25 @@ pub enum StreamBackend {
     MemoryReadable(RefCell<std::io::Cursor<Vec<u8>>>),
     /// In-memory writable stream (general purpose)
     MemoryWritable(RefCell<Vec<u8>>),
+    /// In-memory stream that reads, writes and seeks like a file (`io:memory()`)
+    Memory(RefCell<std::io::Cursor<Vec<u8>>>),
+}
+
+/// Write buffering policy for file streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamBuffering {
+    /// Every write goes straight to the OS (default)
+    #[default]
+    None,
+    /// Writes are held until a newline is written
+    Line,
+    /// Writes are held until the buffer fills up
+    Block,
+}
+
+impl StreamBuffering {
+    /// Buffer size that triggers a write-out in block mode
+    pub const BLOCK_SIZE: usize = 8 * 1024;
 }
 
 /// Handle for stream I/O operations
@@ -428,6 +762,12 @@ pub struct StreamHandle {
     pub is_closed: Cell<bool>,
     /// Display name for the stream
     pub name: String,
+    /// Write buffering policy (only applies to file streams)
+    pub buffering: StreamBuffering,
+    /// Whether writes are rejected (file streams opened in "r" mode)
+    pub read_only: bool,
+    /// Bytes written but not yet passed to the backend
+    pub write_buffer: RefCell<Vec<u8>>,
 }
 
 impl StreamHandle {
@@ -437,6 +777,9 @@ impl StreamHandle {
             backend: StreamBackend::Stdin(RefCell::new(BufReader::new(std::io::stdin()))),
             is_closed: Cell::new(false),
             name: "stdin".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -446,6 +789,9 @@ impl StreamHandle {
             backend: StreamBackend::Stdout(RefCell::new(std::io::stdout())),
             is_closed: Cell::new(false),
             name: "stdout".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -455,6 +801,9 @@ impl StreamHandle {
             backend: StreamBackend::Stderr(RefCell::new(std::io::stderr())),
             is_closed: Cell::new(false),
             name: "stderr".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -467,6 +816,9 @@ impl StreamHandle {
             ))),
             is_closed: Cell::new(false),
             name: "test_readable".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -477,6 +829,9 @@ impl StreamHandle {
             backend: StreamBackend::TestWritable(RefCell::new(Vec::new())),
             is_closed: Cell::new(false),
             name: "test_writable".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -495,6 +850,9 @@ impl StreamHandle {
             backend: StreamBackend::MemoryReadable(RefCell::new(std::io::Cursor::new(bytes))),
             is_closed: Cell::new(false),
             name: "mem_read".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
         }
     }
 
@@ -504,6 +862,32 @@ impl StreamHandle {
             backend: StreamBackend::MemoryWritable(RefCell::new(Vec::new())),
             is_closed: Cell::new(false),
             name: "mem_write".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Create an in-memory read/write stream holding `bytes`, positioned at the start
+    pub fn new_memory(bytes: Vec<u8>) -> Self {
+        Self {
+            backend: StreamBackend::Memory(RefCell::new(std::io::Cursor::new(bytes))),
+            is_closed: Cell::new(false),
+            name: "memory".to_string(),
+            buffering: StreamBuffering::None,
+            read_only: false,
+            write_buffer: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Copy of everything an in-memory stream holds, regardless of its position
+    pub fn memory_contents(&self) -> Option<Vec<u8>> {
+        match &self.backend {
+            StreamBackend::MemoryReadable(cursor) | StreamBackend::Memory(cursor) => {
+                Some(cursor.borrow().get_ref().clone())
+            }
+            StreamBackend::MemoryWritable(buffer) => Some(buffer.borrow().clone()),
+            _ => None,
         }
     }
 
@@ -519,11 +903,30 @@ impl StreamHandle {
         }
     }
 
+    /// Pass any buffered writes to the backend
+    pub fn flush_write_buffer(&self) -> std::io::Result<()> {
+        let mut pending = self.write_buffer.borrow_mut();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        if let StreamBackend::File(file_ref) = &self.backend {
+            use std::io::Write;
+            let mut file = file_ref.borrow_mut();
+            file.write_all(&pending)?;
+            file.flush()?;
+        }
+        pending.clear();
+        Ok(())
+    }
+
     /// Check if this stream is readable
     pub fn is_readable(&self) -> bool {
         matches!(
             self.backend,
-            StreamBackend::Stdin(_) | StreamBackend::File(_) | StreamBackend::MemoryReadable(_)
+            StreamBackend::Stdin(_)
+                | StreamBackend::File(_)
+                | StreamBackend::MemoryReadable(_)
+                | StreamBackend::Memory(_)
         ) || {
             #[cfg(test)]
             {
@@ -544,6 +947,7 @@ impl StreamHandle {
                 | StreamBackend::Stderr(_)
                 | StreamBackend::File(_)
                 | StreamBackend::MemoryWritable(_)
+                | StreamBackend::Memory(_)
         ) || {
             #[cfg(test)]
             {
@@ -556,3 +960,10 @@ impl StreamHandle {
         }
     }
 }
+
+impl Drop for StreamHandle {
+    fn drop(&mut self) {
+        // Best effort: don't lose buffered writes when the stream goes away
+        self.flush_write_buffer().ok();
+    }
+}
@@ -55,8 +55,26 @@ pub fn eval_string_expr(input: &str) -> Result<Value, Box<dyn std::error::Error>
 
 /// Helper to evaluate a program (multiple statements)
 pub fn eval_program(input: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    eval_program_in(input, create_test_env())
+}
+
+/// Helper to evaluate a program with embedder-provided read-only constants
+pub fn eval_program_with_constants(
+    input: &str,
+    constants: Vec<(&str, Value)>,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let env = Rc::new(Env::new());
+    suji_stdlib::setup_global_env_with_constants(
+        &env,
+        constants
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value)),
+    );
+    eval_program_in(input, env)
+}
+
+fn eval_program_in(input: &str, env: Rc<Env>) -> Result<Value, Box<dyn std::error::Error>> {
     let statements = parse_program(input)?;
-    let env = create_test_env();
 
     // Register builtins BEFORE creating the module registry
     // so that __builtins__ module is populated correctly
@@ -1,3 +1,6 @@
+#[path = "common/mod.rs"]
+mod common;
+
 #[path = "components/ast/mod.rs"]
 mod ast;
 #[path = "components/lexer/mod.rs"]
@@ -1,3 +1,4 @@
+use suji_lexer::LexError;
 use suji_lexer::Lexer;
 use suji_lexer::Token;
 
@@ -87,6 +88,33 @@ fn test_numbers() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_numbers_scientific_notation() {
+    let input = "1e10 1.5e-3 2E+5 3e0";
+    let tokens = Lexer::lex(input).unwrap();
+
+    let expected = vec![
+        Token::Number("1e10".to_string()),
+        Token::Number("1.5e-3".to_string()),
+        Token::Number("2E+5".to_string()),
+        Token::Number("3e0".to_string()),
+        Token::Eof,
+    ];
+
+    let actual: Vec<Token> = tokens.into_iter().map(|t| t.token).collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_numbers_invalid_exponent() {
+    for (input, literal) in [("1e", "1e"), ("2.5e+", "2.5e+"), ("3E-x", "3E-")] {
+        match Lexer::lex(input) {
+            Err(LexError::InvalidNumber { literal: found, .. }) => assert_eq!(found, literal),
+            other => panic!("Expected InvalidNumber for {:?}, got {:?}", input, other),
+        }
+    }
+}
+
 // ============================================================================
 // Nil and Semicolon Tests
 // ============================================================================
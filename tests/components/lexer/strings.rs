@@ -406,3 +406,38 @@ fn test_string_interpolation_nested_braces() {
     let actual: Vec<Token> = tokens.into_iter().map(|t| t.token).collect();
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_interpolation_format_spec() {
+    let tokens = Lexer::lex(r#""${pi:.2}|${m:key}""#).unwrap();
+    let actual: Vec<Token> = tokens.into_iter().map(|t| t.token).collect();
+
+    let expected = vec![
+        Token::StringStart,
+        Token::InterpStart,
+        Token::Identifier("pi".to_string()),
+        Token::FormatSpec(suji_lexer::FormatSpec::parse(".2").unwrap()),
+        Token::InterpEnd,
+        Token::StringText("|".to_string()),
+        Token::InterpStart,
+        Token::Identifier("m".to_string()),
+        Token::Colon,
+        Token::Identifier("key".to_string()),
+        Token::InterpEnd,
+        Token::StringEnd,
+        Token::Eof,
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_interpolation_invalid_format_spec() {
+    let result = Lexer::lex(r#""${x:.2q}""#);
+    match result {
+        Err(suji_lexer::LexError::InvalidFormatSpec { spec, span }) => {
+            assert_eq!(spec, ".2q");
+            assert_eq!(span.start, 4);
+        }
+        other => panic!("Expected InvalidFormatSpec, got {:?}", other),
+    }
+}
@@ -185,7 +185,7 @@ fn test_parse_function_with_default_params_single_expression() {
 #[test]
 fn function_defaults_with_pipe_terminator() {
     let src = r#"
-f = |x, y = 10| x + y
+let f = |x, y = 10| x + y
 f(3)
 "#;
     let v = eval_program(src).unwrap();
@@ -370,8 +370,8 @@ fn test_regex_not_split_by_alternation_bar() {
 #[test]
 fn test_match_single_negative_literal() {
     let source = r#"
-        x = -5
-        result = match x {
+        let x = -5
+        let result = match x {
             -5 => "hit",
             _ => "miss",
         }
@@ -385,8 +385,8 @@ fn test_match_single_negative_literal() {
 #[test]
 fn test_match_negative_with_alternation() {
     let source = r#"
-        x = -10
-        result = match x {
+        let x = -10
+        let result = match x {
             -10 | -5 | 0 => "hit",
             _ => "miss",
         }
@@ -400,8 +400,8 @@ fn test_match_negative_with_alternation() {
 #[test]
 fn test_match_negative_alternation_middle() {
     let source = r#"
-        x = -5
-        result = match x {
+        let x = -5
+        let result = match x {
             -10 | -5 | 0 => "hit",
             _ => "miss",
         }
@@ -415,8 +415,8 @@ fn test_match_negative_alternation_middle() {
 #[test]
 fn test_match_negative_alternation_last() {
     let source = r#"
-        x = 0
-        result = match x {
+        let x = 0
+        let result = match x {
             -10 | -5 | 0 => "hit",
             _ => "miss",
         }
@@ -430,8 +430,8 @@ fn test_match_negative_alternation_last() {
 #[test]
 fn test_match_mixed_positive_and_negative() {
     let source = r#"
-        x = -3
-        result = match x {
+        let x = -3
+        let result = match x {
             -5 => "negative five",
             -3 => "negative three",
             0 => "zero",
@@ -449,8 +449,8 @@ fn test_match_mixed_positive_and_negative() {
 #[test]
 fn test_match_negative_zero() {
     let source = r#"
-        x = -0
-        result = match x {
+        let x = -0
+        let result = match x {
             -0 => "negative zero",
             0 => "zero",
             _ => "other",
@@ -466,8 +466,8 @@ fn test_match_negative_zero() {
 #[test]
 fn test_match_large_negative_number() {
     let source = r#"
-        x = -999999
-        result = match x {
+        let x = -999999
+        let result = match x {
             -999999 => "large negative",
             _ => "other",
         }
@@ -481,8 +481,8 @@ fn test_match_large_negative_number() {
 #[test]
 fn test_match_negative_does_not_match_positive() {
     let source = r#"
-        x = 5
-        result = match x {
+        let x = 5
+        let result = match x {
             -5 => "negative",
             _ => "positive or other",
         }
@@ -496,8 +496,8 @@ fn test_match_negative_does_not_match_positive() {
 #[test]
 fn test_match_positive_does_not_match_negative() {
     let source = r#"
-        x = -5
-        result = match x {
+        let x = -5
+        let result = match x {
             5 => "positive",
             _ => "negative or other",
         }
@@ -511,8 +511,8 @@ fn test_match_positive_does_not_match_negative() {
 #[test]
 fn test_match_negative_decimal() {
     let source = r#"
-        x = -3.14
-        result = match x {
+        let x = -3.14
+        let result = match x {
             -3.14 => "pi",
             _ => "other",
         }
@@ -526,8 +526,8 @@ fn test_match_negative_decimal() {
 #[test]
 fn test_match_negative_in_tuple_pattern() {
     let source = r#"
-        pair = (-1, 2)
-        result = match pair {
+        let pair = (-1, 2)
+        let result = match pair {
             (-1, 2) => "match",
             _ => "no match",
         }
@@ -537,3 +537,208 @@ fn test_match_negative_in_tuple_pattern() {
     let result = eval_program(source).expect("Evaluation failed");
     assert_eq!(result, Value::String("match".to_string()));
 }
+
+#[test]
+fn match_type_patterns_parse() {
+    let src = r#"
+match x {
+    :number => 1,
+    :nil => 2,
+    _ => 3,
+}
+"#;
+
+    let expr = parse_expression(src).unwrap();
+    if let Expr::Match { arms, .. } = expr {
+        assert_eq!(arms.len(), 3);
+        assert!(matches!(&arms[0].pattern, Pattern::Type { name, .. } if name == "number"));
+        assert!(matches!(&arms[1].pattern, Pattern::Type { name, .. } if name == "nil"));
+    } else {
+        panic!("Expected match expression");
+    }
+
+    assert!(parse_expression("match x { :numbr => 1 }").is_err());
+}
+
+#[test]
+fn match_range_patterns_parse() {
+    let src = r#"
+match x {
+    1..10 => 1,
+    -5..=0 => 2,
+    _ => 3,
+}
+"#;
+
+    let expr = parse_expression(src).unwrap();
+    if let Expr::Match { arms, .. } = expr {
+        assert_eq!(arms.len(), 3);
+        assert!(matches!(
+            &arms[0].pattern,
+            Pattern::Range { start, end, inclusive: false, .. } if start.to_string() == "1" && end.to_string() == "10"
+        ));
+        assert!(matches!(
+            &arms[1].pattern,
+            Pattern::Range { start, end, inclusive: true, .. } if start.to_string() == "-5" && end.to_string() == "0"
+        ));
+    } else {
+        panic!("Expected match expression");
+    }
+
+    assert!(parse_expression("match x { 1.. => 1 }").is_err());
+    // Bounds are parsed once here, so ones that do not fit a decimal fail to parse
+    let err = parse_expression("match x { 0..1e40 => 1 }").unwrap_err();
+    assert!(
+        err.to_string().contains("'1e40' is out of range"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn match_alternation_inside_tuple_parses_as_or_pattern() {
+    let src = r#"
+match x {
+    (1 | 2, :string | nil) => 1,
+    _ => 2,
+}
+"#;
+
+    let expr = parse_expression(src).unwrap();
+    if let Expr::Match { arms, .. } = expr {
+        assert_eq!(arms.len(), 2);
+        let Pattern::Tuple { patterns, .. } = &arms[0].pattern else {
+            panic!("Expected tuple pattern");
+        };
+        assert!(matches!(&patterns[0], Pattern::Or { patterns, .. } if patterns.len() == 2));
+        assert!(matches!(
+            &patterns[1],
+            Pattern::Or { patterns, .. }
+                if matches!(patterns[0], Pattern::Type { .. })
+                    && matches!(patterns[1], Pattern::Literal { value: ValueLike::Nil, .. })
+        ));
+    } else {
+        panic!("Expected match expression");
+    }
+
+    assert!(parse_expression("match x { (1 |, 2) => 1 }").is_err());
+}
+
+#[test]
+fn match_binding_pattern_parses() {
+    let src = r#"
+match p {
+    pt @ (x @ 1, _) => pt,
+    _ => nil,
+}
+"#;
+
+    let expr = parse_expression(src).unwrap();
+    if let Expr::Match { arms, .. } = expr {
+        let Pattern::Binding { name, pattern, .. } = &arms[0].pattern else {
+            panic!("Expected binding pattern");
+        };
+        assert_eq!(name, "pt");
+        let Pattern::Tuple { patterns, .. } = pattern.as_ref() else {
+            panic!("Expected tuple pattern under binding");
+        };
+        assert!(matches!(&patterns[0], Pattern::Binding { name, .. } if name == "x"));
+        assert_eq!(arms[0].pattern.bound_names(), vec!["pt", "x"]);
+    } else {
+        panic!("Expected match expression");
+    }
+}
+
+#[test]
+fn match_binding_same_name_twice_reports_error() {
+    let err = parse_expression("match p { (a @ 1, a @ 2) => a, }").unwrap_err();
+    assert!(err.to_string().contains("'a' is bound more than once"));
+
+    assert!(parse_expression("match p { n @ 1 | m @ 2 => 1, }").is_err());
+    assert!(parse_expression("match p { n @ 1 | n @ 2 => n, }").is_ok());
+}
+
+#[test]
+fn boolean_match_value_must_cover_true_and_false() {
+    let err = parse_statement("label = match flag { true => \"on\", }").unwrap_err();
+    assert!(err.to_string().contains("no arm handles `false`"));
+    assert!(parse_statement("let label = match flag { false => \"off\", }").is_err());
+
+    assert!(parse_statement("label = match flag { true => \"on\", false => \"off\", }").is_ok());
+    assert!(parse_statement("label = match flag { true => \"on\", _ => \"off\", }").is_ok());
+    // Every other place that uses the value is checked too
+    for source in [
+        "return match flag { true => 1, }",
+        "f(match flag { true => 1, })",
+        "f(x: match flag { true => 1, })",
+        "xs::push(match flag { true => 1, })",
+        "g = |flag| match flag { true => 1, }",
+        "g = |flag| { match flag { true => 1, } }",
+        "g = |flag| {\n    let n = 1\n    match flag { true => n, }\n}",
+        "total = 1 + match flag { true => 1, }",
+        "match flag { true => 1, }::to_string()",
+    ] {
+        let err = parse_statement(source).unwrap_err();
+        assert!(
+            err.to_string().contains("no arm handles `false`"),
+            "{}",
+            source
+        );
+    }
+
+    // Statement-level matches are the `if` idiom and stay unchecked
+    assert!(parse_statement("match flag { true => count = 1, }").is_ok());
+    assert!(
+        parse_statement("g = |flag| {\n    match flag { true => count = 1, }\n    nil\n}").is_ok()
+    );
+    assert!(parse_statement("loop { match flag { true => break, } }").is_ok());
+    // Matches that do not dispatch on booleans alone are unaffected
+    assert!(parse_statement("kind = match v { true => 1, 0 => 2, }").is_ok());
+}
+
+#[test]
+fn match_string_affix_patterns_parse() {
+    let src = r#"
+match cmd {
+    "go " + dir => dir,
+    name + ".txt" => name,
+    "<" + _ + ">" => nil,
+}
+"#;
+
+    let expr = parse_expression(src).unwrap();
+    let Expr::Match { arms, .. } = expr else {
+        panic!("Expected match expression");
+    };
+    let affix = |pattern: &Pattern| match pattern {
+        Pattern::StringAffix {
+            prefix,
+            rest,
+            suffix,
+            ..
+        } => (prefix.clone(), rest.clone(), suffix.clone()),
+        other => panic!("Expected string affix pattern, got {:?}", other),
+    };
+    assert_eq!(
+        affix(&arms[0].pattern),
+        ("go ".to_string(), Some("dir".to_string()), String::new())
+    );
+    assert_eq!(
+        affix(&arms[1].pattern),
+        (String::new(), Some("name".to_string()), ".txt".to_string())
+    );
+    assert_eq!(
+        affix(&arms[2].pattern),
+        ("<".to_string(), None, ">".to_string())
+    );
+    assert_eq!(arms[0].pattern.bound_names(), vec!["dir"]);
+}
+
+#[test]
+fn match_string_affix_pattern_errors() {
+    assert!(parse_expression(r#"match s { "a" + "b" => 1, }"#).is_err());
+    assert!(parse_expression(r#"match s { x + y => 1, }"#).is_err());
+    assert!(parse_expression(r#"match s { "a" + x + y => 1, }"#).is_err());
+    let err = parse_expression(r#"match s { ("a" + x, x + "b") => x, }"#).unwrap_err();
+    assert!(err.to_string().contains("'x' is bound more than once"));
+}
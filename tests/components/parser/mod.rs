@@ -1,5 +1,4 @@
-#[path = "../../common/mod.rs"]
-mod common;
+use crate::common;
 
 mod arithmetic;
 mod errors;
@@ -9,4 +8,5 @@ mod literals;
 mod r#match;
 mod precedence;
 mod ranges;
+mod source;
 mod statements;
@@ -0,0 +1,82 @@
+use super::common::parse_program;
+use suji_ast::Stmt;
+
+/// Print a program back to source, one statement per line
+fn print_program(input: &str) -> String {
+    parse_program(input)
+        .unwrap_or_else(|e| panic!("failed to parse {:?}: {:?}", input, e))
+        .iter()
+        .map(Stmt::to_source)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Printing must be stable: the printed code parses back to a tree that prints the same
+fn assert_prints(input: &str, expected: &str) {
+    let printed = print_program(input);
+    assert_eq!(printed, expected, "printing {:?}", input);
+    assert_eq!(print_program(&printed), printed, "reprinting {:?}", printed);
+}
+
+#[test]
+fn test_source_keeps_precedence() {
+    assert_prints("x = (1 + 2) * 3 - -y ^ 2", "x = (1 + 2) * 3 - -y^2");
+    assert_prints("a || b && !c == d", "a || b && !c == d");
+    assert_prints(
+        "items | filter >> map |> f <| g",
+        "items | filter >> map |> f <| g",
+    );
+    assert_prints(
+        "total += xs[i + 1]::length()",
+        "total += xs[i + 1]::length()",
+    );
+    assert_prints("xs[start + 1:]", "xs[start + 1:]");
+    assert_prints("r = 0..=10", "r = 0..=10");
+}
+
+#[test]
+fn test_source_prints_literals_and_strings() {
+    assert_prints(
+        r#"m = { name: "a\"b", "two words": [1, 2,], 3: (x,), }"#,
+        r#"m = { name: "a\"b", "two words": [1, 2], 3: (x,) }"#,
+    );
+    assert_prints(
+        r#"s = "tab\there ${user:name} \${literal} ${n:>8.2}""#,
+        r#"s = "tab\there ${user:name} \${literal} ${n:>8.2}""#,
+    );
+    assert_prints("out = `echo ${name}`", "out = `echo ${name}`");
+    assert_prints("ok = line ~ /^\\d+$/", "ok = line ~ /^\\d+$/");
+    // A map literal where a block could start is parenthesized
+    assert_prints(
+        "r = match x { 1 => { \"a\": 1 }, }",
+        "r = match x {\n    1 => ({ a: 1 }),\n}",
+    );
+}
+
+#[test]
+fn test_source_prints_closures_and_control_flow() {
+    assert_prints(
+        "add = |a, b = 1| a + b\nf = || { return a, b }",
+        "add = |a, b = 1| a + b\nf = || {\n    return a, b\n}",
+    );
+    assert_prints(
+        "r = add(1, b: m:key,)\nwrap(x: f(y: 2))",
+        "r = add(1, b: m:key)\nwrap(x: f(y: 2))",
+    );
+    assert_prints(
+        "loop through items with k, v as outer { match v { 1 | 2 => continue outer, (x, _) => { break }, name @ :string => name, \"go \" + rest => rest, _ => nil, } }",
+        "loop through items with k, v as outer {\n    match v {\n        1 => continue outer,\n        2 => continue outer,\n        (\"x\", _) => {\n            break\n        },\n        name @ :string => name,\n        \"go \" + rest => rest,\n        _ => nil,\n    }\n}",
+    );
+    assert_prints(
+        "match xs { [] => 0, [x @ _, ...rest, 1 | 2,] => 1, [..._] => 2, }",
+        "match xs {\n    [] => 0,\n    [x @ _, ...rest, 1 | 2] => 1,\n    [..._] => 2,\n}",
+    );
+    assert_prints(
+        "(a, b) = pair\nsign = match { n < 0 => -1, _ => 1, }",
+        "(a, b) = pair\nsign = match {\n    n < 0 => -1,\n    true => 1,\n}",
+    );
+    assert_prints(
+        "swap = || { x = 1; (a, b) = (b, a) }",
+        "swap = || {\n    x = 1;\n    (a, b) = (b, a)\n}",
+    );
+}
@@ -486,3 +486,28 @@ fn test_parse_semicolon_with_compound_assignment() {
         panic!("Expected block statement");
     }
 }
+
+#[test]
+fn test_parse_let_declaration() {
+    let ast = parse_program("let x = 1\nlet a, b = (1, 2)").expect("Parsing failed");
+    assert_eq!(ast.len(), 2);
+
+    if let Stmt::Expr(Expr::Let { target, .. }) = &ast[0] {
+        assert!(
+            matches!(target.as_ref(), Expr::Literal(Literal::Identifier(name, _)) if name == "x")
+        );
+    } else {
+        panic!("Expected let declaration");
+    }
+
+    if let Stmt::Expr(Expr::Let { target, .. }) = &ast[1] {
+        assert!(
+            matches!(target.as_ref(), Expr::Destructure { elements, .. } if elements.len() == 2)
+        );
+    } else {
+        panic!("Expected let destructuring declaration");
+    }
+
+    assert!(parse_program("let x[0] = 1").is_err());
+    assert!(parse_program("let x").is_err());
+}
@@ -58,7 +58,7 @@ fn test_undefined_variable_has_span() {
 
 #[test]
 fn test_index_out_of_bounds_has_span() {
-    let source = "xs = [1, 2, 3]\nxs[99]";
+    let source = "let xs = [1, 2, 3]\nxs[99]";
     let result = eval_program_for_error(source);
 
     assert!(result.is_err(), "Expected an error for index out of bounds");
@@ -103,7 +103,7 @@ fn test_type_error_has_span() {
 
 #[test]
 fn test_method_call_error_has_span() {
-    let source = "x = 1\nx::length()";
+    let source = "let x = 1\nx::length()";
     let result = eval_program_for_error(source);
 
     assert!(result.is_err(), "Expected a method error");
@@ -123,9 +123,23 @@ fn test_method_call_error_has_span() {
     );
 }
 
+#[test]
+fn test_unknown_method_span_points_at_method() {
+    let source = "let items = [1, 2]\nlet count = items::length()::badmethod(1, 2)";
+    let error = eval_program_for_error(source).unwrap_err();
+
+    assert!(matches!(
+        error.without_span(),
+        RuntimeError::MethodError { .. }
+    ));
+    let span = error.span().expect("Error should have a span");
+    assert!(span.start < span.end, "Span should not be empty");
+    assert_eq!(&source[span.start..span.end], "::badmethod(1, 2)");
+}
+
 #[test]
 fn test_nested_index_error_has_span() {
-    let source = "xs = [[1, 2], [3, 4]]\nxs[0][99]";
+    let source = "let xs = [[1, 2], [3, 4]]\nxs[0][99]";
     let result = eval_program_for_error(source);
 
     assert!(result.is_err(), "Expected an index out of bounds error");
@@ -147,7 +161,7 @@ fn test_nested_index_error_has_span() {
 
 #[test]
 fn test_division_by_zero_has_span() {
-    let source = "x = 10 / 0";
+    let source = "let x = 10 / 0";
     let result = eval_program_for_error(source);
 
     assert!(result.is_err(), "Expected a division by zero error");
@@ -169,7 +183,7 @@ fn test_division_by_zero_has_span() {
 
 #[test]
 fn test_string_index_error_has_span() {
-    let source = r#"s = "hello"
+    let source = r#"let s = "hello"
 s[99]"#;
     let result = eval_program_for_error(source);
 
@@ -192,7 +206,7 @@ s[99]"#;
 
 #[test]
 fn test_map_key_not_found_has_span() {
-    let source = r#"m = { a: 1 }
+    let source = r#"let m = { a: 1 }
 m:b"#;
     let result = eval_program_for_error(source);
 
@@ -236,7 +250,7 @@ fn test_import_nonexistent_has_span() {
 
 #[test]
 fn test_loop_through_wrong_iterable_has_span() {
-    let source = "x = 1\nloop through x { y = y }";
+    let source = "let x = 1\nloop through x { let y = y }";
     let result = eval_program_for_error(source);
 
     assert!(
@@ -260,7 +274,7 @@ fn test_loop_through_wrong_iterable_has_span() {
 
 #[test]
 fn test_function_arity_mismatch_has_span() {
-    let source = r#"f = |x, y| { return x + y }
+    let source = r#"let f = |x, y| { return x + y }
 f(1)"#;
     let result = eval_program_for_error(source);
 
@@ -283,7 +297,7 @@ f(1)"#;
 
 #[test]
 fn test_compound_assignment_type_error_has_span() {
-    let source = r#"x = "hello"
+    let source = r#"let x = "hello"
 x += 5"#;
     let result = eval_program_for_error(source);
 
@@ -306,7 +320,7 @@ x += 5"#;
 
 #[test]
 fn test_span_includes_line_and_column() {
-    let source = "x = 1\ny = z + 1"; // 'z' is undefined on line 2
+    let source = "let x = 1\nlet y = z + 1"; // 'z' is undefined on line 2
     let result = eval_program_for_error(source);
 
     assert!(result.is_err(), "Expected an undefined variable error");
@@ -1,4 +1,4 @@
-#[path = "../../common/mod.rs"]
-mod common;
+use crate::common;
 
 mod errors;
+mod phases;
@@ -0,0 +1,86 @@
+//! Tests for running parse and evaluation as separate phases
+
+use std::rc::Rc;
+use suji_interpreter::{AstInterpreter, eval_module_source_callback};
+use suji_runtime::{Executor, ModuleRegistry, setup_global_env};
+use suji_values::{DecimalNumber, Env, RuntimeError, Value};
+
+fn setup() -> (Rc<Env>, ModuleRegistry) {
+    let env = Rc::new(Env::new());
+    setup_global_env(&env);
+
+    suji_stdlib::runtime::builtins::register_all_builtins();
+    let mut module_registry = ModuleRegistry::new();
+    module_registry.set_source_evaluator(eval_module_source_callback);
+    suji_stdlib::setup_module_registry(&mut module_registry);
+    (env, module_registry)
+}
+
+#[test]
+fn test_parse_then_eval_matches_eval_source() {
+    let source = "let x = 20\nlet y = x + 22\ny";
+    let (env, module_registry) = setup();
+
+    let statements = AstInterpreter::parse_source(source).unwrap();
+    assert_eq!(statements.len(), 3);
+
+    let interpreter = AstInterpreter;
+    let phased = interpreter
+        .eval_statements(&statements, env, &module_registry, false)
+        .unwrap();
+    assert_eq!(phased, Value::Number(DecimalNumber::from_i64(42)));
+
+    let (env, module_registry) = setup();
+    let direct = interpreter
+        .eval_source(source, env, &module_registry, false)
+        .unwrap();
+    assert_eq!(phased, direct);
+}
+
+#[test]
+fn test_eval_statements_expect_export() {
+    let (env, module_registry) = setup();
+    let interpreter = AstInterpreter;
+
+    let statements = AstInterpreter::parse_source("let x = 1\nexport x + 1").unwrap();
+    let exported = interpreter
+        .eval_statements(&statements, env.clone(), &module_registry, true)
+        .unwrap();
+    assert_eq!(exported, Value::Number(DecimalNumber::from_i64(2)));
+
+    let statements = AstInterpreter::parse_source("let x = 1").unwrap();
+    let result = interpreter.eval_statements(&statements, env, &module_registry, true);
+    assert!(matches!(result, Err(RuntimeError::InvalidOperation { .. })));
+}
+
+#[test]
+fn test_parse_source_reports_errors_without_evaluating() {
+    let result = AstInterpreter::parse_source("x = (");
+    assert!(matches!(result, Err(RuntimeError::Parse(_))));
+}
+
+#[test]
+fn test_restore_discards_bindings_from_evaluated_snippet() {
+    let (env, module_registry) = setup();
+    let interpreter = AstInterpreter;
+    interpreter
+        .eval_source("let x = 1", env.clone(), &module_registry, false)
+        .unwrap();
+
+    let snapshot = env.snapshot();
+    interpreter
+        .eval_source(
+            "let x = 2\nlet helper = |n| n * 2",
+            env.clone(),
+            &module_registry,
+            false,
+        )
+        .unwrap();
+    env.restore(snapshot);
+
+    assert_eq!(
+        env.get("x").unwrap(),
+        Value::Number(DecimalNumber::from_i64(1))
+    );
+    assert!(!env.contains("helper"));
+}
@@ -3,6 +3,8 @@ mod common;
 
 #[path = "integration/arithmetic.rs"]
 mod arithmetic;
+#[path = "integration/constants.rs"]
+mod constants;
 #[path = "integration/functions.rs"]
 mod functions;
 #[path = "integration/indexing.rs"]
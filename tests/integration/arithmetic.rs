@@ -1,4 +1,4 @@
-use super::common::{eval_program, eval_string_expr};
+use super::common::{assert_eval_fails, eval_program, eval_string_expr};
 use suji_values::DecimalNumber;
 use suji_values::Value;
 
@@ -124,6 +124,78 @@ fn test_short_circuit_evaluation() {
     );
 }
 
+#[test]
+fn test_truthiness_matrix() {
+    // to_bool(): only nil and false are falsy
+    let cases = [
+        ("nil", false),
+        ("false", false),
+        ("true", true),
+        ("0", true),
+        ("1.5", true),
+        ("\"\"", true),
+        ("\"no\"", true),
+        ("[]", true),
+        ("[false]", true),
+        ("{}", true),
+        ("{ a: nil }", true),
+        ("(nil, false)", true),
+        ("/x/", true),
+        ("|x| x", true),
+    ];
+    for (literal, expected) in cases {
+        let program = format!("let value = {}\nvalue::to_bool()", literal);
+        assert_eq!(
+            eval_program(&program).unwrap(),
+            Value::Boolean(expected),
+            "{}::to_bool()",
+            literal
+        );
+
+        // Conditions never coerce: only booleans are accepted, everywhere alike
+        let conditions = [
+            "value && true",
+            "true && value",
+            "value || false",
+            "false || value",
+            "!value",
+            "match { value => 1, _ => 2, }",
+            "let items = [1]\nitems::filter(|x| value)",
+        ];
+        for condition in conditions {
+            let program = format!("let value = {}\n{}", literal, condition);
+            let result = eval_program(&program);
+            match literal {
+                "true" | "false" => assert!(result.is_ok(), "{}: {}", literal, condition),
+                _ => {
+                    let message = result.unwrap_err().to_string();
+                    assert!(
+                        message.contains("must be a boolean")
+                            || message.contains("Cannot apply logical NOT"),
+                        "{}: {} failed with {}",
+                        literal,
+                        condition,
+                        message
+                    );
+                }
+            }
+        }
+    }
+
+    assert_eval_fails(
+        "nil && true",
+        "Logical AND operand must be a boolean, got nil",
+    );
+    assert_eval_fails(
+        "match { 1 => \"one\", }",
+        "Match condition must be a boolean, got number (use to_bool() to convert)",
+    );
+    assert_eq!(
+        eval_program("let name = nil\nname::to_bool() || false").unwrap(),
+        Value::Boolean(false)
+    );
+}
+
 #[test]
 fn test_complex_literals() {
     let Value::String(s) = eval_string_expr(r#""hello""#).unwrap() else {
@@ -232,7 +304,7 @@ fn test_program_evaluation() {
         Value::Number(DecimalNumber::from_i64(5))
     );
     assert_eq!(
-        eval_program("x = 5").unwrap(),
+        eval_program("let x = 5").unwrap(),
         Value::Number(DecimalNumber::from_i64(5))
     );
 }
@@ -244,15 +316,140 @@ fn test_program_evaluation() {
 #[test]
 fn test_postfix_operators() {
     assert_eq!(
-        eval_program("x = 5\nx++").unwrap(),
+        eval_program("let x = 5\nx++").unwrap(),
         Value::Number(DecimalNumber::from_i64(6))
     );
     assert_eq!(
-        eval_program("y = 10\ny--").unwrap(),
+        eval_program("let y = 10\ny--").unwrap(),
         Value::Number(DecimalNumber::from_i64(9))
     );
     assert_eq!(
-        eval_program("z = 7\nz++\nz").unwrap(),
+        eval_program("let z = 7\nz++\nz").unwrap(),
         Value::Number(DecimalNumber::from_i64(8))
     );
 }
+
+// ============================================================================
+// Scientific Notation Tests
+// ============================================================================
+
+#[test]
+fn test_scientific_notation_literals() {
+    assert_eq!(
+        eval_program("1e10").unwrap(),
+        Value::Number(DecimalNumber::from_i64(10_000_000_000))
+    );
+    assert_eq!(
+        eval_program("1.5e-3 * 1000").unwrap(),
+        Value::Number(DecimalNumber::parse("1.5").unwrap())
+    );
+    assert_eq!(
+        eval_program("2E+5 - 1").unwrap(),
+        Value::Number(DecimalNumber::from_i64(199_999))
+    );
+}
+
+#[test]
+fn test_large_integers_stay_exact() {
+    let result = eval_program(
+        r#"
+        let factorial = |n| match n { 0 => 1, _ => n * factorial(n - 1), }
+        let result = (2 ^ 60, 2 ^ 95, factorial(20), factorial(27), 10 ^ 28 + 1)
+        result
+    "#,
+    )
+    .unwrap();
+    let number = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            number("1152921504606846976"),
+            number("39614081257132168796771975168"),
+            number("2432902008176640000"),
+            number("10888869450418352160768000000"),
+            number("10000000000000000000000000001"),
+        ])
+    );
+}
+
+#[test]
+fn test_large_factorials_promote_to_big_integers() {
+    let result = eval_program(
+        r#"
+        let factorial = |n| match n { 0 => 1, _ => n * factorial(n - 1), }
+        let result = (
+            factorial(28),
+            factorial(30),
+            (1..=50)::product(),
+            factorial(30) / factorial(28),
+            factorial(30) > factorial(29),
+            (1..=100)::product()::to_string()::length(),
+        )
+        result
+    "#,
+    )
+    .unwrap();
+    let number = |s: &str| Value::Number(DecimalNumber::parse(s).unwrap());
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            number("304888344611713860501504000000"),
+            number("265252859812191058636308480000000"),
+            number("30414093201713378043612608166064768844377641568960512000000000000"),
+            number("870"),
+            Value::Boolean(true),
+            number("158"),
+        ])
+    );
+}
+
+#[test]
+fn test_integer_results_beyond_the_decimal_range_stay_exact() {
+    let cases = [
+        ("2 ^ 96", "79228162514264337593543950336"),
+        ("2 ^ 200 / 2 ^ 199", "2"),
+        (
+            "79228162514264337593543950335 + 1",
+            "79228162514264337593543950336",
+        ),
+        (
+            "-79228162514264337593543950335 - 1",
+            "-79228162514264337593543950336",
+        ),
+        (
+            "79228162514264337593543950335 / 0.5",
+            "158456325028528675187087900670",
+        ),
+        ("(2 ^ 100 + 7) % 10", "3"),
+        ("2 ^ 100 * 1.5", "1901475900342344102245054808064"),
+        ("2 ^ 100 - 2 ^ 100 + 0.25", "0.25"),
+        (
+            "[79228162514264337593543950335, 1]::sum()",
+            "79228162514264337593543950336",
+        ),
+        (
+            "let x = 79228162514264337593543950335\nx++\nx",
+            "79228162514264337593543950336",
+        ),
+        ("100000000000000000000000000000000 == 10 ^ 32", "true"),
+        ("(2 ^ 100)::to_string()", "1267650600228229401496703205376"),
+    ];
+    for (code, expected) in cases {
+        let result = eval_program(code).unwrap();
+        assert_eq!(result.to_string(), expected, "{}", code);
+    }
+}
+
+#[test]
+fn test_arithmetic_overflow_is_an_error() {
+    // Fractional results cannot grow past the decimal range
+    for code in [
+        "79228162514264337593543950335 + 0.5",
+        "2 ^ 100 + 0.5",
+        "2 ^ 100 / 3",
+        "1.5 ^ 200",
+        "2 ^ 2000000",
+    ] {
+        assert_eval_fails(code, "Number overflow");
+    }
+}
@@ -0,0 +1,61 @@
+use super::common::eval_program_with_constants;
+use indexmap::IndexMap;
+use suji_values::{DecimalNumber, MapKey, Value};
+
+fn constants() -> Vec<(&'static str, Value)> {
+    let mut config = IndexMap::new();
+    config.insert(
+        MapKey::String("host".to_string()),
+        Value::String("db.local".to_string()),
+    );
+    vec![
+        ("CONFIG", Value::Map(config)),
+        ("LIMIT", Value::Number(DecimalNumber::from_i64(3))),
+        ("ITEMS", Value::List(vec![Value::Boolean(true)])),
+    ]
+}
+
+#[test]
+fn test_constants_are_readable() {
+    let result = eval_program_with_constants(
+        r#"
+        let doubled = LIMIT * 2
+        let read = || CONFIG:host
+        "${read()} ${doubled} ${ITEMS::length()}"
+    "#,
+        constants(),
+    );
+    assert_eq!(result.unwrap(), Value::String("db.local 6 1".to_string()));
+}
+
+#[test]
+fn test_constants_cannot_be_reassigned_or_mutated() {
+    let programs = [
+        "LIMIT = 4",
+        "LIMIT += 1",
+        "LIMIT++",
+        "let LIMIT = 4",
+        "(LIMIT, other) = (1, 2)",
+        "CONFIG:host = \"evil\"",
+        "ITEMS[0] = false",
+        "ITEMS::push(1)",
+        "let change = || { LIMIT = 0 }\nchange()",
+        "import std:json as LIMIT",
+    ];
+    for program in programs {
+        let err = eval_program_with_constants(program, constants())
+            .expect_err(program)
+            .to_string();
+        assert!(
+            err.contains("Cannot assign to constant") || err.contains("immutable value"),
+            "{}: {}",
+            program,
+            err
+        );
+    }
+
+    // A function may still shadow a constant with its own parameter
+    let result =
+        eval_program_with_constants("let f = |LIMIT| LIMIT + 1\nf(10) + LIMIT", constants());
+    assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(14)));
+}
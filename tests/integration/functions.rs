@@ -1,15 +1,16 @@
 use suji_values::DecimalNumber;
 
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program, parse_program};
+use suji_parser::ParseError;
 use suji_values::Value;
 
 #[test]
 fn test_function_evaluation() {
-    let result = eval_program("func = |x| { return x * 2 }").unwrap();
+    let result = eval_program("let func = |x| { return x * 2 }").unwrap();
     assert!(matches!(result, Value::Function(_)));
 
     assert_eq!(
-        eval_program("add = |x, y| { x + y }; add(2, 3)").unwrap(),
+        eval_program("let add = |x, y| { x + y }; add(2, 3)").unwrap(),
         Value::Number(DecimalNumber::from_i64(5))
     );
 }
@@ -31,29 +32,231 @@ fn test_function_calls_comprehensive() {
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
     // Test user-defined function calls
-    let result = eval_program("add = |x, y| { x + y }\nadd(2, 3)").unwrap();
+    let result = eval_program("let add = |x, y| { x + y }\nadd(2, 3)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 
     // Test function call with no arguments
-    let result = eval_program("get_five = || { 5 }\nget_five()").unwrap();
+    let result = eval_program("let get_five = || { 5 }\nget_five()").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 
     // Test function call with default parameters
-    let result = eval_program("greet = |name = \"World\"| { name }\ngreet()").unwrap();
+    let result = eval_program("let greet = |name = \"World\"| { name }\ngreet()").unwrap();
     assert_eq!(result, Value::String("World".to_string()));
 
-    let result = eval_program("greet = |name = \"World\"| { name }\ngreet(\"Alice\")").unwrap();
+    let result = eval_program("let greet = |name = \"World\"| { name }\ngreet(\"Alice\")").unwrap();
     assert_eq!(result, Value::String("Alice".to_string()));
 }
 
+#[test]
+fn test_default_params_reference_earlier_params() {
+    let result = eval_program("let f = |a, b = a + 1| { b }\nf(5)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(6)));
+
+    let result = eval_program("let f = |a, b = a + 1| { b }\nf(5, 10)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(10)));
+
+    // Chained defaults each see the parameters bound before them
+    let result = eval_program("let f = |a, b = a * 2, c = a + b| { c }\nf(3)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(9)));
+}
+
+#[test]
+fn test_default_params_use_definition_environment() {
+    // Defaults resolve names where the function was defined, not at the call site
+    let program = r#"
+        let make = || {
+            let scale = 2
+            return |x, factor = scale| x * factor
+        }
+        let double = make()
+        let call_it = || {
+            let scale = 100
+            return double(4)
+        }
+        call_it()
+    "#;
+    let result = eval_program(program).unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(8)));
+}
+
+#[test]
+fn test_default_params_evaluated_at_call_time() {
+    // Each call evaluates the default afresh, seeing the current value of captured state
+    let program = r#"
+        let base = 1
+        let f = |x = base| x
+        let first = f()
+        base = 5
+        let second = f()
+        first * 10 + second
+    "#;
+    let result = eval_program(program).unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(15)));
+
+    // Mutable defaults are not shared between calls
+    let program = r#"
+        let push = |item, items = []| {
+            items::push(item)
+            return items
+        }
+        push(1)
+        push(2)::length()
+    "#;
+    let result = eval_program(program).unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(1)));
+}
+
+#[test]
+fn test_named_arguments() {
+    let f = "let f = |a, b = 10, c = a + b| { a * 100 + b * 10 + c }\nlet one = 1\n";
+    let cases = [
+        ("f(c: 3, a: 1, b: 2)", 123),
+        ("f(1, c: 3)", 203),
+        ("f(one, c: 3)", 203),
+        ("f(1, b: 2)", 123),
+        ("f(a: 1,)", 211),
+    ];
+    for (call, expected) in cases {
+        let result = eval_program(&format!("{}{}", f, call)).unwrap();
+        assert_eq!(result, Value::Number(DecimalNumber::from_i64(expected)));
+    }
+
+    // `map:key` access, written without a space, is still a positional argument
+    let result = eval_program("let m = { k: 4 }\nlet f = |x| x\nf(m:k)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(4)));
+
+    // An assignment is still an assignment, in plain calls and method calls alike
+    let result = eval_program("let b = 0\nlet g = |v| v * 2\ng(b = 5) + b").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(15)));
+    let result = eval_program("let b = 0\nlet xs = []\nxs::push(b = 5)\nxs[0] + b").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(10)));
+
+    assert_eval_fails(&format!("{}f(1, d: 4)", f), "no parameter named 'd'");
+    assert_eval_fails(&format!("{}f(1, a: 2)", f), "'a' is given more than once");
+    assert_eval_fails(
+        &format!("{}f(b: 1, b: 2)", f),
+        "'b' is given more than once",
+    );
+    assert_eval_fails(
+        &format!("{}f(b: 2)", f),
+        "Function expects 3 arguments, got 1",
+    );
+    assert_eval_fails(
+        "import std:math\nmath:sin(x: 1)",
+        "does not accept named arguments",
+    );
+}
+
+#[test]
+fn test_named_argument_parse_errors_point_at_argument() {
+    let cases = [
+        (
+            "f(a: 1, b: 2, 3)",
+            "3",
+            "Positional arguments must come before named ones (found one after 'b: ...')",
+        ),
+        (
+            "xs::push(item: 1)",
+            "item",
+            "Methods only take positional arguments (found 'item: ...')",
+        ),
+        (
+            "let v = m: k",
+            "k",
+            "Map access is written without a space after ':'",
+        ),
+    ];
+    for (source, at, expected) in cases {
+        let error = parse_program(source).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+        let span = match error {
+            ParseError::PositionalAfterNamed { span, .. }
+            | ParseError::NamedArgumentInMethodCall { span, .. }
+            | ParseError::SpacedMapAccess { span } => span,
+            other => panic!("expected a spanned argument error, got {:?}", other),
+        };
+        assert_eq!(span.start, source.rfind(at).unwrap(), "{}", source);
+    }
+}
+
+#[test]
+fn test_function_partial_binds_leading_args() {
+    let result =
+        eval_program("let add = |a, b| a + b\nlet add10 = add::partial(10)\nadd10(5)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(15)));
+
+    // Partials compose with value pipelines and list methods
+    let program = r#"
+        let scale = |factor, x| x * factor
+        let triple = scale::partial(3)
+        let total = 4 |> triple
+        let doubled = [1, 2, 3]::map(scale::partial(2))
+        "${total} ${doubled}"
+    "#;
+    let result = eval_program(program).unwrap();
+    assert_eq!(result, Value::String("12 [2, 4, 6]".to_string()));
+
+    // Binding every argument yields a zero-argument function
+    let result = eval_program("let add = |a, b| a + b\nadd::partial(1, 2)()").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
+}
+
+#[test]
+fn test_function_source_prints_the_definition() {
+    let result = eval_program("let f = |a, b = 1| a + b\nf::source()").unwrap();
+    assert_eq!(result, Value::String("|a, b = 1| a + b".to_string()));
+
+    let result = eval_program("let f = |x| { let y = x * 2\n return y }\nf::source()").unwrap();
+    assert_eq!(
+        result,
+        Value::String("|x| {\n    let y = x * 2\n    return y\n}".to_string())
+    );
+
+    assert!(eval_program("import std:json\njson:parse::source()").is_err());
+}
+
+#[test]
+fn test_partial_source_shows_target_and_bound_arguments() {
+    let result = eval_program(
+        "let greet = |greeting, name| greeting + name
+greet::partial(\"hi \")::source()",
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::String("(|greeting, name| greeting + name)::partial(\"hi \")".to_string())
+    );
+
+    let result = eval_program(
+        "let add3 = |a, b, c| a + b + c
+add3::partial(1)::partial(2)::source()",
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::String("(|a, b, c| a + b + c)::partial(1, 2)".to_string())
+    );
+}
+
+#[test]
+fn test_function_partial_keeps_defaults_and_arity() {
+    // Remaining defaults still see earlier (bound) parameters
+    let result = eval_program("let f = |a, b, c = a + b| c\nlet g = f::partial(1)\ng(2)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
+
+    // Too many combined arguments fail the normal arity check
+    assert!(eval_program("let add = |a, b| a + b\nadd::partial(1)(2, 3)").is_err());
+    assert!(eval_program("let add = |a, b| a + b\nadd::partial(1, 2, 3)").is_err());
+}
+
 #[test]
 fn test_function_multi_return_destructuring() {
     let program = r#"
-        make_pair = || {
+        let make_pair = || {
             return 1, 2
         }
 
-        left, right = make_pair()
+        let left, right = make_pair()
         right
     "#;
 
@@ -68,9 +271,9 @@ fn test_function_multi_return_destructuring() {
 #[test]
 fn test_compose_right_runtime() {
     let program = r#"
-        add2 = |n| n + 2
-        mul3 = |n| n * 3
-        f = add2 >> mul3
+        let add2 = |n| n + 2
+        let mul3 = |n| n * 3
+        let f = add2 >> mul3
         f(1)
     "#;
     let result = eval_program(program).unwrap();
@@ -80,9 +283,9 @@ fn test_compose_right_runtime() {
 #[test]
 fn test_compose_left_runtime() {
     let program = r#"
-        add2 = |n| n + 2
-        mul3 = |n| n * 3
-        f = add2 << mul3
+        let add2 = |n| n + 2
+        let mul3 = |n| n * 3
+        let f = add2 << mul3
         f(1)
     "#;
     let result = eval_program(program).unwrap();
@@ -92,9 +295,9 @@ fn test_compose_left_runtime() {
 #[test]
 fn test_composition_chaining() {
     let program = r#"
-        add2 = |n| n + 2
-        mul3 = |n| n * 3
-        g = (add2 >> mul3) >> add2
+        let add2 = |n| n + 2
+        let mul3 = |n| n * 3
+        let g = (add2 >> mul3) >> add2
         g(1)
     "#;
     let result = eval_program(program).unwrap();
@@ -104,23 +307,44 @@ fn test_composition_chaining() {
 #[test]
 fn test_composition_type_errors() {
     use super::common::assert_eval_fails;
-    assert_eval_fails("f = 1 >> (|x| x)", "Cannot compose");
-    assert_eval_fails("f = (|x| x) << \"s\"", "Cannot compose");
+    assert_eval_fails("let f = 1 >> (|x| x)", "Cannot compose");
+    assert_eval_fails("let f = (|x| x) << \"s\"", "Cannot compose");
+}
+
+#[test]
+fn test_std_compose_list_of_functions() {
+    let program = r#"
+        import std:compose
+        let add2 = |n| n + 2
+        let mul3 = |n| n * 3
+        let g = compose([add2, mul3, add2])
+        let identity = compose([])
+        g(1) + identity(100)
+    "#;
+    let result = eval_program(program).unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(111)));
+
+    use super::common::assert_eval_fails;
+    assert_eval_fails(
+        "import std:compose\nlet f = compose([|x| x, 3])",
+        "Cannot compose",
+    );
 }
 
 // Phase 6 Tests - Optional Braces for Single Expressions
 
 #[test]
 fn test_optional_braces_function_evaluation() {
-    let result = eval_program("multiply = |x, y| x * y\nresult = multiply(3, 4)");
+    let result = eval_program("let multiply = |x, y| x * y\nlet result = multiply(3, 4)");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(12)));
 
-    let result = eval_program("square = |x| x * x\nresult = square(5)");
+    let result = eval_program("let square = |x| x * x\nlet result = square(5)");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(25)));
 
-    let result = eval_program("greet = |name| \"Hello, \" + name\nresult = greet(\"world\")");
+    let result =
+        eval_program("let greet = |name| \"Hello, \" + name\nlet result = greet(\"world\")");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::String("Hello, world".to_string()));
 }
@@ -128,13 +352,13 @@ fn test_optional_braces_function_evaluation() {
 #[test]
 fn test_optional_braces_match_evaluation() {
     let result = eval_program(
-        "x = 3\nmatch x { 1 => result = \"one\", 2 => result = \"two\", 3 => result = \"three\", _ => result = \"other\", }",
+        "let x = 3\nmatch x { 1 => let result = \"one\", 2 => let result = \"two\", 3 => let result = \"three\", _ => let result = \"other\", }",
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::String("three".to_string()));
 
     let result = eval_program(
-        "x = 5\nmatch x { 1 => result = 10, 2 => result = 20, 3 => result = 30, _ => result = 0, }",
+        "let x = 5\nmatch x { 1 => let result = 10, 2 => let result = 20, 3 => let result = 30, _ => let result = 0, }",
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(0)));
@@ -143,7 +367,7 @@ fn test_optional_braces_match_evaluation() {
 #[test]
 fn test_optional_braces_mixed_evaluation() {
     let result = eval_program(
-        "x = 2\nmatch x { 1 => result = x * 2, 2 => { doubled = x * 2; result = doubled + 1 }, _ => result = 0, }",
+        "let x = 2\nmatch x { 1 => let result = x * 2, 2 => { let doubled = x * 2; let result = doubled + 1 }, _ => let result = 0, }",
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(5)));
@@ -151,30 +375,31 @@ fn test_optional_braces_mixed_evaluation() {
 
 #[test]
 fn test_optional_braces_implicit_return() {
-    let result = eval_program("add = |x, y| x + y\nresult = add(3, 4)");
+    let result = eval_program("let add = |x, y| x + y\nlet result = add(3, 4)");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(7)));
 
-    let result =
-        eval_program("complex = |x| { doubled = x * 2; doubled + 1 }\nresult = complex(5)");
+    let result = eval_program(
+        "let complex = |x| { let doubled = x * 2; doubled + 1 }\nlet result = complex(5)",
+    );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(11)));
 }
 
 #[test]
 fn test_optional_braces_empty_function() {
-    let result = eval_program("constant = || 42\nresult = constant()");
+    let result = eval_program("let constant = || 42\nlet result = constant()");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(42)));
 }
 
 #[test]
 fn test_optional_braces_function_with_default_params() {
-    let result = eval_program("add = |x = 0, y = 1| x + y\nresult = add(3, 4)");
+    let result = eval_program("let add = |x = 0, y = 1| x + y\nlet result = add(3, 4)");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(7)));
 
-    let result = eval_program("add = |x = 0, y = 1| x + y\nresult = add()");
+    let result = eval_program("let add = |x = 0, y = 1| x + y\nlet result = add()");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(1)));
 }
@@ -182,7 +407,7 @@ fn test_optional_braces_function_with_default_params() {
 #[test]
 fn test_optional_braces_nested_expressions() {
     let result = eval_program(
-        "x = 2\nmatch x { 1 => result = x * 2, 2 => result = x + 10, _ => result = 0, }",
+        "let x = 2\nmatch x { 1 => let result = x * 2, 2 => let result = x + 10, _ => let result = 0, }",
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(12)));
@@ -190,22 +415,23 @@ fn test_optional_braces_nested_expressions() {
 
 #[test]
 fn test_optional_braces_backward_compatibility() {
-    let result = eval_program("add = |x, y| { return x + y }\nresult = add(3, 4)");
+    let result = eval_program("let add = |x, y| { return x + y }\nlet result = add(3, 4)");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(7)));
 
-    let result = eval_program("match 1 { 1 => { result = \"one\" }, 2 => { result = \"two\" }, }");
+    let result =
+        eval_program("match 1 { 1 => { let result = \"one\" }, 2 => { let result = \"two\" }, }");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::String("one".to_string()));
 }
 
 #[test]
 fn test_optional_braces_boolean_match() {
-    let result = eval_program("match true { true => result = 1, false => result = 0, }");
+    let result = eval_program("match true { true => let result = 1, false => let result = 0, }");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(1)));
 
-    let result = eval_program("match false { true => result = 1, false => result = 0, }");
+    let result = eval_program("match false { true => let result = 1, false => let result = 0, }");
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(0)));
 }
@@ -213,7 +439,7 @@ fn test_optional_braces_boolean_match() {
 #[test]
 fn test_optional_braces_wildcard_match() {
     let result = eval_program(
-        "match 42 { 1 => result = \"one\", 2 => result = \"two\", _ => result = \"other\", }",
+        "match 42 { 1 => let result = \"one\", 2 => let result = \"two\", _ => let result = \"other\", }",
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::String("other".to_string()));
@@ -33,10 +33,10 @@ fn test_indexing_slicing_comprehensive() {
     }
 
     // Test on variables
-    let result = eval_program("list = [1, 2, 3, 4]\nlist[1]").unwrap();
+    let result = eval_program("let list = [1, 2, 3, 4]\nlist[1]").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
-    let result = eval_program("list = [1, 2, 3, 4]\nlist[1:3]").unwrap();
+    let result = eval_program("let list = [1, 2, 3, 4]\nlist[1:3]").unwrap();
     if let Value::List(items) = result {
         assert_eq!(items.len(), 2);
     } else {
@@ -51,8 +51,8 @@ fn test_indexing_slicing_comprehensive() {
 #[test]
 fn test_index_with_function_call() {
     let code = r#"
-        get_idx = || 2
-        nums = [10, 20, 30, 40]
+        let get_idx = || 2
+        let nums = [10, 20, 30, 40]
         nums[get_idx()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -62,8 +62,8 @@ fn test_index_with_function_call() {
 #[test]
 fn test_index_with_arithmetic() {
     let code = r#"
-        nums = [10, 20, 30, 40]
-        i = 1
+        let nums = [10, 20, 30, 40]
+        let i = 1
         nums[i + 1]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -73,7 +73,7 @@ fn test_index_with_arithmetic() {
 #[test]
 fn test_index_with_method_call() {
     let code = r#"
-        nums = [10, 20, 30, 40]
+        let nums = [10, 20, 30, 40]
         nums[nums::length() - 1]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -83,8 +83,8 @@ fn test_index_with_method_call() {
 #[test]
 fn test_index_with_pipeline() {
     let code = r#"
-        nums = [10, 20, 30, 40]
-        get_two = |x| 2
+        let nums = [10, 20, 30, 40]
+        let get_two = |x| 2
         nums[0 |> get_two]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -94,8 +94,8 @@ fn test_index_with_pipeline() {
 #[test]
 fn test_nested_indexing() {
     let code = r#"
-        indices = [0, 1, 2]
-        data = [10, 20, 30]
+        let indices = [0, 1, 2]
+        let data = [10, 20, 30]
         data[indices[1]]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -105,9 +105,9 @@ fn test_nested_indexing() {
 #[test]
 fn test_complex_mixed_operations() {
     let code = r#"
-        get_one = || 1
-        list = [0, 1]
-        data = [100, 200, 300]
+        let get_one = || 1
+        let list = [0, 1]
+        let data = [100, 200, 300]
         data[get_one() + list[0]]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -117,8 +117,8 @@ fn test_complex_mixed_operations() {
 #[test]
 fn test_assignment_with_function_call_index() {
     let code = r#"
-        get_idx = || 2
-        nums = [10, 20, 30, 40]
+        let get_idx = || 2
+        let nums = [10, 20, 30, 40]
         nums[get_idx()] = 99
         nums[2]
     "#;
@@ -129,8 +129,8 @@ fn test_assignment_with_function_call_index() {
 #[test]
 fn test_assignment_with_arithmetic_index() {
     let code = r#"
-        nums = [10, 20, 30, 40]
-        i = 1
+        let nums = [10, 20, 30, 40]
+        let i = 1
         nums[i + 1] = 99
         nums[2]
     "#;
@@ -141,7 +141,7 @@ fn test_assignment_with_arithmetic_index() {
 #[test]
 fn test_assignment_with_method_call_index() {
     let code = r#"
-        nums = [10, 20, 30, 40]
+        let nums = [10, 20, 30, 40]
         nums[nums::length() - 1] = 99
         nums[3]
     "#;
@@ -152,8 +152,8 @@ fn test_assignment_with_method_call_index() {
 #[test]
 fn test_assignment_with_nested_index() {
     let code = r#"
-        indices = [0, 1, 2]
-        matrix = [[1, 2], [3, 4], [5, 6]]
+        let indices = [0, 1, 2]
+        let matrix = [[1, 2], [3, 4], [5, 6]]
         matrix[indices[1]][0] = 99
         matrix[1][0]
     "#;
@@ -164,7 +164,7 @@ fn test_assignment_with_nested_index() {
 #[test]
 fn test_slice_syntax_unaffected() {
     let code = r#"
-        nums = [10, 20, 30, 40, 50]
+        let nums = [10, 20, 30, 40, 50]
         nums[1:3]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -174,7 +174,7 @@ fn test_slice_syntax_unaffected() {
 #[test]
 fn test_method_on_indexed_value() {
     let code = r#"
-        words = ["hello", "world"]
+        let words = ["hello", "world"]
         words[0]::length()
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -184,9 +184,9 @@ fn test_method_on_indexed_value() {
 #[test]
 fn test_chain_multiple_complex_indices() {
     let code = r#"
-        get_one = || 1
-        get_zero = || 0
-        matrix = [[1, 2], [3, 4], [5, 6]]
+        let get_one = || 1
+        let get_zero = || 0
+        let matrix = [[1, 2], [3, 4], [5, 6]]
         matrix[get_one()][get_zero()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -196,8 +196,8 @@ fn test_chain_multiple_complex_indices() {
 #[test]
 fn test_map_indexing_with_function_call() {
     let code = r#"
-        get_key = || "b"
-        data = { a: 10, b: 20, c: 30 }
+        let get_key = || "b"
+        let data = { a: 10, b: 20, c: 30 }
         data[get_key()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -207,8 +207,8 @@ fn test_map_indexing_with_function_call() {
 #[test]
 fn test_map_indexing_with_arithmetic() {
     let code = r#"
-        base = 1
-        data = { 1: "one", 2: "two", 3: "three" }
+        let base = 1
+        let data = { 1: "one", 2: "two", 3: "three" }
         data[base + 1]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -218,8 +218,8 @@ fn test_map_indexing_with_arithmetic() {
 #[test]
 fn test_string_indexing_with_function_call() {
     let code = r#"
-        get_idx = || 1
-        text = "hello"
+        let get_idx = || 1
+        let text = "hello"
         text[get_idx()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -229,8 +229,8 @@ fn test_string_indexing_with_function_call() {
 #[test]
 fn test_negative_index_with_arithmetic() {
     let code = r#"
-        nums = [10, 20, 30, 40, 50]
-        offset = 2
+        let nums = [10, 20, 30, 40, 50]
+        let offset = 2
         nums[-1 - offset]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -240,9 +240,9 @@ fn test_negative_index_with_arithmetic() {
 #[test]
 fn test_complex_expression_multiple_operations() {
     let code = r#"
-        double = |x| x * 2
-        nums = [0, 1, 2, 3, 4, 5]
-        base = 1
+        let double = |x| x * 2
+        let nums = [0, 1, 2, 3, 4, 5]
+        let base = 1
         nums[double(base) + 1]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -252,9 +252,9 @@ fn test_complex_expression_multiple_operations() {
 #[test]
 fn test_nested_function_calls_in_index() {
     let code = r#"
-        get_two = || 2
-        add_one = |x| x + 1
-        nums = [10, 20, 30, 40, 50]
+        let get_two = || 2
+        let add_one = |x| x + 1
+        let nums = [10, 20, 30, 40, 50]
         nums[add_one(get_two())]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -264,8 +264,8 @@ fn test_nested_function_calls_in_index() {
 #[test]
 fn test_method_chain_in_index() {
     let code = r#"
-        nums = [10, 20, 30]
-        indexes = [0, 1, 2]
+        let nums = [10, 20, 30]
+        let indexes = [0, 1, 2]
         nums[indexes::length() - 1]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -275,9 +275,9 @@ fn test_method_chain_in_index() {
 #[test]
 fn test_pipeline_with_multiple_stages_in_index() {
     let code = r#"
-        inc = |x| x + 1
-        double = |x| x * 2
-        nums = [10, 20, 30, 40, 50]
+        let inc = |x| x + 1
+        let double = |x| x * 2
+        let nums = [10, 20, 30, 40, 50]
         nums[0 |> inc |> double]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -287,9 +287,9 @@ fn test_pipeline_with_multiple_stages_in_index() {
 #[test]
 fn test_complex_assignment_deep_nesting() {
     let code = r#"
-        get_i = || 0
-        get_j = || 1
-        matrix = [[1, 2, 3], [4, 5, 6], [7, 8, 9]]
+        let get_i = || 0
+        let get_j = || 1
+        let matrix = [[1, 2, 3], [4, 5, 6], [7, 8, 9]]
         matrix[get_i()][get_j()] = 99
         matrix[0][1]
     "#;
@@ -300,9 +300,9 @@ fn test_complex_assignment_deep_nesting() {
 #[test]
 fn test_mixed_map_and_list_complex_index() {
     let code = r#"
-        get_key = || "items"
-        get_idx = || 1
-        data = { 
+        let get_key = || "items"
+        let get_idx = || 1
+        let data = { 
             items: [10, 20, 30],
             count: 3
         }
@@ -315,9 +315,9 @@ fn test_mixed_map_and_list_complex_index() {
 #[test]
 fn test_assignment_mixed_structures_complex_index() {
     let code = r#"
-        get_key = || "values"
-        get_idx = || 2
-        data = { 
+        let get_key = || "values"
+        let get_idx = || 2
+        let data = { 
             values: [1, 2, 3, 4, 5]
         }
         data[get_key()][get_idx()] = 99
@@ -330,12 +330,12 @@ fn test_assignment_mixed_structures_complex_index() {
 #[test]
 fn test_ternary_like_index_expression() {
     let code = r#"
-        use_first = true
-        get_idx = || match use_first {
+        let use_first = true
+        let get_idx = || match use_first {
             true => 0,
             false => 1,
         }
-        nums = [100, 200]
+        let nums = [100, 200]
         nums[get_idx()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -345,8 +345,8 @@ fn test_ternary_like_index_expression() {
 #[test]
 fn test_list_method_result_as_index() {
     let code = r#"
-        nums = [10, 20, 30, 40]
-        indices = [2]
+        let nums = [10, 20, 30, 40]
+        let indices = [2]
         nums[indices::first()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -356,10 +356,10 @@ fn test_list_method_result_as_index() {
 #[test]
 fn test_very_complex_nested_expression() {
     let code = r#"
-        get_offset = || 1
-        multiplier = 2
-        lists = [[10, 20], [30, 40], [50, 60]]
-        idx = get_offset() * multiplier - 1
+        let get_offset = || 1
+        let multiplier = 2
+        let lists = [[10, 20], [30, 40], [50, 60]]
+        let idx = get_offset() * multiplier - 1
         lists[idx][get_offset()]
     "#;
     let result = eval_code(code).expect("Evaluation failed");
@@ -6,7 +6,7 @@ fn test_json_module_integration() {
     let result = eval_program(
         r#"
         import std:json
-        data = json:parse("{\"name\": \"Alice\", \"age\": 30}")
+        let data = json:parse("{\"name\": \"Alice\", \"age\": 30}")
         json:generate(data)
     "#,
     );
@@ -26,7 +26,7 @@ fn test_json_direct_imports() {
         r#"
         import std:json:parse
         import std:json:generate
-        data = parse("{\"value\": 42}")
+        let data = parse("{\"value\": 42}")
         generate(data)
     "#,
     );
@@ -44,9 +44,9 @@ fn test_json_roundtrip_integration() {
     let result = eval_program(
         r#"
         import std:json
-        original = { name: "Bob", hobbies: ["reading", "coding"] }
-        json_str = json:generate(original)
-        parsed = json:parse(json_str)
+        let original = { name: "Bob", hobbies: ["reading", "coding"] }
+        let json_str = json:generate(original)
+        let parsed = json:parse(json_str)
         parsed:name
     "#,
     );
@@ -59,15 +59,15 @@ fn test_json_nested_structures() {
     let result = eval_program(
         r#"
         import std:json
-        complex = {
+        let complex = {
             users: [
                 { name: "Alice", age: 30 },
                 { name: "Bob", age: 25 }
             ],
             metadata: { version: "1.0", count: 2 }
         }
-        json_str = json:generate(complex)
-        parsed = json:parse(json_str)
+        let json_str = json:generate(complex)
+        let parsed = json:parse(json_str)
         parsed:metadata:version
     "#,
     );
@@ -80,9 +80,9 @@ fn test_json_with_boolean_values() {
     let result = eval_program(
         r#"
         import std:json
-        data = { active: true, disabled: false, count: 0 }
-        json_str = json:generate(data)
-        parsed = json:parse(json_str)
+        let data = { active: true, disabled: false, count: 0 }
+        let json_str = json:generate(data)
+        let parsed = json:parse(json_str)
         parsed:active
     "#,
     );
@@ -95,12 +95,94 @@ fn test_json_with_nil_values() {
     let result = eval_program(
         r#"
         import std:json
-        data = { value: nil, name: "test" }
-        json_str = json:generate(data)
-        parsed = json:parse(json_str)
+        let data = { value: nil, name: "test" }
+        let json_str = json:generate(data)
+        let parsed = json:parse(json_str)
         parsed:value
     "#,
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Nil);
 }
+
+#[test]
+fn test_json_numeric_map_keys_round_trip() {
+    let result = eval_program(
+        r#"
+        import std:json
+        let scores = { 1: "gold", 2.50: "silver", name: "race" }
+        let text = json:generate(scores)
+        let plain = json:parse(text)
+        let restored = json:parse(text, { parse_numeric_keys: true })
+        let outcome = (text, plain::contains("1"), plain::contains(1), restored == scores)
+        outcome
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::String(r#"{"1":"gold","2.5":"silver","name":"race"}"#.to_string()),
+            Value::Boolean(true),
+            Value::Boolean(false),
+            Value::Boolean(true),
+        ])
+    );
+}
+
+#[test]
+fn test_json_rejects_boolean_and_tuple_map_keys() {
+    for source in [
+        "import std:json\njson:generate({ true: 1 })",
+        "import std:json\njson:generate({ (1, 2): 1 })",
+    ] {
+        let err = eval_program(source).unwrap_err();
+        assert!(err.to_string().contains("cannot be converted to JSON"));
+    }
+    assert!(eval_program("import std:json\njson:parse(\"{}\", { numeric: true })").is_err());
+    assert!(eval_program("import std:json\njson:parse(\"{}\", true)").is_err());
+}
+
+#[test]
+fn test_json_path_queries() {
+    let result = eval_program(
+        r#"
+        import std:json
+        let data = json:parse("{\"users\": [{\"name\": \"Ann\", \"id\": 1}, {\"name\": \"Ben\", \"id\": 2}]}")
+        let first = json:path(data, "$.users[0].name")
+        let names = json:path(data, "$.users[*].name")
+        let last = json:path(data, "$.users[-1].id")
+        let missing = json:path(data, "$.users[5].name")
+        "${first} ${names} ${last} ${missing}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("[Ann] [Ann, Ben] [2] []".to_string())
+    );
+
+    assert!(eval_program("import std:json\njson:path({}, \"$.users[0\")").is_err());
+    assert!(eval_program("import std:json\njson:path({}, \"users\")").is_err());
+}
+
+#[test]
+fn test_json_parse_relaxed() {
+    let result = eval_program(
+        r#"
+        import std:json
+        let text = '{
+            // server
+            host: "localhost",
+            port: 8080, /* default */
+            tags: ["a", "b",],
+        }'
+        let relaxed = json:parse_relaxed(text)
+        let strict = json:parse('{"host": "localhost", "port": 8080, "tags": ["a", "b"]}')
+        relaxed == strict
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+
+    // json:parse stays strict
+    assert!(eval_program("import std:json\njson:parse('{a: 1}')").is_err());
+    assert!(eval_program("import std:json\njson:parse_relaxed('{a: }')").is_err());
+}
@@ -34,10 +34,10 @@ fn test_map_access_comprehensive() {
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
     // Test map access on variables
-    let result = eval_program("data = {\"name\": \"Alice\", \"age\": 30}\ndata:name").unwrap();
+    let result = eval_program("let data = {\"name\": \"Alice\", \"age\": 30}\ndata:name").unwrap();
     assert_eq!(result, Value::String("Alice".to_string()));
 
-    let result = eval_program("data = {\"name\": \"Alice\", \"age\": 30}\ndata:age").unwrap();
+    let result = eval_program("let data = {\"name\": \"Alice\", \"age\": 30}\ndata:age").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(30)));
 
     // Test map access on complex expressions
@@ -69,13 +69,13 @@ fn test_map_access_comprehensive() {
 fn test_map_keys_method_integration() {
     let result = eval_program(
         r#"
-        config = { 
+        let config = { 
             name: "Alice", 
             age: 30, 
             city: "New York",
             active: true
         }
-        keys = config::keys()
+        let keys = config::keys()
         keys::length()
     "#,
     );
@@ -87,8 +87,8 @@ fn test_map_keys_method_integration() {
 fn test_map_values_method_integration() {
     let result = eval_program(
         r#"
-        scores = { 1: 100, 2: 85, 3: 92 }
-        values = scores::values()
+        let scores = { 1: 100, 2: 85, 3: 92 }
+        let values = scores::values()
         values::length()
     "#,
     );
@@ -100,8 +100,8 @@ fn test_map_values_method_integration() {
 fn test_map_to_list_method_integration() {
     let result = eval_program(
         r#"
-        user = { name: "Bob", age: 25 }
-        pairs = user::to_list()
+        let user = { name: "Bob", age: 25 }
+        let pairs = user::to_list()
         pairs::length()
     "#,
     );
@@ -113,7 +113,7 @@ fn test_map_to_list_method_integration() {
 fn test_map_length_method_integration() {
     let result = eval_program(
         r#"
-        data = { a: 1, b: 2, c: 3, d: 4, e: 5 }
+        let data = { a: 1, b: 2, c: 3, d: 4, e: 5 }
         data::length()
     "#,
     );
@@ -121,11 +121,53 @@ fn test_map_length_method_integration() {
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(5)));
 }
 
+#[test]
+fn test_map_deep_set_builds_nested_config() {
+    let result = eval_program(
+        r#"
+        let config = {}
+        config::deep_set(["server", "tls", "port"], 8443)
+        config::deep_set(["server", "hosts"], ["a", "b"])
+        config::deep_set(["server", "hosts", -1], "c")
+        "${config:server:tls:port} ${config:server:hosts}"
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::String("8443 [a, c]".to_string()));
+}
+
+#[test]
+fn test_map_deep_get_missing_paths() {
+    let result = eval_program(
+        r#"
+        let data = { users: [{ name: "Ann" }, { name: "Bob" }] }
+        let found = data::deep_get(["users", 1, "name"])
+        let missing = data::deep_get(["users", 5, "name"])
+        let fallback = data::deep_get(["settings", "theme"], "light")
+        "${found} ${missing} ${fallback}"
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::String("Bob nil light".to_string()));
+}
+
+#[test]
+fn test_map_deep_set_errors() {
+    // Cannot descend into a scalar
+    let result = eval_program(r#"let m = { a: "text" }; m::deep_set(["a", "b"], 1)"#);
+    assert!(result.is_err());
+
+    // List segments must refer to existing elements
+    let result = eval_program(r#"let m = { items: [1] }; m::deep_set(["items", 3], 1)"#);
+    assert!(result.is_err());
+
+    let result = eval_program(r#"let m = {}; m::deep_set([], 1)"#);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_map_methods_combined_usage() {
     let result = eval_program(
         r#"
-        config = { 
+        let config = { 
             name: "Alice", 
             age: 30, 
             city: "New York",
@@ -133,10 +175,10 @@ fn test_map_methods_combined_usage() {
         }
         
         # Test all methods together
-        key_count = config::keys()::length()
-        value_count = config::values()::length()
-        pair_count = config::to_list()::length()
-        direct_count = config::length()
+        let key_count = config::keys()::length()
+        let value_count = config::values()::length()
+        let pair_count = config::to_list()::length()
+        let direct_count = config::length()
         
         # All should be equal
         key_count == value_count && value_count == pair_count && pair_count == direct_count
@@ -150,22 +192,22 @@ fn test_map_methods_combined_usage() {
 fn test_map_methods_with_iteration() {
     let result = eval_program(
         r#"
-        scores = { math: 95, science: 87, english: 92 }
+        let scores = { math: 95, science: 87, english: 92 }
         
         # Iterate over keys
-        key_count = 0
+        let key_count = 0
         loop through scores::keys() with key {
             key_count = key_count + 1
         }
         
         # Iterate over values
-        value_count = 0
+        let value_count = 0
         loop through scores::values() with value {
             value_count = value_count + 1
         }
         
         # Iterate over pairs
-        pair_count = 0
+        let pair_count = 0
         loop through scores::to_list() with pair {
             pair_count = pair_count + 1
         }
@@ -186,8 +228,8 @@ fn test_map_methods_with_iteration() {
 fn test_map_iteration_no_bindings() {
     let result = eval_program(
         r#"
-        map = { a: 1, b: 2 }
-        count = 0
+        let map = { a: 1, b: 2 }
+        let count = 0
         loop through map {
             count = count + 1
         }
@@ -202,8 +244,8 @@ fn test_map_iteration_no_bindings() {
 fn test_map_iteration_one_binding() {
     let result = eval_program(
         r#"
-        map = { a: 1, b: 2 }
-        result = ""
+        let map = { a: 1, b: 2 }
+        let result = ""
         loop through map with k {
             result = result + k + " "
         }
@@ -224,8 +266,8 @@ fn test_map_iteration_one_binding() {
 fn test_map_iteration_two_bindings() {
     let result = eval_program(
         r#"
-        map = { a: 10, b: 20 }
-        total = 0
+        let map = { a: 10, b: 20 }
+        let total = 0
         loop through map with k, v {
             total = total + v
         }
@@ -240,8 +282,8 @@ fn test_map_iteration_two_bindings() {
 fn test_map_iteration_empty_map() {
     let result = eval_program(
         r#"
-        empty = {}
-        count = 0
+        let empty = {}
+        let count = 0
         loop through empty with k, v {
             count = count + 1
         }
@@ -256,10 +298,10 @@ fn test_map_iteration_empty_map() {
 fn test_map_iteration_variable_scoping() {
     let result = eval_program(
         r#"
-        outer = "original"
-        map = { key: "value" }
+        let outer = "original"
+        let map = { key: "value" }
         loop through map with k, v {
-            inner = "inside"
+            let inner = "inside"
             outer = "modified"
         }
         outer
@@ -273,8 +315,8 @@ fn test_map_iteration_variable_scoping() {
 fn test_map_iteration_control_flow_break() {
     let result = eval_program(
         r#"
-        map = { a: 1, b: 2, c: 3, d: 4 }
-        result = ""
+        let map = { a: 1, b: 2, c: 3, d: 4 }
+        let result = ""
         loop through map with k, v {
             match k {
                 "c" => { break },
@@ -299,8 +341,8 @@ fn test_map_iteration_control_flow_break() {
 fn test_map_iteration_mixed_key_types() {
     let result = eval_program(
         r#"
-        mixed = { "string": 1, 42: "number", true: false }
-        count = 0
+        let mixed = { "string": 1, 42: "number", true: false }
+        let count = 0
         loop through mixed with k, v {
             count = count + 1
         }
@@ -315,11 +357,11 @@ fn test_map_iteration_mixed_key_types() {
 fn test_map_iteration_nested_maps() {
     let result = eval_program(
         r#"
-        nested = {
+        let nested = {
             user: { name: "Alice" },
             settings: { theme: "dark" }
         }
-        count = 0
+        let count = 0
         loop through nested with section, data {
             count = count + 1
         }
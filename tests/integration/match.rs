@@ -1,14 +1,15 @@
 use suji_values::DecimalNumber;
 
-use super::common::eval_program;
+use super::common::{assert_parse_fails, eval_program, parse_program};
+use suji_parser::ParseError;
 use suji_values::Value;
 
 #[test]
 fn test_nil_pattern_matching() {
     let result = eval_program(
         r#"
-        user = nil
-        result = match user {
+        let user = nil
+        let result = match user {
             nil => "No user found",
             _ => "Some user found",
         }
@@ -23,7 +24,7 @@ fn test_nil_pattern_matching() {
 fn test_return_statements_in_match_arms() {
     let result = eval_program(
         r#"
-        f = || {
+        let f = || {
             match 1 {
                 1 => return 1,
                 2 => return 2,
@@ -41,7 +42,7 @@ fn test_return_statements_in_match_arms() {
 fn test_implicit_returns_in_match_arms() {
     let result = eval_program(
         r#"
-        g = || {
+        let g = || {
             match 1 {
                 1 => 1,
                 2 => 2,
@@ -59,7 +60,7 @@ fn test_implicit_returns_in_match_arms() {
 fn test_map_literals_in_match_arms() {
     let result = eval_program(
         r#"
-        result = match 1 {
+        let result = match 1 {
             1 => { "status": "success", "value": 42 },
             2 => { "status": "error", "message": "failed" },
             _ => { "status": "unknown" },
@@ -76,7 +77,7 @@ fn test_mixed_match_arm_syntax() {
     let result = eval_program(
         r#"
         import std:println
-        h = || {
+        let h = || {
             match 1 {
                 1 => {
                     println("one")
@@ -97,14 +98,14 @@ fn test_mixed_match_arm_syntax() {
 fn test_complex_nil_handling() {
     let result = eval_program(
         r#"
-        find_user = |id| {
+        let find_user = |id| {
             match id {
                 1 => { "name": "Alice" },
                 2 => { "name": "Bob" },
                 _ => nil,
             }
         }
-        user_info = match find_user(3) {
+        let user_info = match find_user(3) {
             nil => "User not found",
             user => "User: " + user:name,
         }
@@ -123,8 +124,8 @@ fn test_complex_nil_handling() {
 fn test_conditional_match_basic() {
     let result = eval_program(
         r#"
-        x = 5
-        result = match {
+        let x = 5
+        let result = match {
             x > 0 => "positive",
             _ => "non-positive",
         }
@@ -139,8 +140,8 @@ fn test_conditional_match_basic() {
 fn test_conditional_match_multiple_conditions() {
     let result = eval_program(
         r#"
-        x = 7
-        result = match {
+        let x = 7
+        let result = match {
             x > 10 => "very large",
             x > 5 => "large",
             x > 0 => "positive",
@@ -157,9 +158,9 @@ fn test_conditional_match_multiple_conditions() {
 fn test_conditional_match_complex_boolean() {
     let result = eval_program(
         r#"
-        x = 3
-        y = 4
-        result = match {
+        let x = 3
+        let y = 4
+        let result = match {
             x > 0 && y > 0 => "both positive",
             x > 0 => "only x positive",
             y > 0 => "only y positive",
@@ -176,7 +177,7 @@ fn test_conditional_match_complex_boolean() {
 fn test_conditional_match_in_function() {
     let result = eval_program(
         r#"
-        f = |x| {
+        let f = |x| {
             return match {
                 x > 10 => "very large",
                 x > 5 => "large",
@@ -195,16 +196,16 @@ fn test_conditional_match_in_function() {
 fn test_conditional_match_mixed_with_traditional() {
     let result = eval_program(
         r#"
-        x = 2
-        y = 3
+        let x = 2
+        let y = 3
 
-        result1 = match x {
+        let result1 = match x {
             1 => "one",
             2 => "two",
             _ => "other",
         }
 
-        result2 = match {
+        let result2 = match {
             y > 2 => "y is large",
             _ => "y is small",
         }
@@ -220,8 +221,8 @@ fn test_conditional_match_mixed_with_traditional() {
 fn test_conditional_match_all_conditions_false() {
     let result = eval_program(
         r#"
-        x = -5
-        result = match {
+        let x = -5
+        let result = match {
             x > 0 => "positive",
             x > -10 => "small negative",
             _ => "large negative",
@@ -237,8 +238,8 @@ fn test_conditional_match_all_conditions_false() {
 fn test_conditional_match_with_wildcard() {
     let result = eval_program(
         r#"
-        x = 0
-        result = match {
+        let x = 0
+        let result = match {
             x > 0 => "positive",
             x < 0 => "negative",
             _ => "zero",
@@ -254,9 +255,9 @@ fn test_conditional_match_with_wildcard() {
 fn test_conditional_match_nested() {
     let result = eval_program(
         r#"
-        x = 3
-        y = 4
-        result = match {
+        let x = 3
+        let y = 4
+        let result = match {
             x > 0 => match {
                 y > 0 => "both positive",
                 _ => "x positive, y non-positive",
@@ -274,9 +275,9 @@ fn test_conditional_match_nested() {
 fn test_conditional_match_in_binary_operation() {
     let result = eval_program(
         r#"
-        x = 5
-        y = 3
-        result = match {
+        let x = 5
+        let y = 3
+        let result = match {
             x > 0 => 10,
             _ => 0,
         } + match {
@@ -294,8 +295,8 @@ fn test_conditional_match_in_binary_operation() {
 fn test_conditional_match_with_string_comparison() {
     let result = eval_program(
         r#"
-        name = "alice"
-        result = match {
+        let name = "alice"
+        let result = match {
             name == "alice" => "Hello Alice",
             name == "bob" => "Hello Bob",
             _ => "Hello stranger",
@@ -306,3 +307,267 @@ fn test_conditional_match_with_string_comparison() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::String("Hello Alice".to_string()));
 }
+
+#[test]
+fn test_alternation_patterns() {
+    let result = eval_program(
+        r#"
+        let classify = |x| match x {
+            1 | 2 | 3 => "low",
+            10..20 | 100 => "high",
+            (0 | 1, "on" | "yes") => "enabled",
+            (_, :nil | :boolean) => "flag",
+            _ => "other",
+        }
+        let values = [2, 15, 100, (1, "yes"), (0, "on"), (5, true), (5, "x"), 4]
+        values::map(classify)::join(",")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("low,high,high,enabled,enabled,flag,other,other".to_string())
+    );
+}
+
+#[test]
+fn test_alternation_arm_body_can_use_pipes_and_closures() {
+    // '|' separates alternatives only before '=>'; bodies keep their usual meaning
+    let result = eval_program(
+        r#"
+        let x = 2
+        match x {
+            1 | 2 => [x, x]::map(|n| n * 10) |> |xs| xs::sum(),
+            _ => 0,
+        }
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Number(suji_values::DecimalNumber::from_i64(40))
+    );
+}
+
+#[test]
+fn test_type_pattern_dispatch() {
+    let result = eval_program(
+        r#"
+        let describe = |x| match x {
+            :number => "number",
+            :string | :list => "sequence",
+            :map => "map",
+            (:number, :string) => "pair",
+            _ => "other",
+        }
+        let values = [1, "a", [1], { k: 1 }, (1, "a"), true]
+        values::map(describe)::join(",")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("number,sequence,sequence,map,pair,other".to_string())
+    );
+}
+
+#[test]
+fn test_range_pattern_dispatch() {
+    let result = eval_program(
+        r#"
+        let size = |x| match x {
+            0 => "zero",
+            1..10 => "small",
+            10..=99 => "medium",
+            -100..0 => "negative",
+            _ => "other",
+        }
+        let values = [0, 1, 9.5, 10, 99, -3, 100, "5"]
+        values::map(size)::join(",")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("zero,small,small,medium,medium,negative,other,other".to_string())
+    );
+}
+
+#[test]
+fn test_binding_pattern_binds_matched_value() {
+    let result = eval_program(
+        r#"
+        let describe = |p| match p {
+            pt @ (0, _) => "on axis ${pt}",
+            (x @ 1..10, y @ _) => "small ${x},${y}",
+            n @ 1 | n @ 2 => "tiny ${n}",
+            n @ :number => n * 10,
+            _ => "other",
+        }
+        let points = [(0, 5), (3, 4), 2, 7, "s"]
+        points::map(describe)::join(";")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("on axis (0, 5);small 3,4;tiny 2;70;other".to_string())
+    );
+}
+
+#[test]
+fn test_binding_pattern_does_not_leak_out_of_arm() {
+    let result = eval_program(
+        r#"
+        let n = "outer"
+        let inner = match 5 {
+            n @ :number => n,
+        }
+        "${n} ${inner}"
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::String("outer 5".to_string()));
+}
+
+#[test]
+fn test_string_affix_patterns_bind_the_rest() {
+    let result = eval_program(
+        r#"
+        let dispatch = |cmd| match cmd {
+            "go " + dir => "move ${dir}",
+            "say " + _ => "talk",
+            "<" + tag + ">" => "tag ${tag}",
+            name + ".txt" => "text ${name}",
+            _ => "other",
+        }
+        let cmds = ["go north", "say hi", "<b>", "notes.txt", "go", "<", 42]
+        cmds::map(dispatch)::join(";")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("move north;talk;tag b;text notes;other;other;other".to_string())
+    );
+}
+
+#[test]
+fn test_list_patterns_with_rest_anywhere() {
+    let result = eval_program(
+        r#"
+        let describe = |xs| match xs {
+            [] => "empty",
+            [only @ _] => "one ${only}",
+            ["cmd", ...args] => "cmd with ${args::length()}",
+            [first @ _, ...middle, last @ :number] => "${first}..${last} around ${middle::length()}",
+            [..._, "end"] => "ends",
+            _ => "other",
+        }
+        let inputs = [[], [1], ["cmd"], ["cmd", "-v", "x"], [1, 9], [1, 2, 3, 9], ["a", "end"], ["a", "b"], (1, 2)]
+        inputs::map(describe)::join(";")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String(
+            "empty;one 1;one cmd;cmd with 2;1..9 around 0;1..9 around 2;ends;other;other"
+                .to_string()
+        )
+    );
+
+    assert_parse_fails(
+        "match x { [a, ...b, ...c] => 1, }",
+        "A list pattern can have at most one '...' rest",
+    );
+    assert_parse_fails(
+        "match x { [first @ _, ...first] => 1, }",
+        "'first' is bound more than once in the same pattern",
+    );
+}
+
+#[test]
+fn test_match_scrutinee_is_evaluated_once() {
+    let result = eval_program(
+        r#"
+        let calls = 0
+        let mode = |value| {
+            calls = calls + 1
+            return value
+        }
+        let results = []
+        loop through ["a", "b", "c", "z"] with name {
+            let kind = match mode(name) {
+                "a" => "first",
+                "b" => "second",
+                "c" => "third",
+                _ => "none",
+            }
+            results::push(kind)
+        }
+        "${results::join(",")} ${calls}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("first,second,third,none 4".to_string())
+    );
+}
+
+#[test]
+fn test_else_arm_catches_everything_else() {
+    let result = eval_program(
+        r#"
+        let describe = |value| match value {
+            0 => "zero",
+            else => "other",
+        }
+        let grade = |score| match {
+            score >= 90 => "A",
+            else => { "B or lower" }
+        }
+        let else_name = match "else" {
+            "x" => 1,
+            else => 2,
+        }
+        let results = [describe(0), describe("x"), grade(95), grade(50), else_name]
+        results::join(";")
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("zero;other;A;B or lower;2".to_string())
+    );
+
+    assert_parse_fails(
+        "match 1 { 1 => 1, _ => 2, else => 3, }",
+        "Duplicate catch-all arms",
+    );
+    assert_parse_fails("match { else => 1, _ => 2, }", "Duplicate catch-all arms");
+    assert_parse_fails(
+        "match 1 { else => 1, else => 2, }",
+        "Duplicate catch-all arms",
+    );
+}
+
+#[test]
+fn test_duplicate_catch_all_error_points_at_second_arm() {
+    let cases = [
+        (
+            "match 1 { 1 => 1, _ => 2, else => 3, }",
+            "else => 3",
+            "Duplicate catch-all arms in match: use either `_ =>` or `else =>`, not both",
+        ),
+        (
+            "match { else => 1, _ => 2, }",
+            "_ => 2",
+            "Duplicate catch-all arms in match: use either `_ =>` or `else =>`, not both",
+        ),
+        (
+            "match 1 { else => 1, else => 2, }",
+            "else => 2",
+            "Duplicate catch-all arms in match: `else` appears more than once",
+        ),
+    ];
+    for (source, second_arm, expected) in cases {
+        let error = parse_program(source).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+        let ParseError::DuplicateCatchAll { span, .. } = error else {
+            panic!("expected a duplicate catch-all error, got {:?}", error);
+        };
+        assert_eq!(span.start, source.find(second_arm).unwrap(), "{}", source);
+    }
+}
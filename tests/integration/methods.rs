@@ -1,6 +1,6 @@
 use suji_values::DecimalNumber;
 
-use super::common::{eval_program, eval_string_expr};
+use super::common::{assert_eval_fails, eval_program, eval_string_expr};
 use suji_values::Value;
 
 #[test]
@@ -50,7 +50,7 @@ fn test_method_calls() {
         Value::String("hahaha".to_string())
     );
     assert_eq!(
-        eval_program("list = [1, 2, 3, 4]\nlist::length()").unwrap(),
+        eval_program("let list = [1, 2, 3, 4]\nlist::length()").unwrap(),
         Value::Number(DecimalNumber::from_i64(4))
     );
     assert_eq!(
@@ -66,9 +66,9 @@ fn test_pipe_single_hop() {
 import std:println
 import std:io
 
-make_destination = || {
+let make_destination = || {
     return || {
-        lines = io:stdin::read_lines()
+        let lines = io:stdin::read_lines()
         match lines::length() > 0 {
             true => { "output received" },
             false => { "no output" },
@@ -76,13 +76,13 @@ make_destination = || {
     }
 }
 
-make_source = || {
+let make_source = || {
     return || {
         println("test")
     }
 }
 
-result = make_source() | make_destination()
+let result = make_source() | make_destination()
 result
 "#;
 
@@ -147,15 +147,15 @@ fn test_number_methods() {
 #[test]
 fn test_number_method_expressions() {
     assert_eq!(
-        eval_program("x = -5\ny = x::abs()\ny").unwrap(),
+        eval_program("let x = -5\nlet y = x::abs()\ny").unwrap(),
         Value::Number(DecimalNumber::from_i64(5))
     );
     assert_eq!(
-        eval_program("x = -3.7\ny = x::abs()::ceil()\ny").unwrap(),
+        eval_program("let x = -3.7\nlet y = x::abs()::ceil()\ny").unwrap(),
         Value::Number(DecimalNumber::from_i64(4))
     );
     assert_eq!(
-        eval_program("x = 2\ny = 3\nz = x::pow(y) + 5::sqrt()\nz").unwrap(),
+        eval_program("let x = 2\nlet y = 3\nlet z = x::pow(y) + 5::sqrt()\nz").unwrap(),
         Value::Number(DecimalNumber::parse("10.236067977499789696409173669").unwrap())
     );
 
@@ -163,3 +163,164 @@ fn test_number_method_expressions() {
     assert!(eval_string_expr("2::pow(\"invalid\")").is_err());
     assert!(eval_string_expr("10::min(\"invalid\")").is_err());
 }
+
+#[test]
+fn test_clone_and_assignment_do_not_alias() {
+    let result = eval_program(
+        r#"
+        let a = [1, [2, 3]]
+        let b = a::clone()
+        b::push(4)
+        b[1] = [9]
+        let c = a
+        c::push(5)
+        let m = { k: [1] }
+        let n = m::clone()
+        n:k = [2]
+        let result = (a, b, c, m:k, n:k)
+        result
+    "#,
+    )
+    .unwrap();
+    let num = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+    let list = |items: Vec<Value>| Value::List(items);
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            list(vec![num(1), list(vec![num(2), num(3)])]),
+            list(vec![num(1), list(vec![num(9)]), num(4)]),
+            list(vec![num(1), list(vec![num(2), num(3)]), num(5)]),
+            list(vec![num(1)]),
+            list(vec![num(2)]),
+        ])
+    );
+}
+
+#[test]
+fn test_list_min_by_and_max_by() {
+    let result = eval_program(
+        r#"
+        let people = [
+            { name: "ann", score: 7 },
+            { name: "bob", score: 9 },
+            { name: "cat", score: 3 },
+            { name: "dan", score: 9 },
+            { name: "eve", score: 3 },
+        ]
+        let best = people::max_by(|p| p:score)
+        let worst = people::min_by(|p| p:score)
+        let shortest = ["ccc", "a", "bb"]::min_by(|s| s::length())
+        let result = (best:name, worst:name, shortest)
+        result
+    "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::String("bob".to_string()),
+            Value::String("cat".to_string()),
+            Value::String("a".to_string()),
+        ])
+    );
+
+    assert_eval_fails("[]::max_by(|x| x)", "max_by() called on empty list");
+    assert_eval_fails(
+        r#"[1, "a"]::min_by(|x| x)"#,
+        "min_by() keys must be comparable",
+    );
+}
+
+#[test]
+fn test_list_dedup_consecutive() {
+    let result = eval_program(
+        r#"
+        let runs = [1, 1, 2, 1, 1, 1, 3]::dedup_consecutive()
+        let words = ["Apple", "apple", "pear", "APPLE"]::dedup_consecutive_by(|w| w::lower())
+        let result = (runs, words, []::dedup_consecutive())
+        result
+    "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::List(vec![
+                Value::Number(DecimalNumber::from_i64(1)),
+                Value::Number(DecimalNumber::from_i64(2)),
+                Value::Number(DecimalNumber::from_i64(1)),
+                Value::Number(DecimalNumber::from_i64(3)),
+            ]),
+            Value::List(vec![
+                Value::String("Apple".to_string()),
+                Value::String("pear".to_string()),
+                Value::String("APPLE".to_string()),
+            ]),
+            Value::List(vec![]),
+        ])
+    );
+
+    assert_eval_fails(
+        "[1]::dedup_consecutive(1)",
+        "dedup_consecutive() takes no arguments",
+    );
+}
+
+#[test]
+fn test_list_reduce_and_fold_right() {
+    let result = eval_program(
+        r#"
+        let words = ["a", "b", "c"]
+        let result = (
+            [3, 1, 4, 1, 5]::reduce(|best, x| match x > best { true => x, false => best, }),
+            [7]::reduce(|acc, x| acc + x),
+            words::fold_right("", |acc, x| acc + x),
+            []::fold_right("seed", |acc, x| acc + x),
+        )
+        result
+    "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::Number(DecimalNumber::from_i64(5)),
+            Value::Number(DecimalNumber::from_i64(7)),
+            Value::String("cba".to_string()),
+            Value::String("seed".to_string()),
+        ])
+    );
+
+    assert_eval_fails(
+        "[]::reduce(|acc, x| acc + x)",
+        "reduce() called on empty list",
+    );
+}
+
+#[test]
+fn test_list_frequency() {
+    let result = eval_program(
+        r#"
+        let counts = ["b", "a", "b", "c", "b", "a"]::frequency()
+        let mixed = [1, 1.0, true, (1, 2), (1, 2)]::frequency()
+        let result = (counts::to_list(), mixed::values(), []::frequency()::length())
+        result
+    "#,
+    )
+    .unwrap();
+    let n = |n: i64| Value::Number(DecimalNumber::from_i64(n));
+    let pair = |key: &str, count: i64| Value::Tuple(vec![Value::String(key.to_string()), n(count)]);
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::List(vec![pair("b", 3), pair("a", 2), pair("c", 1)]),
+            Value::List(vec![n(2), n(1), n(2)]),
+            n(0),
+        ])
+    );
+
+    assert_eval_fails(
+        "[1, [2]]::frequency()",
+        "frequency() element 1 is a list, which cannot be used as a map key",
+    );
+}
@@ -10,7 +10,7 @@ fn backtick_left_to_closure() {
     let program = r#"
 import std:io
 
-destination = || {
+let destination = || {
     loop through io:stdin::read_lines() with line {
         match {
             line ~ /test/ => return "output received",
@@ -18,7 +18,7 @@ destination = || {
     }
 }
 
-out = `echo test` | destination()
+let out = `echo test` | destination()
 out
 "#;
     let result = eval_program(program).unwrap();
@@ -30,7 +30,7 @@ fn closure_to_backtick_right_preserves_newline() {
     let program = r#"
 import std:println
 
-make_source = || {
+let make_source = || {
     return || {
         println("alpha")
         println("beta")
@@ -38,7 +38,7 @@ make_source = || {
     }
 }
 
-out = make_source() | `grep beta`
+let out = make_source() | `grep beta`
 out::replace("\n", "\\n")
 "#;
     let result = eval_program(program).unwrap();
@@ -51,7 +51,7 @@ fn closure_to_backtick_to_closure_middle() {
 import std:println
 import std:io
 
-make_source = || {
+let make_source = || {
     return || {
         println("alpha")
         println("beta")
@@ -59,7 +59,7 @@ make_source = || {
     }
 }
 
-make_sink = || {
+let make_sink = || {
     loop through io:stdin::read_lines() with line {
         match {
             line ~ /beta/ => return "beta received",
@@ -67,7 +67,7 @@ make_sink = || {
     }
 }
 
-out = make_source() | `grep beta` | make_sink()
+let out = make_source() | `grep beta` | make_sink()
 out
 "#;
     let result = eval_program(program).unwrap();
@@ -77,7 +77,7 @@ out
 #[test]
 fn commands_only_pipeline() {
     let program = r#"
-out = `printf 'line1\nline2\n'` | `grep line2`
+let out = `printf 'line1\nline2\n'` | `grep line2`
 out::replace("\n", "\\n")
 "#;
     let result = eval_program(program).unwrap();
@@ -89,11 +89,11 @@ fn bare_function_stages_error() {
     let program = r#"
 import std:println
 
-source = || {
+let source = || {
     println("value")
 }
 
-destination = || {
+let destination = || {
     println("sink")
 }
 
@@ -112,13 +112,13 @@ fn pipelines_accept_invocation_builders() {
 import std:println
 import std:io
 
-producer = |left, right| {
+let producer = |left, right| {
     return || {
         println("${left},${right}")
     }
 }
 
-consumer = |pattern| {
+let consumer = |pattern| {
     return || {
         loop through io:stdin::read_lines() with line {
             match {
@@ -128,7 +128,7 @@ consumer = |pattern| {
     }
 }
 
-out = producer("alpha", "beta") | consumer(/beta/)
+let out = producer("alpha", "beta") | consumer(/beta/)
 out
 "#;
 
@@ -143,8 +143,8 @@ out
 #[test]
 fn forward_apply_basic() {
     let src = r#"
-inc = |x| x + 1
-double = |x| x * 2
+let inc = |x| x + 1
+let double = |x| x * 2
 3 |> inc
 "#;
     let v = eval_program(src).unwrap();
@@ -154,8 +154,8 @@ double = |x| x * 2
 #[test]
 fn forward_apply_chain() {
     let src = r#"
-inc = |x| x + 1
-double = |x| x * 2
+let inc = |x| x + 1
+let double = |x| x * 2
 3 |> inc |> double
 "#;
     let v = eval_program(src).unwrap();
@@ -165,7 +165,7 @@ double = |x| x * 2
 #[test]
 fn precedence_with_arithmetic() {
     let src = r#"
-inc = |x| x + 1
+let inc = |x| x + 1
 2 * 3 |> inc
 "#;
     let v = eval_program(src).unwrap();
@@ -175,7 +175,7 @@ inc = |x| x + 1
 #[test]
 fn defaults_honored() {
     let src = r#"
-add = |x, y = 10| x + y
+let add = |x, y = 10| x + y
 5 |> add
 "#;
     let v = eval_program(src).unwrap();
@@ -187,16 +187,16 @@ fn builtin_with_pipe_apply() {
     let program1 = r#"
 import std:json
 
-s = '{ "name": "Philip" }'
+let s = '{ "name": "Philip" }'
 
 s |> json:parse
 "#;
     let program2 = r#"
 import std:json
 
-s = '{ "name": "Philip" }'
+let s = '{ "name": "Philip" }'
 
-parse_wrapper = |x| json:parse(x)
+let parse_wrapper = |x| json:parse(x)
 
 s |> parse_wrapper
 "#;
@@ -210,8 +210,8 @@ s |> parse_wrapper
 fn mixing_with_stream_pipe_grouping_parse_only() {
     // Ensure parsing groups as (a |> f) | g; we don't execute an actual stream stage here
     let src = r#"
-f = |x| x
-g = |x| x
+let f = |x| x
+let g = |x| x
 1 |> f
 "#;
     let v = eval_program(src).unwrap();
@@ -233,19 +233,19 @@ fn mixed_precedence_stream_then_apply() {
 import std:println
 import std:io
 
-producer = || {
+let producer = || {
     println("foo")
     println("bar")
     println("baz")
 }
 
-counter = || {
+let counter = || {
     io:stdin::read_lines()::length()
 }
 
-format = |n| "Count: ${n}"
+let format = |n| "Count: ${n}"
 
-result = producer() | `grep bar` | counter() |> format
+let result = producer() | `grep bar` | counter() |> format
 result
 "#;
     let result = eval_program(program).unwrap();
@@ -256,7 +256,7 @@ result
 fn test_pipe_apply_with_expression_left_side() {
     // Define an identity unary function and use |> with expression on the left
     let program2 = r#"
-        id = |s| s
+        let id = |s| s
         '{"k":2}' |> id
     "#;
     if let Value::String(s) = eval_program(program2).unwrap() {
@@ -273,8 +273,8 @@ fn test_pipe_apply_with_expression_left_side() {
 #[test]
 fn backward_apply_call() {
     let src = r#"
-inc = |x| x + 1
-double = |x| x * 2
+let inc = |x| x + 1
+let double = |x| x * 2
 double <| inc(3)
 "#;
     let v = eval_program(src).unwrap();
@@ -284,8 +284,8 @@ double <| inc(3)
 #[test]
 fn backward_apply_right_associative() {
     let src = r#"
-inc = |x| x + 1
-double = |x| x * 2
+let inc = |x| x + 1
+let double = |x| x * 2
 double <| inc <| 3
 "#;
     let v = eval_program(src).unwrap();
@@ -304,7 +304,7 @@ fn type_errors_for_non_functions_backward() {
 #[test]
 fn test_pipe_apply_with_expression_right_side() {
     let program = r#"
-        id = |s| s
+        let id = |s| s
         id <| '{"k":3}'
     "#;
     if let Value::String(s) = eval_program(program).unwrap() {
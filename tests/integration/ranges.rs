@@ -89,7 +89,7 @@ fn test_exclusive_vs_inclusive() {
 #[test]
 fn test_inclusive_range_in_loop() {
     let code = r#"
-        sum = 0
+        let sum = 0
         loop through 1..=5 with i {
             sum = sum + i
         }
@@ -102,7 +102,7 @@ fn test_inclusive_range_in_loop() {
 #[test]
 fn test_inclusive_range_assignment() {
     let code = r#"
-        nums = 0..=3
+        let nums = 0..=3
         nums
     "#;
     let result = eval_program(code).unwrap();
@@ -128,7 +128,7 @@ fn test_inclusive_range_descending_10_to_5() {
 #[test]
 fn test_inclusive_range_in_list_method() {
     let code = r#"
-        nums = 1..=10
+        let nums = 1..=10
         nums::length()
     "#;
     let result = eval_program(code).unwrap();
@@ -138,7 +138,7 @@ fn test_inclusive_range_in_list_method() {
 #[test]
 fn test_inclusive_range_with_indexing() {
     let code = r#"
-        nums = 0..=10
+        let nums = 0..=10
         nums[5]
     "#;
     let result = eval_program(code).unwrap();
@@ -148,8 +148,8 @@ fn test_inclusive_range_with_indexing() {
 #[test]
 fn test_range_comparison() {
     let code = r#"
-        exclusive = 0..10
-        inclusive = 0..=10
+        let exclusive = 0..10
+        let inclusive = 0..=10
         exclusive::length() < inclusive::length()
     "#;
     let result = eval_program(code).unwrap();
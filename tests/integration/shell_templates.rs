@@ -8,7 +8,8 @@ fn test_shell_command_templates() {
     };
     assert_eq!(s, "hello");
 
-    let Value::String(s) = eval_program("name = \"world\"\n`echo hello ${name}`").unwrap() else {
+    let Value::String(s) = eval_program("let name = \"world\"\n`echo hello ${name}`").unwrap()
+    else {
         panic!("Expected string")
     };
     assert_eq!(s, "hello world");
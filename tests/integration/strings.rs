@@ -1,10 +1,11 @@
-use super::common::eval_program_with_modules;
+use super::common::{eval_program, eval_program_with_modules};
+use suji_values::Value;
 
 #[test]
 fn test_nested_string_double_quotes() {
     let input = r#"
 import std:println
-msg = "Hello ${"world"}"
+let msg = "Hello ${"world"}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -15,7 +16,7 @@ println(msg)
 fn test_nested_string_single_quotes() {
     let input = r#"
 import std:println
-msg = 'Hello ${"world"}'
+let msg = 'Hello ${"world"}'
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -26,7 +27,7 @@ println(msg)
 fn test_nested_string_with_concat() {
     let input = r#"
 import std:println
-msg = "Result: ${"Hello" + " " + "World"}"
+let msg = "Result: ${"Hello" + " " + "World"}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -37,9 +38,9 @@ println(msg)
 fn test_nested_string_with_expression() {
     let input = r#"
 import std:println
-x = 5
-y = 10
-msg = "Sum: ${x + y}"
+let x = 5
+let y = 10
+let msg = "Sum: ${x + y}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -50,8 +51,8 @@ println(msg)
 fn test_nested_string_with_function_call() {
     let input = r#"
 import std:println
-add = |a, b| a + b
-msg = "Result: ${add(3, 4)}"
+let add = |a, b| a + b
+let msg = "Result: ${add(3, 4)}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -62,8 +63,8 @@ println(msg)
 fn test_nested_string_with_method_call() {
     let input = r#"
 import std:println
-list = [1, 2, 3]
-msg = "Length: ${list::length()}"
+let list = [1, 2, 3]
+let msg = "Length: ${list::length()}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -74,8 +75,8 @@ println(msg)
 fn test_nested_string_with_map_access() {
     let input = r#"
 import std:println
-map = { name: "Alice", age: 30 }
-msg = "Name: ${map:name}"
+let map = { name: "Alice", age: 30 }
+let msg = "Name: ${map:name}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -86,8 +87,8 @@ println(msg)
 fn test_nested_string_with_nested_interpolation() {
     let input = r#"
 import std:println
-x = "world"
-msg = "Hello ${"${x}"}"
+let x = "world"
+let msg = "Hello ${"${x}"}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -98,8 +99,8 @@ println(msg)
 fn test_nested_string_with_list_access() {
     let input = r#"
 import std:println
-list = ["a", "b", "c"]
-msg = "First: ${list[0]}"
+let list = ["a", "b", "c"]
+let msg = "First: ${list[0]}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -110,8 +111,8 @@ println(msg)
 fn test_nested_string_with_conditional() {
     let input = r#"
 import std:println
-x = 5
-msg = "Value: ${match x > 0 { true => "positive", false => "negative", }}"
+let x = 5
+let msg = "Value: ${match x > 0 { true => "positive", false => "negative", }}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -122,8 +123,8 @@ println(msg)
 fn test_nested_string_with_match() {
     let input = r#"
 import std:println
-x = 2
-msg = "Result: ${match x { 1 => "one", 2 => "two", _ => "other", }}"
+let x = 2
+let msg = "Result: ${match x { 1 => "one", 2 => "two", _ => "other", }}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
@@ -134,12 +135,110 @@ println(msg)
 fn test_nested_string_complex_expression() {
     let input = r#"
 import std:println
-a = 3
-b = 4
-c = 5
-msg = "Sum: ${a + b * c}"
+let a = 3
+let b = 4
+let c = 5
+let msg = "Sum: ${a + b * c}"
 println(msg)
 "#;
     let result = eval_program_with_modules(input);
     assert!(result.is_ok(), "Evaluation failed: {:?}", result.err());
 }
+
+#[test]
+fn test_interpolation_format_specs() {
+    let result = eval_program(
+        r#"
+let pi = 3.14159
+let row = { name: "ab", qty: 7 }
+let items = [1, 2, 3]
+"${pi:.2}|${row:name:<4}|${row:qty:03}|${items[0:2]}|${row:qty}"
+"#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("3.14|ab  |007|[1, 2]|7".to_string())
+    );
+}
+
+#[test]
+fn test_loop_through_string_lines() {
+    let result = eval_program(
+        r#"
+let text = "alpha\r\nbeta\n\ngamma"
+let seen = []
+loop through text::lines() with line {
+    seen::push(line)
+}
+let count = 0
+loop through "one\ntwo\n"::lines() {
+    count = count + 1
+}
+let early = ""
+loop through "a\nb\nc"::lines() with line {
+    match line {
+        "b" => { break },
+        _ => { early = early + line },
+    }
+}
+(seen, count, early, text::lines() == seen)
+"#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::List(vec![
+                Value::String("alpha".to_string()),
+                Value::String("beta".to_string()),
+                Value::String(String::new()),
+                Value::String("gamma".to_string()),
+            ]),
+            Value::Number(suji_values::DecimalNumber::from_i64(2)),
+            Value::String("a".to_string()),
+            Value::Boolean(true),
+        ])
+    );
+}
+
+#[test]
+fn test_loop_through_lines_errors() {
+    assert!(eval_program("loop through 5::lines() with x { x }").is_err());
+    assert!(eval_program("loop through \"a\"::lines() with k, v { k }").is_err());
+}
+
+#[test]
+fn test_string_length_indexing_and_slicing_agree_on_multibyte_chars() {
+    let result = eval_program(
+        r#"
+let s = "café 🚀!"
+let last = s[s::length() - 1]
+let rocket = s[s::index_of("🚀")]
+"${s::length()}|${s::byte_len()}|${last}|${rocket}|${s[3:5]}|${s[-2:]}"
+"#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("7|11|!|🚀|é |🚀!".to_string())
+    );
+}
+
+#[test]
+fn test_string_each_match_calls_closure_per_match() {
+    let result = eval_program(
+        r#"
+let pairs = []
+"a=1 b=22 c"::each_match(/(\w)=(\d+)?/, |groups| { pairs::push(groups::join(":")) })
+"a=1 b= c"::each_match("(\\w)=(\\d+)?", |groups| { pairs::push(groups[1]) })
+let empty_matches = 0
+"abc"::each_match(/x*/, |groups| { empty_matches = empty_matches + 1 })
+"${pairs} ${empty_matches}"
+"#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("[a:1, b:22, 1, nil] 4".to_string())
+    );
+
+    assert!(eval_program("\"a\"::each_match(/a/)").is_err());
+    assert!(eval_program("\"a\"::each_match(1, |g| g)").is_err());
+}
@@ -1,35 +1,37 @@
 use suji_values::DecimalNumber;
 
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_values::Value;
 
 #[test]
 fn test_function_basics_and_defaults() {
-    let result = eval_program("add = |x, y| { return x + y }\nresult = add(3, 4)").unwrap();
+    let result = eval_program("let add = |x, y| { return x + y }\nlet result = add(3, 4)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(7)));
 
-    let result = eval_program("square = |x| { return x * x }\nresult = square(5)").unwrap();
+    let result = eval_program("let square = |x| { return x * x }\nlet result = square(5)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(25)));
 
-    let result = eval_program("getfive = || { return 5 }\nresult = getfive()").unwrap();
+    let result = eval_program("let getfive = || { return 5 }\nlet result = getfive()").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 
     let result =
-        eval_program("ispositive = |x| { return x > 0 }\nresult = ispositive(-3)").unwrap();
+        eval_program("let ispositive = |x| { return x > 0 }\nlet result = ispositive(-3)").unwrap();
     assert_eq!(result, Value::Boolean(false));
 
-    let result =
-        eval_program("greet = |name| { return \"Hello, ${name}!\" }\nresult = greet(\"world\")")
-            .unwrap();
+    let result = eval_program(
+        "let greet = |name| { return \"Hello, ${name}!\" }\nlet result = greet(\"world\")",
+    )
+    .unwrap();
     assert_eq!(result, Value::String("Hello, world!".to_string()));
 
-    let result =
-        eval_program("greet = |name = \"world\"| { return \"Hello, ${name}!\" }\nresult = greet()")
-            .unwrap();
+    let result = eval_program(
+        "let greet = |name = \"world\"| { return \"Hello, ${name}!\" }\nlet result = greet()",
+    )
+    .unwrap();
     assert_eq!(result, Value::String("Hello, world!".to_string()));
 
     let result = eval_program(
-        "greet = |name = \"world\"| { return \"Hello, ${name}!\" }\nresult = greet(\"Alice\")",
+        "let greet = |name = \"world\"| { return \"Hello, ${name}!\" }\nlet result = greet(\"Alice\")",
     )
     .unwrap();
     assert_eq!(result, Value::String("Hello, Alice!".to_string()));
@@ -37,127 +39,169 @@ fn test_function_basics_and_defaults() {
 
 #[test]
 fn test_closures_and_hof() {
-    let result = eval_program("x = 10\naddx = |y| { return x + y }\nresult = addx(5)").unwrap();
+    let result =
+        eval_program("let x = 10\nlet addx = |y| { return x + y }\nlet result = addx(5)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(15)));
 
-    let result = eval_program("makeadder = |base| { return |x| { return base + x } }\nadd10 = makeadder(10)\nresult = add10(7)").unwrap();
+    let result = eval_program("let makeadder = |base| { return |x| { return base + x } }\nlet add10 = makeadder(10)\nlet result = add10(7)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(17)));
 
-    let result = eval_program("makeadder = |base| { return |x| { return base + x } }\nadd5 = makeadder(5)\nadd20 = makeadder(20)\nresult1 = add5(3)\nresult2 = add20(3)\nresult = result1 + result2").unwrap();
+    let result = eval_program("let makeadder = |base| { return |x| { return base + x } }\nlet add5 = makeadder(5)\nlet add20 = makeadder(20)\nlet result1 = add5(3)\nlet result2 = add20(3)\nlet result = result1 + result2").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(31)));
 
-    let result = eval_program("counter = 0\nincrement = || { counter = counter + 1; return counter }\nfirst = increment()\nsecond = increment()\nresult = second").unwrap();
+    let result = eval_program("let counter = 0\nlet increment = || { counter = counter + 1; return counter }\nlet first = increment()\nlet second = increment()\nlet result = second").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
     let result = eval_program(
-        "apply = |f, x| { return f(x) }\ndouble = |n| { return n * 2 }\nresult = apply(double, 6)",
+        "let apply = |f, x| { return f(x) }\nlet double = |n| { return n * 2 }\nlet result = apply(double, 6)",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
 
-    let result = eval_program("makemultiplier = |factor| { return |x| { return x * factor } }\ntriple = makemultiplier(3)\nresult = triple(4)").unwrap();
+    let result = eval_program("let makemultiplier = |factor| { return |x| { return x * factor } }\nlet triple = makemultiplier(3)\nlet result = triple(4)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
 
     let result = eval_program(
-        "add1 = |x| { return x + 1 }\nmul2 = |x| { return x * 2 }\nresult = mul2(add1(5))",
+        "let add1 = |x| { return x + 1 }\nlet mul2 = |x| { return x * 2 }\nlet result = mul2(add1(5))",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
 
-    let result = eval_program("compose = |f, g| { return |x| { return f(g(x)) } }\nadd3 = |x| { return x + 3 }\nmul2 = |x| { return x * 2 }\ncomposed = compose(mul2, add3)\nresult = composed(4)").unwrap();
+    let result = eval_program("let compose = |f, g| { return |x| { return f(g(x)) } }\nlet add3 = |x| { return x + 3 }\nlet mul2 = |x| { return x * 2 }\nlet composed = compose(mul2, add3)\nlet result = composed(4)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(14)));
 }
 
 #[test]
 fn test_function_scope_and_loops() {
-    let result = eval_program("x = 10\ntest = |x| { return x * 2 }\nresult = test(5)").unwrap();
+    let result =
+        eval_program("let x = 10\nlet test = |x| { return x * 2 }\nlet result = test(5)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(10)));
 
-    let result =
-        eval_program("outer = 100\ntest = |inner| { return inner + outer }\nresult = test(5)")
-            .unwrap();
+    let result = eval_program(
+        "let outer = 100\nlet test = |inner| { return inner + outer }\nlet result = test(5)",
+    )
+    .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(105)));
 
     let result = eval_program(
-        "x = 1\ntest = || { x = 2; inner = || { return x }; return inner() }\nresult = test()",
+        "let x = 1\nlet test = || { x = 2; let inner = || { return x }; return inner() }\nlet result = test()",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
-    let result = eval_program("x = 1\ntest = || { x = 2; return x }\ntest()\nresult = x").unwrap();
+    let result =
+        eval_program("let x = 1\nlet test = || { x = 2; return x }\ntest()\nlet result = x")
+            .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
 
     let result = eval_program(
-        "counter = 0\nloop { counter++; match counter { 5 => { break }, } }\nresult = counter",
+        "let counter = 0\nloop { counter++; match counter { 5 => { break }, } }\nlet result = counter",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 
-    let result = eval_program("sum = 0\ni = 0\nloop { i++; match i { 3 => { continue }, 6 => { break }, } sum = sum + i }\nresult = sum").unwrap();
+    let result = eval_program("let sum = 0\nlet i = 0\nloop { i++; match i { 3 => { continue }, 6 => { break }, } sum = sum + i }\nlet result = sum").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
 
-    let result = eval_program("count = 0\nloop through 0..5 { count++ }\nresult = count").unwrap();
+    let result =
+        eval_program("let count = 0\nloop through 0..5 { count++ }\nlet result = count").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 }
 
+#[test]
+fn test_let_declarations_shadow_outer_bindings() {
+    let result = eval_program("let x = 1\n{ let x = 2 }\nlet result = x").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(1)));
+
+    let result = eval_program(
+        "let x = 1\nlet test = || { let x = 5; x = x + 1; return x }\nlet y = test()\nlet result = x + y",
+    )
+    .unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(7)));
+
+    let result = eval_program("let a, b = (3, 4)\nlet result = a * b").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
+}
+
+#[test]
+fn test_assignment_requires_a_declared_variable() {
+    // Reassigning a declared variable updates it, from any nested scope
+    let result =
+        eval_program("let total = 1\nlet add = || { total = total + 2 }\nadd()\ntotal").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
+
+    // Assigning a name that was never declared is an error and declares nothing
+    for program in [
+        "totl = 1",
+        "let (a, b) = (1, 2)\na, c = (3, 4)",
+        "|| { fresh = 1 }()",
+    ] {
+        assert_eval_fails(program, "Undefined variable");
+    }
+    assert_eval_fails("|| { fresh = 1 }()\nfresh", "Undefined variable: fresh");
+}
+
 #[test]
 fn test_loop_through_bindings_and_match() {
     let result =
-        eval_program("sum = 0\nloop through 0..5 with i { sum = sum + i }\nresult = sum").unwrap();
+        eval_program("let sum = 0\nloop through 0..5 with i { sum = sum + i }\nlet result = sum")
+            .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(10)));
 
     let result = eval_program(
-        "nums = [10, 20, 30]\nsum = 0\nloop through nums with n { sum = sum + n }\nresult = sum",
+        "let nums = [10, 20, 30]\nlet sum = 0\nloop through nums with n { sum = sum + n }\nlet result = sum",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(60)));
 
     let result = eval_program(
-        "nums = [1, 2, 3]\nsum = 0\nloop through nums with n { sum = sum + (n * 2) }\nresult = sum",
+        "let nums = [1, 2, 3]\nlet sum = 0\nloop through nums with n { sum = sum + (n * 2) }\nlet result = sum",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(12)));
 
-    let result = eval_program("count = 0\nloop through 0..3 { count++ }\nresult = count").unwrap();
+    let result =
+        eval_program("let count = 0\nloop through 0..3 { count++ }\nlet result = count").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
 
-    let result =
-        eval_program("i = 0\nloop as outer { i++; match i { 3 => { break outer }, } }\nresult = i")
-            .unwrap();
+    let result = eval_program(
+        "let i = 0\nloop as outer { i++; match i { 3 => { break outer }, } }\nlet result = i",
+    )
+    .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
 
-    let result = eval_program("counter = 0\nloop as my_loop { counter++; match counter { 5 => { break my_loop }, } }\nresult = counter").unwrap();
+    let result = eval_program("let counter = 0\nloop as my_loop { counter++; match counter { 5 => { break my_loop }, } }\nlet result = counter").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(5)));
 
     let result = eval_program(
-        "x = 42\nmatch x { 42 => { result = \"found\" }, 99 => { result = \"not found\" }, }",
+        "let x = 42\nmatch x { 42 => { let result = \"found\" }, 99 => { let result = \"not found\" }, }",
     )
     .unwrap();
     assert_eq!(result, Value::String("found".to_string()));
 
     let result = eval_program(
-        "flag = true\nmatch flag { false => { result = \"false\" }, true => { result = \"true\" }, }",
+        "let flag = true\nmatch flag { false => { let result = \"false\" }, true => { let result = \"true\" }, }",
     )
     .unwrap();
     assert_eq!(result, Value::String("true".to_string()));
 
-    let result = eval_program("x = 2\nmatch x { 1 => { result = \"one\" }, 2 => { result = \"two\" }, 3 => { result = \"three\" }, }").unwrap();
+    let result = eval_program("let x = 2\nmatch x { 1 => { let result = \"one\" }, 2 => { let result = \"two\" }, 3 => { let result = \"three\" }, }").unwrap();
     assert_eq!(result, Value::String("two".to_string()));
 
     let result = eval_program(
-        "x = 5\nmatch x { 5 => { result = \"first\" }, 5 => { result = \"second\" }, }",
+        "let x = 5\nmatch x { 5 => { let result = \"first\" }, 5 => { let result = \"second\" }, }",
     )
     .unwrap();
     assert_eq!(result, Value::String("first".to_string()));
 
     let result = eval_program(
-        "x = 99\nmatch x { 1 => { result = \"one\" }, _ => { result = \"wildcard\" }, }",
+        "let x = 99\nmatch x { 1 => { let result = \"one\" }, _ => { let result = \"wildcard\" }, }",
     )
     .unwrap();
     assert_eq!(result, Value::String("wildcard".to_string()));
 
     let result = eval_program(
-        "x = 1\nmatch x { 1 => { result = \"one\" }, _ => { result = \"wildcard\" }, }",
+        "let x = 1\nmatch x { 1 => { let result = \"one\" }, _ => { let result = \"wildcard\" }, }",
     )
     .unwrap();
     assert_eq!(result, Value::String("one".to_string()));
@@ -167,21 +211,22 @@ fn test_loop_through_bindings_and_match() {
 fn test_match_patterns_regex_tuples_and_nil() {
     // Regex pattern in match arm
     let result = eval_program(
-        "s = \"user_123\"\nresult = match s { /^user_[0-9]+$/ => \"ok\", _ => \"no\", }",
+        "let s = \"user_123\"\nlet result = match s { /^user_[0-9]+$/ => \"ok\", _ => \"no\", }",
     )
     .unwrap();
     assert_eq!(result, Value::String("ok".to_string()));
 
     // Tuple patterns and wildcards
     let result = eval_program(
-        "t = (2, 10, 0)\nresult = match t {\n  (3, 4, 0) => \"exact\",\n  (2, 10, 0) => \"partial\",\n  _ => \"none\",\n }",
+        "let t = (2, 10, 0)\nlet result = match t {\n  (3, 4, 0) => \"exact\",\n  (2, 10, 0) => \"partial\",\n  _ => \"none\",\n }",
     )
     .unwrap();
     assert_eq!(result, Value::String("partial".to_string()));
 
     // Nil in match arms
     let result =
-        eval_program("x = nil\nresult = match x { nil => \"none\", _ => \"some\", }").unwrap();
+        eval_program("let x = nil\nlet result = match x { nil => \"none\", _ => \"some\", }")
+            .unwrap();
     assert_eq!(result, Value::String("none".to_string()));
 }
 
@@ -189,21 +234,21 @@ fn test_match_patterns_regex_tuples_and_nil() {
 fn test_match_without_expression_and_method_calls_in_conditions() {
     // Conditional match (no expression)
     let result = eval_program(
-        "x = 5\nresult = match { x > 10 => \"big\", x > 0 => \"positive\", _ => \"other\", }",
+        "let x = 5\nlet result = match { x > 10 => \"big\", x > 0 => \"positive\", _ => \"other\", }",
     )
     .unwrap();
     assert_eq!(result, Value::String("positive".to_string()));
 
     // Method calls in conditions
     let result = eval_program(
-        "text = \"hello world\"\nresult = match { text::contains(\"world\") => \"has world\", text::length() > 10 => \"long\", _ => \"short\", }",
+        "let text = \"hello world\"\nlet result = match { text::contains(\"world\") => \"has world\", text::length() > 10 => \"long\", _ => \"short\", }",
     )
     .unwrap();
     assert_eq!(result, Value::String("has world".to_string()));
 
     // Labeled continue example
     let result = eval_program(
-        "outer = 0\nloop as outer_loop {\n  match outer { 2 => { break outer_loop }, }\n  inner = 0\n  loop {\n    inner++\n    match inner { 3 => { outer++; continue outer_loop }, }\n  }\n}\nresult = outer",
+        "let outer = 0\nloop as outer_loop {\n  match outer { 2 => { break outer_loop }, }\n  let inner = 0\n  loop {\n    inner++\n    match inner { 3 => { outer++; continue outer_loop }, }\n  }\n}\nlet result = outer",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
@@ -212,6 +257,7 @@ fn test_match_without_expression_and_method_calls_in_conditions() {
 #[test]
 fn test_tuple_wildcard_pattern() {
     let result =
-        eval_program("t = (2, 3)\nresult = match t { (2, _) => \"ok\", _ => \"no\", }").unwrap();
+        eval_program("let t = (2, 3)\nlet result = match t { (2, _) => \"ok\", _ => \"no\", }")
+            .unwrap();
     assert_eq!(result, Value::String("ok".to_string()));
 }
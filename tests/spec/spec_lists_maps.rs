@@ -83,17 +83,18 @@ fn test_list_indexing_and_slicing() {
 
 #[test]
 fn test_list_assignment() {
-    let result = eval_program("xs = [10, 20, 30]\nxs[1] = 99\nresult = xs[1]").unwrap();
+    let result = eval_program("let xs = [10, 20, 30]\nxs[1] = 99\nlet result = xs[1]").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(99)));
 
-    let result = eval_program("xs = [10, 20, 30]\nxs[-1] = 0\nresult = xs[2]").unwrap();
+    let result = eval_program("let xs = [10, 20, 30]\nxs[-1] = 0\nlet result = xs[2]").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(0)));
 
-    let result = eval_program("xs = [1, 2, 3]\nxs[0] = \"hello\"\nresult = xs[0]").unwrap();
+    let result = eval_program("let xs = [1, 2, 3]\nxs[0] = \"hello\"\nlet result = xs[0]").unwrap();
     assert_eq!(result, Value::String("hello".to_string()));
 
     let result =
-        eval_program("xs = [1, 2, 3]\nxs[0] = 10\nxs[1] = 20\nxs[2] = 30\nresult = xs").unwrap();
+        eval_program("let xs = [1, 2, 3]\nxs[0] = 10\nxs[1] = 20\nxs[2] = 30\nlet result = xs")
+            .unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -106,37 +107,44 @@ fn test_list_assignment() {
 
 #[test]
 fn test_map_access_and_assignment() {
-    let result = eval_program("m = { name: \"Ada\", age: 37 }\nresult = m:name").unwrap();
+    let result = eval_program("let m = { name: \"Ada\", age: 37 }\nlet result = m:name").unwrap();
     assert_eq!(result, Value::String("Ada".to_string()));
-    let result = eval_program("m = { name: \"Ada\", age: 37 }\nresult = m:age").unwrap();
+    let result = eval_program("let m = { name: \"Ada\", age: 37 }\nlet result = m:age").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(37)));
-    let result = eval_program("m = { name: \"Ada\", age: 37 }\nresult = m[\"name\"]").unwrap();
+    let result =
+        eval_program("let m = { name: \"Ada\", age: 37 }\nlet result = m[\"name\"]").unwrap();
     assert_eq!(result, Value::String("Ada".to_string()));
-    let result = eval_program("m = { name: \"Ada\", age: 37 }\nresult = m[\"age\"]").unwrap();
+    let result =
+        eval_program("let m = { name: \"Ada\", age: 37 }\nlet result = m[\"age\"]").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(37)));
     let result =
-        eval_program("m = { name: \"Ada\", age: 37 }\nk = \"name\"\nresult = m[k]").unwrap();
+        eval_program("let m = { name: \"Ada\", age: 37 }\nlet k = \"name\"\nlet result = m[k]")
+            .unwrap();
     assert_eq!(result, Value::String("Ada".to_string()));
     let result =
-        eval_program("m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nresult = m[1]").unwrap();
-    assert_eq!(result, Value::String("one".to_string()));
-    let result =
-        eval_program("m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nresult = m[\"two\"]")
+        eval_program("let m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nlet result = m[1]")
             .unwrap();
+    assert_eq!(result, Value::String("one".to_string()));
+    let result = eval_program(
+        "let m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nlet result = m[\"two\"]",
+    )
+    .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(2)));
     let result =
-        eval_program("m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nresult = m[true]")
+        eval_program("let m = { 1: \"one\", \"two\": 2, true: \"boolean\" }\nlet result = m[true]")
             .unwrap();
     assert_eq!(result, Value::String("boolean".to_string()));
 
     let result =
-        eval_program("m = { name: \"Ada\" }\nm:name = \"Lovelace\"\nresult = m:name").unwrap();
+        eval_program("let m = { name: \"Ada\" }\nm:name = \"Lovelace\"\nlet result = m:name")
+            .unwrap();
     assert_eq!(result, Value::String("Lovelace".to_string()));
     let result =
-        eval_program("m = { name: \"Ada\" }\nm[\"age\"] = 37\nresult = m[\"age\"]").unwrap();
+        eval_program("let m = { name: \"Ada\" }\nm[\"age\"] = 37\nlet result = m[\"age\"]")
+            .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(37)));
     let result = eval_program(
-        "m = { name: \"Ada\" }\nk = \"country\"\nm[k] = \"UK\"\nresult = m[\"country\"]",
+        "let m = { name: \"Ada\" }\nlet k = \"country\"\nm[k] = \"UK\"\nlet result = m[\"country\"]",
     )
     .unwrap();
     assert_eq!(result, Value::String("UK".to_string()));
@@ -148,7 +156,7 @@ fn test_indexing_errors_and_strings() {
     assert!(eval_string_expr("[1, 2, 3][-5]").is_err());
     assert!(eval_string_expr("[1, 2, 3][1.5]").is_err());
     assert!(eval_string_expr("[1, 2, 3][\"0\"]").is_err());
-    assert!(eval_program("m = { name: \"Ada\" }\nresult = m[\"missing\"]").is_err());
+    assert!(eval_program("let m = { name: \"Ada\" }\nlet result = m[\"missing\"]").is_err());
     assert!(eval_string_expr("42[0]").is_err());
 
     let result = eval_string_expr("\"hello\"[0]").unwrap();
@@ -176,7 +184,8 @@ fn test_list_concatenation_and_map_iteration_contains() {
     );
 
     // Original lists unchanged (pass-by-value semantics)
-    let result = eval_program("a = [1,2]\nb = [3,4]\nc = a + b\nresult = a").unwrap();
+    let result =
+        eval_program("let a = [1,2]\nlet b = [3,4]\nlet c = a + b\nlet result = a").unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -187,7 +196,7 @@ fn test_list_concatenation_and_map_iteration_contains() {
 
     // Map iteration with key/value
     let result = eval_program(
-        "m = { one: 1, two: 2 }\nacc = \"\"\nloop through m with k, v { acc = acc + k + \"=\" + v::to_string() + \" \" }\nresult = acc",
+        "let m = { one: 1, two: 2 }\nlet acc = \"\"\nloop through m with k, v { acc = acc + k + \"=\" + v::to_string() + \" \" }\nlet result = acc",
     )
     .unwrap();
     let s = result.to_string();
@@ -195,7 +204,7 @@ fn test_list_concatenation_and_map_iteration_contains() {
 
     // Map contains
     let result = eval_program(
-        "m = { name: \"Alice\", age: 30 }\nresult = m::contains(\"age\") && !m::contains(\"missing\")",
+        "let m = { name: \"Alice\", age: 30 }\nlet result = m::contains(\"age\") && !m::contains(\"missing\")",
     )
     .unwrap();
     assert_eq!(result, Value::Boolean(true));
@@ -205,20 +214,20 @@ fn test_list_concatenation_and_map_iteration_contains() {
 fn test_deep_and_mixed_nested_access_and_assignment() {
     // Deep list nesting access and assignment
     let result =
-        eval_program("matrix = [[1, 2], [3, 4]]\nmatrix[0][1] = 99\nresult = matrix[0][1]")
+        eval_program("let matrix = [[1, 2], [3, 4]]\nmatrix[0][1] = 99\nlet result = matrix[0][1]")
             .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(99)));
 
     // Deep map nesting access and assignment
     let result = eval_program(
-        "config = { user: { profile: { settings: { display: { theme: \"dark\", layout: { columns: 3, rows: 2 } } } } } }\nconfig:user:profile:settings:display:layout:columns = 4\nresult = config:user:profile:settings:display:layout:columns",
+        "let config = { user: { profile: { settings: { display: { theme: \"dark\", layout: { columns: 3, rows: 2 } } } } } }\nconfig:user:profile:settings:display:layout:columns = 4\nlet result = config:user:profile:settings:display:layout:columns",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(4)));
 
     // Mixed nested structures
     let result = eval_program(
-        "data = [{ name: \"item1\" }, { name: \"item2\" }]\ndata[0]:name = \"updated\"\nresult = data[0]:name",
+        "let data = [{ name: \"item1\" }, { name: \"item2\" }]\ndata[0]:name = \"updated\"\nlet result = data[0]:name",
     )
     .unwrap();
     assert_eq!(result, Value::String("updated".to_string()));
@@ -227,14 +236,14 @@ fn test_deep_and_mixed_nested_access_and_assignment() {
 #[test]
 fn test_list_first_last_defaults_and_average() {
     // first/last with defaults
-    let result = eval_program("xs = []\nresult = xs::first(42)").unwrap();
+    let result = eval_program("let xs = []\nlet result = xs::first(42)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(42)));
-    let result = eval_program("ys = []\nresult = ys::last(\"n/a\")").unwrap();
+    let result = eval_program("let ys = []\nlet result = ys::last(\"n/a\")").unwrap();
     assert_eq!(result, Value::String("n/a".to_string()));
-    let result = eval_program("zs = [1,2,3]\nresult = zs::first(0)").unwrap();
+    let result = eval_program("let zs = [1,2,3]\nlet result = zs::first(0)").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(1)));
 
     // average
-    let result = eval_program("nums = [1,2,3,4]\nresult = nums::average()").unwrap();
+    let result = eval_program("let nums = [1,2,3,4]\nlet result = nums::average()").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::parse("2.5").unwrap()));
 }
@@ -71,7 +71,7 @@ fn test_list_methods() {
         Value::Number(DecimalNumber::from_i64(0))
     );
 
-    let result = eval_program("list = [1, 2]\nlist::push(3)\nresult = list").unwrap();
+    let result = eval_program("let list = [1, 2]\nlist::push(3)\nlet result = list").unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -81,7 +81,8 @@ fn test_list_methods() {
         ])
     );
 
-    let result = eval_program("list = [1, 2]\nlist::push(\"hello\")\nresult = list").unwrap();
+    let result =
+        eval_program("let list = [1, 2]\nlist::push(\"hello\")\nlet result = list").unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -91,10 +92,11 @@ fn test_list_methods() {
         ])
     );
 
-    let result = eval_program("list = [1, 2, 3]\nlast = list::pop()\nresult = last").unwrap();
+    let result =
+        eval_program("let list = [1, 2, 3]\nlet last = list::pop()\nlet result = last").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(3)));
 
-    let result = eval_program("list = [1, 2, 3]\nlist::pop()\nresult = list").unwrap();
+    let result = eval_program("let list = [1, 2, 3]\nlist::pop()\nlet result = list").unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -103,12 +105,13 @@ fn test_list_methods() {
         ])
     );
 
-    let result = eval_program("list = [42]\nlast = list::pop()\nresult = last").unwrap();
+    let result =
+        eval_program("let list = [42]\nlet last = list::pop()\nlet result = last").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(42)));
 
     assert_eq!(
         eval_string_expr("[\"a\", \"b\", \"c\"]::join()").unwrap(),
-        Value::String("a b c".to_string())
+        Value::String("abc".to_string())
     );
     assert_eq!(
         eval_string_expr("[\"a\", \"b\", \"c\"]::join(\",\")").unwrap(),
@@ -134,29 +137,33 @@ fn test_list_methods() {
 
 #[test]
 fn test_map_methods_and_requirements() {
-    let result = eval_program("m = { a: 1, b: 2, c: 3 }\nm::delete(\"b\")\nresult = m").unwrap();
+    let result =
+        eval_program("let m = { a: 1, b: 2, c: 3 }\nm::delete(\"b\")\nlet result = m").unwrap();
     assert!(result.to_string().contains("a"));
     assert!(result.to_string().contains("c"));
     assert!(!result.to_string().contains("b"));
 
-    let result = eval_program("m = { a: 1 }\nm::delete(\"missing\")\nresult = m").unwrap();
+    let result = eval_program("let m = { a: 1 }\nm::delete(\"missing\")\nlet result = m").unwrap();
     assert!(result.to_string().contains("a"));
 
     let result =
-        eval_program("m = { a: 1, b: 2 }\nm::delete(\"a\")\nm::delete(\"b\")\nresult = m").unwrap();
+        eval_program("let m = { a: 1, b: 2 }\nm::delete(\"a\")\nm::delete(\"b\")\nlet result = m")
+            .unwrap();
     assert_eq!(result, Value::Map(indexmap::IndexMap::new()));
 
-    let result = eval_program("m = { x: 1, y: 2 }\nk = \"x\"\nm::delete(k)\nresult = m").unwrap();
+    let result =
+        eval_program("let m = { x: 1, y: 2 }\nlet k = \"x\"\nm::delete(k)\nlet result = m")
+            .unwrap();
     assert!(result.to_string().contains("y"));
     assert!(!result.to_string().contains("x"));
 
-    assert!(eval_program("list = []\nresult = list::pop()").is_err());
+    assert!(eval_program("let list = []\nlet result = list::pop()").is_err());
     assert!(eval_string_expr("\"hello\"::split(42)").is_err());
     assert!(eval_string_expr("[\"a\", \"b\"]::join(42)").is_err());
     assert!(eval_string_expr("null::length()").is_err());
     assert!(eval_string_expr("|| { return 1 }::length()").is_err());
 
-    let result = eval_program("list = [1, 2]\nlist::push(3)\nresult = list").unwrap();
+    let result = eval_program("let list = [1, 2]\nlist::push(3)\nlet result = list").unwrap();
     assert_eq!(
         result,
         Value::List(vec![
@@ -181,7 +188,7 @@ fn test_map_methods_and_requirements() {
 fn test_advanced_methods_v0_1_1_and_v0_1_5() {
     // list::filter/map/fold/sum/product
     let result = eval_program(
-        "numbers = [1,2,3,4,5]\nev = numbers::filter(|x| { return x % 2 == 0 })\nresult = ev",
+        "let numbers = [1,2,3,4,5]\nlet ev = numbers::filter(|x| { return x % 2 == 0 })\nlet result = ev",
     )
     .unwrap();
     assert_eq!(
@@ -193,7 +200,7 @@ fn test_advanced_methods_v0_1_1_and_v0_1_5() {
     );
 
     let result = eval_program(
-        "numbers = [1,2,3]\nsquares = numbers::map(|x| { return x * x })\nresult = squares",
+        "let numbers = [1,2,3]\nlet squares = numbers::map(|x| { return x * x })\nlet result = squares",
     )
     .unwrap();
     assert_eq!(
@@ -206,7 +213,7 @@ fn test_advanced_methods_v0_1_1_and_v0_1_5() {
     );
 
     let result = eval_program(
-        "numbers = [1,2,3,4,5]\nsum = numbers::fold(0, |acc, x| { return acc + x })\nresult = sum",
+        "let numbers = [1,2,3,4,5]\nlet sum = numbers::fold(0, |acc, x| { return acc + x })\nlet result = sum",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(15)));
@@ -218,12 +225,12 @@ fn test_advanced_methods_v0_1_1_and_v0_1_5() {
 
     // index_of on list and string
     let result = eval_program(
-        "fruits = [\"apple\", \"banana\", \"cherry\"]\nresult = fruits::index_of(\"banana\")",
+        "let fruits = [\"apple\", \"banana\", \"cherry\"]\nlet result = fruits::index_of(\"banana\")",
     )
     .unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(1)));
     let result =
-        eval_program("text = \"hello world\"\nresult = text::index_of(\"world\")").unwrap();
+        eval_program("let text = \"hello world\"\nlet result = text::index_of(\"world\")").unwrap();
     assert_eq!(result, Value::Number(DecimalNumber::from_i64(6)));
 
     // New string methods
@@ -265,11 +272,13 @@ fn test_advanced_methods_v0_1_1_and_v0_1_5() {
     }
 
     // Map get and merge
-    let result = eval_program("m = { name: \"Alice\" }\nresult = m::get(\"age\", 0)").unwrap();
-    assert_eq!(result, Value::Number(DecimalNumber::from_i64(0)));
     let result =
-        eval_program("user = { name: \"Bob\" }\nuser::merge({ city: \"NY\" })\nresult = user:city")
-            .unwrap();
+        eval_program("let m = { name: \"Alice\" }\nlet result = m::get(\"age\", 0)").unwrap();
+    assert_eq!(result, Value::Number(DecimalNumber::from_i64(0)));
+    let result = eval_program(
+        "let user = { name: \"Bob\" }\nuser::merge({ city: \"NY\" })\nlet result = user:city",
+    )
+    .unwrap();
     assert_eq!(result, Value::String("NY".to_string()));
 
     // Number methods
@@ -21,22 +21,23 @@ fn test_shell_commands() {
 
 #[test]
 fn test_shell_interpolation() {
-    let result = eval_program("name = \"world\"\nresult = `echo Hello, ${name}`").unwrap();
+    let result = eval_program("let name = \"world\"\nlet result = `echo Hello, ${name}`").unwrap();
     assert_eq!(result, Value::String("Hello, world".to_string()));
 
-    let result = eval_program("num = 42\nresult = `echo ${num}`").unwrap();
+    let result = eval_program("let num = 42\nlet result = `echo ${num}`").unwrap();
     assert_eq!(result, Value::String("42".to_string()));
 
-    let result = eval_program("x = 3\ny = 4\nresult = `echo ${x + y}`").unwrap();
+    let result = eval_program("let x = 3\nlet y = 4\nlet result = `echo ${x + y}`").unwrap();
     assert_eq!(result, Value::String("7".to_string()));
 
-    let result = eval_program("a = \"foo\"\nb = \"bar\"\nresult = `echo ${a}-${b}`").unwrap();
+    let result =
+        eval_program("let a = \"foo\"\nlet b = \"bar\"\nlet result = `echo ${a}-${b}`").unwrap();
     assert_eq!(result, Value::String("foo-bar".to_string()));
 
-    let result = eval_program("base = 5\nresult = `echo Result: ${base * base}`").unwrap();
+    let result = eval_program("let base = 5\nlet result = `echo Result: ${base * base}`").unwrap();
     assert_eq!(result, Value::String("Result: 25".to_string()));
 
-    let result = eval_program("flag = true\nresult = `echo ${flag}`").unwrap();
+    let result = eval_program("let flag = true\nlet result = `echo ${flag}`").unwrap();
     assert_eq!(result, Value::String("true".to_string()));
 }
 
@@ -52,7 +53,8 @@ fn test_shell_pipes_and_operations() {
     let result = eval_string_expr("`printf '%s' 'test'`").unwrap();
     assert_eq!(result, Value::String("test".to_string()));
 
-    let result = eval_program("cmd = \"date\"\nresult = `echo Current command: ${cmd}`").unwrap();
+    let result =
+        eval_program("let cmd = \"date\"\nlet result = `echo Current command: ${cmd}`").unwrap();
     assert!(result.to_string().contains("Current command: date"));
 }
 
@@ -65,15 +67,18 @@ fn test_shell_command_errors() {
 
 #[test]
 fn test_shell_commands_in_context() {
-    let result = eval_program("output = `echo assigned`\nresult = output").unwrap();
+    let result = eval_program("let output = `echo assigned`\nlet result = output").unwrap();
     assert_eq!(result, Value::String("assigned".to_string()));
 
-    let result = eval_program("getdate = || { return `date +%Y` }\nresult = getdate()").unwrap();
+    let result =
+        eval_program("let getdate = || { return `date +%Y` }\nlet result = getdate()").unwrap();
     assert!(result.to_string().chars().all(|c| c.is_ascii_digit()));
 
-    let result = eval_program("prefix = \"Output: \"\nresult = prefix + `echo test`").unwrap();
+    let result =
+        eval_program("let prefix = \"Output: \"\nlet result = prefix + `echo test`").unwrap();
     assert_eq!(result, Value::String("Output: test".to_string()));
 
-    let result = eval_program("output = `echo hello`\nresult = (output == \"hello\")").unwrap();
+    let result =
+        eval_program("let output = `echo hello`\nlet result = (output == \"hello\")").unwrap();
     assert_eq!(result, Value::Boolean(true));
 }
@@ -3,17 +3,18 @@ use suji_values::{DecimalNumber, Value};
 
 #[test]
 fn test_string_interpolation() {
-    let result = eval_program("name = \"Ada\"\nresult = \"Hello, ${name}\"").unwrap();
+    let result = eval_program("let name = \"Ada\"\nlet result = \"Hello, ${name}\"").unwrap();
     assert_eq!(result, Value::String("Hello, Ada".to_string()));
 
-    let result = eval_program("a = 3\nb = 4\nresult = \"${a} + ${b} = ${a + b}\"").unwrap();
+    let result =
+        eval_program("let a = 3\nlet b = 4\nlet result = \"${a} + ${b} = ${a + b}\"").unwrap();
     assert_eq!(result, Value::String("3 + 4 = 7".to_string()));
 
-    let result = eval_program("x = 5\nresult = \"Square of ${x} is ${x ^ 2}\"").unwrap();
+    let result = eval_program("let x = 5\nlet result = \"Square of ${x} is ${x ^ 2}\"").unwrap();
     assert_eq!(result, Value::String("Square of 5 is 25".to_string()));
 
     let result = eval_program(
-        "first = \"Ada\"\nlast = \"Lovelace\"\nresult = \"${first} ${last} was a mathematician\"",
+        "let first = \"Ada\"\nlet last = \"Lovelace\"\nlet result = \"${first} ${last} was a mathematician\"",
     )
     .unwrap();
     assert_eq!(
@@ -21,29 +22,34 @@ fn test_string_interpolation() {
         Value::String("Ada Lovelace was a mathematician".to_string())
     );
 
-    let result =
-        eval_program("x = 10\ny = 20\nresult = \"Sum is ${x + y}, product is ${x * y}\"").unwrap();
+    let result = eval_program(
+        "let x = 10\nlet y = 20\nlet result = \"Sum is ${x + y}, product is ${x * y}\"",
+    )
+    .unwrap();
     assert_eq!(
         result,
         Value::String("Sum is 30, product is 200".to_string())
     );
 
-    let result =
-        eval_program("name = \"world\"\ngreeting = \"Hello, ${name}\" + \"!\"\nresult = greeting")
-            .unwrap();
+    let result = eval_program(
+        "let name = \"world\"\nlet greeting = \"Hello, ${name}\" + \"!\"\nlet result = greeting",
+    )
+    .unwrap();
     assert_eq!(result, Value::String("Hello, world!".to_string()));
 
-    let result = eval_program("flag = true\nresult = \"The flag is ${flag}\"").unwrap();
+    let result = eval_program("let flag = true\nlet result = \"The flag is ${flag}\"").unwrap();
     assert_eq!(result, Value::String("The flag is true".to_string()));
 
-    let result = eval_program("items = [1, 2, 3]\nresult = \"First item: ${items[0]}\"").unwrap();
+    let result =
+        eval_program("let items = [1, 2, 3]\nlet result = \"First item: ${items[0]}\"").unwrap();
     assert_eq!(result, Value::String("First item: 1".to_string()));
 
     let result =
-        eval_program("obj = { name: \"test\", value: 42 }\nresult = \"Object: ${obj}\"").unwrap();
+        eval_program("let obj = { name: \"test\", value: 42 }\nlet result = \"Object: ${obj}\"")
+            .unwrap();
     assert!(result.to_string().contains("Object:"));
 
-    let result = eval_program("result = \"Result: ${(5 + 3) * 2}\"").unwrap();
+    let result = eval_program("let result = \"Result: ${(5 + 3) * 2}\"").unwrap();
     assert_eq!(result, Value::String("Result: 16".to_string()));
 }
 
@@ -130,7 +136,8 @@ fn test_regex_operations_and_precedence() {
         Value::Boolean(true)
     );
 
-    let result = eval_program("pattern = \"hello\"\nresult = \"hello world\" ~ /hello/").unwrap();
+    let result =
+        eval_program("let pattern = \"hello\"\nlet result = \"hello world\" ~ /hello/").unwrap();
     assert_eq!(result, Value::Boolean(true));
 
     assert_eq!(
@@ -185,7 +192,7 @@ fn test_string_slicing_and_single_quotes() {
         Value::Number(DecimalNumber::from_i64(5))
     );
     assert_eq!(
-        eval_program("name = 'Alice'\nresult = 'Hello, ${name}!' ").unwrap(),
+        eval_program("let name = 'Alice'\nlet result = 'Hello, ${name}!' ").unwrap(),
         Value::String("Hello, Alice!".to_string())
     );
 }
@@ -194,28 +201,28 @@ fn test_string_slicing_and_single_quotes() {
 fn test_multiline_strings_and_escaped_dollar_brace() {
     // Multiline triple-quoted strings
     let program = r#"
-poem = """
+let poem = """
 Roses are red,
 Violets are blue
 """
-result = poem::contains("Roses")
+let result = poem::contains("Roses")
 "#;
     let result = eval_program(program).unwrap();
     assert_eq!(result, Value::Boolean(true));
 
     // Multiline with interpolation
     let program2 = r#"
-name = "Ada"
-profile = """
+let name = "Ada"
+let profile = """
 Name: ${name}
 """
-result = profile::contains("Ada")
+let result = profile::contains("Ada")
 "#;
     let result2 = eval_program(program2).unwrap();
     assert_eq!(result2, Value::Boolean(true));
 
     // Escape ${ with \${ to produce literal sequence
-    let program3 = "s = \"Price: \\${amount}\"\nresult = s";
+    let program3 = "let s = \"Price: \\${amount}\"\nlet result = s";
     let result3 = eval_program(program3).unwrap();
     assert_eq!(result3, Value::String("Price: ${amount}".to_string()));
 }
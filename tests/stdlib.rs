@@ -11,6 +11,8 @@ mod std_dotenv;
 mod std_encoding;
 #[path = "stdlib/std_env_args.rs"]
 mod std_env_args;
+#[path = "stdlib/std_fs.rs"]
+mod std_fs;
 #[path = "stdlib/std_io.rs"]
 mod std_io;
 #[path = "stdlib/std_math.rs"]
@@ -1,4 +1,4 @@
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_values::Value;
 
 #[test]
@@ -6,7 +6,7 @@ fn test_crypto_sha256_known_value() {
     let result = eval_program(
         r#"
         import std:crypto
-        hash = crypto:sha256("Hello, World!")
+        let hash = crypto:sha256("Hello, World!")
         hash
     "#,
     );
@@ -24,7 +24,7 @@ fn test_crypto_sha256_empty_string() {
     let result = eval_program(
         r#"
         import std:crypto
-        hash = crypto:sha256("")
+        let hash = crypto:sha256("")
         hash
     "#,
     );
@@ -42,7 +42,7 @@ fn test_crypto_md5_known_value() {
     let result = eval_program(
         r#"
         import std:crypto
-        hash = crypto:md5("Hello, World!")
+        let hash = crypto:md5("Hello, World!")
         hash
     "#,
     );
@@ -58,7 +58,7 @@ fn test_crypto_sha1_known_value() {
     let result = eval_program(
         r#"
         import std:crypto
-        hash = crypto:sha1("Hello, World!")
+        let hash = crypto:sha1("Hello, World!")
         hash
     "#,
     );
@@ -74,7 +74,7 @@ fn test_crypto_sha512_produces_string() {
     let result = eval_program(
         r#"
         import std:crypto
-        hash = crypto:sha512("test")
+        let hash = crypto:sha512("test")
         hash::length() == 128
     "#,
     );
@@ -87,7 +87,7 @@ fn test_crypto_hmac_sha256_known_value() {
     let result = eval_program(
         r#"
         import std:crypto
-        hmac = crypto:hmac_sha256("secret", "test message")
+        let hmac = crypto:hmac_sha256("secret", "test message")
         hmac
     "#,
     );
@@ -100,13 +100,48 @@ fn test_crypto_hmac_sha256_known_value() {
     );
 }
 
+#[test]
+fn test_crypto_hmac_sha512_and_generic_hmac() {
+    let result = eval_program(
+        r#"
+        import std:crypto
+        let result = (
+            crypto:hmac_sha512("secret", "test message"),
+            crypto:hmac("SHA512", "secret", "test message"),
+            crypto:hmac("sha256", "secret", "test message") == crypto:hmac_sha256("secret", "test message"),
+            crypto:hmac("sha1", "secret", "test message"),
+        )
+        result
+    "#,
+    );
+    let sha512 = "4ca0748eaeaaf0789d1a4785c5c5ccb4421c97c558f763c8c09eaf96ce71b805e8f670c9368c869dc994554508d6729b4d022a72718e5a6a9ae1ba1f6dedf4b5";
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::String(sha512.to_string()),
+            Value::String(sha512.to_string()),
+            Value::Boolean(true),
+            Value::String("0382ad319c25265eb29b52714cda6f798f1d5f53".to_string()),
+        ])
+    );
+
+    assert_eval_fails(
+        "import std:crypto\ncrypto:hmac(\"md5\", \"k\", \"m\")",
+        "crypto:hmac does not support the algorithm 'md5' (supported: sha1, sha256, sha512)",
+    );
+    assert_eval_fails(
+        "import std:crypto\ncrypto:hmac_sha512(\"k\", 1)",
+        "crypto:hmac_sha512 message must be a string, got number",
+    );
+}
+
 #[test]
 fn test_crypto_hmac_sha256_different_keys() {
     let result = eval_program(
         r#"
         import std:crypto
-        hmac1 = crypto:hmac_sha256("secret1", "message")
-        hmac2 = crypto:hmac_sha256("secret2", "message")
+        let hmac1 = crypto:hmac_sha256("secret1", "message")
+        let hmac2 = crypto:hmac_sha256("secret2", "message")
         hmac1 != hmac2
     "#,
     );
@@ -119,8 +154,8 @@ fn test_crypto_hmac_sha256_different_messages() {
     let result = eval_program(
         r#"
         import std:crypto
-        hmac1 = crypto:hmac_sha256("secret", "message1")
-        hmac2 = crypto:hmac_sha256("secret", "message2")
+        let hmac1 = crypto:hmac_sha256("secret", "message1")
+        let hmac2 = crypto:hmac_sha256("secret", "message2")
         hmac1 != hmac2
     "#,
     );
@@ -133,9 +168,9 @@ fn test_crypto_hash_consistency() {
     let result = eval_program(
         r#"
         import std:crypto
-        text = "consistent data"
-        hash1 = crypto:sha256(text)
-        hash2 = crypto:sha256(text)
+        let text = "consistent data"
+        let hash1 = crypto:sha256(text)
+        let hash2 = crypto:sha256(text)
         hash1 == hash2
     "#,
     );
@@ -149,11 +184,41 @@ fn test_crypto_direct_imports() {
         r#"
         import std:crypto:sha256
         import std:crypto:md5
-        sha_hash = sha256("test")
-        md5_hash = md5("test")
+        let sha_hash = sha256("test")
+        let md5_hash = md5("test")
         sha_hash::length() > md5_hash::length()
     "#,
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Boolean(true));
 }
+
+#[test]
+fn test_crypto_pbkdf2_known_value() {
+    let result = eval_program(
+        r#"
+        import std:crypto
+        crypto:pbkdf2("passwd", "salt", 1, 16)
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("55ac046e56e3089fec1691c22544b605".to_string())
+    );
+    assert!(eval_program("import std:crypto\ncrypto:pbkdf2(\"p\", \"s\", 0, 16)").is_err());
+}
+
+#[test]
+fn test_crypto_bcrypt_round_trip() {
+    let result = eval_program(
+        r#"
+        import std:crypto
+        let hash = crypto:bcrypt_hash("hunter2", 4)
+        let ok = crypto:bcrypt_verify("hunter2", hash)
+        let wrong = crypto:bcrypt_verify("hunter3", hash)
+        ok && !wrong && hash != crypto:bcrypt_hash("hunter2", 4)
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+    assert!(eval_program("import std:crypto\ncrypto:bcrypt_hash(\"pw\", 3)").is_err());
+}
@@ -1,12 +1,12 @@
 use super::common::eval_program;
-use suji_values::Value;
+use suji_values::{DecimalNumber, Value};
 
 #[test]
 fn test_csv_module_integration() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a,b,c\n1,2,3")
+        let rows = csv:parse("a,b,c\n1,2,3")
         rows::length()
     "#,
     );
@@ -23,7 +23,7 @@ fn test_csv_basic_parse() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a,b,c\n1,2,3")
+        let rows = csv:parse("a,b,c\n1,2,3")
         rows[0][0]
     "#,
     );
@@ -52,9 +52,9 @@ fn test_csv_roundtrip() {
     let result = eval_program(
         r#"
         import std:csv
-        original = [["name", "age"], ["Alice", "30"], ["Bob", "25"]]
-        csv_str = csv:generate(original)
-        parsed = csv:parse(csv_str)
+        let original = [["name", "age"], ["Alice", "30"], ["Bob", "25"]]
+        let csv_str = csv:generate(original)
+        let parsed = csv:parse(csv_str)
         parsed[1][0]
     "#,
     );
@@ -67,7 +67,7 @@ fn test_csv_custom_delimiter() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a|b|c\n1|2|3", "|")
+        let rows = csv:parse("a|b|c\n1|2|3", "|")
         rows[0][1]
     "#,
     );
@@ -96,7 +96,7 @@ fn test_csv_quoted_fields() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("\"a\",\"b,c\",\"d\"")
+        let rows = csv:parse("\"a\",\"b,c\",\"d\"")
         rows[0][1]
     "#,
     );
@@ -126,7 +126,7 @@ fn test_csv_escaped_quotes() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("\"a\"\"b\"")
+        let rows = csv:parse("\"a\"\"b\"")
         rows[0][0]
     "#,
     );
@@ -139,7 +139,7 @@ fn test_csv_multiline_quoted_field() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("\"line1\nline2\",\"b\"")
+        let rows = csv:parse("\"line1\nline2\",\"b\"")
         rows[0][0]
     "#,
     );
@@ -152,7 +152,7 @@ fn test_csv_empty_input_parse() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("")
+        let rows = csv:parse("")
         rows::length()
     "#,
     );
@@ -182,7 +182,7 @@ fn test_csv_direct_imports() {
         r#"
         import std:csv:parse
         import std:csv:generate
-        rows = parse("a,b\n1,2")
+        let rows = parse("a,b\n1,2")
         generate(rows)
     "#,
     );
@@ -199,7 +199,7 @@ fn test_csv_semicolon_delimiter() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a;b;c\n1;2;3", ";")
+        let rows = csv:parse("a;b;c\n1;2;3", ";")
         rows[1][2]
     "#,
     );
@@ -212,7 +212,7 @@ fn test_csv_tab_delimiter() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a\tb\tc\n1\t2\t3", "\t")
+        let rows = csv:parse("a\tb\tc\n1\t2\t3", "\t")
         rows[0][2]
     "#,
     );
@@ -299,7 +299,7 @@ fn test_csv_whitespace_preserved() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse(" a , b ")
+        let rows = csv:parse(" a , b ")
         rows[0][0]
     "#,
     );
@@ -313,7 +313,7 @@ fn test_csv_multiple_rows() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("name,age\nAlice,30\nBob,25\nCharlie,35")
+        let rows = csv:parse("name,age\nAlice,30\nBob,25\nCharlie,35")
         rows::length()
     "#,
     );
@@ -330,7 +330,7 @@ fn test_csv_single_column() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a\nb\nc")
+        let rows = csv:parse("a\nb\nc")
         rows[1][0]
     "#,
     );
@@ -343,7 +343,7 @@ fn test_csv_empty_fields() {
     let result = eval_program(
         r#"
         import std:csv
-        rows = csv:parse("a,,c\n1,2,")
+        let rows = csv:parse("a,,c\n1,2,")
         rows[0][1]
     "#,
     );
@@ -366,3 +366,58 @@ fn test_csv_generate_empty_strings() {
         panic!("Expected string output");
     }
 }
+
+#[test]
+fn test_csv_writer_streams_rows() {
+    let mut path = std::env::temp_dir();
+    path.push(format!("suji_test_csv_writer_{}", std::process::id()));
+
+    let source = format!(
+        r#"import std:csv
+import std:io
+
+let out = io:open("{path}", true, false)
+let writer = csv:writer(out, ";")
+writer:write_row(["name", "note"])
+writer:write_row(["Alice", "says \"hi\"; bye"])
+writer:close()
+
+io:open("{path}")::read_all()
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&source);
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        result.unwrap(),
+        Value::String("name;note\nAlice;\"says \"\"hi\"\"; bye\"\n".to_string())
+    );
+}
+
+#[test]
+fn test_csv_parse_infers_types() {
+    let result = eval_program(
+        r#"
+        import std:csv
+        let rows = csv:parse("zip,count,price,active,note\n02134,3,-1.5,true,\n", nil, { infer: true })
+        rows[1]
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::List(vec![
+            Value::String("02134".to_string()),
+            Value::Number(DecimalNumber::from_i64(3)),
+            Value::Number(DecimalNumber::parse("-1.5").unwrap()),
+            Value::Boolean(true),
+            Value::Nil,
+        ])
+    );
+
+    // Strings stay the default, and options are checked
+    let result = eval_program("import std:csv\ncsv:parse(\"1,true\", \";\")[0][0]");
+    assert_eq!(result.unwrap(), Value::String("1,true".to_string()));
+    assert!(eval_program("import std:csv\ncsv:parse(\"1\", nil, { infer: 1 })").is_err());
+    assert!(eval_program("import std:csv\ncsv:parse(\"1\", nil, { types: true })").is_err());
+}
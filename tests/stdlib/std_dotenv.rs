@@ -22,7 +22,7 @@ fn test_dotenv_basic_load() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["BASIC_LOAD_KEY"]
         "#,
         path
@@ -41,7 +41,7 @@ fn test_dotenv_multiple_keys() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["MULTI_KEY1"] + "," + loaded["MULTI_KEY2"] + "," + loaded["MULTI_KEY3"]
         "#,
         path
@@ -61,7 +61,7 @@ fn test_dotenv_skip_comments() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["COMMENT_KEY1"] + "," + loaded["COMMENT_KEY2"]
         "#,
         path
@@ -80,7 +80,7 @@ fn test_dotenv_skip_blank_lines() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["BLANK_KEY1"] + "," + loaded["BLANK_KEY2"]
         "#,
         path
@@ -99,7 +99,7 @@ fn test_dotenv_skip_malformed_lines() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["MALFORM_KEY1"] + "," + loaded["MALFORM_KEY2"]
         "#,
         path
@@ -118,7 +118,7 @@ fn test_dotenv_skip_empty_keys() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["EMPTYKEY_KEY1"] + "," + loaded["EMPTYKEY_KEY2"]
         "#,
         path
@@ -137,7 +137,7 @@ fn test_dotenv_trim_whitespace() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["TRIM_KEY1"] + "," + loaded["TRIM_KEY2"]
         "#,
         path
@@ -230,7 +230,7 @@ fn test_dotenv_return_value_contains_loaded_keys() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         # Return number of keys loaded
         loaded::length()
         "#,
@@ -257,7 +257,7 @@ fn test_dotenv_return_value_excludes_skipped_keys() {
         env:var["KEY1"] = "existing"
 
         # Load without override - KEY1 should not be in return map
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
 
         # Return should be empty map since KEY1 was not loaded
         loaded::length()
@@ -279,7 +279,7 @@ fn test_dotenv_empty_file() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded::length()
         "#,
         path
@@ -300,7 +300,7 @@ fn test_dotenv_values_with_equals_sign() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         loaded["EQUALS_KEY1"]
         "#,
         path
@@ -319,7 +319,7 @@ fn test_dotenv_empty_values() {
         r#"
         import std:dotenv
 
-        loaded = dotenv:load("{}", false)
+        let loaded = dotenv:load("{}", false)
         # Empty values should be skipped, so KEY1 should not be in loaded
         # KEY2 should be in loaded
         !loaded::contains("KEY1") && loaded::contains("KEY2")
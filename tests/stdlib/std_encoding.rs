@@ -1,4 +1,4 @@
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_values::Value;
 
 #[test]
@@ -6,9 +6,9 @@ fn test_encoding_base64_roundtrip() {
     let result = eval_program(
         r#"
         import std:encoding
-        text = "Hello, World!"
-        encoded = encoding:base64_encode(text)
-        decoded = encoding:base64_decode(encoded)
+        let text = "Hello, World!"
+        let encoded = encoding:base64_encode(text)
+        let decoded = encoding:base64_decode(encoded)
         decoded
     "#,
     );
@@ -21,7 +21,7 @@ fn test_encoding_base64_known_value() {
     let result = eval_program(
         r#"
         import std:encoding
-        encoded = encoding:base64_encode("Hello")
+        let encoded = encoding:base64_encode("Hello")
         encoded
     "#,
     );
@@ -29,14 +29,60 @@ fn test_encoding_base64_known_value() {
     assert_eq!(result.unwrap(), Value::String("SGVsbG8=".to_string()));
 }
 
+#[test]
+fn test_encoding_base64url_known_values() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        let standard = encoding:base64_encode("<<???>>")
+        let url = encoding:base64url_encode("<<???>>")
+        let padded = encoding:base64url_encode("<<???>>", true)
+        "${standard} ${url} ${padded}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("PDw/Pz8+Pg== PDw_Pz8-Pg PDw_Pz8-Pg==".to_string())
+    );
+}
+
+#[test]
+fn test_encoding_base64url_decode_accepts_padding() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        let unpadded = encoding:base64url_decode("PDw_Pz8-Pg")
+        let padded = encoding:base64url_decode("PDw_Pz8-Pg==")
+        let roundtrip = encoding:base64url_decode(encoding:base64url_encode("héllo wörld"))
+        unpadded == "<<???>>" && padded == "<<???>>" && roundtrip == "héllo wörld"
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_encoding_base64url_decode_rejects_standard_alphabet() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        encoding:base64url_decode("PDw/Pz8+Pg==")
+    "#,
+    );
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("unexpected character '/' at offset 3")
+    );
+}
+
 #[test]
 fn test_encoding_hex_roundtrip() {
     let result = eval_program(
         r#"
         import std:encoding
-        text = "Hello"
-        encoded = encoding:hex_encode(text)
-        decoded = encoding:hex_decode(encoded)
+        let text = "Hello"
+        let encoded = encoding:hex_encode(text)
+        let decoded = encoding:hex_decode(encoded)
         decoded
     "#,
     );
@@ -49,7 +95,7 @@ fn test_encoding_hex_known_value() {
     let result = eval_program(
         r#"
         import std:encoding
-        encoded = encoding:hex_encode("ABC")
+        let encoded = encoding:hex_encode("ABC")
         encoded
     "#,
     );
@@ -62,7 +108,7 @@ fn test_encoding_hex_case_insensitive() {
     let result = eval_program(
         r#"
         import std:encoding
-        decoded = encoding:hex_decode("48656C6C6F")
+        let decoded = encoding:hex_decode("48656C6C6F")
         decoded
     "#,
     );
@@ -75,7 +121,7 @@ fn test_encoding_percent_encode() {
     let result = eval_program(
         r#"
         import std:encoding
-        encoded = encoding:percent_encode("hello world & stuff")
+        let encoded = encoding:percent_encode("hello world & stuff")
         encoded
     "#,
     );
@@ -91,7 +137,7 @@ fn test_encoding_percent_decode() {
     let result = eval_program(
         r#"
         import std:encoding
-        decoded = encoding:percent_decode("hello%20world")
+        let decoded = encoding:percent_decode("hello%20world")
         decoded
     "#,
     );
@@ -104,9 +150,9 @@ fn test_encoding_percent_roundtrip() {
     let result = eval_program(
         r#"
         import std:encoding
-        text = "foo bar & baz"
-        encoded = encoding:percent_encode(text)
-        decoded = encoding:percent_decode(encoded)
+        let text = "foo bar & baz"
+        let encoded = encoding:percent_encode(text)
+        let decoded = encoding:percent_decode(encoded)
         decoded
     "#,
     );
@@ -120,7 +166,7 @@ fn test_encoding_direct_imports() {
         r#"
         import std:encoding:base64_encode
         import std:encoding:base64_decode
-        encoded = base64_encode("test")
+        let encoded = base64_encode("test")
         base64_decode(encoded)
     "#,
     );
@@ -149,3 +195,106 @@ fn test_encoding_hex_decode_invalid() {
     );
     assert!(result.is_err());
 }
+
+#[test]
+fn test_encoding_jsonl_roundtrip() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        let records = [{ id: 1, ok: true }, { id: 2, ok: false }]
+        let text = encoding:jsonl_generate(records)
+        let parsed = encoding:jsonl_parse(text)
+        text::split("\n")::length() == 3 && parsed == records
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_encoding_jsonl_generate_compact_lines() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        encoding:jsonl_generate([[1, 2], "x", nil])
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("[1,2]\n\"x\"\nnull\n".to_string())
+    );
+}
+
+#[test]
+fn test_encoding_csv_row_matches_csv_generate() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        import std:csv
+        let fields = ["id", "name, full", "said \"hi\""]
+        let line = encoding:csv_row(fields)
+        let result = (line, line + "\n" == csv:generate([fields]), encoding:csv_row(["a", "b"], "\t"))
+        result
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::String("id,\"name, full\",\"said \"\"hi\"\"\"".to_string()),
+            Value::Boolean(true),
+            Value::String("a\tb".to_string()),
+        ])
+    );
+
+    assert_eval_fails(
+        "import std:encoding\nencoding:csv_row([\"a\", 1])",
+        "encoding:csv_row expects all cells to be strings",
+    );
+}
+
+#[test]
+fn test_encoding_jsonl_parse_invalid_line() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        encoding:jsonl_parse("{\"a\": 1}\n{oops}")
+    "#,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_encoding_hex_decode_tolerant() {
+    let result = eval_program(
+        r#"
+        import std:encoding
+        let spaced = encoding:hex_decode("48 65:6c\n6c 0x6F", true)
+        let prefixed = encoding:hex_decode("0x4869", true)
+        let result = (spaced, prefixed)
+        result
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::String("Hello".to_string()),
+            Value::String("Hi".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_encoding_hex_decode_tolerant_errors() {
+    assert_eval_fails(
+        "import std:encoding\nencoding:hex_decode(\"48 65 6\", true)",
+        "Odd number of digits (5)",
+    );
+    assert_eval_fails(
+        "import std:encoding\nencoding:hex_decode(\"48 zz\", true)",
+        "Invalid character 'z' at position 3",
+    );
+    // Strict mode is still the default
+    assert_eval_fails(
+        "import std:encoding\nencoding:hex_decode(\"48 65\")",
+        "invalid hex",
+    );
+}
@@ -1,5 +1,5 @@
 use suji_interpreter::AstInterpreter;
-use suji_runtime::ModuleRegistry;
+use suji_runtime::{ModuleRegistry, call_builtin};
 use suji_values::Env;
 use suji_values::{MapKey, Value};
 
@@ -58,3 +58,132 @@ fn std_env_args_and_argv_present_and_shaped() {
     // Lengths should match
     assert_eq!(args_map.len(), argv_map.len());
 }
+
+#[test]
+fn std_env_args_parse_flags_options_and_positional() {
+    let result = super::common::eval_program(
+        r#"
+        import std:env
+        let parsed = env:args_parse(
+            { verbose: "bool", quiet: "bool", output: "string", count: "number", dry_run: "bool" },
+            ["-vq", "--output=out.txt", "in.txt", "--count", "3", "--dry-run", "--", "--raw"]
+        )
+        let result = (parsed:verbose, parsed:quiet, parsed:output, parsed:count, parsed:dry_run, parsed:positional)
+        result
+    "#,
+    )
+    .unwrap();
+
+    let expected = Value::Tuple(vec![
+        Value::Boolean(true),
+        Value::Boolean(true),
+        Value::String("out.txt".to_string()),
+        Value::Number(suji_values::DecimalNumber::from_i64(3)),
+        Value::Boolean(true),
+        Value::List(vec![
+            Value::String("in.txt".to_string()),
+            Value::String("--raw".to_string()),
+        ]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn std_env_args_parse_defaults() {
+    let result = super::common::eval_program(
+        r#"
+        import std:env
+        let parsed = env:args_parse({ verbose: "bool", output: "string" }, [])
+        let result = (parsed:verbose, parsed:output, parsed:positional::length())
+        result
+    "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::Boolean(false),
+            Value::Nil,
+            Value::Number(suji_values::DecimalNumber::from_i64(0)),
+        ])
+    );
+}
+
+#[test]
+fn std_env_args_parse_negative_numbers_are_positional() {
+    let result = super::common::eval_program(
+        r#"
+        import std:env
+        let parsed = env:args_parse({ verbose: "bool", offset: "number" }, ["-5", "-v", "--offset", "-2", "-0.5"])
+        let result = (parsed:verbose, parsed:offset, parsed:positional)
+        result
+    "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::Boolean(true),
+            Value::Number(suji_values::DecimalNumber::from_i64(-2)),
+            Value::List(vec![
+                Value::String("-5".to_string()),
+                Value::String("-0.5".to_string()),
+            ]),
+        ])
+    );
+}
+
+#[test]
+fn std_env_args_parse_arity_message() {
+    suji_stdlib::runtime::builtins::register_all_builtins();
+    let error = call_builtin("env_args_parse", &[Value::Map(Default::default())]).unwrap_err();
+    assert!(
+        error.to_string().contains("expects 2 arguments"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn std_env_args_parse_errors() {
+    let cases = [
+        (
+            r#"env:args_parse({ verbose: "bool" }, ["--nope"])"#,
+            "Unknown flag '--nope'",
+        ),
+        (
+            r#"env:args_parse({ verbose: "bool" }, ["-x"])"#,
+            "Unknown flag '-x'",
+        ),
+        (
+            r#"env:args_parse({ output: "string" }, ["--output"])"#,
+            "expects a value",
+        ),
+        (
+            r#"env:args_parse({ count: "number" }, ["--count=abc"])"#,
+            "expects a number",
+        ),
+        (
+            r#"env:args_parse({ verbose: "bool", version: "bool" }, ["-v"])"#,
+            "Ambiguous flag '-v'",
+        ),
+        (
+            r#"env:args_parse({ verbose: "flag" }, [])"#,
+            "must be \"bool\"",
+        ),
+    ];
+
+    for (call, expected) in cases {
+        let code = format!("import std:env\n{}", call);
+        let error_msg = super::common::eval_program(&code).unwrap_err().to_string();
+        assert!(
+            error_msg.contains(expected),
+            "{} should fail with '{}', got: {}",
+            call,
+            expected,
+            error_msg
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use super::common::eval_program;
+use suji_values::Value;
+
+fn unique_temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("suji_test_fs_{}_{}", name, std::process::id()))
+}
+
+#[test]
+fn test_fs_copy_file() {
+    let src = unique_temp_path("copy_src.txt");
+    let dst = unique_temp_path("copy_dst.txt");
+    std::fs::write(&src, "content").unwrap();
+
+    let code = format!(
+        r#"
+        import std:fs
+        fs:copy("{}", "{}")
+    "#,
+        src.display(),
+        dst.display()
+    );
+
+    let result = eval_program(&code);
+    assert_eq!(result.unwrap(), Value::Nil);
+    assert_eq!(std::fs::read_to_string(&dst).unwrap(), "content");
+    assert!(src.exists());
+
+    std::fs::remove_file(src).ok();
+    std::fs::remove_file(dst).ok();
+}
+
+#[test]
+fn test_fs_copy_missing_source_error() {
+    let code = r#"
+        import std:fs
+        fs:copy("/nonexistent/suji/src.txt", "/nonexistent/suji/dst.txt")
+    "#;
+
+    let error_msg = eval_program(code).unwrap_err().to_string();
+    assert!(
+        error_msg.contains("Failed to copy"),
+        "Should report copy failure, got: {}",
+        error_msg
+    );
+}
+
+#[test]
+fn test_fs_move_file() {
+    let src = unique_temp_path("move_src.txt");
+    let dst = unique_temp_path("move_dst.txt");
+    std::fs::write(&src, "moved").unwrap();
+
+    let code = format!(
+        r#"
+        import std:fs
+        fs:move("{}", "{}")
+    "#,
+        src.display(),
+        dst.display()
+    );
+
+    let result = eval_program(&code);
+    assert_eq!(result.unwrap(), Value::Nil);
+    assert!(!src.exists());
+    assert_eq!(std::fs::read_to_string(&dst).unwrap(), "moved");
+
+    std::fs::remove_file(dst).ok();
+}
+
+#[test]
+fn test_fs_mkdir_and_remove() {
+    let root = unique_temp_path("mkdir_tree");
+    let nested = root.join("a").join("b");
+
+    let code = format!(
+        r#"
+        import std:fs
+        import std:os
+        fs:mkdir("{}", true)
+        fs:mkdir("{}")
+        fs:mkdir == os:mkdir
+    "#,
+        nested.display(),
+        nested.display()
+    );
+    assert_eq!(eval_program(&code).unwrap(), Value::Boolean(true));
+    assert!(nested.is_dir());
+
+    let code = format!(
+        r#"
+        import std:fs
+        fs:remove("{}")
+    "#,
+        root.display()
+    );
+    assert!(
+        eval_program(&code).is_err(),
+        "Non-recursive remove of a non-empty directory should fail"
+    );
+    assert!(root.exists());
+
+    let code = format!(
+        r#"
+        import std:fs
+        fs:remove("{}", true)
+    "#,
+        root.display()
+    );
+    assert_eq!(eval_program(&code).unwrap(), Value::Nil);
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_fs_mkdir_without_parents_error() {
+    let root = unique_temp_path("mkdir_no_parents");
+    let nested = root.join("child");
+
+    let code = format!(
+        r#"
+        import std:fs
+        fs:mkdir("{}", false)
+    "#,
+        nested.display()
+    );
+
+    let error_msg = eval_program(&code).unwrap_err().to_string();
+    assert!(
+        error_msg.contains("Failed to create directory"),
+        "Should report mkdir failure, got: {}",
+        error_msg
+    );
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_fs_walk_visits_tree_in_order_and_skips() {
+    let root = unique_temp_path("walk_tree");
+    std::fs::create_dir_all(root.join("a").join("skipped")).unwrap();
+    std::fs::create_dir_all(root.join("b")).unwrap();
+    std::fs::write(root.join("a").join("one.txt"), "1").unwrap();
+    std::fs::write(root.join("a").join("skipped").join("hidden.txt"), "").unwrap();
+    std::fs::write(root.join("b").join("two.txt"), "22").unwrap();
+
+    let code = format!(
+        r#"
+        import std:fs
+        let seen = []
+        fs:walk("{}", |path, info| {{
+            seen::push("${{info:depth}}:${{info:name}}")
+            match info:name {{
+                "skipped" => fs:SKIP,
+                _ => nil,
+            }}
+        }})
+        seen::join(",")
+    "#,
+        root.display()
+    );
+    let result = eval_program(&code);
+    std::fs::remove_dir_all(&root).ok();
+    assert_eq!(
+        result.unwrap(),
+        Value::String("1:a,2:one.txt,2:skipped,1:b,2:two.txt".to_string())
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fs_walk_guards_symlink_loops_and_reports_errors() {
+    let root = unique_temp_path("walk_loop");
+    std::fs::create_dir_all(root.join("dir")).unwrap();
+    std::os::unix::fs::symlink(&root, root.join("dir").join("loop")).unwrap();
+
+    let code = format!(
+        r#"
+        import std:fs
+        let count = 0
+        fs:walk("{}", |path, info| {{ count = count + 1 }}, true)
+        let errors = []
+        fs:walk("{}", |path, info| errors::push(info::get("error")))
+        let result = (count, errors::length(), errors[0]::contains("Failed to read"))
+        result
+    "#,
+        root.display(),
+        root.join("missing").display()
+    );
+    let result = eval_program(&code);
+    std::fs::remove_dir_all(&root).ok();
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::Number(suji_values::DecimalNumber::from_i64(2)),
+            Value::Number(suji_values::DecimalNumber::from_i64(1)),
+            Value::Boolean(true),
+        ])
+    );
+}
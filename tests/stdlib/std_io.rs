@@ -1,6 +1,6 @@
 //! Integration tests for io streams.
 
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_interpreter::AstInterpreter;
 use suji_runtime::{ModuleRegistry, call_builtin};
 use suji_values::{DecimalNumber, MapKey, RuntimeError, Value};
@@ -396,7 +396,7 @@ fn test_io_open_write_and_read_all() {
     let source = format!(
         r#"import std:io
 
-f = io:open("{path}", true, false)
+let f = io:open("{path}", true, false)
 f::write("hello\nworld")
 f::close()
 
@@ -422,8 +422,8 @@ fn test_io_open_read_lines_and_line() {
     let program_lines = format!(
         r#"import std:io
 
-f = io:open("{path}")
-lines = f::read_lines()
+let f = io:open("{path}")
+let lines = f::read_lines()
 lines
 "#,
         path = path.display()
@@ -443,12 +443,12 @@ lines
     let program_line = format!(
         r#"import std:io
 
-f = io:open("{path}")
-first = f::read_line()
-second = f::read_line()
-third = f::read_line()
-fourth = f::read_line()
-result = []
+let f = io:open("{path}")
+let first = f::read_line()
+let second = f::read_line()
+let third = f::read_line()
+let fourth = f::read_line()
+let result = []
 result::push(first)
 result::push(second)
 result::push(third)
@@ -480,7 +480,7 @@ fn test_io_open_write_returns_bytes() {
     let program = format!(
         r#"import std:io
 
-f = io:open("{path}", true, false)
+let f = io:open("{path}", true, false)
 f::write("abc")
 "#,
         path = path.display()
@@ -492,6 +492,85 @@ f::write("abc")
     cleanup(&path);
 }
 
+#[test]
+fn test_io_open_modes() {
+    let path = temp_path("modes");
+    cleanup(&path);
+
+    let program = format!(
+        r#"import std:io
+
+let w = io:open("{path}", "w")
+w::write("one\n")
+w::close()
+let a = io:open("{path}", {{ mode: "a" }})
+a::write("two\n")
+a::close()
+let r = io:open("{path}", "r")
+let content = r::read_all()
+r::close()
+content
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&program).unwrap();
+    assert_eq!(result, Value::String("one\ntwo\n".to_string()));
+
+    let program = format!(
+        r#"import std:io
+
+let rw = io:open("{path}", "rw")
+rw::write("ONE")
+rw::close()
+io:open("{path}", "r")::read_all()
+"#,
+        path = path.display()
+    );
+    let result = eval_program(&program).unwrap();
+    assert_eq!(result, Value::String("ONE\ntwo\n".to_string()));
+
+    cleanup(&path);
+}
+
+#[test]
+fn test_io_open_read_only_rejects_writes() {
+    let path = temp_path("read_only");
+    fs::write(&path, b"keep").unwrap();
+
+    let program = format!(
+        "import std:io\nlet f = io:open(\"{path}\", \"r\")\nf::write(\"x\")",
+        path = path.display()
+    );
+    let error_msg = eval_program(&program).unwrap_err().to_string();
+    assert!(error_msg.contains("read-only"), "got: {}", error_msg);
+    assert_eq!(fs::read(&path).unwrap(), b"keep");
+
+    let program = format!(
+        "import std:io\nlet f = io:open(\"{path}\")\nf::write(\"x\")",
+        path = path.display()
+    );
+    let error_msg = eval_program(&program).unwrap_err().to_string();
+    assert!(error_msg.contains("read-only"), "got: {}", error_msg);
+    assert_eq!(fs::read(&path).unwrap(), b"keep");
+
+    let program = format!(
+        "import std:io\nio:open(\"{path}\", {{ mode: \"r\", truncate: true }})",
+        path = path.display()
+    );
+    let error_msg = eval_program(&program).unwrap_err().to_string();
+    assert!(error_msg.contains("mode \"r\""), "got: {}", error_msg);
+    assert_eq!(fs::read(&path).unwrap(), b"keep");
+
+    let program = format!(
+        "import std:io\nio:open(\"{path}\", \"x\")",
+        path = path.display()
+    );
+    assert!(eval_program(&program).is_err());
+
+    cleanup(&path);
+}
+
 #[test]
 fn test_io_open_closed_stream_errors() {
     let path = temp_path("closed_error");
@@ -500,7 +579,7 @@ fn test_io_open_closed_stream_errors() {
     let program = format!(
         r#"import std:io
 
-f = io:open("{path}", true, false)
+let f = io:open("{path}", true, false)
 f::close()
 f::read_all()
 "#,
@@ -512,3 +591,217 @@ f::read_all()
 
     cleanup(&path);
 }
+
+#[test]
+fn test_io_open_line_buffering() {
+    let path = temp_path("line_buffering");
+    cleanup(&path);
+
+    let source = format!(
+        r#"import std:io
+
+let out = io:open("{path}", {{ create: true, truncate: true, buffering: "line" }})
+out::write("progress: ")
+let before_newline = io:open("{path}")::read_all()
+out::write("50%\n")
+let after_newline = io:open("{path}")::read_all()
+out::close()
+let result = [before_newline, after_newline]
+result
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![
+            Value::String("".to_string()),
+            Value::String("progress: 50%\n".to_string()),
+        ])
+    );
+
+    cleanup(&path);
+}
+
+#[test]
+fn test_io_open_block_buffering_and_flush() {
+    let path = temp_path("block_buffering");
+    cleanup(&path);
+
+    let source = format!(
+        r#"import std:io
+
+let out = io:open("{path}", {{ create: true, buffering: "block" }})
+out::write("line 1\n")
+let buffered = io:open("{path}")::read_all()
+out::flush()
+let flushed = io:open("{path}")::read_all()
+let result = [buffered, flushed]
+result
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![
+            Value::String("".to_string()),
+            Value::String("line 1\n".to_string()),
+        ])
+    );
+
+    cleanup(&path);
+}
+
+#[test]
+fn test_io_open_seek_and_tell() {
+    let path = temp_path("seek_tell");
+    fs::write(&path, b"header:0012\nbody").unwrap();
+
+    let source = format!(
+        r#"import std:io
+
+let f = io:open("{path}", "rw")
+f::seek(7)
+let offset = f::read_line()
+f::seek(-4, "end")
+let body = f::read_all()
+f::seek(0)
+f::write("HEAD")
+let position = f::tell()
+f::seek(0)
+let result = [offset, body, position, f::read_line()]
+result
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&source).unwrap();
+    assert_eq!(
+        result,
+        Value::List(vec![
+            Value::String("0012".to_string()),
+            Value::String("body".to_string()),
+            Value::Number(DecimalNumber::from_i64(4)),
+            Value::String("HEADer:0012".to_string()),
+        ])
+    );
+
+    let error_msg = eval_program("import std:io\nio:stdout::tell()")
+        .unwrap_err()
+        .to_string();
+    assert!(error_msg.contains("not seekable"), "got: {}", error_msg);
+
+    cleanup(&path);
+}
+
+#[test]
+fn test_io_memory_stream_reads_and_writes_like_a_file() {
+    let result = eval_program(
+        r#"
+        import std:io
+        import std:println
+
+        let out = io:memory()
+        println("first", out)
+        out::write("second\n")
+        let at_end = out::read_all()
+        out::seek(0)
+        let lines = out::read_lines()
+
+        let patched = io:memory("hello world")
+        patched::seek(6)
+        patched::write("there")
+        let result = (out::contents(), at_end, lines, out::tell(), patched::contents())
+        result
+    "#,
+    )
+    .unwrap();
+    assert_eq!(
+        result,
+        Value::Tuple(vec![
+            Value::String("first\nsecond\n".to_string()),
+            Value::String("".to_string()),
+            Value::List(vec![
+                Value::String("first".to_string()),
+                Value::String("second".to_string()),
+            ]),
+            Value::Number(DecimalNumber::from_i64(13)),
+            Value::String("hello there".to_string()),
+        ])
+    );
+
+    assert_eval_fails(
+        "import std:io\nio:stdout::contents()",
+        "contents() is only available on in-memory streams",
+    );
+    assert_eval_fails(
+        "import std:io\nio:memory(1)",
+        "expects initial to be a string",
+    );
+}
+
+#[test]
+fn test_io_stream_flush_errors() {
+    let result = eval_program("import std:io\nio:stdin::flush()");
+    let error_msg = result.unwrap_err().to_string();
+    assert!(
+        error_msg.contains("Cannot flush read-only stream"),
+        "got: {}",
+        error_msg
+    );
+
+    let result = eval_program("import std:io\nio:open(\"/tmp/x\", { buffering: \"full\" })");
+    assert!(result.is_err());
+
+    let result = eval_program("import std:io\nio:open(\"/tmp/x\", { append: true })");
+    let error_msg = result.unwrap_err().to_string();
+    assert!(error_msg.contains("unknown option"), "got: {}", error_msg);
+}
+
+#[test]
+fn test_print_and_println_options() {
+    let path = temp_path("print_options");
+    cleanup(&path);
+
+    let source = format!(
+        r#"import std:io
+import std:print
+import std:println
+
+let f = io:open("{path}", true, false)
+println((1, "a", [2]), {{ sep: ", ", end: "!\n", out: f }})
+print((3, 4), {{ sep: "-", out: f }})
+println("", {{ out: f }})
+println((5, 6), {{ out: f }})
+println((7, 8), f)
+f::close()
+
+f = io:open("{path}", false, false)
+f::read_all()
+"#,
+        path = path.display()
+    );
+
+    let result = eval_program(&source).unwrap();
+    assert_eq!(
+        result,
+        Value::String("1, a, [2]!\n3-4\n5 6\n(7, 8)\n".to_string())
+    );
+
+    cleanup(&path);
+}
+
+#[test]
+fn test_println_rejects_bad_options() {
+    assert_eval_fails(
+        "import std:println\nprintln(1, { spe: \",\" })",
+        "Unknown print option 'spe'",
+    );
+    assert_eval_fails(
+        "import std:println\nprintln(1, { sep: 2 })",
+        "print option 'sep' must be a string",
+    );
+}
@@ -1,13 +1,13 @@
-use super::common::eval_program;
-use suji_values::Value;
+use super::common::{assert_eval_fails, eval_program};
+use suji_values::{DecimalNumber, Value};
 
 #[test]
 fn test_math_constants() {
     let result = eval_program(
         r#"
         import std:math
-        pi_ok = math:PI > 3.14 && math:PI < 3.15
-        e_ok = math:E > 2.71 && math:E < 2.72
+        let pi_ok = math:PI > 3.14 && math:PI < 3.15
+        let e_ok = math:E > 2.71 && math:E < 2.72
         pi_ok && e_ok
     "#,
     );
@@ -20,7 +20,7 @@ fn test_math_sin() {
     let result = eval_program(
         r#"
         import std:math
-        result = math:sin(0)
+        let result = math:sin(0)
         result > -0.01 && result < 0.01
     "#,
     );
@@ -33,7 +33,7 @@ fn test_math_cos() {
     let result = eval_program(
         r#"
         import std:math
-        result = math:cos(0)
+        let result = math:cos(0)
         result > 0.99 && result < 1.01
     "#,
     );
@@ -46,9 +46,9 @@ fn test_math_log_exp_identity() {
     let result = eval_program(
         r#"
         import std:math
-        x = 5
-        result = math:log(math:exp(x))
-        diff = result - x
+        let x = 5
+        let result = math:log(math:exp(x))
+        let diff = result - x
         diff::abs() < 0.0001
     "#,
     );
@@ -61,7 +61,7 @@ fn test_math_log10() {
     let result = eval_program(
         r#"
         import std:math
-        result = math:log10(100)
+        let result = math:log10(100)
         result > 1.99 && result < 2.01
     "#,
     );
@@ -90,3 +90,93 @@ fn test_math_log_negative() {
     );
     assert!(result.is_err());
 }
+
+#[test]
+fn test_math_round_half_even() {
+    let result = eval_program(
+        r#"
+        import std:math
+        [math:round_half_even(2.5), math:round_half_even(3.5), math:round_half_even(2.675, 2), math:round_half_even(-0.125, 2)]
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::List(vec![
+            Value::Number(DecimalNumber::from_i64(2)),
+            Value::Number(DecimalNumber::from_i64(4)),
+            Value::Number(DecimalNumber::parse("2.68").unwrap()),
+            Value::Number(DecimalNumber::parse("-0.12").unwrap()),
+        ])
+    );
+
+    let result = eval_program(
+        r#"
+        import std:math
+        math:round_half_even(1.5, -1)
+    "#,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_math_clamp_lerp_map_range() {
+    let result = eval_program(
+        r#"
+        import std:math
+        [math:clamp(120, 0, 100), math:clamp(-5, 0, 100), math:clamp(7, 0, 100), math:lerp(10, 20, 0.25), math:lerp(10, 20, 2), math:map_range(50, 0, 200, 0, 1), math:map_range(1, 0, 3, 10, 0), math:map_range(5, 10, 0, 0, 100)]
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::List(vec![
+            Value::Number(DecimalNumber::from_i64(100)),
+            Value::Number(DecimalNumber::from_i64(0)),
+            Value::Number(DecimalNumber::from_i64(7)),
+            Value::Number(DecimalNumber::parse("12.5").unwrap()),
+            Value::Number(DecimalNumber::from_i64(30)),
+            Value::Number(DecimalNumber::parse("0.25").unwrap()),
+            Value::Number(DecimalNumber::parse("6.6666666666666666666666666667").unwrap()),
+            Value::Number(DecimalNumber::from_i64(50)),
+        ])
+    );
+
+    assert!(eval_program("import std:math\nmath:map_range(1, 2, 2, 0, 1)").is_err());
+    assert!(eval_program("import std:math\nmath:clamp(1, 5, 0)").is_err());
+}
+
+#[test]
+fn test_math_statistics() {
+    let result = eval_program(
+        r#"
+        import std:math
+        let scores = [2, 4, 4, 4, 5, 5, 7, 9]
+        let stats = [math:mean(scores), math:median(scores), math:variance(scores), math:stddev(scores), math:median([3, 1, 2]), math:mean([1, 2]), math:stddev([1.5])]
+        stats
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::List(vec![
+            Value::Number(DecimalNumber::from_i64(5)),
+            Value::Number(DecimalNumber::parse("4.5").unwrap()),
+            Value::Number(DecimalNumber::from_i64(4)),
+            Value::Number(DecimalNumber::from_i64(2)),
+            Value::Number(DecimalNumber::from_i64(2)),
+            Value::Number(DecimalNumber::parse("1.5").unwrap()),
+            Value::Number(DecimalNumber::from_i64(0)),
+        ])
+    );
+
+    assert_eval_fails(
+        "import std:math\nmath:mean([])",
+        "math:mean of an empty list is undefined",
+    );
+    assert_eval_fails(
+        "import std:math\nmath:median([1, \"2\"])",
+        "math:median expects a list of numbers, but element 1 is a string",
+    );
+    assert_eval_fails(
+        "import std:math\nmath:stddev(3)",
+        "math:stddev expects a list of numbers, got number",
+    );
+}
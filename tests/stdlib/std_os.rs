@@ -6,8 +6,8 @@ fn test_os_name_returns_valid_string() {
     let result = eval_program(
         r#"
         import std:os
-        name = os:name()
-        is_valid = (name == "linux") || (name == "darwin") || (name == "windows")
+        let name = os:name()
+        let is_valid = (name == "linux") || (name == "darwin") || (name == "windows")
         is_valid
     "#,
     );
@@ -32,7 +32,7 @@ fn test_os_hostname_returns_non_empty_string() {
     let result = eval_program(
         r#"
         import std:os
-        hostname = os:hostname()
+        let hostname = os:hostname()
         hostname::length() > 0
     "#,
     );
@@ -57,7 +57,7 @@ fn test_os_uptime_ms_returns_non_negative_number() {
     let result = eval_program(
         r#"
         import std:os
-        uptime = os:uptime_ms()
+        let uptime = os:uptime_ms()
         uptime >= 0
     "#,
     );
@@ -82,7 +82,7 @@ fn test_os_tmp_dir_returns_non_empty_string() {
     let result = eval_program(
         r#"
         import std:os
-        tmp_dir = os:tmp_dir()
+        let tmp_dir = os:tmp_dir()
         tmp_dir::length() > 0
     "#,
     );
@@ -107,7 +107,7 @@ fn test_os_home_dir_returns_non_empty_string() {
     let result = eval_program(
         r#"
         import std:os
-        home_dir = os:home_dir()
+        let home_dir = os:home_dir()
         home_dir::length() > 0
     "#,
     );
@@ -132,7 +132,7 @@ fn test_os_work_dir_returns_non_empty_string() {
     let result = eval_program(
         r#"
         import std:os
-        work_dir = os:work_dir()
+        let work_dir = os:work_dir()
         work_dir::length() > 0
     "#,
     );
@@ -157,7 +157,7 @@ fn test_os_pid_returns_positive_number() {
     let result = eval_program(
         r#"
         import std:os
-        pid = os:pid()
+        let pid = os:pid()
         pid > 0
     "#,
     );
@@ -182,7 +182,7 @@ fn test_os_ppid_returns_non_negative_number() {
     let result = eval_program(
         r#"
         import std:os
-        ppid = os:ppid()
+        let ppid = os:ppid()
         ppid >= 0
     "#,
     );
@@ -208,7 +208,7 @@ fn test_os_uid_returns_non_negative_number_unix() {
     let result = eval_program(
         r#"
         import std:os
-        uid = os:uid()
+        let uid = os:uid()
         uid >= 0
     "#,
     );
@@ -251,7 +251,7 @@ fn test_os_gid_returns_non_negative_number_unix() {
     let result = eval_program(
         r#"
         import std:os
-        gid = os:gid()
+        let gid = os:gid()
         gid >= 0
     "#,
     );
@@ -325,6 +325,8 @@ fn test_os_all_functions_available() {
         "import std:os; os:ppid()",
         "import std:os; os:uid()",
         "import std:os; os:gid()",
+        "import std:os; os:cpu_count()",
+        "import std:os; os:mem_info()",
     ];
 
     for test in tests {
@@ -338,15 +340,15 @@ fn test_os_directories_are_absolute_paths() {
     let result = eval_program(
         r#"
         import std:os
-        tmp_dir = os:tmp_dir()
-        home_dir = os:home_dir()
-        work_dir = os:work_dir()
+        let tmp_dir = os:tmp_dir()
+        let home_dir = os:home_dir()
+        let work_dir = os:work_dir()
         
         # On Unix, absolute paths start with /
         # On Windows, they start with drive letter or \\
-        tmp_has_content = tmp_dir::length() > 0
-        home_has_content = home_dir::length() > 0
-        work_has_content = work_dir::length() > 0
+        let tmp_has_content = tmp_dir::length() > 0
+        let home_has_content = home_dir::length() > 0
+        let work_has_content = work_dir::length() > 0
         
         tmp_has_content && home_has_content && work_has_content
     "#,
@@ -354,3 +356,169 @@ fn test_os_directories_are_absolute_paths() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Boolean(true));
 }
+
+#[test]
+fn test_os_cpu_count_returns_positive_number_or_nil() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let count = os:cpu_count()
+        match count {
+            nil => true,
+            _ => count >= 1 && count::is_int(),
+        }
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_os_mem_info_reports_total_and_available() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let info = os:mem_info()
+        match info {
+            nil => true,
+            _ => info:total > 0 && info:available >= 0 && info:available <= info:total,
+        }
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_os_interfaces_lists_addresses() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let interfaces = os:interfaces()
+        let malformed = interfaces::filter(|iface| {
+            !(iface:name::is_string() && iface:address::is_string() && iface:is_loopback::is_bool())
+        })
+        let odd_loopbacks = interfaces::filter(|iface| {
+            iface:is_loopback && !(iface:address::starts_with("127.") || iface:address == "::1")
+        })
+        malformed::is_empty() && odd_loopbacks::is_empty()
+    "#,
+    )
+    .unwrap();
+    assert_eq!(result, Value::Boolean(true));
+    assert!(eval_program("import std:os; os:interfaces(1)").is_err());
+}
+
+#[test]
+fn test_os_cpu_count_and_mem_info_take_no_arguments() {
+    assert!(eval_program("import std:os; os:cpu_count(1)").is_err());
+    assert!(eval_program("import std:os; os:mem_info(1)").is_err());
+}
+
+#[test]
+fn test_os_getenv_or_respects_env_overlay() {
+    let result = eval_program(
+        r#"
+        import std:os
+        import std:env
+        env:var["SUJI_GETENV_OR_TEST"] = "set"
+        os:getenv_or("SUJI_GETENV_OR_TEST", "fallback")
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::String("set".to_string()));
+}
+
+#[test]
+fn test_os_getenv_or_returns_default_when_unset() {
+    let result = eval_program(
+        r#"
+        import std:os
+        os:getenv_or("SUJI_GETENV_OR_MISSING", "fallback")
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::String("fallback".to_string()));
+    assert!(eval_program("import std:os; os:getenv_or(1, \"x\")").is_err());
+    assert!(eval_program("import std:os; os:getenv_or(\"X\")").is_err());
+}
+
+#[test]
+fn test_os_env_expand_uses_env_overlay() {
+    let result = eval_program(
+        r#"
+        import std:os
+        import std:env
+        env:var["SUJI_ENV_EXPAND_DIR"] = "/srv/app"
+        let expanded = os:env_expand("\${SUJI_ENV_EXPAND_DIR}/logs:$SUJI_ENV_EXPAND_MISSING:\\\$5")
+        let kept = os:env_expand("$SUJI_ENV_EXPAND_MISSING/x", true)
+        "${expanded}|${kept}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("/srv/app/logs::$5|$SUJI_ENV_EXPAND_MISSING/x".to_string())
+    );
+    assert!(eval_program("import std:os; os:env_expand(1)").is_err());
+    assert!(eval_program("import std:os; os:env_expand(\"$A\", 1)").is_err());
+}
+
+#[test]
+fn test_os_program_name_returns_string_or_nil() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let name = os:program_name()
+        match name {
+            nil => true,
+            _ => name::length() > 0,
+        }
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+    assert!(eval_program("import std:os; os:program_name(1)").is_err());
+}
+
+#[test]
+fn test_os_exec_pipes_stdin_and_quotes_args() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let text = "apple pie\nbanana\napple tart\n"
+        let matches = os:exec("grep", ["apple"], { stdin: text })
+        let quoted = os:exec("printf '%s|'", ["it's", "a b", 3])
+        "${matches}/${quoted}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("apple pie\napple tart/it's|a b|3|".to_string())
+    );
+}
+
+#[test]
+fn test_os_exec_errors_are_reported() {
+    assert!(eval_program("import std:os; os:exec(\"exit 3\")").is_err());
+    assert!(eval_program("import std:os; os:exec(\"cat\", [], { stdin: 1 })").is_err());
+    assert!(eval_program("import std:os; os:exec(\"cat\", [], { input: \"x\" })").is_err());
+    assert!(eval_program("import std:os; os:exec(\"cat\", \"x\")").is_err());
+}
+
+#[test]
+fn test_os_kill_signals_processes() {
+    let result = eval_program(
+        r#"
+        import std:os
+        let alive = os:kill(os:pid(), 0)
+        let worker = os:exec("sleep 30 > /dev/null 2>&1 & echo $!")::to_number()
+        let stopped = os:kill(worker, "sigterm")
+        alive && stopped
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_os_kill_errors_are_reported() {
+    assert!(eval_program("import std:os; os:kill(999999999, 0)").is_err());
+    assert!(eval_program("import std:os; os:kill(0)").is_err());
+    assert!(eval_program("import std:os; os:kill(os:pid(), \"NOPE\")").is_err());
+    assert!(eval_program("import std:os; os:kill(\"1\")").is_err());
+    assert!(eval_program("import std:os; os:kill()").is_err());
+}
@@ -1,4 +1,4 @@
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_values::Value;
 
 #[test]
@@ -386,3 +386,166 @@ fn test_os_rmdir_type_errors() {
         error_msg
     );
 }
+
+#[test]
+fn test_os_temp_file_creates_unique_files() {
+    let result = eval_program(
+        r#"
+        import std:os
+        (os:temp_file(), os:temp_file())
+    "#,
+    )
+    .unwrap();
+
+    if let Value::Tuple(paths) = result {
+        let (Value::String(a), Value::String(b)) = (&paths[0], &paths[1]) else {
+            panic!("Expected string paths");
+        };
+        assert_ne!(a, b);
+        assert!(std::path::Path::new(a).is_file());
+        assert!(a.starts_with(std::env::temp_dir().to_str().unwrap()));
+        std::fs::remove_file(a).unwrap();
+        std::fs::remove_file(b).unwrap();
+    } else {
+        panic!("Expected tuple of paths");
+    }
+}
+
+#[test]
+fn test_os_temp_dir_create() {
+    let result = eval_program(
+        r#"
+        import std:os
+        os:temp_dir_create()
+    "#,
+    )
+    .unwrap();
+
+    if let Value::String(path) = result {
+        assert!(std::path::Path::new(&path).is_dir());
+        std::fs::remove_dir(&path).unwrap();
+    } else {
+        panic!("Expected string path");
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_os_chmod_and_chown() {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let test_file = std::env::temp_dir().join("suji_test_chmod.sh");
+    std::fs::write(&test_file, "#!/bin/sh\n").unwrap();
+    let path_str = test_file.to_str().unwrap();
+    let gid = std::fs::metadata(&test_file).unwrap().gid();
+
+    let code = format!(
+        r#"
+        import std:os
+        os:chmod("{path}", "755")
+        let first = os:stat("{path}"):mode
+        os:chmod("{path}", 0)
+        os:chmod("{path}", first)
+        os:chown("{path}", nil, {gid})
+    "#,
+        path = path_str,
+        gid = gid
+    );
+    let result = eval_program(&code);
+    let mode = std::fs::metadata(&test_file).unwrap().permissions().mode();
+    std::fs::remove_file(&test_file).ok();
+    assert_eq!(result.unwrap(), Value::Nil);
+    assert_eq!(mode & 0o7777, 0o755);
+
+    assert!(eval_program("import std:os\nos:chmod(\"/nonexistent/suji\", \"644\")").is_err());
+    assert!(eval_program("import std:os\nos:chmod(\"x\", \"9\")").is_err());
+    assert!(eval_program("import std:os\nos:chown(\"/nonexistent/suji\", nil, nil)").is_err());
+    assert!(eval_program("import std:os\nos:chown(\"x\", -1, nil)").is_err());
+}
+
+/// Keep writing `file` until `done` is set, so a watch sees it however long setup takes
+fn touch_until_done(
+    file: std::path::PathBuf,
+    done: std::sync::Arc<std::sync::atomic::AtomicBool>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        for i in 0..100 {
+            if done.load(std::sync::atomic::Ordering::Relaxed) {
+                break;
+            }
+            std::fs::write(&file, i.to_string()).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+    })
+}
+
+#[test]
+fn test_os_watch_reports_changes_until_stopped() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let dir = std::env::temp_dir().join(format!("suji_test_watch_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = touch_until_done(dir.join("watched.txt"), done.clone());
+
+    let code = format!(
+        r#"
+        import std:os
+        let seen = []
+        os:watch("{}", |event| {{
+            seen::push(event)
+            !event:path::ends_with("watched.txt")
+        }})
+        let last = seen[-1]
+        let result = (last:path::ends_with("watched.txt"), ["create", "modify"]::contains(last:kind))
+        result
+    "#,
+        dir.to_str().unwrap()
+    );
+    let result = eval_program(&code);
+    done.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![Value::Boolean(true), Value::Boolean(true)])
+    );
+    assert_eq!(suji_stdlib::runtime::builtins::watch::active_watches(), 0);
+}
+
+#[test]
+fn test_os_watch_released_when_callback_errors() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let dir = std::env::temp_dir().join(format!("suji_test_watch_err_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = touch_until_done(dir.join("watched.txt"), done.clone());
+
+    let code = format!(
+        r#"
+        import std:os
+        os:watch("{}", |event| event:no_such_key::length())
+    "#,
+        dir.to_str().unwrap()
+    );
+    let result = eval_program(&code);
+    done.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert!(result.is_err());
+    assert_eq!(suji_stdlib::runtime::builtins::watch::active_watches(), 0);
+}
+
+#[test]
+fn test_os_watch_missing_path_errors() {
+    assert_eval_fails(
+        r#"import std:os
+os:watch("/definitely/not/a/real/path", |event| false)"#,
+        "Failed to watch '/definitely/not/a/real/path'",
+    );
+}
@@ -12,7 +12,7 @@ fn test_os_stat_basic() {
     let code = format!(
         r#"
         import std:os
-        stat = os:stat("{}")
+        let stat = os:stat("{}")
         stat:size
     "#,
         path_str
@@ -34,7 +34,7 @@ fn test_os_stat_directory() {
     let code = format!(
         r#"
         import std:os
-        stat = os:stat("{}")
+        let stat = os:stat("{}")
         stat:is_directory
     "#,
         path_str
@@ -63,7 +63,7 @@ fn test_os_stat_follow_symlinks() {
     let code1 = format!(
         r#"
         import std:os
-        stat = os:stat("{}", false)
+        let stat = os:stat("{}", false)
         stat:is_symlink
     "#,
         link_str
@@ -76,7 +76,7 @@ fn test_os_stat_follow_symlinks() {
     let code2 = format!(
         r#"
         import std:os
-        stat = os:stat("{}", true)
+        let stat = os:stat("{}", true)
         stat:is_symlink
     "#,
         link_str
@@ -197,10 +197,10 @@ fn test_os_stat_map_fields() {
     let code = format!(
         r#"
         import std:os
-        stat = os:stat("{}")
-        has_size = stat::contains("size")
-        has_is_dir = stat::contains("is_directory")
-        has_is_symlink = stat::contains("is_symlink")
+        let stat = os:stat("{}")
+        let has_size = stat::contains("size")
+        let has_is_dir = stat::contains("is_directory")
+        let has_is_symlink = stat::contains("is_symlink")
         has_size && has_is_dir && has_is_symlink
     "#,
         path_str
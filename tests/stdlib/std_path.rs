@@ -327,15 +327,49 @@ fn test_path_normalize_too_many_dotdot_absolute() {
     assert_eq!(eval_path(code), Value::String("/".to_string()));
 }
 
+#[test]
+#[cfg(unix)]
+fn test_path_relative_unix() {
+    let cases = [
+        (r#"path:relative("/a/b/c", "/a/d")"#, "../b/c"),
+        (r#"path:relative("/a/b", "/a/b/")"#, "."),
+        (r#"path:relative("/a", "/a/b/c")"#, "../.."),
+        (r#"path:relative("/x/./y", "/")"#, "x/y"),
+        (r#"path:relative("src/main.si", ".")"#, "src/main.si"),
+        (r#"path:relative("lib", "src/bin")"#, "../../lib"),
+    ];
+    for (call, expected) in cases {
+        let code = format!("import std:path\n{}", call);
+        assert_eq!(eval_path(&code), Value::String(expected.to_string()));
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_path_absolute_unix() {
+    let code = r#"
+        import std:path
+        path:absolute("/tmp/./x/../y/")
+    "#;
+    assert_eq!(eval_path(code), Value::String("/tmp/y".to_string()));
+
+    let code = r#"
+        import std:path
+        import std:os
+        path:absolute("src/../lib") == os:work_dir() + "/lib"
+    "#;
+    assert_eq!(eval_path(code), Value::Boolean(true));
+}
+
 #[test]
 fn test_path_integration_dirname_basename() {
     let code = r#"
         import std:path
-        p = "/foo/bar/baz.txt"
-        dir = path:dirname(p)
-        base = path:basename(p)
-        ext = path:extname(p)
-        result = dir + "/" + base
+        let p = "/foo/bar/baz.txt"
+        let dir = path:dirname(p)
+        let base = path:basename(p)
+        let ext = path:extname(p)
+        let result = dir + "/" + base
         result
     "#;
     assert_eq!(
@@ -363,13 +397,13 @@ fn test_path_platform_detection() {
         import std:os
 
         # The separator should match the OS
-        sep = match os:name() {
+        let sep = match os:name() {
             "windows" => "\\",
             _ => "/",
         }
 
         # Join should use the correct separator
-        joined = path:join(["a", "b"])
+        let joined = path:join(["a", "b"])
         joined::contains(sep)
     "#;
     assert_eq!(eval_path(code), Value::Boolean(true));
@@ -1,4 +1,4 @@
-use super::common::eval_program_with_modules;
+use super::common::{assert_eval_fails, eval_program_with_modules};
 use suji_values::{DecimalNumber, Value};
 
 #[test]
@@ -7,7 +7,7 @@ fn random_import_and_seed() {
 import std:random
 
 random:seed(42)
-val = random:random()
+let val = random:random()
 "#;
     let result = eval_program_with_modules(src).expect("eval ok");
     assert!(result.is_some());
@@ -18,7 +18,7 @@ fn test_random_string() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:string("abc", 10)
+let s = random:string("abc", 10)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -30,7 +30,7 @@ fn test_random_hex_string() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:hex_string(8)
+let s = random:hex_string(8)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -42,7 +42,7 @@ fn test_random_hex_string_default() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:hex_string()
+let s = random:hex_string()
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -54,7 +54,7 @@ fn test_random_alpha_string() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:alpha_string(10, false)
+let s = random:alpha_string(10, false)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -66,7 +66,7 @@ fn test_random_alpha_string_defaults() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:alpha_string()
+let s = random:alpha_string()
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -78,7 +78,7 @@ fn test_random_numeric_string() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:numeric_string(6)
+let s = random:numeric_string(6)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -90,7 +90,7 @@ fn test_random_numeric_string_default() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:numeric_string()
+let s = random:numeric_string()
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -102,7 +102,7 @@ fn test_random_alphanumeric_string() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:alphanumeric_string(12, true)
+let s = random:alphanumeric_string(12, true)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -114,7 +114,7 @@ fn test_random_alphanumeric_string_defaults() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:alphanumeric_string()
+let s = random:alphanumeric_string()
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
@@ -126,10 +126,10 @@ fn test_random_string_only_allowed_chars() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:hex_string(20)
-allowed = "0123456789abcdef"
-chars = s::to_list()
-all_valid = true
+let s = random:hex_string(20)
+let allowed = "0123456789abcdef"
+let chars = s::to_list()
+let all_valid = true
 loop through chars with c {
     match allowed::contains(c) {
         false => { all_valid = false; break },
@@ -147,9 +147,51 @@ fn test_random_string_zero_length() {
     let code = r#"
 import std:random
 random:seed(42)
-s = random:string("abc", 0)
+let s = random:string("abc", 0)
 s::length()
 "#;
     let result = eval_program_with_modules(code).expect("eval ok");
     assert_eq!(result.unwrap(), Value::Number(DecimalNumber::from_i64(0)));
 }
+
+#[test]
+fn test_random_weighted_choice() {
+    let code = r#"
+import std:random
+random:seed(7)
+let first = (0..20)::map(|n| random:weighted_choice(["a", "b", "c"], [1, 0, 3]))
+random:seed(7)
+let second = (0..20)::map(|n| random:weighted_choice(["a", "b", "c"], [1, 0, 3]))
+let result = (first == second, first::contains("b"), random:weighted_choice(["only"], [0.5]))
+result
+"#;
+    let result = eval_program_with_modules(code).expect("eval ok");
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::Boolean(true),
+            Value::Boolean(false),
+            Value::String("only".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_random_weighted_choice_rejects_bad_weights() {
+    assert_eval_fails(
+        "import std:random\nrandom:weighted_choice([1, 2], [1])",
+        "2 items but 1 weights",
+    );
+    assert_eval_fails(
+        "import std:random\nrandom:weighted_choice([1, 2], [1, -1])",
+        "negative",
+    );
+    assert_eval_fails(
+        "import std:random\nrandom:weighted_choice([1, 2], [0, 0])",
+        "positive total weight",
+    );
+    assert_eval_fails(
+        "import std:random\nrandom:weighted_choice([], [])",
+        "positive total weight",
+    );
+}
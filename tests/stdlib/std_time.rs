@@ -1,4 +1,4 @@
-use super::common::eval_program;
+use super::common::{assert_eval_fails, eval_program};
 use suji_values::Value;
 
 #[test]
@@ -6,10 +6,10 @@ fn test_time_now_shape() {
     let result = eval_program(
         r#"
         import std:time
-        now = time:now()
-        has_epoch = now::contains("epoch_ms")
-        has_iso = now::contains("iso")
-        has_tz = now::contains("tz")
+        let now = time:now()
+        let has_epoch = now::contains("epoch_ms")
+        let has_iso = now::contains("iso")
+        let has_tz = now::contains("tz")
         has_epoch && has_iso && has_tz
     "#,
     );
@@ -22,7 +22,7 @@ fn test_time_sleep_returns_nil() {
     let result = eval_program(
         r#"
         import std:time
-        result = time:sleep(10)
+        let result = time:sleep(10)
         result
     "#,
     );
@@ -35,7 +35,7 @@ fn test_time_parse_iso() {
     let result = eval_program(
         r#"
         import std:time
-        parsed = time:parse_iso("2024-03-15T14:30:00Z")
+        let parsed = time:parse_iso("2024-03-15T14:30:00Z")
         parsed::contains("epoch_ms")
     "#,
     );
@@ -48,12 +48,144 @@ fn test_time_roundtrip() {
     let result = eval_program(
         r#"
         import std:time
-        iso_string = "2024-03-15T14:30:00Z"
-        parsed = time:parse_iso(iso_string)
-        formatted = time:format_iso(parsed:epoch_ms, "Z")
+        let iso_string = "2024-03-15T14:30:00Z"
+        let parsed = time:parse_iso(iso_string)
+        let formatted = time:format_iso(parsed:epoch_ms, "Z")
         formatted::contains("2024-03-15") && formatted::contains("14:30:00")
     "#,
     );
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), Value::Boolean(true));
 }
+
+#[test]
+fn test_time_to_zone_components() {
+    let result = eval_program(
+        r#"
+        import std:time
+        # 2024-03-15T14:30:00Z is after the US daylight saving switch
+        let t = time:to_zone(1710513000000, "America/New_York")
+        "${t:year}-${t:month}-${t:day} ${t:hour}:${t:minute} ${t:offset} ${t:offset_seconds} ${t:tz}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("2024-3-15 10:30 -04:00 -14400 America/New_York".to_string())
+    );
+}
+
+#[test]
+fn test_time_to_zone_tracks_dst_and_half_hour_offsets() {
+    let result = eval_program(
+        r#"
+        import std:time
+        let winter = time:to_zone(time:parse_iso("2024-01-15T12:00:00Z"), "America/New_York")
+        let india = time:to_zone(1705320000000, "Asia/Kolkata")
+        "${winter:hour} ${winter:offset} ${india:hour}:${india:minute} ${india:iso}"
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::String("7 -05:00 17:30 2024-01-15T17:30:00.000+05:30".to_string())
+    );
+}
+
+#[test]
+fn test_time_to_zone_invalid_name() {
+    let result = eval_program(
+        r#"
+        import std:time
+        time:to_zone(0, "Mars/Olympus_Mons")
+    "#,
+    );
+    let err = result.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid time zone name 'Mars/Olympus_Mons'")
+    );
+}
+
+#[test]
+fn test_time_to_local_preserves_instant() {
+    let result = eval_program(
+        r#"
+        import std:time
+        let t = time:to_local(1710513000000)
+        t:epoch_ms == 1710513000000 && t::contains("offset_seconds") && t:tz == t:offset
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+}
+
+#[test]
+fn test_time_deadline_and_sleep_until() {
+    let result = eval_program(
+        r#"
+        import std:time
+        let start = time:now()
+        let deadline = time:deadline(0.03)
+        let ahead = deadline:epoch_ms - start:epoch_ms
+        time:sleep_until(deadline)
+        let waited = time:now():epoch_ms >= deadline:epoch_ms
+        let past = time:sleep_until(start)
+        ahead >= 30 && ahead < 1000 && waited && past == nil
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+
+    assert!(eval_program("import std:time\ntime:sleep_until(\"soon\")").is_err());
+
+    let result = eval_program(
+        r#"
+        import std:time
+        let ahead = time:deadline(90):epoch_ms - time:now():epoch_ms
+        ahead >= 89000 && ahead <= 90000
+    "#,
+    );
+    assert_eq!(result.unwrap(), Value::Boolean(true));
+
+    for seconds in ["1e18", "9223372036854775807", "-9223372036854775807"] {
+        let error = eval_program(&format!("import std:time\ntime:deadline({})", seconds))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("out of range"), "got: {}", error);
+    }
+}
+
+#[test]
+fn test_time_every_ticks_until_false() {
+    let result = eval_program(
+        r#"
+        import std:time
+        let start = time:now():epoch_ms
+        let ticks = 0
+        let result = time:every(0.02, || {
+            ticks = ticks + 1
+            # The closure's own run time does not push later ticks back
+            time:sleep(5)
+            ticks < 4
+        })
+        let elapsed = time:now():epoch_ms - start
+        let summary = (result, ticks, elapsed >= 60, elapsed < 1000)
+        summary
+    "#,
+    );
+    assert_eq!(
+        result.unwrap(),
+        Value::Tuple(vec![
+            Value::Nil,
+            Value::Number(suji_values::DecimalNumber::from_i64(4)),
+            Value::Boolean(true),
+            Value::Boolean(true),
+        ])
+    );
+
+    assert_eval_fails(
+        "import std:time\ntime:every(0, || false)",
+        "interval must be at least 0.001 seconds",
+    );
+    assert_eval_fails(
+        "import std:time\ntime:every(\"1s\", || false)",
+        "interval in seconds as a number",
+    );
+}
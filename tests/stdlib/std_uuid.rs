@@ -6,7 +6,7 @@ fn test_uuid_v4_format() {
     let result = eval_program(
         r#"
         import std:uuid
-        id = uuid:v4()
+        let id = uuid:v4()
         uuid:is_valid(id)
     "#,
     );
@@ -19,7 +19,7 @@ fn test_uuid_v4_is_string() {
     let result = eval_program(
         r#"
         import std:uuid
-        id = uuid:v4()
+        let id = uuid:v4()
         id::length() == 36
     "#,
     );
@@ -32,7 +32,7 @@ fn test_uuid_v4_version_bit() {
     let result = eval_program(
         r#"
         import std:uuid
-        id = uuid:v4()
+        let id = uuid:v4()
         id[14:15] == "4"
     "#,
     );
@@ -45,9 +45,9 @@ fn test_uuid_v5_deterministic() {
     let result = eval_program(
         r#"
         import std:uuid
-        dns_namespace = "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
-        uuid1 = uuid:v5(dns_namespace, "example.com")
-        uuid2 = uuid:v5(dns_namespace, "example.com")
+        let dns_namespace = "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        let uuid1 = uuid:v5(dns_namespace, "example.com")
+        let uuid2 = uuid:v5(dns_namespace, "example.com")
         uuid1 == uuid2
     "#,
     );